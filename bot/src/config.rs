@@ -0,0 +1,28 @@
+use serde::Deserialize;
+
+pub const CONFIG_FILE: &str = "config.toml";
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct BotConfig {
+    pub gateway_uri: String,
+    #[serde(default)]
+    pub use_chain_simulator: bool,
+    pub wallet_pem: String,
+    pub contract_address: String,
+    pub poll_interval_seconds: u64,
+    pub retry_cooldown_seconds: u64,
+    pub max_attempts: u32,
+    pub execute_gas: u64,
+    pub gas_step: u64,
+    pub max_execute_gas: u64,
+}
+
+impl BotConfig {
+    /// Load the config file given as first argument, `config.toml` otherwise
+    pub fn load() -> Self {
+        let path = std::env::args().nth(1).unwrap_or_else(|| CONFIG_FILE.to_string());
+        let content =
+            std::fs::read_to_string(&path).unwrap_or_else(|_| panic!("cannot open {path}"));
+        toml::from_str(&content).unwrap_or_else(|err| panic!("invalid {path}: {err}"))
+    }
+}
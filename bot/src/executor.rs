@@ -0,0 +1,270 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use log::{info, warn};
+use multiversx_sc_snippets::imports::*;
+use stellarnova_sc::limit_orders::LimitOrder;
+use stellarnova_sc::reasons::ReasonCode;
+
+use crate::config::BotConfig;
+use crate::pricing::{decide, Decision};
+
+/// Per-order retry state
+#[derive(Default)]
+struct OrderAttempts {
+    attempts: u32,
+    last_attempt: u64,
+    gas: u64,
+}
+
+pub struct ExecutorBot {
+    interactor: Interactor,
+    executor: Address,
+    contract: Bech32Address,
+    config: BotConfig,
+    attempts: HashMap<u64, OrderAttempts>,
+}
+
+impl ExecutorBot {
+    pub async fn new(config: BotConfig) -> Self {
+        let mut interactor = Interactor::new(&config.gateway_uri)
+            .await
+            .use_chain_simulator(config.use_chain_simulator);
+
+        let wallet = Wallet::from_pem_file(&config.wallet_pem)
+            .unwrap_or_else(|err| panic!("cannot load {}: {err}", config.wallet_pem));
+        // The interactor tracks the wallet nonce from here on
+        let executor = interactor.register_wallet(wallet).await;
+        let contract = Bech32Address::from_bech32_string(config.contract_address.clone());
+
+        ExecutorBot {
+            interactor,
+            executor,
+            contract,
+            config,
+            attempts: HashMap::new(),
+        }
+    }
+
+    /// Poll forever; errors of one round never stop the bot
+    pub async fn run(&mut self) {
+        info!(
+            "executor {} watching {}",
+            Bech32Address::from(&self.executor),
+            self.contract
+        );
+        loop {
+            self.poll_once().await;
+            tokio::time::sleep(Duration::from_secs(self.config.poll_interval_seconds)).await;
+        }
+    }
+
+    pub async fn poll_once(&mut self) {
+        let orders = self.pending_orders().await;
+        let now = unix_now();
+
+        // Orders that left the pending set need no retry state anymore
+        self.attempts
+            .retain(|order_id, _| orders.iter().any(|order| order.order_id == *order_id));
+
+        // Tokens of each pair, queried once per poll
+        let mut pair_tokens = HashMap::new();
+        for order in &orders {
+            if !self.is_due(order.order_id, now) {
+                continue;
+            }
+            // Orders on listed tokens swap on their token's pair
+            let pair = self.swap_pair(order).await;
+            let key = pair.to_bech32_string();
+            if !pair_tokens.contains_key(&key) {
+                let tokens = self.pair_tokens(&pair).await;
+                pair_tokens.insert(key.clone(), tokens);
+            }
+            let tokens = &pair_tokens[&key];
+            if !tokens.contains(&order.from_token) || !tokens.contains(&order.to_token) {
+                continue;
+            }
+
+            let quote = self.quote(&pair, order).await;
+            if let Decision::Wait = decide(order, &quote, now) {
+                continue;
+            }
+
+            // Dry run first: a transaction that would fail only burns gas
+            // and an attempt
+            match self.can_execute(order, &quote).await {
+                ReasonCode::Ok | ReasonCode::OrderExpired => {
+                    self.execute(order, &quote, now).await;
+                }
+                // Expected to clear up on a later poll
+                ReasonCode::PriceNotMet
+                | ReasonCode::SignalNotFired
+                | ReasonCode::ConditionPriceStale
+                | ReasonCode::ConditionNotMet
+                | ReasonCode::StrategyPaused
+                | ReasonCode::ExecutionInProgress
+                | ReasonCode::OrderNotPending
+                | ReasonCode::OrderDormant
+                | ReasonCode::PairTripped
+                | ReasonCode::PairPaused
+                | ReasonCode::PriceNotRevealed
+                | ReasonCode::ExecutionAuctionOpen
+                | ReasonCode::RetryBackoff
+                | ReasonCode::ExecutorVolumeExceeded => {}
+                reason => {
+                    warn!("order {}: not executable: {reason:?}", order.order_id);
+                }
+            }
+        }
+    }
+
+    /// Orders are retried after a cooldown, up to `max_attempts`
+    fn is_due(&self, order_id: u64, now: u64) -> bool {
+        match self.attempts.get(&order_id) {
+            None => true,
+            Some(state) => {
+                state.attempts < self.config.max_attempts
+                    && now >= state.last_attempt + self.config.retry_cooldown_seconds
+            }
+        }
+    }
+
+    /// Send `executeLimitOrder` with the quote as current price; out-of-gas
+    /// failures raise the gas of the next attempt
+    async fn execute(&mut self, order: &LimitOrder<StaticApi>, quote: &BigUint<StaticApi>, now: u64) {
+        let default_gas = self.config.execute_gas;
+        let state = self.attempts.entry(order.order_id).or_default();
+        if state.gas == 0 {
+            state.gas = default_gas;
+        }
+        state.attempts += 1;
+        state.last_attempt = now;
+        let gas = state.gas;
+
+        let result = self
+            .interactor
+            .tx()
+            .from(&self.executor)
+            .to(&self.contract)
+            .gas(gas)
+            .raw_call("executeLimitOrder")
+            .argument(&order.order_id)
+            .argument(quote)
+            .argument(&order.from_amount)
+            .returns(ReturnsHandledOrError::new())
+            .run()
+            .await;
+
+        match result {
+            Ok(()) => {
+                info!("order {}: executed (quote {})", order.order_id, quote.to_display());
+                self.attempts.remove(&order.order_id);
+            }
+            Err(err) => {
+                warn!("order {}: execution failed: {}", order.order_id, err.message);
+                if is_out_of_gas(&err.message) {
+                    let state = self.attempts.entry(order.order_id).or_default();
+                    state.gas = (state.gas + self.config.gas_step).min(self.config.max_execute_gas);
+                }
+            }
+        }
+    }
+
+    // ========== QUERIES ==========
+
+    async fn pending_orders(&mut self) -> Vec<LimitOrder<StaticApi>> {
+        self.interactor
+            .query()
+            .to(&self.contract)
+            .raw_call("getPendingOrders")
+            .original_result::<MultiValueEncoded<StaticApi, LimitOrder<StaticApi>>>()
+            .returns(ReturnsResult)
+            .run()
+            .await
+            .into_iter()
+            .collect()
+    }
+
+    /// Why `executeLimitOrder` at the quote would not swap (`Ok` if it would)
+    async fn can_execute(
+        &mut self,
+        order: &LimitOrder<StaticApi>,
+        quote: &BigUint<StaticApi>,
+    ) -> ReasonCode {
+        self.interactor
+            .query()
+            .to(&self.contract)
+            .raw_call("canExecuteOrder")
+            .argument(&order.order_id)
+            .argument(quote)
+            .argument(&order.from_amount)
+            .original_result::<ReasonCode>()
+            .returns(ReturnsResult)
+            .run()
+            .await
+    }
+
+    async fn swap_pair(&mut self, order: &LimitOrder<StaticApi>) -> Bech32Address {
+        let pair = self
+            .interactor
+            .query()
+            .to(&self.contract)
+            .raw_call("getSwapPair")
+            .argument(&order.from_token)
+            .argument(&order.to_token)
+            .original_result::<ManagedAddress<StaticApi>>()
+            .returns(ReturnsResult)
+            .run()
+            .await;
+        Bech32Address::from(pair.to_address())
+    }
+
+    async fn pair_tokens(&mut self, pair: &Bech32Address) -> Vec<TokenIdentifier<StaticApi>> {
+        let mut tokens = Vec::new();
+        for view in ["getFirstTokenId", "getSecondTokenId"] {
+            let token = self
+                .interactor
+                .query()
+                .to(pair)
+                .raw_call(view)
+                .original_result::<TokenIdentifier<StaticApi>>()
+                .returns(ReturnsResult)
+                .run()
+                .await;
+            tokens.push(token);
+        }
+        tokens
+    }
+
+    /// Output of swapping the whole remaining escrow on the pair
+    async fn quote(
+        &mut self,
+        pair: &Bech32Address,
+        order: &LimitOrder<StaticApi>,
+    ) -> BigUint<StaticApi> {
+        self.interactor
+            .query()
+            .to(pair)
+            .raw_call("getAmountOut")
+            .argument(&order.from_token)
+            .argument(&order.from_amount)
+            .original_result::<BigUint<StaticApi>>()
+            .returns(ReturnsResult)
+            .run()
+            .await
+    }
+}
+
+fn is_out_of_gas(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("out of gas") || message.contains("not enough gas")
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("clock before unix epoch")
+        .as_secs()
+}
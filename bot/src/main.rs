@@ -0,0 +1,21 @@
+//! Reference executor bot for StellarNova limit orders
+//!
+//! Polls `getPendingOrders`, quotes each order on the configured xExchange
+//! pair and sends `executeLimitOrder` for orders whose limit price is reached
+//! or that have expired. Failed executions are retried after a cooldown, with
+//! more gas when the failure was out of gas.
+
+mod config;
+mod executor;
+mod pricing;
+
+use config::BotConfig;
+use executor::ExecutorBot;
+
+#[tokio::main]
+async fn main() {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+    let config = BotConfig::load();
+    let mut bot = ExecutorBot::new(config).await;
+    bot.run().await;
+}
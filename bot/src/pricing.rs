@@ -0,0 +1,40 @@
+use multiversx_sc_snippets::imports::*;
+use stellarnova_sc::limit_orders::{LimitOrder, MAX_BASIS_POINTS};
+
+/// What the bot does with a pending order after quoting the pair
+#[derive(Debug, PartialEq, Eq)]
+pub enum Decision {
+    /// Send `executeLimitOrder` with the quote as current price
+    Execute,
+    /// Expired: executing refunds the escrow on the spot
+    Expire,
+    /// The pair quote does not satisfy the order yet
+    Wait,
+}
+
+/// Mirror of the contract checks, so transactions that would fail are not sent:
+/// - price check: `quote / amount_in <= target` (current price at or below the limit)
+/// - swap check: the quote must reach the slippage-adjusted minimum output
+pub fn decide(order: &LimitOrder<StaticApi>, quote: &BigUint<StaticApi>, now: u64) -> Decision {
+    if now > order.expires_at {
+        return Decision::Expire;
+    }
+
+    let current = quote * &order.target_price_denominator;
+    let target = &order.target_price_numerator * &order.from_amount;
+    if current > target {
+        return Decision::Wait;
+    }
+
+    if *quote < min_output(order) {
+        return Decision::Wait;
+    }
+    Decision::Execute
+}
+
+/// Same rounding as the contract's `calculate_min_output`
+pub fn min_output(order: &LimitOrder<StaticApi>) -> BigUint<StaticApi> {
+    let expected =
+        &order.from_amount * &order.target_price_numerator / &order.target_price_denominator;
+    expected * (MAX_BASIS_POINTS - order.slippage_bp) / MAX_BASIS_POINTS
+}
@@ -0,0 +1,107 @@
+//! Mock money market for StellarNova tests
+//!
+//! Implements the subset of the money market interface StellarNova calls:
+//! `mint` (underlying in, shares out) and `redeem` (shares in, underlying
+//! out). Tests control it with:
+//! - `setExchangeRate(num, denom)`: underlying paid per share, starts at 1
+//! - `setFailRedeem(fail)`: make redeems revert
+//!
+//! The market pays shares and interest from what `addReserves` funded it
+//! with, so tests fund it with share tokens and some underlying up front.
+
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub const ERROR_BAD_TOKEN: &str = "Bad token";
+pub const ERROR_ZERO_AMOUNT: &str = "Zero amount";
+pub const ERROR_REDEEM_FAILED: &str = "Redeem failed";
+
+#[multiversx_sc::contract]
+pub trait MockLending {
+    #[init]
+    fn init(&self, underlying_token: TokenIdentifier, share_token: TokenIdentifier) {
+        require!(underlying_token != share_token, ERROR_BAD_TOKEN);
+        self.underlying_token().set(&underlying_token);
+        self.share_token().set(&share_token);
+        self.exchange_rate().set((BigUint::from(1u64), BigUint::from(1u64)));
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    // ========== TEST CONTROLS ==========
+
+    /// Fund the market with share tokens or underlying
+    #[payable]
+    #[endpoint(addReserves)]
+    fn add_reserves(&self) {}
+
+    /// `num / denom` underlying per share
+    #[endpoint(setExchangeRate)]
+    fn set_exchange_rate(&self, rate_num: BigUint, rate_denom: BigUint) {
+        require!(rate_num > 0u64 && rate_denom > 0u64, ERROR_ZERO_AMOUNT);
+        self.exchange_rate().set((rate_num, rate_denom));
+    }
+
+    #[endpoint(setFailRedeem)]
+    fn set_fail_redeem(&self, fail: bool) {
+        self.fail_redeem().set(fail);
+    }
+
+    // ========== MARKET INTERFACE ==========
+
+    /// Supply underlying for shares at the current exchange rate
+    #[payable]
+    #[endpoint(mint)]
+    fn mint(&self) -> EsdtTokenPayment {
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == self.underlying_token().get(),
+            ERROR_BAD_TOKEN
+        );
+        let (rate_num, rate_denom) = self.exchange_rate().get();
+        let shares = &payment.amount * &rate_denom / &rate_num;
+        require!(shares > 0u64, ERROR_ZERO_AMOUNT);
+
+        let output = EsdtTokenPayment::new(self.share_token().get(), 0, shares);
+        self.tx().to(ToCaller).payment(&output).transfer();
+        output
+    }
+
+    /// Return shares for underlying at the current exchange rate
+    #[payable]
+    #[endpoint(redeem)]
+    fn redeem(&self) -> EsdtTokenPayment {
+        require!(!self.fail_redeem().get(), ERROR_REDEEM_FAILED);
+        let payment = self.call_value().single_esdt();
+        require!(
+            payment.token_identifier == self.share_token().get(),
+            ERROR_BAD_TOKEN
+        );
+        let (rate_num, rate_denom) = self.exchange_rate().get();
+        let amount = &payment.amount * &rate_num / &rate_denom;
+
+        let output = EsdtTokenPayment::new(self.underlying_token().get(), 0, amount);
+        self.tx().to(ToCaller).payment(&output).transfer();
+        output
+    }
+
+    // ========== STORAGE ==========
+
+    #[view(getUnderlyingToken)]
+    #[storage_mapper("underlying_token")]
+    fn underlying_token(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getShareToken)]
+    #[storage_mapper("share_token")]
+    fn share_token(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getExchangeRate)]
+    #[storage_mapper("exchangeRate")]
+    fn exchange_rate(&self) -> SingleValueMapper<(BigUint, BigUint)>;
+
+    #[view(getFailRedeem)]
+    #[storage_mapper("failRedeem")]
+    fn fail_redeem(&self) -> SingleValueMapper<bool>;
+}
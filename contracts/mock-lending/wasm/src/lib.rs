@@ -0,0 +1,35 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                            9
+// Async Callback (empty):               1
+// Total number of exported functions:  12
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    mock_lending
+    (
+        init => init
+        upgrade => upgrade
+        addReserves => add_reserves
+        setExchangeRate => set_exchange_rate
+        setFailRedeem => set_fail_redeem
+        mint => mint
+        redeem => redeem
+        getUnderlyingToken => underlying_token
+        getShareToken => share_token
+        getExchangeRate => exchange_rate
+        getFailRedeem => fail_redeem
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
@@ -0,0 +1,417 @@
+//! Mock xExchange Pair for StellarNova tests
+//!
+//! Implements the subset of the xExchange pair interface StellarNova calls:
+//! `swapTokensFixedInput`, `swapTokensFixedOutput`, `addLiquidity`,
+//! `getAmountOut`, `getState`, `getReserve` and `getReservesAndTotalSupply`.
+//! Tests control it with:
+//! - `setPrice(num, denom)`: fixed rate of second token per first token
+//!   (0 = constant product with the xExchange 0.3% fee)
+//! - `setState(state)`: pair state returned by `getState`, swaps need `Active`
+//! - `setFailureMode(mode)`: make swaps revert, pay short or use half the input
+//! - `setBonusPayment()`: send the paid tokens along with the next swap output
+//! - `setLpToken(token)`: LP token minted by `addLiquidity` (the pair needs
+//!   its mint role)
+//!
+//! Reserves are funded with `addReserves` and move with every swap.
+//!
+//! `swapExactIn(amountOutMin, tokenOut)` is the same swap with another
+//! argument layout, standing in for venues with a different swap endpoint.
+
+#![no_std]
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// Total swap fee of an xExchange pair, out of `FEE_DENOMINATOR`
+pub const TOTAL_FEE: u64 = 300;
+pub const FEE_DENOMINATOR: u64 = 100_000;
+
+pub const ERROR_NOT_ACTIVE: &str = "Not active";
+pub const ERROR_BAD_TOKEN: &str = "Bad token";
+pub const ERROR_ZERO_AMOUNT: &str = "Zero amount";
+pub const ERROR_SLIPPAGE: &str = "Slippage exceeded";
+pub const ERROR_NOT_ENOUGH_RESERVE: &str = "Not enough reserve";
+pub const ERROR_SWAP_FAILED: &str = "Swap failed";
+pub const ERROR_ADD_LIQUIDITY_FAILED: &str = "Add liquidity failed";
+pub const ERROR_NO_LP_TOKEN: &str = "LP token not set";
+
+/// Same layout as the xExchange pair state
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum State {
+    Inactive,
+    Active,
+    PartialActive,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FailureMode {
+    /// Swaps behave like a real pair
+    None,
+    /// Every swap reverts
+    Revert,
+    /// Swaps pay half of the quoted output, ignoring `amount_out_min`
+    ShortOutput,
+    /// Swaps use half of the input and return the rest with the output,
+    /// ignoring `amount_out_min`
+    PartialInput,
+}
+
+#[multiversx_sc::contract]
+pub trait MockPair {
+    #[init]
+    fn init(&self, first_token_id: TokenIdentifier, second_token_id: TokenIdentifier) {
+        require!(first_token_id != second_token_id, ERROR_BAD_TOKEN);
+        self.first_token_id().set(&first_token_id);
+        self.second_token_id().set(&second_token_id);
+        self.state().set(State::Active);
+        self.failure_mode().set(FailureMode::None);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    // ========== TEST CONTROLS ==========
+
+    /// Fund the pool with either pair token
+    #[payable]
+    #[endpoint(addReserves)]
+    fn add_reserves(&self) {
+        for payment in self.call_value().all_esdt_transfers().iter() {
+            self.require_pair_token(&payment.token_identifier);
+            self.pair_reserve(&payment.token_identifier)
+                .update(|reserve| *reserve += &payment.amount);
+        }
+    }
+
+    /// Fixed rate: `num / denom` second tokens per first token (0 clears it)
+    #[endpoint(setPrice)]
+    fn set_price(&self, price_num: BigUint, price_denom: BigUint) {
+        if price_num == 0u64 || price_denom == 0u64 {
+            self.fixed_price().clear();
+            return;
+        }
+        self.fixed_price().set((price_num, price_denom));
+    }
+
+    #[endpoint(setState)]
+    fn set_state(&self, state: State) {
+        self.state().set(state);
+    }
+
+    #[endpoint(setFailureMode)]
+    fn set_failure_mode(&self, mode: FailureMode) {
+        self.failure_mode().set(mode);
+    }
+
+    /// Stray payment sent to the caller along with the next swap output
+    #[payable]
+    #[endpoint(setBonusPayment)]
+    fn set_bonus_payment(&self) {
+        let payment = self.call_value().single_esdt();
+        self.bonus_payment().set(payment.clone());
+    }
+
+    #[endpoint(setLpToken)]
+    fn set_lp_token(&self, lp_token: TokenIdentifier) {
+        self.lp_token().set_token_id(lp_token);
+    }
+
+    // ========== PAIR INTERFACE ==========
+
+    /// Swap the whole payment for at least `amount_out_min` of `token_out`
+    #[payable]
+    #[endpoint(swapTokensFixedInput)]
+    fn swap_tokens_fixed_input(
+        &self,
+        token_out: TokenIdentifier,
+        amount_out_min: BigUint,
+    ) -> EsdtTokenPayment {
+        require!(self.state().get() == State::Active, ERROR_NOT_ACTIVE);
+
+        let payment = self.call_value().single_esdt();
+        let token_in = payment.token_identifier.clone();
+        self.require_pair_token(&token_in);
+        self.require_pair_token(&token_out);
+        require!(token_in != token_out, ERROR_BAD_TOKEN);
+        require!(payment.amount > 0u64, ERROR_ZERO_AMOUNT);
+
+        let mut amount_in = payment.amount.clone();
+        if self.failure_mode().get() == FailureMode::PartialInput {
+            amount_in /= 2u64;
+        }
+        let mut amount_out = self.get_amount_out(token_in.clone(), amount_in.clone());
+        match self.failure_mode().get() {
+            FailureMode::None => require!(amount_out >= amount_out_min, ERROR_SLIPPAGE),
+            FailureMode::Revert => sc_panic!(ERROR_SWAP_FAILED),
+            FailureMode::ShortOutput => amount_out /= 2u64,
+            FailureMode::PartialInput => {},
+        }
+        require!(amount_out > 0u64, ERROR_ZERO_AMOUNT);
+        require!(
+            self.pair_reserve(&token_out).get() > amount_out,
+            ERROR_NOT_ENOUGH_RESERVE
+        );
+
+        self.pair_reserve(&token_in)
+            .update(|reserve| *reserve += &amount_in);
+        self.pair_reserve(&token_out)
+            .update(|reserve| *reserve -= &amount_out);
+
+        let output = EsdtTokenPayment::new(token_out, 0, amount_out);
+        let mut payments = ManagedVec::from_single_item(output.clone());
+        if amount_in < payment.amount {
+            payments.push(EsdtTokenPayment::new(token_in, 0, &payment.amount - &amount_in));
+        }
+        if !self.bonus_payment().is_empty() {
+            payments.push(self.bonus_payment().take());
+        }
+        self.tx().to(ToCaller).payment(&payments).transfer();
+        output
+    }
+
+    /// Swap as little of the payment as needed for exactly `amount_out` of
+    /// `token_out`; the rest of the payment is returned with the output
+    #[payable]
+    #[endpoint(swapTokensFixedOutput)]
+    fn swap_tokens_fixed_output(
+        &self,
+        token_out: TokenIdentifier,
+        amount_out: BigUint,
+    ) -> EsdtTokenPayment {
+        require!(self.state().get() == State::Active, ERROR_NOT_ACTIVE);
+        require!(self.failure_mode().get() != FailureMode::Revert, ERROR_SWAP_FAILED);
+
+        let payment = self.call_value().single_esdt();
+        let token_in = payment.token_identifier.clone();
+        self.require_pair_token(&token_in);
+        self.require_pair_token(&token_out);
+        require!(token_in != token_out, ERROR_BAD_TOKEN);
+        require!(amount_out > 0u64, ERROR_ZERO_AMOUNT);
+        require!(
+            self.pair_reserve(&token_out).get() > amount_out,
+            ERROR_NOT_ENOUGH_RESERVE
+        );
+
+        let amount_in = self.get_amount_in(token_out.clone(), amount_out.clone());
+        require!(amount_in <= payment.amount, ERROR_SLIPPAGE);
+
+        self.pair_reserve(&token_in)
+            .update(|reserve| *reserve += &amount_in);
+        self.pair_reserve(&token_out)
+            .update(|reserve| *reserve -= &amount_out);
+
+        let output = EsdtTokenPayment::new(token_out, 0, amount_out);
+        let mut payments = ManagedVec::from_single_item(output.clone());
+        if amount_in < payment.amount {
+            payments.push(EsdtTokenPayment::new(token_in, 0, &payment.amount - &amount_in));
+        }
+        if !self.bonus_payment().is_empty() {
+            payments.push(self.bonus_payment().take());
+        }
+        self.tx().to(ToCaller).payment(&payments).transfer();
+        output
+    }
+
+    /// Add the first and second token payments as liquidity at the current
+    /// rate and mint as many LP tokens as first tokens were added; the
+    /// excess of either token is returned with them
+    #[payable]
+    #[endpoint(addLiquidity)]
+    fn add_liquidity(
+        &self,
+        first_token_amount_min: BigUint,
+        second_token_amount_min: BigUint,
+    ) -> MultiValue3<EsdtTokenPayment, EsdtTokenPayment, EsdtTokenPayment> {
+        require!(self.state().get() == State::Active, ERROR_NOT_ACTIVE);
+        require!(
+            self.failure_mode().get() != FailureMode::Revert,
+            ERROR_ADD_LIQUIDITY_FAILED
+        );
+        require!(!self.lp_token().is_empty(), ERROR_NO_LP_TOKEN);
+
+        let payments = self.call_value().all_esdt_transfers();
+        require!(payments.len() == 2, ERROR_BAD_TOKEN);
+        let first = payments.get(0);
+        let second = payments.get(1);
+        require!(
+            first.token_identifier == self.first_token_id().get()
+                && second.token_identifier == self.second_token_id().get(),
+            ERROR_BAD_TOKEN
+        );
+
+        let (first_added, second_added) = self.liquidity_amounts(&first.amount, &second.amount);
+        require!(first_added > 0u64, ERROR_ZERO_AMOUNT);
+        require!(
+            first_added >= first_token_amount_min && second_added >= second_token_amount_min,
+            ERROR_SLIPPAGE
+        );
+
+        self.pair_reserve(&first.token_identifier)
+            .update(|reserve| *reserve += &first_added);
+        self.pair_reserve(&second.token_identifier)
+            .update(|reserve| *reserve += &second_added);
+
+        let lp_payment = self.lp_token().mint(first_added.clone());
+        let first_left = EsdtTokenPayment::new(
+            first.token_identifier.clone(),
+            0,
+            &first.amount - &first_added,
+        );
+        let second_left = EsdtTokenPayment::new(
+            second.token_identifier.clone(),
+            0,
+            &second.amount - &second_added,
+        );
+        let mut output = ManagedVec::from_single_item(lp_payment.clone());
+        if first_left.amount > 0u64 {
+            output.push(first_left.clone());
+        }
+        if second_left.amount > 0u64 {
+            output.push(second_left.clone());
+        }
+        self.tx().to(ToCaller).payment(&output).transfer();
+        (lp_payment, first_left, second_left).into()
+    }
+
+    /// `swapTokensFixedInput` with the arguments swapped
+    #[payable]
+    #[endpoint(swapExactIn)]
+    fn swap_exact_in(&self, amount_out_min: BigUint, token_out: TokenIdentifier) -> EsdtTokenPayment {
+        self.swap_tokens_fixed_input(token_out, amount_out_min)
+    }
+
+    /// Output of swapping `amount_in` of `token_in`
+    #[view(getAmountOut)]
+    fn get_amount_out(&self, token_in: TokenIdentifier, amount_in: BigUint) -> BigUint {
+        self.require_pair_token(&token_in);
+        let is_first = token_in == self.first_token_id().get();
+
+        if !self.fixed_price().is_empty() {
+            let (price_num, price_denom) = self.fixed_price().get();
+            return if is_first {
+                amount_in * price_num / price_denom
+            } else {
+                amount_in * price_denom / price_num
+            };
+        }
+
+        let token_out = if is_first {
+            self.second_token_id().get()
+        } else {
+            self.first_token_id().get()
+        };
+        let reserve_in = self.pair_reserve(&token_in).get();
+        let reserve_out = self.pair_reserve(&token_out).get();
+
+        let amount_in_with_fee = amount_in * (FEE_DENOMINATOR - TOTAL_FEE);
+        let numerator = &amount_in_with_fee * &reserve_out;
+        let denominator = reserve_in * FEE_DENOMINATOR + amount_in_with_fee;
+        if denominator == 0u64 {
+            return BigUint::zero();
+        }
+        numerator / denominator
+    }
+
+    /// Input needed for `amount_out` of `token_out` (rounded up)
+    #[view(getAmountIn)]
+    fn get_amount_in(&self, token_out: TokenIdentifier, amount_out: BigUint) -> BigUint {
+        self.require_pair_token(&token_out);
+        let is_first_out = token_out == self.first_token_id().get();
+
+        if !self.fixed_price().is_empty() {
+            let (price_num, price_denom) = self.fixed_price().get();
+            let (num, denom) = if is_first_out {
+                (price_num, price_denom)
+            } else {
+                (price_denom, price_num)
+            };
+            return (amount_out * num + &denom - 1u64) / denom;
+        }
+
+        let token_in = if is_first_out {
+            self.second_token_id().get()
+        } else {
+            self.first_token_id().get()
+        };
+        let reserve_in = self.pair_reserve(&token_in).get();
+        let reserve_out = self.pair_reserve(&token_out).get();
+
+        let numerator = reserve_in * &amount_out * FEE_DENOMINATOR;
+        let denominator = (reserve_out - amount_out) * (FEE_DENOMINATOR - TOTAL_FEE);
+        numerator / denominator + 1u64
+    }
+
+    #[view(getReservesAndTotalSupply)]
+    fn get_reserves_and_total_supply(&self) -> MultiValue3<BigUint, BigUint, BigUint> {
+        let first_reserve = self.pair_reserve(&self.first_token_id().get()).get();
+        let second_reserve = self.pair_reserve(&self.second_token_id().get()).get();
+        (first_reserve, second_reserve, BigUint::zero()).into()
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    /// Parts of `first_amount` and `second_amount` that match the current
+    /// rate; an empty pool without a fixed price takes both in full
+    fn liquidity_amounts(&self, first_amount: &BigUint, second_amount: &BigUint) -> (BigUint, BigUint) {
+        let (rate_num, rate_denom) = if !self.fixed_price().is_empty() {
+            self.fixed_price().get()
+        } else {
+            (
+                self.pair_reserve(&self.second_token_id().get()).get(),
+                self.pair_reserve(&self.first_token_id().get()).get(),
+            )
+        };
+        if rate_num == 0u64 || rate_denom == 0u64 {
+            return (first_amount.clone(), second_amount.clone());
+        }
+
+        let second_for_first = first_amount * &rate_num / &rate_denom;
+        if second_for_first <= *second_amount {
+            (first_amount.clone(), second_for_first)
+        } else {
+            (second_amount * &rate_denom / &rate_num, second_amount.clone())
+        }
+    }
+
+    fn require_pair_token(&self, token: &TokenIdentifier) {
+        require!(
+            *token == self.first_token_id().get() || *token == self.second_token_id().get(),
+            ERROR_BAD_TOKEN
+        );
+    }
+
+    // ========== STORAGE ==========
+
+    #[view(getFirstTokenId)]
+    #[storage_mapper("first_token_id")]
+    fn first_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getSecondTokenId)]
+    #[storage_mapper("second_token_id")]
+    fn second_token_id(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getReserve)]
+    #[storage_mapper("reserve")]
+    fn pair_reserve(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getState)]
+    #[storage_mapper("state")]
+    fn state(&self) -> SingleValueMapper<State>;
+
+    #[view(getFixedPrice)]
+    #[storage_mapper("fixedPrice")]
+    fn fixed_price(&self) -> SingleValueMapper<(BigUint, BigUint)>;
+
+    #[view(getLpTokenIdentifier)]
+    #[storage_mapper("lpTokenIdentifier")]
+    fn lp_token(&self) -> FungibleTokenMapper;
+
+    #[storage_mapper("bonusPayment")]
+    fn bonus_payment(&self) -> SingleValueMapper<EsdtTokenPayment>;
+
+    #[view(getFailureMode)]
+    #[storage_mapper("failureMode")]
+    fn failure_mode(&self) -> SingleValueMapper<FailureMode>;
+}
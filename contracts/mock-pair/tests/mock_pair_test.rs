@@ -0,0 +1,219 @@
+use multiversx_sc_scenario::imports::*;
+
+use multiversx_sc_scenario::scenario_model::TxResponseStatus;
+
+use mock_pair::{FailureMode, MockPair, State};
+
+const OWNER: TestAddress = TestAddress::new("owner");
+const TRADER: TestAddress = TestAddress::new("trader");
+const PAIR: TestSCAddress = TestSCAddress::new("mock-pair");
+const CODE_PATH: MxscPath = MxscPath::new("output/mock-pair.mxsc.json");
+
+const WEGLD: TestTokenIdentifier = TestTokenIdentifier::new("WEGLD-123456");
+const USDC: TestTokenIdentifier = TestTokenIdentifier::new("USDC-123456");
+const LP: TestTokenIdentifier = TestTokenIdentifier::new("WEGLDUSDC-123456");
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/mock-pair");
+    blockchain.register_contract(CODE_PATH, mock_pair::ContractBuilder);
+    blockchain
+}
+
+/// Pair funded with 1000 WEGLD / 30000 USDC (price 30)
+fn deploy(world: &mut ScenarioWorld) {
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000u64)
+        .esdt_balance(USDC, 30_000u64);
+    world
+        .account(TRADER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 100u64)
+        .esdt_balance(USDC, 3_000u64);
+
+    world
+        .tx()
+        .from(OWNER)
+        .raw_deploy()
+        .code(CODE_PATH)
+        .argument(&WEGLD)
+        .argument(&USDC)
+        .new_address(PAIR)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(PAIR)
+        .raw_call("addReserves")
+        .esdt(TestEsdtTransfer(WEGLD, 0, 1_000u64))
+        .esdt(TestEsdtTransfer(USDC, 0, 30_000u64))
+        .run();
+}
+
+fn swap(
+    world: &mut ScenarioWorld,
+    amount_in: u64,
+    amount_out_min: u64,
+) -> Result<(), TxResponseStatus> {
+    world
+        .tx()
+        .from(TRADER)
+        .to(PAIR)
+        .raw_call("swapTokensFixedInput")
+        .single_esdt(&WEGLD.to_token_identifier(), 0, &BigUint::from(amount_in))
+        .argument(&USDC)
+        .argument(&amount_out_min)
+        .returns(ReturnsHandledOrError::new())
+        .run()
+}
+
+fn add_liquidity(world: &mut ScenarioWorld, second_min: u64) -> Result<(), TxResponseStatus> {
+    world
+        .tx()
+        .from(TRADER)
+        .to(PAIR)
+        .raw_call("addLiquidity")
+        .esdt(TestEsdtTransfer(WEGLD, 0, 10u64))
+        .esdt(TestEsdtTransfer(USDC, 0, 310u64))
+        .argument(&10u64)
+        .argument(&second_min)
+        .returns(ReturnsHandledOrError::new())
+        .run()
+}
+
+#[test]
+fn constant_product_swap_moves_reserves() {
+    let mut world = world();
+    deploy(&mut world);
+
+    // 10 WEGLD in: 10 * 0.997 * 30000 / (1000 + 10 * 0.997) = 296
+    world
+        .tx()
+        .from(TRADER)
+        .to(PAIR)
+        .raw_call("swapTokensFixedInput")
+        .single_esdt(&WEGLD.to_token_identifier(), 0, &BigUint::from(10u64))
+        .argument(&USDC)
+        .argument(&296u64)
+        .run();
+
+    world
+        .check_account(TRADER)
+        .esdt_balance(WEGLD, 90u64)
+        .esdt_balance(USDC, 3_296u64);
+    world.query().to(PAIR).whitebox(mock_pair::contract_obj, |sc| {
+        assert_eq!(sc.pair_reserve(&WEGLD.to_token_identifier()).get(), 1_010u64);
+        assert_eq!(sc.pair_reserve(&USDC.to_token_identifier()).get(), 29_704u64);
+    });
+}
+
+#[test]
+fn fixed_price_applies_both_ways() {
+    let mut world = world();
+    deploy(&mut world);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(PAIR)
+        .raw_call("setPrice")
+        .argument(&25u64)
+        .argument(&1u64)
+        .run();
+
+    world.query().to(PAIR).whitebox(mock_pair::contract_obj, |sc| {
+        assert_eq!(
+            sc.get_amount_out(WEGLD.to_token_identifier(), BigUint::from(4u64)),
+            100u64
+        );
+        assert_eq!(
+            sc.get_amount_out(USDC.to_token_identifier(), BigUint::from(100u64)),
+            4u64
+        );
+    });
+}
+
+#[test]
+fn swap_respects_amount_out_min() {
+    let mut world = world();
+    deploy(&mut world);
+
+    let err = swap(&mut world, 10, 297).unwrap_err();
+    assert_eq!(err.message, mock_pair::ERROR_SLIPPAGE);
+}
+
+#[test]
+fn failure_modes_and_state() {
+    let mut world = world();
+    deploy(&mut world);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(PAIR)
+        .raw_call("setState")
+        .argument(&State::Inactive)
+        .run();
+    let err = swap(&mut world, 10, 0).unwrap_err();
+    assert_eq!(err.message, mock_pair::ERROR_NOT_ACTIVE);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(PAIR)
+        .raw_call("setState")
+        .argument(&State::Active)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(PAIR)
+        .raw_call("setFailureMode")
+        .argument(&FailureMode::Revert)
+        .run();
+    let err = swap(&mut world, 10, 0).unwrap_err();
+    assert_eq!(err.message, mock_pair::ERROR_SWAP_FAILED);
+
+    // Short output ignores the minimum: half of the 296 quote
+    world
+        .tx()
+        .from(OWNER)
+        .to(PAIR)
+        .raw_call("setFailureMode")
+        .argument(&FailureMode::ShortOutput)
+        .run();
+    swap(&mut world, 10, 296).unwrap();
+    world.check_account(TRADER).esdt_balance(USDC, 3_148u64);
+}
+
+#[test]
+fn add_liquidity_mints_lp_and_returns_the_excess() {
+    let mut world = world();
+    deploy(&mut world);
+    world.set_esdt_local_roles(&PAIR.to_address(), LP.as_bytes(), &[EsdtLocalRole::Mint]);
+    world
+        .tx()
+        .from(OWNER)
+        .to(PAIR)
+        .raw_call("setLpToken")
+        .argument(&LP)
+        .run();
+
+    // 10 WEGLD match 300 of the 310 USDC at the reserve rate of 30
+    let err = add_liquidity(&mut world, 301).unwrap_err();
+    assert_eq!(err.message, mock_pair::ERROR_SLIPPAGE);
+    add_liquidity(&mut world, 300).unwrap();
+
+    world
+        .check_account(TRADER)
+        .esdt_balance(WEGLD, 90u64)
+        .esdt_balance(USDC, 2_700u64)
+        .esdt_balance(LP, 10u64);
+    world.query().to(PAIR).whitebox(mock_pair::contract_obj, |sc| {
+        assert_eq!(sc.pair_reserve(&WEGLD.to_token_identifier()).get(), 1_010u64);
+        assert_eq!(sc.pair_reserve(&USDC.to_token_identifier()).get(), 30_300u64);
+    });
+}
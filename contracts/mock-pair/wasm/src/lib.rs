@@ -0,0 +1,46 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           20
+// Async Callback (empty):               1
+// Total number of exported functions:  23
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    mock_pair
+    (
+        init => init
+        upgrade => upgrade
+        addReserves => add_reserves
+        setPrice => set_price
+        setState => set_state
+        setFailureMode => set_failure_mode
+        setBonusPayment => set_bonus_payment
+        setLpToken => set_lp_token
+        swapTokensFixedInput => swap_tokens_fixed_input
+        swapTokensFixedOutput => swap_tokens_fixed_output
+        addLiquidity => add_liquidity
+        swapExactIn => swap_exact_in
+        getAmountOut => get_amount_out
+        getAmountIn => get_amount_in
+        getReservesAndTotalSupply => get_reserves_and_total_supply
+        getFirstTokenId => first_token_id
+        getSecondTokenId => second_token_id
+        getReserve => pair_reserve
+        getState => state
+        getFixedPrice => fixed_price
+        getLpTokenIdentifier => lp_token
+        getFailureMode => failure_mode
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}
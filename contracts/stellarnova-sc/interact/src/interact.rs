@@ -0,0 +1,460 @@
+mod interact_cli;
+pub mod interact_config;
+mod interact_smoke;
+
+use clap::Parser;
+use multiversx_sc_snippets::imports::*;
+use stellarnova_sc::limit_orders::LimitOrder;
+
+use interact_cli::{InteractCli, InteractCliCommand};
+use interact_config::{load_network_config, NetworkConfig};
+pub use interact_smoke::stellarnova_smoke_cli;
+
+const CODE_PATH: MxscPath = MxscPath::new("../output/stellarnova-sc.mxsc.json");
+
+const DEPLOY_GAS: u64 = 100_000_000;
+const ADMIN_GAS: u64 = 10_000_000;
+const ORDER_GAS: u64 = 30_000_000;
+/// Swap (30M) and callback (10M) gas plus the execution itself
+const EXECUTE_GAS: u64 = 100_000_000;
+
+pub async fn stellarnova_cli() {
+    let cli = InteractCli::parse();
+    let config = load_network_config(&cli.config, &cli.network);
+    let mut interact = StellarNovaInteract::new(config).await;
+
+    match &cli.command {
+        Some(InteractCliCommand::Deploy) => {
+            interact.deploy().await;
+        }
+        Some(InteractCliCommand::Upgrade) => interact.upgrade().await,
+        Some(InteractCliCommand::WhitelistToken(args)) => {
+            interact.whitelist_token(&args.token).await
+        }
+        Some(InteractCliCommand::RemoveToken(args)) => interact.remove_token(&args.token).await,
+        Some(InteractCliCommand::SetPair(args)) => {
+            let pair = args
+                .pair
+                .clone()
+                .unwrap_or_else(|| interact.config.pair_address().to_string());
+            interact.set_pair(&pair).await
+        }
+        Some(InteractCliCommand::SetExecutor(args)) => interact.set_executor(&args.address).await,
+        Some(InteractCliCommand::ExecuteQueued(args)) => {
+            interact.execute_queued_action(args.action_id).await
+        }
+        Some(InteractCliCommand::CreateOrder(args)) => {
+            interact
+                .create_order(
+                    &args.from_token,
+                    args.amount,
+                    &args.to_token,
+                    (args.price_num, args.price_denom),
+                    args.slippage_bp,
+                    args.expires_in,
+                )
+                .await
+        }
+        Some(InteractCliCommand::CancelOrder(args)) => interact.cancel_order(args.order_id).await,
+        Some(InteractCliCommand::ExecuteOrder(args)) => {
+            interact
+                .execute_order(
+                    args.order_id,
+                    (args.price_num, args.price_denom),
+                    args.fill_amount,
+                )
+                .await
+        }
+        Some(InteractCliCommand::Order(args)) => interact.print_order(args.order_id).await,
+        Some(InteractCliCommand::PendingOrders) => interact.print_pending_orders().await,
+        Some(InteractCliCommand::UserOrders(args)) => {
+            interact.print_user_orders(&args.address).await
+        }
+        Some(InteractCliCommand::Config) => interact.print_config().await,
+        None => {}
+    }
+}
+
+pub struct StellarNovaInteract {
+    pub interactor: Interactor,
+    pub wallet_address: Address,
+    pub config: NetworkConfig,
+}
+
+impl StellarNovaInteract {
+    pub async fn new(config: NetworkConfig) -> Self {
+        let mut interactor = Interactor::new(&config.gateway_uri)
+            .await
+            .use_chain_simulator(config.use_chain_simulator());
+
+        let wallet = Wallet::from_pem_file(&config.wallet_pem)
+            .unwrap_or_else(|err| panic!("cannot load {}: {err}", config.wallet_pem));
+        let wallet_address = interactor.register_wallet(wallet).await;
+
+        StellarNovaInteract {
+            interactor,
+            wallet_address,
+            config,
+        }
+    }
+
+    pub fn contract(&self) -> Bech32Address {
+        Bech32Address::from_bech32_string(self.config.contract_address().to_string())
+    }
+
+    // ========== ADMIN ==========
+
+    /// Deploy with the configured max slippage and initial tokens
+    /// Returns the new contract address, to be copied into the config
+    pub async fn deploy(&mut self) -> Bech32Address {
+        let tokens = self.config.tokens.clone();
+        self.deploy_with_tokens(&tokens).await
+    }
+
+    /// Deploy with the configured max slippage and the given initial tokens
+    pub async fn deploy_with_tokens(&mut self, tokens: &[String]) -> Bech32Address {
+        let tokens: MultiValueVec<TokenIdentifier<StaticApi>> = tokens
+            .iter()
+            .map(|token| TokenIdentifier::from(token.as_str()))
+            .collect::<Vec<_>>()
+            .into();
+
+        let new_address = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .gas(DEPLOY_GAS)
+            .raw_deploy()
+            .code(CODE_PATH)
+            .code_metadata(
+                CodeMetadata::UPGRADEABLE
+                    | CodeMetadata::READABLE
+                    | CodeMetadata::PAYABLE
+                    | CodeMetadata::PAYABLE_BY_SC,
+            )
+            .argument(&self.config.max_slippage_bp)
+            .argument(&tokens)
+            .returns(ReturnsNewBech32Address)
+            .run()
+            .await;
+
+        println!("new contract address: {new_address}");
+        new_address
+    }
+
+    pub async fn upgrade(&mut self) {
+        let contract = self.contract();
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(&contract)
+            .gas(DEPLOY_GAS)
+            .raw_upgrade()
+            .code(CODE_PATH)
+            .code_metadata(
+                CodeMetadata::UPGRADEABLE
+                    | CodeMetadata::READABLE
+                    | CodeMetadata::PAYABLE
+                    | CodeMetadata::PAYABLE_BY_SC,
+            )
+            .run()
+            .await;
+
+        println!("upgraded {contract}");
+    }
+
+    pub async fn whitelist_token(&mut self, token: &str) {
+        let contract = self.contract();
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(&contract)
+            .gas(ADMIN_GAS)
+            .raw_call("whitelistToken")
+            .argument(&TokenIdentifier::<StaticApi>::from(token))
+            .run()
+            .await;
+
+        println!("whitelisted {token}");
+    }
+
+    pub async fn remove_token(&mut self, token: &str) {
+        let contract = self.contract();
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(&contract)
+            .gas(ADMIN_GAS)
+            .raw_call("removeToken")
+            .argument(&TokenIdentifier::<StaticApi>::from(token))
+            .run()
+            .await;
+
+        println!("removed {token}");
+    }
+
+    /// Timelocked: applied at once without a delay, queued otherwise
+    pub async fn set_pair(&mut self, pair: &str) {
+        let pair = Bech32Address::from_bech32_string(pair.to_string());
+        let action_id = self.queue_address_action("setXExchangePair", &pair).await;
+        println!("setXExchangePair({pair}): timelock action {action_id}");
+    }
+
+    /// Timelocked: applied at once without a delay, queued otherwise
+    pub async fn set_executor(&mut self, executor: &str) {
+        let executor = Bech32Address::from_bech32_string(executor.to_string());
+        let action_id = self
+            .queue_address_action("setLimitOrderExecutor", &executor)
+            .await;
+        println!("setLimitOrderExecutor({executor}): timelock action {action_id}");
+    }
+
+    pub async fn execute_queued_action(&mut self, action_id: u64) {
+        let contract = self.contract();
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(&contract)
+            .gas(ADMIN_GAS)
+            .raw_call("executeQueuedAction")
+            .argument(&action_id)
+            .run()
+            .await;
+
+        println!("executed timelock action {action_id}");
+    }
+
+    async fn queue_address_action(&mut self, function: &str, address: &Bech32Address) -> u64 {
+        let contract = self.contract();
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(&contract)
+            .gas(ADMIN_GAS)
+            .raw_call(function)
+            .argument(&address.to_address())
+            .original_result::<u64>()
+            .returns(ReturnsResult)
+            .run()
+            .await
+    }
+
+    // ========== ORDERS ==========
+
+    /// Returns the new order id
+    pub async fn create_order(
+        &mut self,
+        from_token: &str,
+        amount: u128,
+        to_token: &str,
+        price: (u128, u128),
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+    ) -> u64 {
+        let contract = self.contract();
+        let order_id = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(&contract)
+            .gas(ORDER_GAS)
+            .raw_call("createLimitOrder")
+            .single_esdt(
+                &TokenIdentifier::from(from_token),
+                0,
+                &BigUint::from(amount),
+            )
+            .argument(&TokenIdentifier::<StaticApi>::from(to_token))
+            .argument(&BigUint::<StaticApi>::from(price.0))
+            .argument(&BigUint::<StaticApi>::from(price.1))
+            .argument(&slippage_bp)
+            .argument(&expires_in_seconds)
+            .original_result::<u64>()
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        println!("created order {order_id}");
+        order_id
+    }
+
+    pub async fn cancel_order(&mut self, order_id: u64) {
+        let contract = self.contract();
+        self.interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(&contract)
+            .gas(ORDER_GAS)
+            .raw_call("cancelLimitOrder")
+            .argument(&order_id)
+            .run()
+            .await;
+
+        println!("cancelled order {order_id}");
+    }
+
+    /// Execute as the configured wallet, which must be the executor
+    pub async fn execute_order(
+        &mut self,
+        order_id: u64,
+        price: (u128, u128),
+        fill_amount: Option<u128>,
+    ) {
+        let contract = self.contract();
+        let tx = self
+            .interactor
+            .tx()
+            .from(&self.wallet_address)
+            .to(&contract)
+            .gas(EXECUTE_GAS);
+
+        match fill_amount {
+            None => {
+                tx.raw_call("executeLimitOrder")
+                    .argument(&order_id)
+                    .argument(&BigUint::<StaticApi>::from(price.0))
+                    .argument(&BigUint::<StaticApi>::from(price.1))
+                    .run()
+                    .await
+            }
+            Some(fill_amount) => {
+                tx.raw_call("executeLimitOrderPartial")
+                    .argument(&order_id)
+                    .argument(&BigUint::<StaticApi>::from(fill_amount))
+                    .argument(&BigUint::<StaticApi>::from(price.0))
+                    .argument(&BigUint::<StaticApi>::from(price.1))
+                    .run()
+                    .await
+            }
+        }
+
+        println!("executed order {order_id}");
+    }
+
+    // ========== VIEWS ==========
+
+    pub async fn order(&mut self, order_id: u64) -> LimitOrder<StaticApi> {
+        let contract = self.contract();
+        self.interactor
+            .query()
+            .to(&contract)
+            .raw_call("getOrder")
+            .argument(&order_id)
+            .original_result::<LimitOrder<StaticApi>>()
+            .returns(ReturnsResult)
+            .run()
+            .await
+    }
+
+    pub async fn pending_orders(&mut self) -> Vec<LimitOrder<StaticApi>> {
+        let contract = self.contract();
+        self.interactor
+            .query()
+            .to(&contract)
+            .raw_call("getPendingOrders")
+            .original_result::<MultiValueEncoded<StaticApi, LimitOrder<StaticApi>>>()
+            .returns(ReturnsResult)
+            .run()
+            .await
+            .into_iter()
+            .collect()
+    }
+
+    pub async fn user_orders(&mut self, user: &str) -> Vec<LimitOrder<StaticApi>> {
+        let contract = self.contract();
+        let user = Bech32Address::from_bech32_string(user.to_string());
+        self.interactor
+            .query()
+            .to(&contract)
+            .raw_call("getUserOrders")
+            .argument(&user.to_address())
+            .original_result::<MultiValueEncoded<StaticApi, LimitOrder<StaticApi>>>()
+            .returns(ReturnsResult)
+            .run()
+            .await
+            .into_iter()
+            .collect()
+    }
+
+    pub async fn print_order(&mut self, order_id: u64) {
+        let order = self.order(order_id).await;
+        print_order(&order);
+    }
+
+    pub async fn print_pending_orders(&mut self) {
+        let orders = self.pending_orders().await;
+        println!("{} pending orders", orders.len());
+        for order in &orders {
+            print_order(order);
+        }
+    }
+
+    pub async fn print_user_orders(&mut self, user: &str) {
+        for order in &self.user_orders(user).await {
+            print_order(order);
+        }
+    }
+
+    pub async fn print_config(&mut self) {
+        let contract = self.contract();
+
+        let pair = self
+            .interactor
+            .query()
+            .to(&contract)
+            .raw_call("getXExchangePair")
+            .original_result::<ManagedAddress<StaticApi>>()
+            .returns(ReturnsResult)
+            .run()
+            .await;
+        let max_slippage = self.query_u64("getMaxSlippage").await;
+        let execution_fee = self.query_u64("getExecutionFeeBps").await;
+        let protocol_fee = self.query_u64("getProtocolFeeBps").await;
+        let tokens = self
+            .interactor
+            .query()
+            .to(&contract)
+            .raw_call("getWhitelistedTokens")
+            .original_result::<MultiValueEncoded<StaticApi, TokenIdentifier<StaticApi>>>()
+            .returns(ReturnsResult)
+            .run()
+            .await;
+
+        println!("contract:          {contract}");
+        println!("xExchange pair:    {}", Bech32Address::from(pair.to_address()));
+        println!("max slippage:      {max_slippage} bp");
+        println!("execution fee:     {execution_fee} bp");
+        println!("protocol fee:      {protocol_fee} bp");
+        for token in tokens {
+            println!("whitelisted token: {token}");
+        }
+    }
+
+    async fn query_u64(&mut self, view: &str) -> u64 {
+        let contract = self.contract();
+        self.interactor
+            .query()
+            .to(&contract)
+            .raw_call(view)
+            .original_result::<u64>()
+            .returns(ReturnsResult)
+            .run()
+            .await
+    }
+}
+
+fn print_order(order: &LimitOrder<StaticApi>) {
+    println!(
+        "#{} {:?} {} {} -> {} at {}/{} (slippage {} bp, expires at {}) by {}",
+        order.order_id,
+        order.status,
+        order.from_amount.to_display(),
+        order.from_token,
+        order.to_token,
+        order.target_price_numerator.to_display(),
+        order.target_price_denominator.to_display(),
+        order.slippage_bp,
+        order.expires_at,
+        Bech32Address::from(order.user.to_address()),
+    );
+}
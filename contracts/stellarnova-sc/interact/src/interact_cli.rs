@@ -0,0 +1,113 @@
+use clap::{Args, Parser, Subcommand};
+
+/// StellarNova interactor CLI
+#[derive(Default, PartialEq, Eq, Debug, Parser)]
+#[command(version, about)]
+#[command(propagate_version = true)]
+pub struct InteractCli {
+    /// Network section of the config file
+    #[arg(long, default_value = "devnet")]
+    pub network: String,
+
+    /// Path of the config file
+    #[arg(long, default_value = crate::interact_config::CONFIG_FILE)]
+    pub config: String,
+
+    #[command(subcommand)]
+    pub command: Option<InteractCliCommand>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum InteractCliCommand {
+    #[command(about = "Deploy the contract with the configured tokens and max slippage")]
+    Deploy,
+    #[command(about = "Upgrade the configured contract")]
+    Upgrade,
+    #[command(about = "Whitelist a token")]
+    WhitelistToken(TokenArgs),
+    #[command(about = "Remove a token from the whitelist")]
+    RemoveToken(TokenArgs),
+    #[command(about = "Set the xExchange pair (the configured one if omitted)")]
+    SetPair(SetPairArgs),
+    #[command(about = "Set the executor address")]
+    SetExecutor(AddressArgs),
+    #[command(about = "Apply a queued timelock action")]
+    ExecuteQueued(ActionArgs),
+    #[command(about = "Create a limit order from the wallet's tokens")]
+    CreateOrder(CreateOrderArgs),
+    #[command(about = "Cancel an order")]
+    CancelOrder(OrderArgs),
+    #[command(about = "Execute an order at the given current price")]
+    ExecuteOrder(ExecuteOrderArgs),
+    #[command(about = "Show an order")]
+    Order(OrderArgs),
+    #[command(about = "List pending orders")]
+    PendingOrders,
+    #[command(about = "List the orders of an address")]
+    UserOrders(AddressArgs),
+    #[command(about = "Show contract configuration views")]
+    Config,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct TokenArgs {
+    /// Token identifier, e.g. WEGLD-d7c6bb
+    pub token: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct SetPairArgs {
+    /// Pair address (bech32)
+    pub pair: Option<String>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct AddressArgs {
+    /// Address (bech32)
+    pub address: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ActionArgs {
+    pub action_id: u64,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct OrderArgs {
+    pub order_id: u64,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct CreateOrderArgs {
+    /// Token to sell
+    #[arg(long)]
+    pub from_token: String,
+    /// Amount to sell, in base units
+    #[arg(long)]
+    pub amount: u128,
+    /// Token to buy
+    #[arg(long)]
+    pub to_token: String,
+    /// Limit price numerator (output per input)
+    #[arg(long)]
+    pub price_num: u128,
+    /// Limit price denominator
+    #[arg(long)]
+    pub price_denom: u128,
+    #[arg(long, default_value_t = 100)]
+    pub slippage_bp: u64,
+    #[arg(long, default_value_t = 86_400)]
+    pub expires_in: u64,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct ExecuteOrderArgs {
+    pub order_id: u64,
+    /// Current price numerator
+    pub price_num: u128,
+    /// Current price denominator
+    pub price_denom: u128,
+    /// Execute only this much of the remaining escrow
+    #[arg(long)]
+    pub fill_amount: Option<u128>,
+}
@@ -0,0 +1,56 @@
+use serde::Deserialize;
+use std::{collections::HashMap, io::Read};
+
+/// Default config file, relative to the interactor directory
+pub const CONFIG_FILE: &str = "config.toml";
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChainType {
+    Real,
+    Simulator,
+}
+
+/// Addresses and settings of one network
+#[derive(Debug, Deserialize, Clone)]
+pub struct NetworkConfig {
+    pub chain_type: ChainType,
+    pub gateway_uri: String,
+    pub wallet_pem: String,
+    pub contract_address: Option<String>,
+    pub pair_address: Option<String>,
+    #[serde(default)]
+    pub tokens: Vec<String>,
+    pub max_slippage_bp: u64,
+}
+
+impl NetworkConfig {
+    pub fn use_chain_simulator(&self) -> bool {
+        self.chain_type == ChainType::Simulator
+    }
+
+    pub fn contract_address(&self) -> &str {
+        self.contract_address
+            .as_deref()
+            .expect("no contract_address configured for this network")
+    }
+
+    pub fn pair_address(&self) -> &str {
+        self.pair_address
+            .as_deref()
+            .expect("no pair_address configured for this network")
+    }
+}
+
+/// Load the section of `network` from the config file at `path`
+pub fn load_network_config(path: &str, network: &str) -> NetworkConfig {
+    let mut file = std::fs::File::open(path).unwrap_or_else(|_| panic!("cannot open {path}"));
+    let mut content = String::new();
+    file.read_to_string(&mut content).unwrap();
+
+    let mut networks: HashMap<String, NetworkConfig> =
+        toml::from_str(&content).unwrap_or_else(|err| panic!("invalid {path}: {err}"));
+    networks
+        .remove(network)
+        .unwrap_or_else(|| panic!("network {network} not found in {path}"))
+}
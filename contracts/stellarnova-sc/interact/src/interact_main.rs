@@ -0,0 +1,4 @@
+#[tokio::main]
+async fn main() {
+    stellarnova_interact::stellarnova_cli().await;
+}
@@ -0,0 +1,159 @@
+use std::time::Duration;
+
+use clap::Parser;
+use multiversx_sc_snippets::imports::*;
+use stellarnova_sc::limit_orders::{OrderStatus, MAX_BASIS_POINTS};
+
+use crate::interact_config::load_network_config;
+use crate::StellarNovaInteract;
+
+/// Async swap and callback land a few blocks after the execute transaction
+const SETTLE_POLLS: u32 = 10;
+const SETTLE_INTERVAL: Duration = Duration::from_secs(6);
+
+const SMOKE_EXPIRY_SECONDS: u64 = 3_600;
+
+/// Deploy a fresh contract and run one order through the real pair
+#[derive(Debug, Parser)]
+#[command(version, about)]
+pub struct SmokeCli {
+    /// Network section of the config file
+    #[arg(long, default_value = "devnet")]
+    pub network: String,
+
+    /// Path of the config file
+    #[arg(long, default_value = crate::interact_config::CONFIG_FILE)]
+    pub config: String,
+
+    /// Amount of the first configured token to sell, in base units
+    #[arg(long, default_value_t = 1_000_000_000_000_000)]
+    pub amount: u128,
+}
+
+pub async fn stellarnova_smoke_cli() {
+    let cli = SmokeCli::parse();
+    let config = load_network_config(&cli.config, &cli.network);
+    let mut interact = StellarNovaInteract::new(config).await;
+    interact.smoke_test(cli.amount).await;
+}
+
+impl StellarNovaInteract {
+    /// End-to-end check of a release build: deploy, whitelist the two
+    /// configured tokens, set the configured pair, then sell `amount` of the
+    /// first token for the second through the pair as owner and executor
+    ///
+    /// Panics on the first failed step or balance mismatch
+    pub async fn smoke_test(&mut self, amount: u128) {
+        let [from_token, to_token] = self.config.tokens.as_slice() else {
+            panic!("smoke test needs exactly two configured tokens");
+        };
+        let (from_token, to_token) = (from_token.clone(), to_token.clone());
+        let pair = self.config.pair_address().to_string();
+
+        let contract = self.deploy_with_tokens(&[]).await;
+        self.config.contract_address = Some(contract.to_bech32_string());
+        self.whitelist_token(&from_token).await;
+        self.whitelist_token(&to_token).await;
+
+        // A fresh contract has no timelock delay, so the pair applies at once
+        self.set_pair(&pair).await;
+        let configured_pair = self.xexchange_pair().await;
+        assert_eq!(configured_pair.to_bech32_string(), pair, "pair not applied");
+
+        // Limit at the current quote: the price check passes and the
+        // slippage allowance absorbs pair movement until execution
+        let quote = self.pair_quote(&from_token, amount).await;
+        assert!(quote > 0u64, "pair quotes nothing for {amount} {from_token}");
+        let quote = quote.to_u64().map(u128::from).unwrap_or_else(|| {
+            panic!("quote of {} {to_token} does not fit a u64", quote.to_display())
+        });
+        let min_output = quote * u128::from(MAX_BASIS_POINTS - self.config.max_slippage_bp)
+            / u128::from(MAX_BASIS_POINTS);
+
+        let from_before = self.esdt_balance(&from_token).await;
+        let to_before = self.esdt_balance(&to_token).await;
+
+        let order_id = self
+            .create_order(
+                &from_token,
+                amount,
+                &to_token,
+                (quote, amount),
+                self.config.max_slippage_bp,
+                SMOKE_EXPIRY_SECONDS,
+            )
+            .await;
+        self.execute_order(order_id, (quote, amount), None).await;
+        let status = self.wait_for_settlement(order_id).await;
+        assert_eq!(status, OrderStatus::Executed, "order {order_id} did not execute");
+
+        let from_after = self.esdt_balance(&from_token).await;
+        let to_after = self.esdt_balance(&to_token).await;
+        assert_eq!(
+            from_after + amount,
+            from_before,
+            "{from_token} balance went from {from_before} to {from_after}, expected -{amount}"
+        );
+        // The wallet is also the executor, so it keeps the execution fee
+        let received = to_after.saturating_sub(to_before);
+        assert!(
+            received >= min_output,
+            "received {received} {to_token}, expected at least {min_output}"
+        );
+
+        println!(
+            "smoke test passed on {contract}: sold {amount} {from_token} for {received} {to_token}"
+        );
+    }
+
+    /// Status of the order once its swap callback ran
+    async fn wait_for_settlement(&mut self, order_id: u64) -> OrderStatus {
+        for _ in 0..SETTLE_POLLS {
+            let order = self.order(order_id).await;
+            if order.status != OrderStatus::Pending {
+                return order.status;
+            }
+            tokio::time::sleep(SETTLE_INTERVAL).await;
+        }
+        panic!("order {order_id} still pending after execution");
+    }
+
+    async fn xexchange_pair(&mut self) -> Bech32Address {
+        let contract = self.contract();
+        let pair = self
+            .interactor
+            .query()
+            .to(&contract)
+            .raw_call("getXExchangePair")
+            .original_result::<ManagedAddress<StaticApi>>()
+            .returns(ReturnsResult)
+            .run()
+            .await;
+        Bech32Address::from(pair.to_address())
+    }
+
+    /// Output the pair gives for `amount` of `token` right now
+    async fn pair_quote(&mut self, token: &str, amount: u128) -> BigUint<StaticApi> {
+        let pair = Bech32Address::from_bech32_string(self.config.pair_address().to_string());
+        self.interactor
+            .query()
+            .to(&pair)
+            .raw_call("getAmountOut")
+            .argument(&TokenIdentifier::<StaticApi>::from(token))
+            .argument(&BigUint::<StaticApi>::from(amount))
+            .original_result::<BigUint<StaticApi>>()
+            .returns(ReturnsResult)
+            .run()
+            .await
+    }
+
+    /// Wallet balance of `token` (0 when the wallet never held it)
+    async fn esdt_balance(&mut self, token: &str) -> u128 {
+        let balances = self.interactor.get_account_esdt(&self.wallet_address).await;
+        balances.get(token).map_or(0, |esdt| {
+            esdt.balance
+                .parse()
+                .unwrap_or_else(|_| panic!("bad {token} balance {}", esdt.balance))
+        })
+    }
+}
@@ -0,0 +1,174 @@
+//! Execution Auctions Module for StellarNova
+//!
+//! Keepers compete for the right to execute a large order: the executor or
+//! the order owner opens an auction (`openExecutionAuction`) for an order of
+//! at least `executionAuctionMinAmount` of its token, and during the window
+//! anyone bids the execution fee (in bps) they accept. The default executor
+//! at the default fee is the standing bid, so bids must undercut it.
+//!
+//! While bids are open the order is not executable. Once the window closes,
+//! the best bidder alone may execute the order, at its bid, for another
+//! window; after that the default executor takes over again.
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::errors::*;
+use crate::reasons::ReasonCode;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct ExecutionAuction<M: ManagedTypeApi> {
+    pub ends_at: u64,         // bids accepted until then
+    pub rights_until: u64,    // the best bidder executes until then
+    pub best_bidder: ManagedAddress<M>,
+    pub best_fee_bps: u64,
+}
+
+#[multiversx_sc::module]
+pub trait AuctionsModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::blacklist::BlacklistModule
+{
+    /// Length of the bidding window and of the winner's execution rights
+    /// (owner only, 0 disables new auctions)
+    #[only_owner]
+    #[endpoint(setExecutionAuctionWindow)]
+    fn set_execution_auction_window(&self, window_seconds: u64) {
+        self.execution_auction_window().set(window_seconds);
+        self.execution_auction_window_changed_event(window_seconds);
+    }
+
+    /// Smallest order of `token` whose execution can be auctioned
+    /// (owner only, 0 disables auctions for the token)
+    #[only_owner]
+    #[endpoint(setExecutionAuctionMinAmount)]
+    fn set_execution_auction_min_amount(&self, token: TokenIdentifier, min_amount: BigUint) {
+        self.execution_auction_min_amount(&token).set(&min_amount);
+        self.execution_auction_min_amount_changed_event(&token, &min_amount);
+    }
+
+    /// Bid the execution fee (in bps) the caller accepts for executing an
+    /// order; the lowest bid when the window closes wins
+    #[endpoint(bidForExecution)]
+    fn bid_for_execution(&self, order_id: u64, fee_bps: u64) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_blacklisted(&caller);
+
+        let auction_mapper = self.execution_auctions(order_id);
+        require!(!auction_mapper.is_empty(), ERROR_AUCTION_NOT_OPEN);
+        let mut auction = auction_mapper.get();
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        require!(current_time <= auction.ends_at, ERROR_AUCTION_NOT_OPEN);
+        require!(fee_bps < auction.best_fee_bps, ERROR_BID_NOT_LOWER);
+
+        auction.best_bidder = caller;
+        auction.best_fee_bps = fee_bps;
+        auction_mapper.set(&auction);
+        self.execution_bid_placed_event(order_id, &auction.best_bidder, fee_bps);
+    }
+
+    #[view(getExecutionAuction)]
+    fn get_execution_auction(&self, order_id: u64) -> OptionalValue<ExecutionAuction<Self::Api>> {
+        let auction_mapper = self.execution_auctions(order_id);
+        if auction_mapper.is_empty() {
+            return OptionalValue::None;
+        }
+        OptionalValue::Some(auction_mapper.get())
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    /// Start the auction of an order's execution rights, with `executor`
+    /// at the default fee as the standing bid (eligibility is checked by
+    /// the caller)
+    fn start_execution_auction(&self, order_id: u64, executor: ManagedAddress) {
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        let auction_mapper = self.execution_auctions(order_id);
+        require!(
+            auction_mapper.is_empty() || current_time > auction_mapper.get().rights_until,
+            ERROR_AUCTION_ALREADY_OPEN
+        );
+
+        let window = self.execution_auction_window().get();
+        let ends_at = current_time + window;
+        let auction = ExecutionAuction {
+            ends_at,
+            rights_until: ends_at + window,
+            best_bidder: executor,
+            best_fee_bps: self.execution_fee_bps().get(),
+        };
+        auction_mapper.set(&auction);
+        self.execution_auction_opened_event(order_id, ends_at, auction.rights_until);
+    }
+
+    /// Whether `caller` may execute the order now: the auction winner while
+    /// it holds the rights, otherwise the default `executor`
+    fn execution_rights_reason(
+        &self,
+        order_id: u64,
+        caller: &ManagedAddress,
+        mut executor: ManagedAddress,
+    ) -> ReasonCode {
+        let auction_mapper = self.execution_auctions(order_id);
+        if !auction_mapper.is_empty() {
+            let auction = auction_mapper.get();
+            #[allow(deprecated)]
+            let current_time = self.blockchain().get_block_timestamp();
+            if current_time <= auction.ends_at {
+                return ReasonCode::ExecutionAuctionOpen;
+            }
+            if current_time <= auction.rights_until {
+                executor = auction.best_bidder;
+            }
+        }
+        if *caller != executor {
+            return ReasonCode::NotExecutor;
+        }
+        ReasonCode::Ok
+    }
+
+    /// Auctioned orders are executed through the pair by the bid winner
+    /// only: no matching or quote fills until its rights lapse
+    fn require_not_auctioned(&self, order_id: u64) {
+        let auction_mapper = self.execution_auctions(order_id);
+        if auction_mapper.is_empty() {
+            return;
+        }
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        require!(
+            current_time > auction_mapper.get().rights_until,
+            ERROR_EXECUTION_AUCTION_OPEN
+        );
+    }
+
+    /// Execution fee (bps) of a fill by `executor`: its winning bid if it
+    /// won the order's auction, the default fee otherwise
+    fn execution_fee_bps_for(&self, order_id: u64, executor: &ManagedAddress) -> u64 {
+        let auction_mapper = self.execution_auctions(order_id);
+        if !auction_mapper.is_empty() {
+            let auction = auction_mapper.get();
+            if auction.best_bidder == *executor {
+                return auction.best_fee_bps;
+            }
+        }
+        self.execution_fee_bps().get()
+    }
+
+    // ========== STORAGE ==========
+
+    #[view(getExecutionAuctionWindow)]
+    #[storage_mapper("executionAuctionWindow")]
+    fn execution_auction_window(&self) -> SingleValueMapper<u64>;
+
+    #[view(getExecutionAuctionMinAmount)]
+    #[storage_mapper("executionAuctionMinAmount")]
+    fn execution_auction_min_amount(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("executionAuctions")]
+    fn execution_auctions(&self, order_id: u64) -> SingleValueMapper<ExecutionAuction<Self::Api>>;
+}
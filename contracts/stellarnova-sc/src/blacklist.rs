@@ -0,0 +1,54 @@
+//! Blacklist Module for StellarNova
+//!
+//! Owner-managed set of sanctioned addresses. Blacklisted addresses cannot
+//! create orders, and no payout (execution output, refund, fee) is sent to them.
+
+multiversx_sc::imports!();
+
+use crate::errors::*;
+
+#[multiversx_sc::module]
+pub trait BlacklistModule: crate::events::EventsModule {
+    /// Add address to blacklist (owner only)
+    #[only_owner]
+    #[endpoint(addToBlacklist)]
+    fn add_to_blacklist(&self, address: ManagedAddress) {
+        require!(
+            self.blacklisted_addresses().insert(address.clone()),
+            ERROR_ALREADY_BLACKLISTED
+        );
+        self.address_blacklisted_event(&address);
+    }
+
+    /// Remove address from blacklist (owner only)
+    #[only_owner]
+    #[endpoint(removeFromBlacklist)]
+    fn remove_from_blacklist(&self, address: ManagedAddress) {
+        require!(
+            self.blacklisted_addresses().swap_remove(&address),
+            ERROR_NOT_BLACKLISTED
+        );
+        self.address_unblacklisted_event(&address);
+    }
+
+    /// Check if address is blacklisted
+    #[view(isBlacklisted)]
+    fn is_blacklisted(&self, address: ManagedAddress) -> bool {
+        self.blacklisted_addresses().contains(&address)
+    }
+
+    // ========== GUARDS ==========
+
+    fn require_not_blacklisted(&self, address: &ManagedAddress) {
+        require!(
+            !self.blacklisted_addresses().contains(address),
+            ERROR_ADDRESS_BLACKLISTED
+        );
+    }
+
+    // ========== STORAGE ==========
+
+    #[view(getBlacklistedAddresses)]
+    #[storage_mapper("blacklistedAddresses")]
+    fn blacklisted_addresses(&self) -> UnorderedSetMapper<ManagedAddress>;
+}
@@ -0,0 +1,113 @@
+//! Circuit Breaker Module for StellarNova
+//!
+//! Every swap records its realized price (output per input, scaled by 1e18)
+//! per pair and input token. When a swap lands more than `maxDeviationBp`
+//! away from the previous one on the same pair and direction, and that one
+//! is at most `windowSeconds` old, the pair trips: `circuit_breaker_tripped`
+//! is emitted and executions on the pair are halted until the owner calls
+//! `clearCircuitBreaker`. The tripping swap itself has already happened and
+//! is settled normally.
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::errors::*;
+
+/// Last realized swap price of a pair in one direction
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct PriceSnapshot<M: ManagedTypeApi> {
+    pub price: BigUint<M>,
+    pub timestamp: u64,
+}
+
+#[multiversx_sc::module]
+pub trait CircuitBreakerModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+{
+    /// Trip a pair when consecutive swaps within `window_seconds` move the
+    /// price by more than `max_deviation_bp` (owner only, 0 disables)
+    #[only_owner]
+    #[endpoint(setCircuitBreaker)]
+    fn set_circuit_breaker(&self, max_deviation_bp: u64, window_seconds: u64) {
+        self.breaker_max_deviation_bp().set(max_deviation_bp);
+        self.breaker_window_seconds().set(window_seconds);
+        self.circuit_breaker_changed_event(max_deviation_bp, window_seconds);
+    }
+
+    /// Resume executions on a tripped pair (owner only)
+    #[only_owner]
+    #[endpoint(clearCircuitBreaker)]
+    fn clear_circuit_breaker(&self, pair: ManagedAddress) {
+        require!(
+            self.tripped_pairs().swap_remove(&pair),
+            ERROR_BREAKER_NOT_TRIPPED
+        );
+        self.circuit_breaker_cleared_event(&pair);
+    }
+
+    /// True while executions on `pair` are halted
+    #[view(isPairTripped)]
+    fn is_pair_tripped(&self, pair: &ManagedAddress) -> bool {
+        self.tripped_pairs().contains(pair)
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    /// Record the realized price of a swap of `token_in` on `pair`, tripping
+    /// the pair if it deviates too far from the previous recent swap
+    fn record_swap_price(&self, pair: &ManagedAddress, token_in: &TokenIdentifier, price: BigUint) {
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        let snapshot_mapper = self.last_swap_price(pair, token_in);
+        let max_deviation_bp = self.breaker_max_deviation_bp().get();
+
+        if max_deviation_bp > 0 && !snapshot_mapper.is_empty() {
+            let last = snapshot_mapper.get();
+            let is_recent = current_time - last.timestamp <= self.breaker_window_seconds().get();
+            let deviation = if price > last.price {
+                &price - &last.price
+            } else {
+                &last.price - &price
+            };
+            if is_recent
+                && deviation * 10_000u64 > &last.price * max_deviation_bp
+                && self.tripped_pairs().insert(pair.clone())
+            {
+                self.circuit_breaker_tripped_event(pair, token_in, &last.price, &price);
+            }
+        }
+
+        snapshot_mapper.set(PriceSnapshot {
+            price,
+            timestamp: current_time,
+        });
+    }
+
+    // ========== STORAGE ==========
+
+    /// Max price move between consecutive swaps, in basis points (0 = off)
+    #[view(getBreakerMaxDeviationBp)]
+    #[storage_mapper("breakerMaxDeviationBp")]
+    fn breaker_max_deviation_bp(&self) -> SingleValueMapper<u64>;
+
+    /// Only swaps at most this far apart are compared
+    #[view(getBreakerWindowSeconds)]
+    #[storage_mapper("breakerWindowSeconds")]
+    fn breaker_window_seconds(&self) -> SingleValueMapper<u64>;
+
+    /// Last realized price of swapping `token_in` on `pair`
+    #[view(getLastSwapPrice)]
+    #[storage_mapper("lastSwapPrice")]
+    fn last_swap_price(
+        &self,
+        pair: &ManagedAddress,
+        token_in: &TokenIdentifier,
+    ) -> SingleValueMapper<PriceSnapshot<Self::Api>>;
+
+    /// Pairs whose executions are halted
+    #[view(getTrippedPairs)]
+    #[storage_mapper("trippedPairs")]
+    fn tripped_pairs(&self) -> UnorderedSetMapper<ManagedAddress>;
+}
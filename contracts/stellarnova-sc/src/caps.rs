@@ -0,0 +1,152 @@
+//! Risk Caps Module for StellarNova
+//!
+//! Owner-configured limits per from token: the largest single order and the
+//! total open interest, i.e. the escrow held for pending orders of the token.
+//! Caps only gate new orders; orders already open are never affected.
+//!
+//! Executors are limited per epoch as well: the amount of a token an executor
+//! may swap or match is capped, so a leaked executor key can only move a
+//! bounded volume before the owner rotates it.
+
+multiversx_sc::imports!();
+
+use crate::errors::*;
+use crate::managers::EpochUsage;
+use crate::reasons::ReasonCode;
+
+#[multiversx_sc::module]
+pub trait CapsModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+{
+    /// Set the max single order amount and the max open interest of a
+    /// token (owner only, 0 = no cap)
+    #[only_owner]
+    #[endpoint(setRiskCaps)]
+    fn set_risk_caps(
+        &self,
+        token: TokenIdentifier,
+        max_order_amount: BigUint,
+        max_open_interest: BigUint,
+    ) {
+        self.max_order_amount(&token).set(&max_order_amount);
+        self.max_open_interest(&token).set(&max_open_interest);
+        self.risk_caps_changed_event(&token, &max_order_amount, &max_open_interest);
+    }
+
+    /// Max amount of `token` a single executor may execute per epoch
+    /// (owner only, 0 = no limit)
+    #[only_owner]
+    #[endpoint(setExecutorDailyLimit)]
+    fn set_executor_daily_limit(&self, token: TokenIdentifier, limit: BigUint) {
+        self.executor_daily_limit(&token).set(&limit);
+        self.executor_daily_limit_changed_event(&token, &limit);
+    }
+
+    /// Amount of `token` `executor` executed in the current epoch
+    #[view(getExecutorVolume)]
+    fn get_executor_volume(&self, executor: &ManagedAddress, token: &TokenIdentifier) -> BigUint {
+        let mapper = self.executor_volume(executor, token);
+        if mapper.is_empty() {
+            return BigUint::zero();
+        }
+        let usage = mapper.get();
+        if usage.epoch == self.blockchain().get_block_epoch() {
+            usage.amount
+        } else {
+            BigUint::zero()
+        }
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    /// Whether a new `amount` order of `token` fits the caps, given the
+    /// token's open interest including that order
+    fn risk_caps_reason(
+        &self,
+        token: &TokenIdentifier,
+        amount: &BigUint,
+        open_interest: &BigUint,
+    ) -> ReasonCode {
+        let max_order_amount = self.max_order_amount(token).get();
+        if max_order_amount > 0u64 && *amount > max_order_amount {
+            return ReasonCode::OrderTooLarge;
+        }
+        let max_open_interest = self.max_open_interest(token).get();
+        if max_open_interest > 0u64 && *open_interest > max_open_interest {
+            return ReasonCode::OpenInterestCapReached;
+        }
+        ReasonCode::Ok
+    }
+
+    /// Whether `executor` may still execute `amount` of `token` this epoch
+    fn executor_volume_reason(
+        &self,
+        executor: &ManagedAddress,
+        token: &TokenIdentifier,
+        amount: &BigUint,
+    ) -> ReasonCode {
+        let limit = self.executor_daily_limit(token).get();
+        if limit > 0u64 && self.get_executor_volume(executor, token) + amount > limit {
+            return ReasonCode::ExecutorVolumeExceeded;
+        }
+        ReasonCode::Ok
+    }
+
+    /// Count `amount` of `token` against the executor's epoch volume
+    fn spend_executor_volume(
+        &self,
+        executor: &ManagedAddress,
+        token: &TokenIdentifier,
+        amount: &BigUint,
+    ) {
+        require!(
+            self.executor_volume_reason(executor, token, amount).is_ok(),
+            ERROR_EXECUTOR_VOLUME_EXCEEDED
+        );
+        let usage = EpochUsage {
+            epoch: self.blockchain().get_block_epoch(),
+            amount: self.get_executor_volume(executor, token) + amount,
+        };
+        self.executor_volume(executor, token).set(&usage);
+    }
+
+    /// Give back volume of a swap that failed in the same epoch
+    fn release_executor_volume(
+        &self,
+        executor: &ManagedAddress,
+        token: &TokenIdentifier,
+        amount: &BigUint,
+    ) {
+        let volume = self.get_executor_volume(executor, token);
+        if volume == 0u64 {
+            return;
+        }
+        let usage = EpochUsage {
+            epoch: self.blockchain().get_block_epoch(),
+            amount: if volume > *amount { volume - amount } else { BigUint::zero() },
+        };
+        self.executor_volume(executor, token).set(&usage);
+    }
+
+    // ========== STORAGE ==========
+
+    #[view(getMaxOrderAmount)]
+    #[storage_mapper("maxOrderAmount")]
+    fn max_order_amount(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getMaxOpenInterest)]
+    #[storage_mapper("maxOpenInterest")]
+    fn max_open_interest(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(getExecutorDailyLimit)]
+    #[storage_mapper("executorDailyLimit")]
+    fn executor_daily_limit(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("executorVolume")]
+    fn executor_volume(
+        &self,
+        executor: &ManagedAddress,
+        token: &TokenIdentifier,
+    ) -> SingleValueMapper<EpochUsage<Self::Api>>;
+}
@@ -0,0 +1,184 @@
+//! Clients Module for StellarNova
+//!
+//! Registry of integrators (third-party front-ends). The owner registers a
+//! client with a payout address; orders created through `createClientOrder`
+//! are attributed to it. For every fill of an attributed order:
+//! - the input amount is added to the client's volume (per input token)
+//! - `clientFeeShareBps` of the protocol fee is credited to the client,
+//!   before the stakers' share is taken from the rest
+//!
+//! Credited fees are claimed to the client's payout address.
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::errors::*;
+use crate::limit_orders::MAX_BASIS_POINTS;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct Client<M: ManagedTypeApi> {
+    pub client_id: u64,
+    pub payout_address: ManagedAddress<M>,
+    pub created_at: u64,
+    pub active: bool,
+}
+
+#[multiversx_sc::module]
+pub trait ClientsModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+{
+    /// Register an integrator paid at `payout_address` (owner only)
+    /// Returns the client id
+    #[only_owner]
+    #[endpoint(registerClient)]
+    fn register_client(&self, payout_address: ManagedAddress) -> u64 {
+        let client_id = self.next_client_id().get() + 1;
+        self.next_client_id().set(client_id);
+
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        let client = Client {
+            client_id,
+            payout_address,
+            created_at: current_time,
+            active: true,
+        };
+        self.clients(client_id).set(&client);
+
+        self.client_registered_event(client_id, &client.payout_address);
+        client_id
+    }
+
+    /// Move a client's payouts to a new address (client or contract owner)
+    #[endpoint(setClientPayoutAddress)]
+    fn set_client_payout_address(&self, client_id: u64, payout_address: ManagedAddress) {
+        let mut client = self.get_client(client_id);
+        let caller = self.blockchain().get_caller();
+        require!(
+            client.payout_address == caller || self.blockchain().get_owner_address() == caller,
+            ERROR_NOT_CLIENT_ADMIN
+        );
+
+        client.payout_address = payout_address;
+        self.clients(client_id).set(&client);
+        self.client_payout_changed_event(client_id, &client.payout_address);
+    }
+
+    /// Allow or stop attributing new orders to a client (owner only)
+    /// Orders already attributed keep earning the client its share
+    #[only_owner]
+    #[endpoint(setClientActive)]
+    fn set_client_active(&self, client_id: u64, active: bool) {
+        let mut client = self.get_client(client_id);
+        client.active = active;
+        self.clients(client_id).set(&client);
+
+        self.client_active_changed_event(client_id, active);
+    }
+
+    /// Set the share of protocol fees paid to the client of an order (owner only)
+    #[only_owner]
+    #[endpoint(setClientFeeShareBps)]
+    fn set_client_fee_share_bps(&self, fee_share_bps: u64) {
+        require!(fee_share_bps <= MAX_BASIS_POINTS, ERROR_CLIENT_SHARE_TOO_HIGH);
+
+        let old_share = self.client_fee_share_bps().replace(fee_share_bps);
+        self.client_fee_share_changed_event(old_share, fee_share_bps);
+    }
+
+    /// Send a client's accrued fees in `token` to its payout address
+    /// (callable by anyone: the receiver does not depend on the caller)
+    #[endpoint(claimClientFees)]
+    fn claim_client_fees(&self, client_id: u64, token: TokenIdentifier) -> BigUint {
+        let client = self.get_client(client_id);
+        let amount = self.client_fees(client_id, &token).take();
+        require!(amount > 0u64, ERROR_NO_CLIENT_FEES);
+
+        self.client_fee_balance(&token)
+            .update(|balance| *balance -= &amount);
+        self.send()
+            .direct_esdt(&client.payout_address, &token, 0, &amount);
+        self.client_fees_claimed_event(client_id, &token, &amount);
+
+        amount
+    }
+
+    #[view(getClient)]
+    fn get_client(&self, client_id: u64) -> Client<Self::Api> {
+        require!(!self.clients(client_id).is_empty(), ERROR_CLIENT_NOT_FOUND);
+        self.clients(client_id).get()
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    /// Attribute a new order to an active client
+    fn attribute_order_client(&self, order_id: u64, client_id: u64) {
+        let client = self.get_client(client_id);
+        require!(client.active, ERROR_CLIENT_INACTIVE);
+
+        self.order_client(order_id).set(client_id);
+        self.order_client_attributed_event(order_id, client_id);
+    }
+
+    /// Credit the client of an order with its share of a protocol fee
+    /// Returns the share (0 for orders without a client)
+    fn credit_client_fee_share(
+        &self,
+        order_id: u64,
+        token: &TokenIdentifier,
+        fee: &BigUint,
+    ) -> BigUint {
+        if self.order_client(order_id).is_empty() {
+            return BigUint::zero();
+        }
+        let client_id = self.order_client(order_id).get();
+        let share = fee * self.client_fee_share_bps().get() / MAX_BASIS_POINTS;
+        if share > 0u64 {
+            self.client_fees(client_id, token)
+                .update(|fees| *fees += &share);
+            self.client_fee_balance(token)
+                .update(|balance| *balance += &share);
+        }
+        share
+    }
+
+    /// Add a fill of an attributed order to its client's volume
+    fn record_client_volume(&self, order_id: u64, token: &TokenIdentifier, amount: &BigUint) {
+        if self.order_client(order_id).is_empty() {
+            return;
+        }
+        let client_id = self.order_client(order_id).get();
+        self.client_volume(client_id, token)
+            .update(|volume| *volume += amount);
+    }
+
+    // ========== STORAGE ==========
+
+    #[storage_mapper("nextClientId")]
+    fn next_client_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("clients")]
+    fn clients(&self, client_id: u64) -> SingleValueMapper<Client<Self::Api>>;
+
+    /// Client an order is attributed to
+    #[view(getOrderClient)]
+    #[storage_mapper("orderClient")]
+    fn order_client(&self, order_id: u64) -> SingleValueMapper<u64>;
+
+    /// Share of protocol fees (in bps) paid to the client of an order
+    #[view(getClientFeeShareBps)]
+    #[storage_mapper("clientFeeShareBps")]
+    fn client_fee_share_bps(&self) -> SingleValueMapper<u64>;
+
+    /// Executed input volume of a client per token
+    #[view(getClientVolume)]
+    #[storage_mapper("clientVolume")]
+    fn client_volume(&self, client_id: u64, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Fees a client can claim per token
+    #[view(getClientFees)]
+    #[storage_mapper("clientFees")]
+    fn client_fees(&self, client_id: u64, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+}
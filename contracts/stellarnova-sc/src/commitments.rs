@@ -0,0 +1,86 @@
+//! Price Commitments Module for StellarNova
+//!
+//! An order created with `createCommittedOrder` stores only
+//! `keccak256(priceNum, priceDenom, salt)` instead of its target price, so
+//! bots scanning storage cannot see the user's price level. The stored order
+//! carries a 1/1 placeholder price until then.
+//!
+//! The user hands the preimage to the executor off-chain; the executor
+//! reveals it with `executeCommittedOrder`, which checks it against the
+//! commitment, stores the price and executes in the same transaction.
+//! Committed orders cannot be executed otherwise, nor matched or filled
+//! from quotes before the reveal.
+//!
+//! The preimage is the nested encoding of `(priceNum, priceDenom, salt)`
+//! with a 32-byte salt. `computePriceCommitment` computes it, but querying
+//! it on a public node discloses the price: compute it client-side.
+
+multiversx_sc::imports!();
+
+use crate::errors::*;
+use crate::reasons::ReasonCode;
+
+#[multiversx_sc::module]
+pub trait CommitmentsModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+{
+    /// Commitment of a target price (see the module docs for the encoding)
+    #[view(computePriceCommitment)]
+    fn compute_price_commitment(
+        &self,
+        price_num: BigUint,
+        price_denom: BigUint,
+        salt: ManagedByteArray<Self::Api, 32>,
+    ) -> ManagedByteArray<Self::Api, 32> {
+        let mut preimage = ManagedBuffer::new();
+        let _ = price_num.dep_encode(&mut preimage);
+        let _ = price_denom.dep_encode(&mut preimage);
+        preimage.append(salt.as_managed_buffer());
+        self.crypto().keccak256(&preimage)
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    /// Check a revealed price against the order's commitment and consume it
+    fn open_price_commitment(
+        &self,
+        order_id: u64,
+        price_num: &BigUint,
+        price_denom: &BigUint,
+        salt: ManagedByteArray<Self::Api, 32>,
+    ) {
+        let commitment_mapper = self.order_price_commitment(order_id);
+        require!(!commitment_mapper.is_empty(), ERROR_NO_PRICE_COMMITMENT);
+        let commitment =
+            self.compute_price_commitment(price_num.clone(), price_denom.clone(), salt);
+        require!(commitment == commitment_mapper.get(), ERROR_PRICE_COMMITMENT_MISMATCH);
+
+        commitment_mapper.clear();
+        self.order_price_revealed_event(order_id, price_num, price_denom);
+    }
+
+    fn require_price_revealed(&self, order_id: u64) {
+        let reason = self.price_commitment_reason(order_id);
+        require!(reason.is_ok(), (reason.error_message()));
+    }
+
+    /// Committed orders are only executable through `executeCommittedOrder`
+    fn price_commitment_reason(&self, order_id: u64) -> ReasonCode {
+        if self.order_price_commitment(order_id).is_empty() {
+            ReasonCode::Ok
+        } else {
+            ReasonCode::PriceNotRevealed
+        }
+    }
+
+    // ========== STORAGE ==========
+
+    /// Hidden target price of an order, until revealed at execution
+    #[view(getOrderPriceCommitment)]
+    #[storage_mapper("orderPriceCommitment")]
+    fn order_price_commitment(
+        &self,
+        order_id: u64,
+    ) -> SingleValueMapper<ManagedByteArray<Self::Api, 32>>;
+}
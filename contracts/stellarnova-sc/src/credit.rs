@@ -0,0 +1,186 @@
+//! Credit Module for StellarNova
+//!
+//! Users can deposit tokens once and keep them as credit in the contract.
+//! Credit funds orders without an ESDT transfer per order (directly or via
+//! relayers) and can be withdrawn at any time.
+
+multiversx_sc::imports!();
+
+use crate::errors::*;
+
+#[multiversx_sc::module]
+pub trait CreditModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::limit_orders::LimitOrdersModule
+    + crate::pause::PauseModule
+    + crate::dex::DexModule
+    + crate::blacklist::BlacklistModule
+    + crate::treasury::TreasuryModule
+    + crate::managers::ManagersModule
+    + crate::positions::PositionsModule
+    + crate::strategies::StrategiesModule
+    + crate::signals::SignalsModule
+    + crate::streams::StreamsModule
+    + crate::listings::ListingsModule
+    + crate::decimals::DecimalsModule
+    + crate::caps::CapsModule
+    + crate::breaker::CircuitBreakerModule
+    + crate::staking::StakingModule
+    + crate::insurance::InsuranceModule
+    + crate::stats::StatsModule
+    + crate::clients::ClientsModule
+    + crate::commitments::CommitmentsModule
+    + crate::auctions::AuctionsModule
+    + crate::gas_station::GasStationModule
+    + crate::forwarding::ForwardingModule
+{
+    /// Deposit a fungible ESDT as credit for the caller
+    #[payable("*")]
+    #[endpoint(deposit)]
+    fn deposit(&self) {
+        let caller = self.blockchain().get_caller();
+        self.require_not_blacklisted(&caller);
+        let (token, amount) = self.receive_fungible_payment();
+        require!(
+            self.whitelisted_tokens().contains(&token),
+            ERROR_TOKEN_NOT_WHITELISTED
+        );
+
+        self.user_credit(&caller, &token).update(|credit| *credit += &amount);
+        self.total_credit(&token).update(|total| *total += &amount);
+
+        self.credit_deposited_event(&caller, &token, &amount);
+    }
+
+    /// Withdraw unused credit back to the caller
+    /// Never blocked by any pause level
+    #[endpoint(withdrawCredit)]
+    fn withdraw_credit(&self, token: TokenIdentifier, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        require!(amount > 0u64, ERROR_INVALID_AMOUNT);
+        self.require_not_blacklisted(&caller);
+
+        self.debit_credit(&caller, &token, &amount);
+        self.send().direct_esdt(&caller, &token, 0, &amount);
+
+        self.credit_withdrawn_event(&caller, &token, &amount);
+    }
+
+    /// Create a limit order funded from the caller's credit
+    ///
+    /// Same as `createLimitOrder`, without a token transfer; refunds on
+    /// cancellation or expiry go to the wallet, not back to credit
+    ///
+    /// # Arguments
+    /// * `from_token` - Credited token to sell
+    /// * `from_amount` - Amount of credit to escrow
+    /// * `to_token` - Token to buy
+    /// * `target_price_num` - Target price numerator
+    /// * `target_price_denom` - Target price denominator
+    /// * `slippage_bp` - Slippage tolerance in basis points
+    /// * `expires_in_seconds` - How long until order expires
+    #[allow(clippy::too_many_arguments)]
+    #[endpoint(createLimitOrderFromCredit)]
+    fn create_limit_order_from_credit(
+        &self,
+        from_token: TokenIdentifier,
+        from_amount: BigUint,
+        to_token: TokenIdentifier,
+        target_price_num: BigUint,
+        target_price_denom: BigUint,
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+    ) -> u64 {
+        self.require_creation_allowed();
+
+        let caller = self.blockchain().get_caller();
+        self.require_not_blacklisted(&caller);
+
+        self.debit_credit(&caller, &from_token, &from_amount);
+        self.lock_escrow(&from_token, &from_amount);
+
+        self.create_order(
+            &caller,
+            from_token,
+            from_amount,
+            to_token,
+            target_price_num,
+            target_price_denom,
+            slippage_bp,
+            expires_in_seconds,
+        )
+    }
+
+    /// Create a limit order for `user` from their credit (approved managers only)
+    ///
+    /// The amount counts against the manager's daily cap for `from_token`
+    /// and the manager's order rate; the order belongs to `user`
+    ///
+    /// # Arguments
+    /// * `user` - Owner of the new order
+    /// * `from_token` - Credited token to sell
+    /// * `from_amount` - Amount of credit to escrow
+    /// * `to_token` - Token to buy
+    /// * `target_price_num` - Target price numerator
+    /// * `target_price_denom` - Target price denominator
+    /// * `slippage_bp` - Slippage tolerance in basis points
+    /// * `expires_in_seconds` - How long until order expires
+    #[allow(clippy::too_many_arguments)]
+    #[endpoint(createLimitOrderAsManager)]
+    fn create_limit_order_as_manager(
+        &self,
+        user: ManagedAddress,
+        from_token: TokenIdentifier,
+        from_amount: BigUint,
+        to_token: TokenIdentifier,
+        target_price_num: BigUint,
+        target_price_denom: BigUint,
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+    ) -> u64 {
+        self.require_creation_allowed();
+
+        let caller = self.blockchain().get_caller();
+        self.require_not_blacklisted(&user);
+        self.require_not_blacklisted(&caller);
+        self.spend_manager_allowance(&user, &caller, &from_token, &from_amount);
+
+        self.debit_credit(&user, &from_token, &from_amount);
+        self.lock_escrow(&from_token, &from_amount);
+
+        self.create_order(
+            &user,
+            from_token,
+            from_amount,
+            to_token,
+            target_price_num,
+            target_price_denom,
+            slippage_bp,
+            expires_in_seconds,
+        )
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    /// Take `amount` out of a user's credit (and the per-token total)
+    fn debit_credit(&self, user: &ManagedAddress, token: &TokenIdentifier, amount: &BigUint) {
+        let credit_mapper = self.user_credit(user, token);
+        let credit = credit_mapper.get();
+        require!(credit >= *amount, ERROR_INSUFFICIENT_CREDIT);
+
+        credit_mapper.set(&(credit - amount));
+        self.total_credit(token).update(|total| *total -= amount);
+    }
+
+    // ========== STORAGE ==========
+
+    /// Deposited credit per user and token
+    #[view(getUserCredit)]
+    #[storage_mapper("userCredit")]
+    fn user_credit(
+        &self,
+        user: &ManagedAddress,
+        token: &TokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+}
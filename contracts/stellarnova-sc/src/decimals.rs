@@ -0,0 +1,93 @@
+//! Decimals Module for StellarNova
+//!
+//! Registry of token decimals, set by the owner or fetched from the ESDT
+//! system contract (`fetchTokenDecimals`, in limit_orders with the other
+//! callbacks), and views converting between the raw `num / denom`
+//! order price (base units of the to token per base unit of the from token)
+//! and a human price: whole to tokens per whole from token, scaled by 1e18.
+
+multiversx_sc::imports!();
+
+use crate::errors::*;
+use crate::limit_orders::PRICE_SCALE;
+
+/// ESDT tokens have at most 18 decimals
+pub const MAX_TOKEN_DECIMALS: u8 = 18;
+
+#[multiversx_sc::module]
+pub trait DecimalsModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+{
+    /// Set the decimals of a whitelisted token (owner only)
+    #[only_owner]
+    #[endpoint(setTokenDecimals)]
+    fn set_token_decimals(&self, token: TokenIdentifier, decimals: u8) {
+        require!(
+            self.whitelisted_tokens().contains(&token),
+            ERROR_NOT_WHITELISTED
+        );
+        self.store_token_decimals(&token, decimals);
+    }
+
+    /// Human price (1e18-scaled whole to tokens per whole from token) of a
+    /// raw `price_num / price_denom` order price, rounded down
+    #[view(toHumanPrice)]
+    fn to_human_price(
+        &self,
+        from_token: TokenIdentifier,
+        to_token: TokenIdentifier,
+        price_num: BigUint,
+        price_denom: BigUint,
+    ) -> BigUint {
+        require!(price_denom > 0u64, ERROR_PRICE_DENOM_ZERO);
+        let from_unit = self.token_unit(&from_token);
+        let to_unit = self.token_unit(&to_token);
+        price_num * from_unit * PRICE_SCALE / (price_denom * to_unit)
+    }
+
+    /// Raw `(price_num, price_denom)` order price for a human price
+    /// (1e18-scaled whole to tokens per whole from token)
+    #[view(fromHumanPrice)]
+    fn from_human_price(
+        &self,
+        from_token: TokenIdentifier,
+        to_token: TokenIdentifier,
+        human_price: BigUint,
+    ) -> MultiValue2<BigUint, BigUint> {
+        self.human_to_raw_price(&from_token, &to_token, human_price)
+            .into()
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    fn human_to_raw_price(
+        &self,
+        from_token: &TokenIdentifier,
+        to_token: &TokenIdentifier,
+        human_price: BigUint,
+    ) -> (BigUint, BigUint) {
+        let from_unit = self.token_unit(from_token);
+        let to_unit = self.token_unit(to_token);
+        (human_price * to_unit, from_unit * PRICE_SCALE)
+    }
+
+    /// Record the decimals of `token`
+    fn store_token_decimals(&self, token: &TokenIdentifier, decimals: u8) {
+        require!(decimals <= MAX_TOKEN_DECIMALS, ERROR_DECIMALS_TOO_HIGH);
+        self.token_decimals(token).set(decimals);
+        self.token_decimals_set_event(token, decimals);
+    }
+
+    /// Base units in one whole token (10^decimals)
+    fn token_unit(&self, token: &TokenIdentifier) -> BigUint {
+        require!(!self.token_decimals(token).is_empty(), ERROR_DECIMALS_NOT_SET);
+        BigUint::from(10u64).pow(self.token_decimals(token).get() as u32)
+    }
+
+    // ========== STORAGE ==========
+
+    #[view(getTokenDecimals)]
+    #[storage_mapper("tokenDecimals")]
+    fn token_decimals(&self, token: &TokenIdentifier) -> SingleValueMapper<u8>;
+}
@@ -1,13 +1,290 @@
-/// DEX Integration Module for StellarNova
-///
-/// This module is kept for future extensibility
-/// Currently, swap logic is directly in limit_orders module
+//! DEX Integration Module for StellarNova
+//!
+//! Swap logic is directly in limit_orders module; this module picks the pair
+//! an order swaps on and holds the pre-swap pair health check. An order uses
+//! the pair set for its from token, else the one set for its to token
+//! (`setTokenPair`), else the default `xExchangePair`. When enabled, an execution first queries the
+//! xExchange pair (synchronously, so the pair must be on the contract's shard):
+//! the pair must be active and its reserve of the input token must be at least
+//! `minReserveMultiple` times the fill. Unhealthy pairs skip the swap with an
+//! event instead of burning async gas on a swap that would fail.
+//!
+//! Min output: by default the swap's minimum output is the order's target
+//! price minus its slippage, which can sit far below the market. In
+//! `LiveQuote` mode the pair's current quote minus the order's slippage is
+//! used instead whenever it is higher (same-shard synchronous query), so a
+//! sandwich can only move the price by the order's slippage. Every execution
+//! emits `execution_min_output` with the bound that applied.
+//!
+//! Venues: swaps call `swapTokensFixedInput(tokenOut, minAmountOut)` unless
+//! the pair has a swap descriptor (`setSwapDescriptor`, timelocked) naming
+//! another function and its argument layout, so pools with a different swap
+//! endpoint (stable, concentrated liquidity) need no code change. The swap
+//! must still pay `tokenOut` back to the contract. Exact-output orders always
+//! call `swapTokensFixedOutput(tokenOut, amountOut)`, which pays the unused
+//! input back with the output.
+//!
+//! `quoteRoute` chains `getAmountOut` over a path of tokens, each hop on the
+//! pair `getSwapPair` picks for it, so candidate routes are compared with the
+//! same pairs and quotes execution uses.
+//!
+//! Routed orders (`createRoutedOrder`) swap A→B→C in a single
+//! `multiPairSwap` call to the xExchange router (`setXExchangeRouter`,
+//! timelocked), each leg on the pair `getSwapPair` picks for it. The first
+//! leg accepts any output; the order's minimum output applies to the final
+//! token only, and the contract never holds the intermediate token.
 
 multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::errors::*;
+
+/// xExchange pair state `Active` (`Inactive` = 0, `PartialActive` = 2)
+const PAIR_STATE_ACTIVE: u8 = 1;
+
+/// Swap endpoint of pairs without a descriptor
+const DEFAULT_SWAP_FUNCTION: &[u8] = b"swapTokensFixedInput";
+
+/// Router endpoint swapping along several pairs in one call
+const MULTI_PAIR_SWAP_FUNCTION: &[u8] = b"multiPairSwap";
+
+/// Swap endpoint of exact-output orders
+const EXACT_OUTPUT_SWAP_FUNCTION: &[u8] = b"swapTokensFixedOutput";
+
+/// Why a pair failed the health check
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PairHealthIssue {
+    Inactive,
+    LowReserves,
+}
+
+/// How the minimum output of an execution's swap is derived
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MinOutputMode {
+    /// Target price minus slippage
+    TargetPrice,
+    /// The higher of the target price and the pair's live quote, minus slippage
+    LiveQuote,
+}
+
+/// Value passed in one argument slot of a venue's swap call
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Copy, PartialEq, Eq, Debug,
+)]
+pub enum SwapArgument {
+    TokenOut,
+    MinAmountOut,
+    TokenIn,
+    AmountIn,
+}
+
+/// How to call a venue's swap: endpoint name and argument layout
+/// (the input tokens are always sent as the call's payment)
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct SwapCallDescriptor<M: ManagedTypeApi> {
+    pub function: ManagedBuffer<M>,
+    pub arguments: ManagedVec<M, SwapArgument>,
+}
+
+/// Which floor set an execution's minimum output
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MinOutputBound {
+    /// Target price minus slippage
+    TargetPrice,
+    /// Live quote minus slippage, above the target-price floor
+    LiveQuote,
+    /// Exact output of the order, grossed up by the fees
+    ExactOutput,
+}
 
 #[multiversx_sc::module]
 pub trait DexModule:
     crate::storage::StorageModule
+    + crate::events::EventsModule
 {
-    // Module reserved for future DEX integrations
+    /// Require the pair to be active and to hold at least `multiple` times the
+    /// fill size of the input token before swapping (owner only, 0 disables)
+    #[only_owner]
+    #[endpoint(setPairHealthCheck)]
+    fn set_pair_health_check(&self, min_reserve_multiple: u64) {
+        self.min_reserve_multiple().set(min_reserve_multiple);
+        self.pair_health_check_changed_event(min_reserve_multiple);
+    }
+
+    /// Choose how the swap's minimum output is derived (owner only)
+    #[only_owner]
+    #[endpoint(setMinOutputMode)]
+    fn set_min_output_mode(&self, mode: MinOutputMode) {
+        self.min_output_mode().set(mode);
+        self.min_output_mode_changed_event(mode);
+    }
+
+    /// Pair that swaps `from_token` for `to_token`
+    #[view(getSwapPair)]
+    fn swap_pair(&self, from_token: &TokenIdentifier, to_token: &TokenIdentifier) -> ManagedAddress {
+        for token in [from_token, to_token] {
+            if !self.token_pair(token).is_empty() {
+                return self.token_pair(token).get();
+            }
+        }
+        self.xexchange_pair().get()
+    }
+
+    /// How swaps on `pair` are called (the xExchange layout by default)
+    #[view(getSwapDescriptor)]
+    fn swap_call_descriptor(&self, pair: &ManagedAddress) -> SwapCallDescriptor<Self::Api> {
+        if !self.swap_descriptor(pair).is_empty() {
+            return self.swap_descriptor(pair).get();
+        }
+
+        let mut arguments = ManagedVec::new();
+        arguments.push(SwapArgument::TokenOut);
+        arguments.push(SwapArgument::MinAmountOut);
+        SwapCallDescriptor {
+            function: ManagedBuffer::from(DEFAULT_SWAP_FUNCTION),
+            arguments,
+        }
+    }
+
+    /// Quote swapping `amount_in` of the first token of `route` along it
+    /// Returns the output of every hop, the last one being the route's output
+    #[view(quoteRoute)]
+    fn quote_route(
+        &self,
+        route: ManagedVec<TokenIdentifier>,
+        amount_in: BigUint,
+    ) -> MultiValueEncoded<BigUint> {
+        require!(route.len() >= 2, ERROR_ROUTE_TOO_SHORT);
+        require!(amount_in > 0u64, ERROR_INVALID_AMOUNT);
+
+        let mut amounts = MultiValueEncoded::new();
+        let mut amount = amount_in;
+        for hop in 1..route.len() {
+            let token_in = route.get(hop - 1);
+            let token_out = route.get(hop);
+            require!(*token_in != *token_out, ERROR_SAME_TOKEN);
+
+            let pair_address = self.swap_pair(&token_in, &token_out);
+            amount = self.quote_amount_out(&pair_address, &token_in, &amount);
+            amounts.push(amount.clone());
+        }
+        amounts
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    /// Swap call of exact-output orders: `swapTokensFixedOutput(tokenOut, amountOut)`,
+    /// with the exact output passed in the min output slot
+    fn exact_output_call_descriptor(&self) -> SwapCallDescriptor<Self::Api> {
+        let mut arguments = ManagedVec::new();
+        arguments.push(SwapArgument::TokenOut);
+        arguments.push(SwapArgument::MinAmountOut);
+        SwapCallDescriptor {
+            function: ManagedBuffer::from(EXACT_OUTPUT_SWAP_FUNCTION),
+            arguments,
+        }
+    }
+
+    /// Router call swapping `from_token` to `to_token` through `via_token`:
+    /// `multiPairSwap` with one `(pair, function, tokenOut, minAmountOut)`
+    /// operation per leg, only the last leg bounded by `min_amount_out`
+    fn routed_swap_call(
+        &self,
+        from_token: &TokenIdentifier,
+        via_token: &TokenIdentifier,
+        to_token: &TokenIdentifier,
+        min_amount_out: &BigUint,
+    ) -> (ManagedBuffer, ManagedArgBuffer<Self::Api>) {
+        let mut arguments = ManagedArgBuffer::new();
+        arguments.push_arg(self.swap_pair(from_token, via_token));
+        arguments.push_arg(ManagedBuffer::<Self::Api>::from(DEFAULT_SWAP_FUNCTION));
+        arguments.push_arg(via_token);
+        arguments.push_arg(BigUint::<Self::Api>::from(1u64));
+        arguments.push_arg(self.swap_pair(via_token, to_token));
+        arguments.push_arg(ManagedBuffer::<Self::Api>::from(DEFAULT_SWAP_FUNCTION));
+        arguments.push_arg(to_token);
+        arguments.push_arg(min_amount_out);
+        (ManagedBuffer::from(MULTI_PAIR_SWAP_FUNCTION), arguments)
+    }
+
+    /// Check `pair_address` before swapping `amount_in` of `token_in`
+    /// Emits `pair_health_check_failed` and returns false when unhealthy
+    fn check_pair_health(
+        &self,
+        order_id: u64,
+        pair_address: &ManagedAddress,
+        token_in: &TokenIdentifier,
+        amount_in: &BigUint,
+    ) -> bool {
+        let multiple = self.min_reserve_multiple().get();
+        if multiple == 0 {
+            return true;
+        }
+
+        let state: u8 = self
+            .tx()
+            .to(pair_address)
+            .raw_call("getState")
+            .original_result::<u8>()
+            .returns(ReturnsResult)
+            .sync_call_readonly();
+        if state != PAIR_STATE_ACTIVE {
+            self.pair_health_check_failed_event(order_id, PairHealthIssue::Inactive);
+            return false;
+        }
+
+        let reserve: BigUint = self
+            .tx()
+            .to(pair_address)
+            .raw_call("getReserve")
+            .argument(token_in)
+            .original_result::<BigUint>()
+            .returns(ReturnsResult)
+            .sync_call_readonly();
+        if reserve < amount_in * multiple {
+            self.pair_health_check_failed_event(order_id, PairHealthIssue::LowReserves);
+            return false;
+        }
+
+        true
+    }
+
+    /// Output of swapping `amount_in` of `token_in` on `pair_address` now
+    /// (synchronous query, so the pair must be on the contract's shard)
+    fn quote_amount_out(
+        &self,
+        pair_address: &ManagedAddress,
+        token_in: &TokenIdentifier,
+        amount_in: &BigUint,
+    ) -> BigUint {
+        self.tx()
+            .to(pair_address)
+            .raw_call("getAmountOut")
+            .argument(token_in)
+            .argument(amount_in)
+            .original_result::<BigUint>()
+            .returns(ReturnsResult)
+            .sync_call_readonly()
+    }
+
+    // ========== STORAGE ==========
+
+    /// Minimum pair reserve of the input token, as a multiple of the fill
+    #[view(getMinReserveMultiple)]
+    #[storage_mapper("minReserveMultiple")]
+    fn min_reserve_multiple(&self) -> SingleValueMapper<u64>;
+
+    /// Swap call of pairs whose endpoint differs from xExchange's
+    #[storage_mapper("swapDescriptor")]
+    fn swap_descriptor(&self, pair: &ManagedAddress) -> SingleValueMapper<SwapCallDescriptor<Self::Api>>;
+
+    /// Source of the swap's minimum output (empty storage decodes as TargetPrice)
+    #[view(getMinOutputMode)]
+    #[storage_mapper("minOutputMode")]
+    fn min_output_mode(&self) -> SingleValueMapper<MinOutputMode>;
 }
@@ -1,15 +1,208 @@
-/// Custom error messages for StellarNova
-/// All errors are explicit and user-friendly for debugging
-
-pub const ERROR_NOT_OWNER: &str = "Only contract owner can call this function";
-pub const ERROR_CONTRACT_PAUSED: &str = "Contract is paused";
-pub const ERROR_TOKEN_NOT_WHITELISTED: &str = "Token is not whitelisted for trading";
-pub const ERROR_INSUFFICIENT_BALANCE: &str = "Insufficient balance in vault";
-pub const ERROR_INVALID_AMOUNT: &str = "Amount must be greater than zero";
-pub const ERROR_SAME_TOKEN: &str = "Cannot swap token to itself";
-pub const ERROR_NO_PAYMENT: &str = "No payment received";
-pub const ERROR_INVALID_TOKEN: &str = "Invalid token sent";
-pub const ERROR_SLIPPAGE_TOO_HIGH: &str = "Slippage tolerance exceeded";
-pub const ERROR_SWAP_FAILED: &str = "DEX swap execution failed";
-pub const ERROR_ALREADY_WHITELISTED: &str = "Token already whitelisted";
-pub const ERROR_NOT_WHITELISTED: &str = "Token not in whitelist";
+//! Custom error messages for StellarNova
+//! All errors are explicit and user-friendly for debugging
+//!
+//! Every message starts with a stable code (`E<nnn>: `) so SDK clients can
+//! match errors programmatically. Codes are never reused or renumbered.
+//! - E1xx: access control, order managers and session keys
+//! - E2xx: pause and compliance
+//! - E3xx: tokens and payments
+//! - E4xx: order parameters
+//! - E5xx: order state and execution
+//! - E6xx: admin configuration, timelock and treasury
+//! - E7xx: upgrades
+//! - E8xx: strategies and clients
+//! - E9xx: governance and staking
+
+// ========== ACCESS CONTROL ==========
+
+pub const ERROR_ONLY_EXECUTOR: &str = "E101: Only executor can execute orders";
+pub const ERROR_NOT_YOUR_ORDER: &str = "E102: Not your order";
+pub const ERROR_SELF_MANAGER: &str = "E103: Cannot approve yourself as manager";
+pub const ERROR_MANAGER_ALREADY_APPROVED: &str = "E104: Manager already approved";
+pub const ERROR_MANAGER_NOT_APPROVED: &str = "E105: Manager not approved";
+pub const ERROR_INVALID_SESSION_PERMISSIONS: &str = "E106: Invalid session key permissions";
+pub const ERROR_SESSION_KEY_NOT_FOUND: &str = "E107: Session key not found";
+pub const ERROR_SESSION_KEY_EXPIRED: &str = "E108: Session key expired";
+pub const ERROR_SESSION_PERMISSION_DENIED: &str = "E109: Session key lacks permission";
+pub const ERROR_SESSION_WRONG_TOKEN: &str = "E110: Session key cannot spend this token";
+pub const ERROR_SESSION_CAP_EXCEEDED: &str = "E111: Session key spend cap exceeded";
+pub const ERROR_INVALID_RELAY_NONCE: &str = "E112: Invalid relay nonce";
+pub const ERROR_ORDER_TOKENIZED: &str = "E113: Order is controlled by its position token";
+pub const ERROR_ONLY_SIGNAL_ORACLE: &str = "E114: Only the signal oracle can fire signals";
+pub const ERROR_MANAGER_NO_CAP: &str = "E115: Manager has no spend cap for this token";
+pub const ERROR_MANAGER_CAP_EXCEEDED: &str = "E116: Manager daily spend cap exceeded";
+pub const ERROR_MANAGER_RATE_LIMITED: &str = "E117: Manager order rate limit reached";
+pub const ERROR_NOT_MARKET_MAKER: &str = "E118: Caller is not an approved market maker";
+pub const ERROR_MARKET_MAKER_ALREADY_APPROVED: &str = "E119: Market maker already approved";
+pub const ERROR_ONLY_PRICE_ORACLE: &str = "E120: Only the price oracle can post prices";
+pub const ERROR_EXECUTOR_VOLUME_EXCEEDED: &str = "E121: Executor daily volume limit reached for this token";
+pub const ERROR_NOT_AUCTION_OPENER: &str = "E122: Only the executor or the order owner can auction an order";
+
+// ========== PAUSE & COMPLIANCE ==========
+
+pub const ERROR_CREATION_PAUSED: &str = "E201: Order creation is paused";
+pub const ERROR_EXECUTION_PAUSED: &str = "E202: Order execution is paused";
+pub const ERROR_ADDRESS_BLACKLISTED: &str = "E203: Address is blacklisted";
+pub const ERROR_ALREADY_BLACKLISTED: &str = "E204: Address already blacklisted";
+pub const ERROR_NOT_BLACKLISTED: &str = "E205: Address not blacklisted";
+pub const ERROR_WIND_DOWN_NOT_ACTIVE: &str = "E206: Wind-down is not active";
+pub const ERROR_PAIR_PAUSED: &str = "E207: Executions on this pair are paused";
+
+// ========== TOKENS & PAYMENTS ==========
+
+pub const ERROR_TOKEN_NOT_WHITELISTED: &str = "E301: Token is not whitelisted for trading";
+pub const ERROR_ALREADY_WHITELISTED: &str = "E302: Token already whitelisted";
+pub const ERROR_NOT_WHITELISTED: &str = "E303: Token not in whitelist";
+pub const ERROR_FROM_TOKEN_NOT_WHITELISTED: &str = "E304: From token not whitelisted";
+pub const ERROR_TO_TOKEN_NOT_WHITELISTED: &str = "E305: To token not whitelisted";
+pub const ERROR_SAME_TOKEN: &str = "E306: Cannot swap token to itself";
+pub const ERROR_INVALID_AMOUNT: &str = "E307: Amount must be greater than zero";
+pub const ERROR_NON_FUNGIBLE_PAYMENT: &str =
+    "E308: Only fungible ESDT payments (nonce 0) are supported, SFT/MetaESDT tokens cannot be escrowed";
+pub const ERROR_TOKEN_DELISTED: &str = "E309: Order token is no longer whitelisted";
+pub const ERROR_INSUFFICIENT_CREDIT: &str = "E310: Insufficient credit";
+pub const ERROR_TOKEN_STILL_WHITELISTED: &str = "E311: Token must be delisted first";
+pub const ERROR_ZAP_TOKEN_NOT_IN_PAIR: &str = "E312: Zap orders must trade a token of the configured pair";
+pub const ERROR_TOKEN_AWAITING_PAIR: &str = "E313: Token has no pair yet";
+pub const ERROR_DECIMALS_NOT_SET: &str = "E314: Token decimals not set";
+pub const ERROR_DECIMALS_TOO_HIGH: &str = "E315: Token decimals cannot exceed 18";
+
+// ========== ORDER PARAMETERS ==========
+
+pub const ERROR_PRICE_NUM_ZERO: &str = "E401: Target price numerator must be positive";
+pub const ERROR_PRICE_DENOM_ZERO: &str = "E402: Target price denominator must be positive";
+pub const ERROR_SLIPPAGE_ABOVE_100_PERCENT: &str = "E403: Slippage cannot exceed 10000 bps";
+pub const ERROR_SLIPPAGE_ABOVE_MAX: &str = "E404: Slippage exceeds maximum allowed";
+pub const ERROR_FILL_EXCEEDS_REMAINING: &str = "E405: Fill amount exceeds remaining order amount";
+pub const ERROR_STREAM_TOO_LONG: &str = "E406: Payout stream duration too long";
+pub const ERROR_PRICE_PAIR_NOT_REGISTERED: &str = "E407: Price pair is not registered";
+pub const ERROR_EXPIRY_TOO_FAR: &str = "E408: Expiry is too far in the future";
+pub const ERROR_ORDER_TOO_LARGE: &str = "E409: Order amount exceeds the token's max order size";
+pub const ERROR_OPEN_INTEREST_CAP: &str = "E410: Token open interest cap reached";
+pub const ERROR_ROUTE_TOO_SHORT: &str = "E411: Route needs at least two tokens";
+pub const ERROR_CONFIDENCE_ABOVE_100_PERCENT: &str = "E412: Intent confidence cannot exceed 10000 bps";
+pub const ERROR_ROUTER_NOT_SET: &str = "E413: No swap router is set";
+pub const ERROR_ALREADY_CHAINED: &str = "E414: Order already has a chained order";
+pub const ERROR_ZAP_ORDER_NOT_CHAINABLE: &str = "E415: Zap orders cannot fund a chained order";
+pub const ERROR_NOT_STOP_LOSS: &str = "E416: Order is not a stop-loss awaiting its trigger";
+pub const ERROR_DESTINATION_NOT_WHITELISTED: &str = "E417: Forward destination is not whitelisted";
+pub const ERROR_INVALID_FORWARD_CALL: &str = "E418: Forward call needs a function and at most 8 arguments";
+pub const ERROR_DUPLICATE_REFERENCE: &str = "E419: Order reference already used by this user";
+
+// ========== ORDER STATE & EXECUTION ==========
+
+pub const ERROR_ORDER_NOT_FOUND: &str = "E501: Order not found";
+pub const ERROR_ORDER_NOT_PENDING: &str = "E502: Order is not pending";
+pub const ERROR_ORDER_EXPIRED: &str = "E503: Order expired";
+pub const ERROR_PRICE_NOT_MET: &str = "E504: Price condition not met";
+pub const ERROR_EXECUTION_IN_PROGRESS: &str = "E505: Order execution in progress";
+pub const ERROR_CONTEXT_NOT_FOUND: &str = "E506: Execution context not found";
+pub const ERROR_OUTPUT_BELOW_MINIMUM: &str = "E507: Swap output below minimum";
+pub const ERROR_POSITION_NOT_FOUND: &str = "E508: Unknown position token";
+pub const ERROR_SIGNAL_NOT_FIRED: &str = "E509: Order signal has not fired yet";
+pub const ERROR_SIGNAL_ALREADY_FIRED: &str = "E510: Signal already fired";
+pub const ERROR_STRATEGY_PAUSED: &str = "E511: Order strategy is paused";
+pub const ERROR_ORDER_TOO_YOUNG_TO_CANCEL: &str = "E512: Order cannot be cancelled this soon after creation";
+pub const ERROR_ORDERS_NOT_OPPOSITE: &str = "E513: Orders are not on opposite sides of the same pair";
+pub const ERROR_PRICES_DO_NOT_CROSS: &str = "E514: Order prices do not cross";
+pub const ERROR_QUOTE_NOT_FOUND: &str = "E515: Quote not found";
+pub const ERROR_QUOTE_EXPIRED: &str = "E516: Quote expired";
+pub const ERROR_QUOTE_MISMATCH: &str = "E517: Quote does not match the order tokens";
+pub const ERROR_QUOTE_INSUFFICIENT: &str = "E518: Quote deposit too small for this fill";
+pub const ERROR_ZAP_ORDER_POOL_ONLY: &str = "E519: Zap orders only execute through the pool";
+pub const ERROR_ESCROW_ALREADY_LENT: &str = "E520: Order escrow is already lent";
+pub const ERROR_ESCROW_LENT: &str = "E521: Order escrow is lent out, redeem it first";
+pub const ERROR_ESCROW_NOT_LENT: &str = "E522: Order escrow is not lent";
+pub const ERROR_STREAM_NOT_FOUND: &str = "E523: No payout stream for this order";
+pub const ERROR_CONDITION_NOT_MET: &str = "E524: Order price condition not met";
+pub const ERROR_CONDITION_PRICE_STALE: &str = "E525: No fresh oracle price for the order condition";
+pub const ERROR_ORDER_DORMANT: &str = "E526: Order is dormant until its token pair is set";
+pub const ERROR_PAIR_TRIPPED: &str = "E527: Executions on this pair are halted by the circuit breaker";
+pub const ERROR_SWAP_INPUT_RETURNED: &str = "E528: Swap returned the whole input";
+pub const ERROR_EXACT_OUTPUT_FULL_FILL: &str = "E529: Exact-output orders only execute in full";
+pub const ERROR_EXACT_OUTPUT_POOL_ONLY: &str = "E530: Exact-output orders only execute through the pool";
+pub const ERROR_SELF_TRADE: &str = "E531: Both sides of this trade belong to the same address";
+pub const ERROR_PRICE_NOT_REVEALED: &str = "E532: Order price is committed, reveal it with executeCommittedOrder";
+pub const ERROR_NO_PRICE_COMMITMENT: &str = "E533: Order has no price commitment";
+pub const ERROR_PRICE_COMMITMENT_MISMATCH: &str = "E534: Revealed price does not match the commitment";
+pub const ERROR_EXECUTION_AUCTION_OPEN: &str = "E535: Execution rights of this order are being auctioned";
+pub const ERROR_AUCTION_NOT_ELIGIBLE: &str = "E536: Order is not eligible for an execution auction";
+pub const ERROR_AUCTION_ALREADY_OPEN: &str = "E537: Order execution rights are already auctioned";
+pub const ERROR_AUCTION_NOT_OPEN: &str = "E538: No execution auction open for bids on this order";
+pub const ERROR_BID_NOT_LOWER: &str = "E539: Bid must undercut the best bid";
+pub const ERROR_RETRY_BACKOFF: &str = "E540: Order is backing off after a failed execution";
+
+// ========== ADMIN CONFIGURATION ==========
+
+pub const ERROR_MAX_SLIPPAGE_ABOVE_100_PERCENT: &str = "E601: Max slippage cannot exceed 10000 bps";
+pub const ERROR_FEE_TOO_HIGH: &str = "E602: Fee too high (max 5%)";
+pub const ERROR_PROTOCOL_FEE_TOO_HIGH: &str = "E603: Protocol fee too high (max 5%)";
+pub const ERROR_BOUNTY_TOO_HIGH: &str = "E604: Bounty too high (max 1%)";
+pub const ERROR_MAX_ATTEMPTS_ZERO: &str = "E605: Max attempts must be positive";
+pub const ERROR_PAIR_NOT_CONTRACT: &str = "E606: Pair must be a smart contract";
+pub const ERROR_EXECUTOR_ZERO: &str = "E607: Executor cannot be the zero address";
+pub const ERROR_TREASURY_ZERO: &str = "E608: Treasury cannot be the zero address";
+pub const ERROR_TIMELOCK_DELAY_TOO_LONG: &str = "E609: Timelock delay too long (max 30 days)";
+pub const ERROR_ACTION_NOT_FOUND: &str = "E610: Queued action not found";
+pub const ERROR_TIMELOCK_NOT_ELAPSED: &str = "E611: Timelock delay has not elapsed";
+pub const ERROR_EXCEEDS_SURPLUS: &str = "E612: Amount exceeds withdrawable surplus";
+pub const ERROR_NO_PROTOCOL_FEES: &str = "E613: No protocol fees to claim";
+pub const ERROR_POSITION_TOKEN_ALREADY_SET: &str = "E614: Position token already set";
+pub const ERROR_POSITION_TOKEN_NOT_SET: &str = "E615: Position token not set";
+pub const ERROR_ORACLE_ZERO: &str = "E616: Signal oracle cannot be the zero address";
+pub const ERROR_CANCELLATION_FEE_TOO_HIGH: &str = "E617: Cancellation fee too high";
+pub const ERROR_CANCELLATION_WINDOW_TOO_LONG: &str = "E618: Cancellation window too long";
+pub const ERROR_ZAP_NOT_CONFIGURED: &str = "E619: Zap orders are not configured";
+pub const ERROR_LENDING_NOT_CONFIGURED: &str = "E620: No lending market for this token";
+pub const ERROR_MARKET_NOT_CONTRACT: &str = "E621: Lending market must be a smart contract";
+pub const ERROR_PRICE_PAIR_ALREADY_REGISTERED: &str = "E622: Price pair already registered";
+pub const ERROR_BREAKER_NOT_TRIPPED: &str = "E623: Circuit breaker is not tripped for this pair";
+pub const ERROR_STAKING_SHARE_TOO_HIGH: &str = "E624: Staking fee share cannot exceed 10000 bps";
+pub const ERROR_INSURANCE_FEE_TOO_HIGH: &str = "E625: Insurance fee share cannot exceed 5000 bps";
+pub const ERROR_COMPENSATION_CAP: &str = "E626: Compensation exceeds the cap for this token";
+pub const ERROR_INSURANCE_FUND_INSUFFICIENT: &str = "E627: Insurance fund is insufficient";
+pub const ERROR_CLIENT_SHARE_TOO_HIGH: &str = "E628: Client fee share cannot exceed 10000 bps";
+pub const ERROR_TREASURY_TOKEN_NOT_SET: &str = "E629: Treasury token not set";
+pub const ERROR_IMPROVEMENT_SHARES_TOO_HIGH: &str = "E630: Price improvement shares cannot exceed 10000 bps";
+pub const ERROR_DESTINATION_NOT_CONTRACT: &str = "E631: Forward destination must be a smart contract";
+pub const ERROR_NO_SURPLUS: &str = "E632: No surplus balance to sweep";
+
+// ========== UPGRADES ==========
+
+pub const ERROR_VERSION_DOWNGRADE: &str = "E701: Cannot downgrade contract version";
+pub const ERROR_STORAGE_VERSION_TOO_NEW: &str = "E702: Stored data is newer than this code";
+pub const ERROR_UNKNOWN_MIGRATION: &str = "E703: No migration registered for storage version";
+pub const ERROR_NO_MIGRATION_PENDING: &str = "E704: No order migration pending";
+
+// ========== STRATEGIES & CLIENTS ==========
+
+pub const ERROR_STRATEGY_NOT_FOUND: &str = "E801: Strategy not found";
+pub const ERROR_NOT_STRATEGY_OWNER: &str = "E802: Not the strategy owner";
+pub const ERROR_STRATEGY_INACTIVE: &str = "E803: Strategy is not active";
+pub const ERROR_CLIENT_NOT_FOUND: &str = "E804: Client not found";
+pub const ERROR_NOT_CLIENT_ADMIN: &str = "E805: Not the client payout address or owner";
+pub const ERROR_CLIENT_INACTIVE: &str = "E806: Client is not active";
+pub const ERROR_NO_CLIENT_FEES: &str = "E807: No client fees to claim";
+pub const ERROR_REBATE_TOO_HIGH: &str = "E808: Strategy rebate cannot exceed 10000 bps";
+pub const ERROR_REBATE_POOL_INSUFFICIENT: &str = "E809: Strategy rebate pool is too small";
+
+// ========== GOVERNANCE AND STAKING ==========
+
+pub const ERROR_GOVERNANCE_NOT_CONFIGURED: &str = "E901: Governance is not configured";
+pub const ERROR_GOVERNANCE_TOKEN_LOCKED: &str = "E902: Governance token cannot change";
+pub const ERROR_WRONG_GOVERNANCE_TOKEN: &str = "E903: Payment must be the governance token";
+pub const ERROR_BELOW_PROPOSAL_THRESHOLD: &str = "E904: Deposit below the proposal threshold";
+pub const ERROR_ACTION_NOT_GOVERNABLE: &str = "E905: Action cannot be proposed by governance";
+pub const ERROR_PROPOSAL_NOT_FOUND: &str = "E906: Proposal not found";
+pub const ERROR_VOTING_CLOSED: &str = "E907: Voting period has ended";
+pub const ERROR_VOTING_OPEN: &str = "E908: Voting period has not ended";
+pub const ERROR_PROPOSAL_FINALIZED: &str = "E909: Proposal already finalized";
+pub const ERROR_NO_VOTE_DEPOSIT: &str = "E910: No vote deposit to withdraw";
+pub const ERROR_VOTING_PERIOD_ZERO: &str = "E911: Voting period must be positive";
+pub const ERROR_STAKING_NOT_CONFIGURED: &str = "E912: Staking is not configured";
+pub const ERROR_STAKING_TOKEN_LOCKED: &str = "E913: Staking token cannot change";
+pub const ERROR_WRONG_STAKING_TOKEN: &str = "E914: Payment must be the staking token";
+pub const ERROR_INSUFFICIENT_STAKE: &str = "E915: Insufficient stake";
+pub const ERROR_NOTHING_UNBONDING: &str = "E916: No unstaked tokens to withdraw";
+pub const ERROR_STILL_UNBONDING: &str = "E917: Unstaked tokens are still unbonding";
+pub const ERROR_NO_STAKING_REWARDS: &str = "E918: No staking rewards to claim";
@@ -1,7 +1,20 @@
 multiversx_sc::imports!();
 
+use crate::dex::PairHealthIssue;
+use crate::governance::Proposal;
+use crate::limit_orders::{CancelReason, OrderTerms};
+use crate::managers::SessionKey;
+use crate::dex::{MinOutputBound, MinOutputMode};
+use crate::pause::PauseLevel;
+use crate::rfq::Quote;
+use crate::signals::{OraclePrice, PriceCondition};
+use crate::timelock::TimelockAction;
+
 /// Events module for StellarNova
 /// JEXchange-style architecture: only limit order events + admin events
+///
+/// Events with a `version` first topic follow the layout of that contract
+/// version (see `getContractVersion`), so indexers can branch decoding per version
 #[multiversx_sc::module]
 pub trait EventsModule {
 
@@ -21,6 +34,619 @@ pub trait EventsModule {
         #[indexed] token: &TokenIdentifier,
     );
 
+    /// Emitted by upgrade() with the previous and new contract version
+    #[event("contract_upgraded")]
+    fn contract_upgraded_event(
+        &self,
+        #[indexed] old_version: u64,
+        new_version: u64,
+    );
+
+    /// Emitted when a user approves an order manager
+    #[event("order_manager_approved")]
+    fn order_manager_approved_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] manager: &ManagedAddress,
+    );
+
+    /// Emitted when a user revokes an order manager
+    #[event("order_manager_revoked")]
+    fn order_manager_revoked_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] manager: &ManagedAddress,
+    );
+
+    /// Emitted when a user sets a manager's daily cap for a token
+    #[event("manager_daily_cap_set")]
+    fn manager_daily_cap_set_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] manager: &ManagedAddress,
+        #[indexed] token: &TokenIdentifier,
+        cap: &BigUint,
+    );
+
+    /// Emitted when a user sets a manager's order rate limit
+    #[event("manager_order_rate_set")]
+    fn manager_order_rate_set_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] manager: &ManagedAddress,
+        max_orders_per_epoch: u64,
+    );
+
+    /// Emitted when a user grants or replaces a session key
+    #[event("session_key_granted")]
+    fn session_key_granted_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] key: &ManagedAddress,
+        session: &SessionKey<Self::Api>,
+    );
+
+    /// Emitted when a user revokes a session key
+    #[event("session_key_revoked")]
+    fn session_key_revoked_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] key: &ManagedAddress,
+    );
+
+    /// Emitted when a user deposits credit
+    #[event("credit_deposited")]
+    fn credit_deposited_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] token: &TokenIdentifier,
+        amount: &BigUint,
+    );
+
+    /// Emitted when a user withdraws credit
+    #[event("credit_withdrawn")]
+    fn credit_withdrawn_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] token: &TokenIdentifier,
+        amount: &BigUint,
+    );
+
+    /// Emitted when the position NFT collection is registered
+    #[event("position_token_set")]
+    fn position_token_set_event(
+        &self,
+        #[indexed] token: &TokenIdentifier,
+    );
+
+    /// Emitted when an order is tokenized into a position NFT
+    #[event("position_minted")]
+    fn position_minted_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] holder: &ManagedAddress,
+        nonce: u64,
+    );
+
+    /// Emitted when a position holder claims held payouts
+    /// `burned` is true when the order was settled and the NFT burned
+    #[event("position_claimed")]
+    fn position_claimed_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] holder: &ManagedAddress,
+        burned: bool,
+    );
+
+    /// Emitted when a strategy is registered
+    #[event("strategy_registered")]
+    fn strategy_registered_event(
+        &self,
+        #[indexed] strategy_id: u64,
+        #[indexed] owner: &ManagedAddress,
+        metadata_hash: &ManagedBuffer,
+    );
+
+    /// Emitted when a strategy points to new metadata
+    #[event("strategy_updated")]
+    fn strategy_updated_event(
+        &self,
+        #[indexed] strategy_id: u64,
+        metadata_hash: &ManagedBuffer,
+    );
+
+    /// Emitted when a strategy stops accepting new orders
+    #[event("strategy_deactivated")]
+    fn strategy_deactivated_event(
+        &self,
+        #[indexed] strategy_id: u64,
+    );
+
+    /// Emitted when a strategy's kill switch is toggled
+    #[event("strategy_paused")]
+    fn strategy_paused_event(
+        &self,
+        #[indexed] strategy_id: u64,
+        paused: bool,
+    );
+
+    /// Emitted when a strategy's fee rebate share changes
+    #[event("strategy_rebate_changed")]
+    fn strategy_rebate_changed_event(
+        &self,
+        #[indexed] strategy_id: u64,
+        rebate_bps: u64,
+    );
+
+    /// Emitted when a strategy owner funds its rebate pool
+    #[event("strategy_rebates_funded")]
+    fn strategy_rebates_funded_event(
+        &self,
+        #[indexed] strategy_id: u64,
+        #[indexed] token: &TokenIdentifier,
+        amount: &BigUint,
+    );
+
+    /// Emitted when a strategy owner takes tokens back from its rebate pool
+    #[event("strategy_rebates_withdrawn")]
+    fn strategy_rebates_withdrawn_event(
+        &self,
+        #[indexed] strategy_id: u64,
+        #[indexed] token: &TokenIdentifier,
+        amount: &BigUint,
+    );
+
+    /// Emitted when a fill of a strategy's order gets part of its
+    /// execution fee back
+    #[event("strategy_rebate_paid")]
+    fn strategy_rebate_paid_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] strategy_id: u64,
+        #[indexed] token: &TokenIdentifier,
+        amount: &BigUint,
+    );
+
+    /// Emitted when two opposite orders are settled against each other
+    #[event("orders_matched")]
+    fn orders_matched_event(
+        &self,
+        #[indexed] buy_id: u64,
+        #[indexed] sell_id: u64,
+        #[indexed] amount_sold_by_buy: &BigUint,
+        amount_sold_by_sell: &BigUint,
+    );
+
+    /// Emitted when the owner approves a market maker
+    #[event("market_maker_added")]
+    fn market_maker_added_event(&self, #[indexed] maker: &ManagedAddress);
+
+    /// Emitted when the owner removes a market maker
+    #[event("market_maker_removed")]
+    fn market_maker_removed_event(&self, #[indexed] maker: &ManagedAddress);
+
+    /// Emitted when a market maker posts a quote
+    #[event("quote_posted")]
+    fn quote_posted_event(
+        &self,
+        #[indexed] quote_id: u64,
+        #[indexed] maker: &ManagedAddress,
+        quote: &Quote<Self::Api>,
+    );
+
+    /// Emitted when a maker cancels a quote and withdraws its deposit
+    #[event("quote_cancelled")]
+    fn quote_cancelled_event(
+        &self,
+        #[indexed] quote_id: u64,
+        #[indexed] maker: &ManagedAddress,
+        refunded_amount: &BigUint,
+    );
+
+    /// Emitted when an order is filled against a quote
+    #[event("quote_filled")]
+    fn quote_filled_event(
+        &self,
+        #[indexed] quote_id: u64,
+        #[indexed] order_id: u64,
+        #[indexed] maker: &ManagedAddress,
+        #[indexed] amount_in: &BigUint,
+        amount_out: &BigUint,
+    );
+
+    /// Emitted when the owner configures zap orders
+    #[event("zap_config_changed")]
+    fn zap_config_changed_event(
+        &self,
+        #[indexed] lp_token: &TokenIdentifier,
+        first_token: &TokenIdentifier,
+    );
+
+    /// Emitted when a zap order chunk was added as liquidity
+    #[event("zap_liquidity_added")]
+    fn zap_liquidity_added_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] executor: &ManagedAddress,
+        lp_amount: &BigUint,
+    );
+
+    /// Emitted when the addLiquidity leg of a zap fails; the swap output
+    /// was paid out and the unswapped half stays in the order
+    #[event("zap_liquidity_failed")]
+    fn zap_liquidity_failed_event(&self, #[indexed] order_id: u64, err_msg: &ManagedBuffer);
+
+    /// Emitted when the lending market of a token changes
+    #[event("lending_market_changed")]
+    fn lending_market_changed_event(
+        &self,
+        #[indexed] token: &TokenIdentifier,
+        #[indexed] market: &ManagedAddress,
+        share_token: &TokenIdentifier,
+    );
+
+    /// Emitted when the lending interest policy changes
+    #[event("lending_interest_policy_changed")]
+    fn lending_interest_policy_changed_event(&self, to_user: bool);
+
+    /// Emitted when an order's escrow was supplied to a lending market
+    #[event("escrow_supplied")]
+    fn escrow_supplied_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] market: &ManagedAddress,
+        #[indexed] principal: &BigUint,
+        shares: &BigUint,
+    );
+
+    /// Emitted when a lent escrow is back; `interest` is what it earned
+    #[event("escrow_redeemed")]
+    fn escrow_redeemed_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] interest_to_user: bool,
+        interest: &BigUint,
+    );
+
+    /// Emitted when supplying or redeeming an order's escrow fails
+    #[event("lending_call_failed")]
+    fn lending_call_failed_event(&self, #[indexed] order_id: u64, err_msg: &ManagedBuffer);
+
+    /// Emitted when an order opts into (or out of) streamed payouts
+    #[event("payout_stream_set")]
+    fn payout_stream_set_event(&self, #[indexed] order_id: u64, duration_seconds: u64);
+
+    /// Emitted when an order starts forwarding its output to a destination
+    #[event("output_forward_set")]
+    fn output_forward_set_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] destination: &ManagedAddress,
+        function: &ManagedBuffer,
+    );
+
+    /// Emitted when an order goes back to paying its output to the owner
+    #[event("output_forward_cleared")]
+    fn output_forward_cleared_event(&self, #[indexed] order_id: u64);
+
+    /// Emitted when a destination accepted a fill's forwarded output
+    #[event("output_forwarded")]
+    fn output_forwarded_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] token: &TokenIdentifier,
+        amount: &BigUint,
+    );
+
+    /// Emitted when a forward call fails and the output goes to the owner
+    #[event("output_forward_failed")]
+    fn output_forward_failed_event(&self, #[indexed] order_id: u64, err_msg: &ManagedBuffer);
+
+    /// Emitted when a fill's output is locked in the order's stream
+    #[event("payout_streamed")]
+    fn payout_streamed_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] vests_until: u64,
+        amount: &BigUint,
+    );
+
+    /// Emitted when the owner claims vested stream output
+    #[event("payout_stream_claimed")]
+    fn payout_stream_claimed_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] user: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    /// Emitted when the owner sets the price oracle
+    #[event("price_oracle_changed")]
+    fn price_oracle_changed_event(&self, #[indexed] oracle: &ManagedAddress);
+
+    /// Emitted when the owner registers a pair for price conditions
+    #[event("price_pair_registered")]
+    fn price_pair_registered_event(
+        &self,
+        #[indexed] base_token: &TokenIdentifier,
+        quote_token: &TokenIdentifier,
+    );
+
+    /// Emitted when the maximum oracle price age changes
+    #[event("price_max_age_changed")]
+    fn price_max_age_changed_event(&self, max_age_seconds: u64);
+
+    /// Emitted when the price oracle posts a price
+    #[event("oracle_price_posted")]
+    fn oracle_price_posted_event(
+        &self,
+        #[indexed] base_token: &TokenIdentifier,
+        #[indexed] quote_token: &TokenIdentifier,
+        price: &OraclePrice<Self::Api>,
+    );
+
+    /// Emitted when an order is gated on another pair's price
+    #[event("order_condition_set")]
+    fn order_condition_set_event(
+        &self,
+        #[indexed] order_id: u64,
+        condition: &PriceCondition<Self::Api>,
+    );
+
+    /// Emitted when an order's price condition is removed
+    #[event("order_condition_cleared")]
+    fn order_condition_cleared_event(&self, #[indexed] order_id: u64);
+
+    /// Emitted when the risk caps of a token change (0 = no cap)
+    #[event("risk_caps_changed")]
+    fn risk_caps_changed_event(
+        &self,
+        #[indexed] token: &TokenIdentifier,
+        #[indexed] max_order_amount: &BigUint,
+        max_open_interest: &BigUint,
+    );
+
+    /// Emitted when the per-executor epoch limit of a token changes (0 = no limit)
+    #[event("executor_daily_limit_changed")]
+    fn executor_daily_limit_changed_event(&self, #[indexed] token: &TokenIdentifier, limit: &BigUint);
+
+    /// Emitted when the pair health check threshold changes
+    #[event("pair_health_check_changed")]
+    fn pair_health_check_changed_event(&self, min_reserve_multiple: u64);
+
+    /// Emitted when the source of the swap's minimum output changes
+    #[event("min_output_mode_changed")]
+    fn min_output_mode_changed_event(&self, mode: MinOutputMode);
+
+    /// Emitted when an execution sends its swap, with the floor that set
+    /// the minimum output
+    #[event("execution_min_output")]
+    fn execution_min_output_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] bound: MinOutputBound,
+        min_amount_out: &BigUint,
+    );
+
+    /// Emitted for each swap callback payment other than the output: unused
+    /// input credited back to the order, or a token refunded to its owner
+    #[event("unexpected_swap_payment")]
+    fn unexpected_swap_payment_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] token: &TokenIdentifier,
+        #[indexed] nonce: u64,
+        #[indexed] credited_to_order: bool,
+        amount: &BigUint,
+    );
+
+    /// Emitted when a user turns self-trade prevention on or off
+    #[event("self_trade_prevention_changed")]
+    fn self_trade_prevention_changed_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        enabled: bool,
+    );
+
+    /// Emitted when an order is created with a committed (hidden) price
+    #[event("order_price_committed")]
+    fn order_price_committed_event(
+        &self,
+        #[indexed] order_id: u64,
+        commitment: &ManagedByteArray<Self::Api, 32>,
+    );
+
+    /// Emitted when the executor reveals the committed price of an order
+    #[event("order_price_revealed")]
+    fn order_price_revealed_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] price_num: &BigUint,
+        price_denom: &BigUint,
+    );
+
+    #[event("gas_station_max_output_changed")]
+    fn gas_station_max_output_changed_event(
+        &self,
+        #[indexed] token: &TokenIdentifier,
+        max_output: &BigUint,
+    );
+
+    /// Emitted when tokens are added to the gas station pool
+    #[event("gas_station_topped_up")]
+    fn gas_station_topped_up_event(
+        &self,
+        #[indexed] from: &ManagedAddress,
+        #[indexed] token: &TokenIdentifier,
+        amount: &BigUint,
+    );
+
+    /// Emitted when the gas station pays part of a fill's execution fee
+    #[event("execution_sponsored")]
+    fn execution_sponsored_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] executor: &ManagedAddress,
+        #[indexed] token: &TokenIdentifier,
+        amount: &BigUint,
+    );
+
+    /// Emitted when the owner executes an order in place of the executor
+    #[event("order_force_executed")]
+    fn order_force_executed_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] owner: &ManagedAddress,
+    );
+
+    /// Emitted when the execution rights of an order go up for auction
+    #[event("execution_auction_opened")]
+    fn execution_auction_opened_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] ends_at: u64,
+        rights_until: u64,
+    );
+
+    /// Emitted for every new best bid on an order's execution rights
+    #[event("execution_bid_placed")]
+    fn execution_bid_placed_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] bidder: &ManagedAddress,
+        fee_bps: u64,
+    );
+
+    #[event("execution_auction_window_changed")]
+    fn execution_auction_window_changed_event(&self, window_seconds: u64);
+
+    #[event("execution_auction_min_amount_changed")]
+    fn execution_auction_min_amount_changed_event(
+        &self,
+        #[indexed] token: &TokenIdentifier,
+        min_amount: &BigUint,
+    );
+
+    /// Emitted when an order is created as an exact-output order
+    #[event("exact_output_order_created")]
+    fn exact_output_order_created_event(
+        &self,
+        #[indexed] order_id: u64,
+        amount_out: &BigUint,
+    );
+
+    /// Emitted when the input an exact-output swap did not need is refunded
+    #[event("exact_output_input_refunded")]
+    fn exact_output_input_refunded_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] user: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    /// Emitted when the swap endpoint called on a pair changes
+    #[event("swap_descriptor_changed")]
+    fn swap_descriptor_changed_event(&self, #[indexed] pair: &ManagedAddress, function: &ManagedBuffer);
+
+    /// Emitted when the router executing routed orders changes
+    #[event("xexchange_router_changed")]
+    fn xexchange_router_changed_event(
+        &self,
+        #[indexed] old_value: &ManagedAddress,
+        new_value: &ManagedAddress,
+    );
+
+    /// Emitted when a contract is added to or removed from the forward destinations
+    #[event("forward_destination_changed")]
+    fn forward_destination_changed_event(
+        &self,
+        #[indexed] destination: &ManagedAddress,
+        allowed: bool,
+    );
+
+    /// Emitted when the circuit breaker thresholds change
+    #[event("circuit_breaker_changed")]
+    fn circuit_breaker_changed_event(&self, #[indexed] max_deviation_bp: u64, window_seconds: u64);
+
+    /// Emitted when a swap's price moved too far and the pair was halted
+    #[event("circuit_breaker_tripped")]
+    fn circuit_breaker_tripped_event(
+        &self,
+        #[indexed] pair: &ManagedAddress,
+        #[indexed] token_in: &TokenIdentifier,
+        #[indexed] previous_price: &BigUint,
+        price: &BigUint,
+    );
+
+    /// Emitted when the owner resumes executions on a tripped pair
+    #[event("circuit_breaker_cleared")]
+    fn circuit_breaker_cleared_event(&self, #[indexed] pair: &ManagedAddress);
+
+    /// Emitted when an execution is skipped because the pair is unhealthy
+    #[event("pair_health_check_failed")]
+    fn pair_health_check_failed_event(&self, #[indexed] order_id: u64, issue: PairHealthIssue);
+
+    /// Emitted when an admin action cancels an order; `token` is the token
+    /// that triggered it
+    #[event("order_force_cancelled")]
+    fn order_force_cancelled_event(
+        &self,
+        #[indexed] version: u64,
+        #[indexed] order_id: u64,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] token: &TokenIdentifier,
+        #[indexed] reason: CancelReason,
+        refunded_amount: &BigUint,
+    );
+
+    /// Emitted after a batch of a strategy's orders was cancelled
+    #[event("strategy_orders_cancelled")]
+    fn strategy_orders_cancelled_event(
+        &self,
+        #[indexed] strategy_id: u64,
+        cancelled_count: u64,
+    );
+
+    /// Emitted when an order is tagged with a strategy
+    #[event("order_strategy_tagged")]
+    fn order_strategy_tagged_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] strategy_id: u64,
+    );
+
+    /// Emitted when the signal oracle address changes
+    #[event("signal_oracle_changed")]
+    fn signal_oracle_changed_event(
+        &self,
+        #[indexed] oracle: &ManagedAddress,
+    );
+
+    /// Emitted when the oracle fires a signal
+    #[event("signal_fired")]
+    fn signal_fired_event(
+        &self,
+        #[indexed] signal_id: u64,
+        timestamp: u64,
+    );
+
+    /// Emitted when a storage migration completes
+    #[event("storage_migrated")]
+    fn storage_migrated_event(
+        &self,
+        #[indexed] from_version: u64,
+        to_version: u64,
+    );
+
+    /// Emitted when a removed token still has pending orders
+    /// Those orders are blocked from execution but remain cancellable
+    #[event("token_delisted_with_open_orders")]
+    fn token_delisted_with_open_orders_event(
+        &self,
+        #[indexed] token: &TokenIdentifier,
+        open_order_count: u64,
+    );
+
     /// Emitted when contract is paused/unpaused
     #[event("pause_state_changed")]
     fn pause_state_changed_event(
@@ -28,12 +654,484 @@ pub trait EventsModule {
         paused: bool,
     );
 
+    /// Emitted when executions between two tokens are frozen or resumed
+    #[event("pair_paused")]
+    fn pair_paused_event(
+        &self,
+        #[indexed] from_token: &TokenIdentifier,
+        #[indexed] to_token: &TokenIdentifier,
+        paused: bool,
+    );
+
+    /// Emitted when the granular pause level changes
+    #[event("pause_level_changed")]
+    fn pause_level_changed_event(
+        &self,
+        level: PauseLevel,
+    );
+
+    /// Emitted when an address is added to the blacklist
+    #[event("address_blacklisted")]
+    fn address_blacklisted_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+    );
+
+    /// Emitted when an address is removed from the blacklist
+    #[event("address_unblacklisted")]
+    fn address_unblacklisted_event(
+        &self,
+        #[indexed] address: &ManagedAddress,
+    );
+
+    // ========== CONFIG CHANGE EVENTS ==========
+
+    /// Emitted when the maximum slippage changes
+    #[event("max_slippage_changed")]
+    fn max_slippage_changed_event(
+        &self,
+        #[indexed] old_value: u64,
+        new_value: u64,
+    );
+
+    /// Emitted when the xExchange pair address changes
+    #[event("xexchange_pair_changed")]
+    fn xexchange_pair_changed_event(
+        &self,
+        #[indexed] old_value: &ManagedAddress,
+        new_value: &ManagedAddress,
+    );
+
+    /// Emitted when the pair swapping a token changes
+    #[event("token_pair_changed")]
+    fn token_pair_changed_event(
+        &self,
+        #[indexed] token: &TokenIdentifier,
+        #[indexed] old_value: &ManagedAddress,
+        new_value: &ManagedAddress,
+    );
+
+    /// Emitted when the decimals of a token are set or fetched
+    #[event("token_decimals_set")]
+    fn token_decimals_set_event(
+        &self,
+        #[indexed] token: &TokenIdentifier,
+        decimals: u8,
+    );
+
+    /// Emitted when a token is whitelisted ahead of its pair
+    #[event("token_awaiting_pair")]
+    fn token_awaiting_pair_event(
+        &self,
+        #[indexed] token: &TokenIdentifier,
+    );
+
+    /// Emitted when a dormant order becomes executable because its
+    /// token got a pair
+    #[event("order_activated")]
+    fn order_activated_event(
+        &self,
+        #[indexed] order_id: u64,
+        token: &TokenIdentifier,
+    );
+
+    /// Emitted when the limit order executor changes
+    #[event("limit_order_executor_changed")]
+    fn limit_order_executor_changed_event(
+        &self,
+        #[indexed] old_value: &ManagedAddress,
+        new_value: &ManagedAddress,
+    );
+
+    /// Emitted when the execution fee changes
+    #[event("execution_fee_changed")]
+    fn execution_fee_changed_event(
+        &self,
+        #[indexed] old_value: u64,
+        new_value: u64,
+    );
+
+    /// Emitted when the price improvement shares change
+    #[event("price_improvement_sharing_changed")]
+    fn price_improvement_sharing_changed_event(
+        &self,
+        #[indexed] executor_bps: u64,
+        treasury_bps: u64,
+    );
+
+    /// Emitted when the minimum execution fee of an output token changes
+    #[event("execution_fee_floor_changed")]
+    fn execution_fee_floor_changed_event(
+        &self,
+        #[indexed] token: &TokenIdentifier,
+        old_min_fee: &BigUint,
+        new_min_fee: &BigUint,
+    );
+
+    /// Emitted when the timelock delay changes
+    #[event("timelock_delay_changed")]
+    fn timelock_delay_changed_event(
+        &self,
+        #[indexed] old_value: u64,
+        new_value: u64,
+    );
+
+    /// Emitted when the expiry sweep bounty changes
+    #[event("expiry_bounty_changed")]
+    fn expiry_bounty_changed_event(
+        &self,
+        #[indexed] old_value: u64,
+        new_value: u64,
+    );
+
+    /// Emitted when the max execution attempts change
+    #[event("max_execution_attempts_changed")]
+    fn max_execution_attempts_changed_event(
+        &self,
+        #[indexed] old_value: u64,
+        new_value: u64,
+    );
+
+    /// Emitted when the retry backoff of failed orders changes
+    #[event("retry_backoff_changed")]
+    fn retry_backoff_changed_event(
+        &self,
+        #[indexed] old_value: u64,
+        new_value: u64,
+    );
+
+    // ========== TIMELOCK EVENTS ==========
+
+    /// Emitted when the owner configures governance
+    #[event("governance_config_changed")]
+    fn governance_config_changed_event(
+        &self,
+        #[indexed] token: &TokenIdentifier,
+        #[indexed] proposal_threshold: &BigUint,
+        #[indexed] quorum: &BigUint,
+        voting_period_seconds: u64,
+    );
+
+    /// Emitted when a governance proposal is opened
+    #[event("proposal_created")]
+    fn proposal_created_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] proposer: &ManagedAddress,
+        proposal: &Proposal<Self::Api>,
+    );
+
+    /// Emitted for every vote, weighted by the locked governance tokens
+    #[event("vote_cast")]
+    fn vote_cast_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] voter: &ManagedAddress,
+        #[indexed] support: bool,
+        weight: &BigUint,
+    );
+
+    /// Emitted when a passed proposal is queued in the timelock
+    #[event("proposal_queued")]
+    fn proposal_queued_event(&self, #[indexed] proposal_id: u64, action_id: u64);
+
+    /// Emitted when a proposal misses the quorum or the majority
+    #[event("proposal_defeated")]
+    fn proposal_defeated_event(&self, #[indexed] proposal_id: u64);
+
+    /// Emitted when a voter takes back its locked governance tokens
+    #[event("vote_deposit_withdrawn")]
+    fn vote_deposit_withdrawn_event(
+        &self,
+        #[indexed] proposal_id: u64,
+        #[indexed] voter: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    /// Emitted when an order is created with a client metadata hash
+    #[event("order_client_tagged")]
+    fn order_client_tagged_event(
+        &self,
+        #[indexed] order_id: u64,
+        client_tag: &ManagedByteArray<Self::Api, 32>,
+    );
+
+    /// Emitted when an order is created with an agent intent
+    #[event("order_intent_recorded")]
+    fn order_intent_recorded_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] strategy_id: u64,
+        #[indexed] model_version: &ManagedByteArray<Self::Api, 32>,
+        confidence_bps: u64,
+    );
+
+    /// Emitted when an order is created under a client reference
+    #[event("order_reference_recorded")]
+    fn order_reference_recorded_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] reference: u64,
+        order_id: u64,
+    );
+
+    /// Emitted when a dormant order is chained to the output of another order
+    #[event("chained_order_created")]
+    fn chained_order_created_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] parent_order_id: u64,
+        user: &ManagedAddress,
+    );
+
+    /// Emitted when a fill's output funds a chained order (activating it on
+    /// the first fill)
+    #[event("chained_order_funded")]
+    fn chained_order_funded_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] parent_order_id: u64,
+        #[indexed] activated: bool,
+        amount: &BigUint,
+    );
+
+    /// Emitted when a bracket's entry, take-profit and stop-loss are created
+    #[event("bracket_order_created")]
+    fn bracket_order_created_event(
+        &self,
+        #[indexed] entry_order_id: u64,
+        #[indexed] take_profit_order_id: u64,
+        stop_loss_order_id: u64,
+    );
+
+    /// Emitted when a stop-loss takes over the escrow of its take-profit
+    #[event("stop_loss_triggered")]
+    fn stop_loss_triggered_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] take_profit_order_id: u64,
+        amount: &BigUint,
+    );
+
+    /// Emitted when an order is created with a route through another token
+    #[event("routed_order_created")]
+    fn routed_order_created_event(&self, #[indexed] order_id: u64, via_token: &TokenIdentifier);
+
+    /// Emitted when the insurance share of execution fees changes
+    #[event("insurance_fee_changed")]
+    fn insurance_fee_changed_event(&self, #[indexed] old_fee_bps: u64, new_fee_bps: u64);
+
+    /// Emitted when the compensation cap of a token changes
+    #[event("compensation_cap_changed")]
+    fn compensation_cap_changed_event(&self, #[indexed] token: &TokenIdentifier, cap: &BigUint);
+
+    /// Emitted when an execution fee slice goes to the insurance fund
+    #[event("insurance_fund_credited")]
+    fn insurance_fund_credited_event(&self, #[indexed] token: &TokenIdentifier, amount: &BigUint);
+
+    /// Emitted when the insurance fund compensates a user
+    #[event("user_compensated")]
+    fn user_compensated_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] token: &TokenIdentifier,
+        amount: &BigUint,
+    );
+
+    /// Emitted when the owner configures staking
+    #[event("staking_config_changed")]
+    fn staking_config_changed_event(
+        &self,
+        #[indexed] token: &TokenIdentifier,
+        #[indexed] fee_share_bps: u64,
+        unbond_epochs: u64,
+    );
+
+    /// Emitted when a user stakes protocol tokens
+    #[event("staked")]
+    fn staked_event(&self, #[indexed] user: &ManagedAddress, amount: &BigUint);
+
+    /// Emitted when a user starts unbonding part of its stake
+    #[event("unstaked")]
+    fn unstaked_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] unlock_epoch: u64,
+        amount: &BigUint,
+    );
+
+    /// Emitted when a user withdraws unbonded tokens
+    #[event("unstaked_withdrawn")]
+    fn unstaked_withdrawn_event(&self, #[indexed] user: &ManagedAddress, amount: &BigUint);
+
+    /// Emitted when a staker claims its share of protocol fees
+    #[event("staking_rewards_claimed")]
+    fn staking_rewards_claimed_event(
+        &self,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] token: &TokenIdentifier,
+        amount: &BigUint,
+    );
+
+    /// Emitted when the owner registers a client
+    #[event("client_registered")]
+    fn client_registered_event(&self, #[indexed] client_id: u64, payout_address: &ManagedAddress);
+
+    /// Emitted when a client's payout address changes
+    #[event("client_payout_changed")]
+    fn client_payout_changed_event(&self, #[indexed] client_id: u64, payout_address: &ManagedAddress);
+
+    /// Emitted when a client is enabled or disabled for new orders
+    #[event("client_active_changed")]
+    fn client_active_changed_event(&self, #[indexed] client_id: u64, active: bool);
+
+    /// Emitted when the client share of protocol fees changes
+    #[event("client_fee_share_changed")]
+    fn client_fee_share_changed_event(&self, #[indexed] old_share_bps: u64, new_share_bps: u64);
+
+    /// Emitted when an order is attributed to a client
+    #[event("order_client_attributed")]
+    fn order_client_attributed_event(&self, #[indexed] order_id: u64, #[indexed] client_id: u64);
+
+    /// Emitted when a client's fees are paid to its payout address
+    #[event("client_fees_claimed")]
+    fn client_fees_claimed_event(
+        &self,
+        #[indexed] client_id: u64,
+        #[indexed] token: &TokenIdentifier,
+        amount: &BigUint,
+    );
+
+    /// Emitted when a sensitive admin action is queued
+    #[event("timelock_action_queued")]
+    fn timelock_action_queued_event(
+        &self,
+        #[indexed] action_id: u64,
+        #[indexed] executable_at: u64,
+        action: &TimelockAction<Self::Api>,
+    );
+
+    /// Emitted when a queued action is applied
+    #[event("timelock_action_executed")]
+    fn timelock_action_executed_event(
+        &self,
+        #[indexed] action_id: u64,
+        action: &TimelockAction<Self::Api>,
+    );
+
+    /// Emitted when a queued action is dropped
+    #[event("timelock_action_cancelled")]
+    fn timelock_action_cancelled_event(
+        &self,
+        #[indexed] action_id: u64,
+        action: &TimelockAction<Self::Api>,
+    );
+
+    /// Emitted when stray tokens are withdrawn by the owner
+    /// Carries the balance and escrow total at withdrawal time for auditing
+    #[event("emergency_withdrawal")]
+    fn emergency_withdrawal_event(
+        &self,
+        #[indexed] receiver: &ManagedAddress,
+        #[indexed] token: &TokenIdentifier,
+        #[indexed] amount: &BigUint,
+        #[indexed] balance_before: &BigUint,
+        escrow_total: &BigUint,
+    );
+
+    /// Emitted by `reconcile`: what the contract holds of a token against
+    /// what it owes; a positive delta is surplus, a negative one a shortfall
+    #[event("escrow_reconciled")]
+    fn escrow_reconciled_event(
+        &self,
+        #[indexed] token: &TokenIdentifier,
+        #[indexed] balance: &BigUint,
+        #[indexed] accounted: &BigUint,
+        delta: &BigInt,
+    );
+
+    /// Emitted when a token's surplus balance is swept to the treasury
+    #[event("surplus_swept")]
+    fn surplus_swept_event(
+        &self,
+        #[indexed] receiver: &ManagedAddress,
+        #[indexed] token: &TokenIdentifier,
+        amount: &BigUint,
+    );
+
+    // ========== TREASURY EVENTS ==========
+
+    /// Emitted when a protocol fee is taken from a fill
+    #[event("fee_collected")]
+    fn fee_collected_event(
+        &self,
+        #[indexed] token: &TokenIdentifier,
+        #[indexed] source_order: u64,
+        amount: &BigUint,
+    );
+
+    /// Emitted when accrued protocol fees are paid out
+    #[event("fees_claimed")]
+    fn fees_claimed_event(
+        &self,
+        #[indexed] receiver: &ManagedAddress,
+        #[indexed] token: &TokenIdentifier,
+        amount: &BigUint,
+    );
+
+    #[event("treasury_token_changed")]
+    fn treasury_token_changed_event(&self, #[indexed] token: &TokenIdentifier);
+
+    /// Emitted when accrued protocol fees are swapped into the treasury token
+    #[event("protocol_fees_converted")]
+    fn protocol_fees_converted_event(
+        &self,
+        #[indexed] token: &TokenIdentifier,
+        #[indexed] treasury_token: &TokenIdentifier,
+        amount: &BigUint,
+        output_amount: &BigUint,
+    );
+
+    /// Emitted when a fee conversion swap fails; the fees stay accrued
+    #[event("protocol_fee_conversion_failed")]
+    fn protocol_fee_conversion_failed_event(
+        &self,
+        #[indexed] token: &TokenIdentifier,
+        amount: &BigUint,
+        reason: &ManagedBuffer,
+    );
+
+    /// Emitted when the protocol fee changes
+    #[event("protocol_fee_changed")]
+    fn protocol_fee_changed_event(
+        &self,
+        #[indexed] old_value: u64,
+        new_value: u64,
+    );
+
+    /// Emitted when the cancellation window or fee changes
+    #[event("cancellation_policy_changed")]
+    fn cancellation_policy_changed_event(
+        &self,
+        #[indexed] window_seconds: u64,
+        fee_bps: u64,
+    );
+
+    /// Emitted when the treasury address changes
+    #[event("treasury_address_changed")]
+    fn treasury_address_changed_event(
+        &self,
+        #[indexed] old_value: &ManagedAddress,
+        new_value: &ManagedAddress,
+    );
+
     // ========== LIMIT ORDER EVENTS ==========
 
     /// Emitted when a limit order is created
     #[event("limit_order_created")]
     fn limit_order_created_event(
         &self,
+        #[indexed] version: u64,
         #[indexed] order_id: u64,
         #[indexed] user: &ManagedAddress,
         #[indexed] from_token: &TokenIdentifier,
@@ -41,6 +1139,8 @@ pub trait EventsModule {
         #[indexed] to_token: &TokenIdentifier,
         #[indexed] target_price_num: &BigUint,
         #[indexed] target_price_denom: &BigUint,
+        #[indexed] epoch: u64,
+        #[indexed] round: u64,
         expires_at: u64,  // Only this one non-indexed (data)
     );
 
@@ -48,22 +1148,73 @@ pub trait EventsModule {
     #[event("limit_order_executed")]
     fn limit_order_executed_event(
         &self,
+        #[indexed] version: u64,
         #[indexed] order_id: u64,
         #[indexed] user: &ManagedAddress,
+        #[indexed] executor: &ManagedAddress,
         #[indexed] from_token: &TokenIdentifier,
         #[indexed] from_amount: &BigUint,
         #[indexed] to_token: &TokenIdentifier,
         #[indexed] to_amount: &BigUint,
-        timestamp: u64,  // Only this one non-indexed (data)
+        #[indexed] execution_price: &BigUint,  // output per input, scaled by 1e18
+        #[indexed] timestamp: u64,
+        #[indexed] epoch: u64,
+        #[indexed] round: u64,
+        #[indexed] price_improvement: &BigUint,  // output above the target price
+        #[indexed] improvement_to_executor: &BigUint,
+        #[indexed] improvement_to_treasury: &BigUint,
+        execution_fee: &BigUint,  // Only this one non-indexed (data)
+    );
+
+    /// Emitted when a pending order's price, slippage or expiry is modified
+    #[event("limit_order_modified")]
+    fn limit_order_modified_event(
+        &self,
+        #[indexed] version: u64,
+        #[indexed] order_id: u64,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] previous: &OrderTerms<Self::Api>,
+        new_terms: &OrderTerms<Self::Api>,
+    );
+
+    /// Emitted when an order is replaced by a new one using the same escrow
+    #[event("limit_order_replaced")]
+    fn limit_order_replaced_event(
+        &self,
+        #[indexed] version: u64,
+        #[indexed] old_order_id: u64,
+        #[indexed] new_order_id: u64,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] previous: &OrderTerms<Self::Api>,
+        new_terms: &OrderTerms<Self::Api>,
+    );
+
+    /// Emitted for each chunk of an order that is filled partially
+    #[event("limit_order_partially_filled")]
+    fn limit_order_partially_filled_event(
+        &self,
+        #[indexed] version: u64,
+        #[indexed] order_id: u64,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] fill_in: &BigUint,
+        #[indexed] fill_out: &BigUint,
+        #[indexed] cumulative_filled: &BigUint,
+        #[indexed] price_improvement: &BigUint,
+        #[indexed] improvement_to_executor: &BigUint,
+        #[indexed] improvement_to_treasury: &BigUint,
+        remaining: &BigUint,
     );
 
     /// Emitted when a limit order is cancelled
     #[event("limit_order_cancelled")]
     fn limit_order_cancelled_event(
         &self,
+        #[indexed] version: u64,
         #[indexed] order_id: u64,
         #[indexed] user: &ManagedAddress,
         #[indexed] token: &TokenIdentifier,
+        #[indexed] epoch: u64,
+        #[indexed] round: u64,
         amount: &BigUint,  // Only this one non-indexed (data)
     );
 
@@ -76,4 +1227,36 @@ pub trait EventsModule {
         #[indexed] token: &TokenIdentifier,
         amount: &BigUint,  // Only this one non-indexed (data)
     );
+
+    /// Emitted when the async swap of an execution fails
+    #[event("order_execution_failed")]
+    fn order_execution_failed_event(
+        &self,
+        #[indexed] version: u64,
+        #[indexed] order_id: u64,
+        #[indexed] executor: &ManagedAddress,
+        #[indexed] attempt: u64,
+        error_message: &ManagedBuffer,
+    );
+
+    /// Emitted when a failed order is held back until `retry_after`
+    #[event("order_retry_scheduled")]
+    fn order_retry_scheduled_event(
+        &self,
+        #[indexed] order_id: u64,
+        #[indexed] attempt: u64,
+        retry_after: u64,
+    );
+
+    /// Emitted when an order is refunded after its swap attempts are exhausted
+    #[event("limit_order_failed")]
+    fn limit_order_failed_event(
+        &self,
+        #[indexed] version: u64,
+        #[indexed] order_id: u64,
+        #[indexed] user: &ManagedAddress,
+        #[indexed] token: &TokenIdentifier,
+        #[indexed] amount: &BigUint,
+        attempts: u64,
+    );
 }
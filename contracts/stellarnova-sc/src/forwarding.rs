@@ -0,0 +1,119 @@
+//! Output Forwarding Module for StellarNova
+//!
+//! An order can forward its swap output to another protocol instead of
+//! paying it to its owner: each fill calls `function(arguments...)` on a
+//! destination contract with the output attached (e.g. a staking deposit).
+//! Destinations must be whitelisted through the timelock
+//! (`setForwardDestination`). The call is asynchronous; if it fails the
+//! output comes back and is paid to the order owner, and any tokens the
+//! destination sends back on success (e.g. a receipt token) go to the
+//! owner as well.
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// Most arguments a forward call can carry
+pub const MAX_FORWARD_ARGUMENTS: usize = 8;
+
+/// Gas for the destination call of a forwarded output
+pub const FORWARD_GAS: u64 = 20_000_000;
+
+/// Gas reserved for `forward_callback`
+pub const FORWARD_CALLBACK_GAS: u64 = 10_000_000;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct OutputForward<M: ManagedTypeApi> {
+    pub destination: ManagedAddress<M>,
+    pub function: ManagedBuffer<M>,
+    pub arguments: ManagedVec<M, ManagedBuffer<M>>,
+}
+
+#[multiversx_sc::module]
+pub trait ForwardingModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::positions::PositionsModule
+{
+    /// Callback of a forwarded output: a failed call gives the output
+    /// back to the order owner, a successful one passes on whatever the
+    /// destination returned
+    #[promises_callback]
+    fn forward_callback(
+        &self,
+        order_id: u64,
+        user: ManagedAddress,
+        token: TokenIdentifier,
+        amount: BigUint,
+        #[call_result] result: ManagedAsyncCallResult<MultiValueEncoded<ManagedBuffer>>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(_) => {
+                for payment in self.call_value().all_esdt_transfers().iter() {
+                    self.send().direct_esdt(
+                        &user,
+                        &payment.token_identifier,
+                        payment.token_nonce,
+                        &payment.amount,
+                    );
+                }
+                self.output_forwarded_event(order_id, &token, &amount);
+            }
+            ManagedAsyncCallResult::Err(err) => {
+                // The output came back with the callback
+                self.pay_order_owner(order_id, &user, &token, &amount);
+                self.output_forward_failed_event(order_id, &err.err_msg);
+            }
+        }
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    /// Whether a fill of the order goes to a still whitelisted destination
+    fn is_forwarded(&self, order_id: u64) -> bool {
+        if self.order_forward(order_id).is_empty() || self.is_tokenized(order_id) {
+            return false;
+        }
+        let forward = self.order_forward(order_id).get();
+        self.forward_destinations().contains(&forward.destination)
+    }
+
+    /// Send a fill's output to the order's destination
+    fn forward_output(
+        &self,
+        order_id: u64,
+        user: &ManagedAddress,
+        token: &TokenIdentifier,
+        amount: &BigUint,
+    ) {
+        if *amount == 0u64 {
+            return;
+        }
+
+        let forward = self.order_forward(order_id).get();
+        let mut arguments = ManagedArgBuffer::new();
+        for argument in forward.arguments.iter() {
+            arguments.push_arg_raw(argument.clone_value());
+        }
+        self.tx()
+            .to(&forward.destination)
+            .gas(FORWARD_GAS)
+            .raw_call(forward.function)
+            .arguments_raw(arguments)
+            .single_esdt(token, 0u64, amount)
+            .with_callback(self.callbacks().forward_callback(order_id, user, token, amount))
+            .with_extra_gas_for_callback(FORWARD_CALLBACK_GAS)
+            .register_promise();
+    }
+
+    // ========== STORAGE ==========
+
+    /// Contracts order outputs may be forwarded to
+    #[view(getForwardDestinations)]
+    #[storage_mapper("forwardDestinations")]
+    fn forward_destinations(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getOrderForward)]
+    #[storage_mapper("orderForward")]
+    fn order_forward(&self, order_id: u64) -> SingleValueMapper<OutputForward<Self::Api>>;
+}
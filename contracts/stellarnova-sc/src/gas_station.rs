@@ -0,0 +1,105 @@
+//! Gas Station Module for StellarNova
+//!
+//! Sponsored execution for small retail orders: the execution fee floor
+//! (`setExecutionFeeFloor`) keeps executors paid on tiny fills, but charges
+//! the user far more than the bps fee. For fills paying out at most
+//! `gasStationMaxOutput` of a token, the gap between the bps fee and the
+//! floor is paid to the executor from a per-token pool instead, as long as
+//! the pool lasts; the user only pays the bps fee.
+//!
+//! The pool is funded by the treasury: anyone can top it up with tokens
+//! (`topUpGasStation`) and the owner can move accrued protocol fees into it
+//! (`fundGasStationFromFees`).
+
+multiversx_sc::imports!();
+
+use crate::errors::*;
+
+#[multiversx_sc::module]
+pub trait GasStationModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+{
+    /// Sponsor fills paying out at most `max_output` of `token` (owner
+    /// only, 0 disables sponsoring for the token)
+    #[only_owner]
+    #[endpoint(setGasStationMaxOutput)]
+    fn set_gas_station_max_output(&self, token: TokenIdentifier, max_output: BigUint) {
+        self.gas_station_max_output(&token).set(&max_output);
+        self.gas_station_max_output_changed_event(&token, &max_output);
+    }
+
+    /// Add the fungible tokens sent with the call to the gas station pool
+    #[payable("*")]
+    #[endpoint(topUpGasStation)]
+    fn top_up_gas_station(&self) {
+        let payment = self.call_value().single_esdt();
+        require!(payment.token_nonce == 0, ERROR_NON_FUNGIBLE_PAYMENT);
+        require!(payment.amount > 0u64, ERROR_INVALID_AMOUNT);
+
+        self.gas_station_pool(&payment.token_identifier)
+            .update(|pool| *pool += &payment.amount);
+        let caller = self.blockchain().get_caller();
+        self.gas_station_topped_up_event(&caller, &payment.token_identifier, &payment.amount);
+    }
+
+    /// Move `amount` of the accrued protocol fees in `token` to the gas
+    /// station pool (owner only)
+    #[only_owner]
+    #[endpoint(fundGasStationFromFees)]
+    fn fund_gas_station_from_fees(&self, token: TokenIdentifier, amount: BigUint) {
+        require!(amount > 0u64, ERROR_INVALID_AMOUNT);
+        require!(
+            amount <= self.protocol_fees(&token).get(),
+            ERROR_NO_PROTOCOL_FEES
+        );
+
+        self.protocol_fees(&token).update(|accrued| *accrued -= &amount);
+        self.gas_station_pool(&token).update(|pool| *pool += &amount);
+        let caller = self.blockchain().get_caller();
+        self.gas_station_topped_up_event(&caller, &token, &amount);
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    /// Part of a fill's `gap` up to the fee floor the pool covers: all of
+    /// it when the fill is small enough, as far as the pool goes
+    fn sponsored_fee_amount(
+        &self,
+        token: &TokenIdentifier,
+        output_amount: &BigUint,
+        gap: &BigUint,
+    ) -> BigUint {
+        let max_output = self.gas_station_max_output(token).get();
+        if max_output == 0u64 || *output_amount > max_output {
+            return BigUint::zero();
+        }
+
+        let pool = self.gas_station_pool(token).get();
+        if *gap < pool {
+            gap.clone()
+        } else {
+            pool
+        }
+    }
+
+    /// Pay the sponsored part of a fill's execution fee from the pool
+    fn pay_sponsored_fee(
+        &self,
+        order_id: u64,
+        executor: &ManagedAddress,
+        token: &TokenIdentifier,
+        amount: &BigUint,
+    ) {
+        self.gas_station_pool(token).update(|pool| *pool -= amount);
+        self.send().direct_esdt(executor, token, 0u64, amount);
+        self.execution_sponsored_event(order_id, executor, token, amount);
+    }
+
+    // ========== STORAGE ==========
+
+    /// Largest fill output in a token whose execution fee is sponsored
+    #[view(getGasStationMaxOutput)]
+    #[storage_mapper("gasStationMaxOutput")]
+    fn gas_station_max_output(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+}
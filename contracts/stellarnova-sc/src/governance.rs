@@ -0,0 +1,282 @@
+//! Governance Module for StellarNova
+//!
+//! Holders of the governance token propose and vote on protocol parameters:
+//! execution and protocol fees, max slippage and pair listings. Votes are
+//! token-weighted: voters lock governance tokens in the proposal until its
+//! voting period ends. A proposal passes with more yes than no votes and at
+//! least `quorum` tokens voting; anyone then finalizes it and the action is
+//! queued in the timelock like an owner action, so the owner can still veto
+//! it with `cancelQueuedAction` during the delay.
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::errors::*;
+use crate::timelock::TimelockAction;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalStatus {
+    Active,
+    Queued,
+    Defeated,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct Proposal<M: ManagedTypeApi> {
+    pub proposal_id: u64,
+    pub proposer: ManagedAddress<M>,
+    pub action: TimelockAction<M>,
+    pub voting_ends_at: u64,
+    pub yes_votes: BigUint<M>,
+    pub no_votes: BigUint<M>,
+    pub status: ProposalStatus,
+    pub action_id: u64, // timelock action id once queued
+}
+
+#[multiversx_sc::module]
+pub trait GovernanceModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::limit_orders::LimitOrdersModule
+    + crate::pause::PauseModule
+    + crate::dex::DexModule
+    + crate::blacklist::BlacklistModule
+    + crate::treasury::TreasuryModule
+    + crate::managers::ManagersModule
+    + crate::positions::PositionsModule
+    + crate::strategies::StrategiesModule
+    + crate::signals::SignalsModule
+    + crate::streams::StreamsModule
+    + crate::listings::ListingsModule
+    + crate::decimals::DecimalsModule
+    + crate::caps::CapsModule
+    + crate::breaker::CircuitBreakerModule
+    + crate::staking::StakingModule
+    + crate::insurance::InsuranceModule
+    + crate::stats::StatsModule
+    + crate::clients::ClientsModule
+    + crate::commitments::CommitmentsModule
+    + crate::auctions::AuctionsModule
+    + crate::gas_station::GasStationModule
+    + crate::forwarding::ForwardingModule
+    + crate::timelock::TimelockModule
+{
+    /// Configure governance (owner only); the token cannot change once set
+    ///
+    /// # Arguments
+    /// * `proposal_threshold` - Minimum deposit to open a proposal
+    /// * `quorum` - Minimum total votes for a proposal to pass
+    /// * `voting_period_seconds` - How long proposals are open for votes
+    #[only_owner]
+    #[endpoint(setGovernanceConfig)]
+    fn set_governance_config(
+        &self,
+        token: TokenIdentifier,
+        proposal_threshold: BigUint,
+        quorum: BigUint,
+        voting_period_seconds: u64,
+    ) {
+        require!(
+            self.governance_token().is_empty() || self.governance_token().get() == token,
+            ERROR_GOVERNANCE_TOKEN_LOCKED
+        );
+        require!(voting_period_seconds > 0, ERROR_VOTING_PERIOD_ZERO);
+
+        self.governance_token().set(&token);
+        self.proposal_threshold().set(&proposal_threshold);
+        self.governance_quorum().set(&quorum);
+        self.voting_period().set(voting_period_seconds);
+        self.governance_config_changed_event(&token, &proposal_threshold, &quorum, voting_period_seconds);
+    }
+
+    /// Open a proposal; the governance tokens sent with the call must reach
+    /// the proposal threshold and count as the proposer's yes vote
+    ///
+    /// Returns the proposal id
+    #[payable("*")]
+    #[endpoint(propose)]
+    fn propose(&self, action: TimelockAction<Self::Api>) -> u64 {
+        require!(action.is_governable(), ERROR_ACTION_NOT_GOVERNABLE);
+        self.validate_action(&action);
+
+        let caller = self.blockchain().get_caller();
+        let deposit = self.receive_vote_deposit();
+        require!(
+            deposit >= self.proposal_threshold().get(),
+            ERROR_BELOW_PROPOSAL_THRESHOLD
+        );
+
+        let proposal_id = self.next_proposal_id().get() + 1;
+        self.next_proposal_id().set(proposal_id);
+
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        let proposal = Proposal {
+            proposal_id,
+            proposer: caller.clone(),
+            action,
+            voting_ends_at: current_time + self.voting_period().get(),
+            yes_votes: BigUint::zero(),
+            no_votes: BigUint::zero(),
+            status: ProposalStatus::Active,
+            action_id: 0,
+        };
+        self.proposals(proposal_id).set(&proposal);
+        self.proposal_created_event(proposal_id, &caller, &proposal);
+
+        self.record_vote(proposal_id, &caller, true, &deposit);
+        proposal_id
+    }
+
+    /// Vote with the governance tokens sent with the call; they stay locked
+    /// until the voting period ends
+    #[payable("*")]
+    #[endpoint(vote)]
+    fn vote(&self, proposal_id: u64, support: bool) {
+        let caller = self.blockchain().get_caller();
+        let deposit = self.receive_vote_deposit();
+        self.record_vote(proposal_id, &caller, support, &deposit);
+    }
+
+    /// Close the vote of a proposal once its period has ended (callable by
+    /// anyone). A passed proposal is queued in the timelock
+    #[endpoint(finalizeProposal)]
+    fn finalize_proposal(&self, proposal_id: u64) -> ProposalStatus {
+        let mut proposal = self.require_proposal(proposal_id);
+        require!(
+            proposal.status == ProposalStatus::Active,
+            ERROR_PROPOSAL_FINALIZED
+        );
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        require!(current_time >= proposal.voting_ends_at, ERROR_VOTING_OPEN);
+
+        let total_votes = &proposal.yes_votes + &proposal.no_votes;
+        if proposal.yes_votes > proposal.no_votes && total_votes >= self.governance_quorum().get() {
+            proposal.status = ProposalStatus::Queued;
+            proposal.action_id = self.queue_action(proposal.action.clone());
+            self.proposal_queued_event(proposal_id, proposal.action_id);
+        } else {
+            proposal.status = ProposalStatus::Defeated;
+            self.proposal_defeated_event(proposal_id);
+        }
+        self.proposals(proposal_id).set(&proposal);
+        proposal.status
+    }
+
+    /// Take back the governance tokens locked in a proposal after its
+    /// voting period
+    #[endpoint(withdrawVoteDeposit)]
+    fn withdraw_vote_deposit(&self, proposal_id: u64) -> BigUint {
+        let proposal = self.require_proposal(proposal_id);
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        require!(current_time >= proposal.voting_ends_at, ERROR_VOTING_OPEN);
+
+        let caller = self.blockchain().get_caller();
+        let amount = self.vote_deposit(proposal_id, &caller).take();
+        require!(amount > 0u64, ERROR_NO_VOTE_DEPOSIT);
+
+        let token = self.governance_token().get();
+        self.governance_deposits(&token)
+            .update(|total| *total -= &amount);
+        self.send().direct_esdt(&caller, &token, 0, &amount);
+        self.vote_deposit_withdrawn_event(proposal_id, &caller, &amount);
+
+        amount
+    }
+
+    #[view(getProposal)]
+    fn get_proposal(&self, proposal_id: u64) -> Proposal<Self::Api> {
+        self.require_proposal(proposal_id)
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    /// Governance tokens sent with the call, locked as a vote deposit
+    fn receive_vote_deposit(&self) -> BigUint {
+        require!(
+            !self.governance_token().is_empty(),
+            ERROR_GOVERNANCE_NOT_CONFIGURED
+        );
+        let (token, amount) = self.receive_fungible_payment();
+        require!(
+            token == self.governance_token().get(),
+            ERROR_WRONG_GOVERNANCE_TOKEN
+        );
+        require!(amount > 0u64, ERROR_INVALID_AMOUNT);
+        amount
+    }
+
+    fn record_vote(
+        &self,
+        proposal_id: u64,
+        voter: &ManagedAddress,
+        support: bool,
+        weight: &BigUint,
+    ) {
+        let mut proposal = self.require_proposal(proposal_id);
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        require!(
+            proposal.status == ProposalStatus::Active && current_time < proposal.voting_ends_at,
+            ERROR_VOTING_CLOSED
+        );
+
+        if support {
+            proposal.yes_votes += weight;
+        } else {
+            proposal.no_votes += weight;
+        }
+        self.proposals(proposal_id).set(&proposal);
+
+        self.vote_deposit(proposal_id, voter)
+            .update(|deposit| *deposit += weight);
+        self.governance_deposits(&self.governance_token().get())
+            .update(|total| *total += weight);
+        self.vote_cast_event(proposal_id, voter, support, weight);
+    }
+
+    fn require_proposal(&self, proposal_id: u64) -> Proposal<Self::Api> {
+        require!(
+            !self.proposals(proposal_id).is_empty(),
+            ERROR_PROPOSAL_NOT_FOUND
+        );
+        self.proposals(proposal_id).get()
+    }
+
+    // ========== STORAGE ==========
+
+    #[view(getGovernanceToken)]
+    #[storage_mapper("governanceToken")]
+    fn governance_token(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getProposalThreshold)]
+    #[storage_mapper("proposalThreshold")]
+    fn proposal_threshold(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getGovernanceQuorum)]
+    #[storage_mapper("governanceQuorum")]
+    fn governance_quorum(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getVotingPeriod)]
+    #[storage_mapper("votingPeriod")]
+    fn voting_period(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("nextProposalId")]
+    fn next_proposal_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("proposals")]
+    fn proposals(&self, proposal_id: u64) -> SingleValueMapper<Proposal<Self::Api>>;
+
+    /// Governance tokens a voter locked in a proposal
+    #[view(getVoteDeposit)]
+    #[storage_mapper("voteDeposit")]
+    fn vote_deposit(
+        &self,
+        proposal_id: u64,
+        voter: &ManagedAddress,
+    ) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,88 @@
+//! Insurance Module for StellarNova
+//!
+//! A slice (`insuranceFeeBps`) of every execution fee stays in the contract
+//! as a per-token insurance fund instead of going to the executor. The fund
+//! makes users whole after incidents such as stuck callbacks or mispriced
+//! fills: compensations are timelock actions (owner or governance), capped
+//! per payout and token by `compensationCap`.
+
+multiversx_sc::imports!();
+
+use crate::errors::*;
+use crate::limit_orders::MAX_BASIS_POINTS;
+
+/// Maximum share of an execution fee kept for the insurance fund (50%)
+pub const MAX_INSURANCE_FEE_BPS: u64 = 5_000;
+
+#[multiversx_sc::module]
+pub trait InsuranceModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+{
+    /// Set the share of every execution fee kept for the insurance fund
+    /// (owner only)
+    #[only_owner]
+    #[endpoint(setInsuranceFeeBps)]
+    fn set_insurance_fee_bps(&self, fee_bps: u64) {
+        require!(fee_bps <= MAX_INSURANCE_FEE_BPS, ERROR_INSURANCE_FEE_TOO_HIGH);
+        let old_fee_bps = self.insurance_fee_bps().replace(fee_bps);
+        self.insurance_fee_changed_event(old_fee_bps, fee_bps);
+    }
+
+    /// Set the largest single compensation in `token` (owner only)
+    /// 0 disables compensations in the token
+    #[only_owner]
+    #[endpoint(setCompensationCap)]
+    fn set_compensation_cap(&self, token: TokenIdentifier, cap: BigUint) {
+        self.compensation_cap(&token).set(&cap);
+        self.compensation_cap_changed_event(&token, &cap);
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    /// Pay a fill's execution fee, keeping the insurance slice
+    fn pay_execution_fee(&self, executor: &ManagedAddress, token: &TokenIdentifier, fee: &BigUint) {
+        let insurance = fee * self.insurance_fee_bps().get() / MAX_BASIS_POINTS;
+        if insurance > 0u64 {
+            self.insurance_fund(token).update(|fund| *fund += &insurance);
+            self.insurance_fund_credited_event(token, &insurance);
+        }
+
+        let executor_amount = fee - &insurance;
+        if executor_amount > 0u64 {
+            self.send().direct_esdt(executor, token, 0u64, &executor_amount);
+        }
+    }
+
+    /// Check a compensation against the cap and the fund
+    fn require_compensation_allowed(&self, token: &TokenIdentifier, amount: &BigUint) {
+        require!(*amount > 0u64, ERROR_INVALID_AMOUNT);
+        require!(
+            *amount <= self.compensation_cap(token).get(),
+            ERROR_COMPENSATION_CAP
+        );
+        require!(
+            *amount <= self.insurance_fund(token).get(),
+            ERROR_INSURANCE_FUND_INSUFFICIENT
+        );
+    }
+
+    /// Pay `amount` of the insurance fund to `user`
+    fn pay_compensation(&self, user: &ManagedAddress, token: &TokenIdentifier, amount: &BigUint) {
+        self.require_compensation_allowed(token, amount);
+
+        self.insurance_fund(token).update(|fund| *fund -= amount);
+        self.send().direct_esdt(user, token, 0u64, amount);
+        self.user_compensated_event(user, token, amount);
+    }
+
+    // ========== STORAGE ==========
+
+    #[view(getInsuranceFeeBps)]
+    #[storage_mapper("insuranceFeeBps")]
+    fn insurance_fee_bps(&self) -> SingleValueMapper<u64>;
+
+    #[view(getCompensationCap)]
+    #[storage_mapper("compensationCap")]
+    fn compensation_cap(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+}
@@ -5,8 +5,44 @@ multiversx_sc::imports!();
 pub mod storage;
 pub mod events;
 pub mod errors;
+pub mod reasons;
+pub mod listings;
+pub mod decimals;
+pub mod caps;
+pub mod breaker;
+pub mod governance;
+pub mod staking;
+pub mod insurance;
+pub mod stats;
 pub mod dex;
 pub mod limit_orders;
+pub mod pause;
+pub mod timelock;
+pub mod blacklist;
+pub mod treasury;
+pub mod migration;
+pub mod managers;
+pub mod credit;
+pub mod relayed;
+pub mod positions;
+pub mod strategies;
+pub mod clients;
+pub mod commitments;
+pub mod auctions;
+pub mod gas_station;
+pub mod signals;
+pub mod rfq;
+pub mod streams;
+pub mod forwarding;
+pub mod stellarnova_proxy;
+
+use errors::*;
+
+/// Code version, bumped with every release that changes storage or event layouts
+/// Version 1 is the original deployment (no version stored), version 2 tagged
+/// lifecycle events with the version, version 3 added epoch and round to them
+/// along with the order fields and pools introduced since
+pub const CONTRACT_VERSION: u64 = 3;
 
 /// StellarNova Smart Contract
 /// AI-powered limit order system on MultiversX
@@ -25,6 +61,32 @@ pub trait StellarNova:
     + events::EventsModule
     + dex::DexModule
     + limit_orders::LimitOrdersModule
+    + pause::PauseModule
+    + timelock::TimelockModule
+    + blacklist::BlacklistModule
+    + treasury::TreasuryModule
+    + migration::MigrationModule
+    + managers::ManagersModule
+    + credit::CreditModule
+    + relayed::RelayedModule
+    + positions::PositionsModule
+    + strategies::StrategiesModule
+    + signals::SignalsModule
+    + rfq::RfqModule
+    + streams::StreamsModule
+    + listings::ListingsModule
+    + decimals::DecimalsModule
+    + caps::CapsModule
+    + breaker::CircuitBreakerModule
+    + governance::GovernanceModule
+    + staking::StakingModule
+    + insurance::InsuranceModule
+    + stats::StatsModule
+    + clients::ClientsModule
+    + commitments::CommitmentsModule
+    + auctions::AuctionsModule
+    + gas_station::GasStationModule
+    + forwarding::ForwardingModule
 {
 
     /// Initialize the contract
@@ -38,11 +100,18 @@ pub trait StellarNova:
         max_slippage_bp: u64,
         initial_tokens: MultiValueEncoded<TokenIdentifier>,
     ) {
+        require!(
+            max_slippage_bp <= limit_orders::MAX_BASIS_POINTS,
+            ERROR_MAX_SLIPPAGE_ABOVE_100_PERCENT
+        );
+
         let caller = self.blockchain().get_caller();
 
         self.owner().set(&caller);
+        self.contract_version().set(CONTRACT_VERSION);
+        self.storage_version().set(migration::STORAGE_VERSION);
         self.max_slippage().set(max_slippage_bp);
-        self.paused().set(false);
+        self.pause_level().set(pause::PauseLevel::Active);
 
         // Whitelist initial tokens
         for token in initial_tokens {
@@ -55,6 +124,9 @@ pub trait StellarNova:
 
         // Set default execution fee: 10 bps = 0.1%
         self.execution_fee_bps().set_if_empty(10u64);
+
+        self.max_execution_attempts()
+            .set_if_empty(limit_orders::DEFAULT_MAX_EXECUTION_ATTEMPTS);
     }
 
     // ========== ADMIN ENDPOINTS ==========
@@ -67,47 +139,221 @@ pub trait StellarNova:
     fn whitelist_token(&self, token: TokenIdentifier) {
         require!(
             !self.whitelisted_tokens().contains(&token),
-            "Token already whitelisted"
+            ERROR_ALREADY_WHITELISTED
         );
 
         self.whitelisted_tokens().insert(token.clone());
         self.token_whitelisted_event(&token);
     }
 
+    /// Configure zap orders: LP token of the xExchange pair and the pair's
+    /// first token, which decides the addLiquidity payment order
+    #[only_owner]
+    #[endpoint(setZapConfig)]
+    fn set_zap_config(&self, lp_token: TokenIdentifier, first_token: TokenIdentifier) {
+        self.zap_lp_token().set(&lp_token);
+        self.zap_first_token().set(&first_token);
+        self.zap_config_changed_event(&lp_token, &first_token);
+    }
+
     /// Remove token from whitelist
+    /// Pending orders on the token can no longer execute but stay cancellable
     #[only_owner]
     #[endpoint(removeToken)]
     fn remove_token(&self, token: TokenIdentifier) {
         require!(
             self.whitelisted_tokens().contains(&token),
-            "Token not in whitelist"
+            ERROR_NOT_WHITELISTED
         );
 
         self.whitelisted_tokens().swap_remove(&token);
+        self.awaiting_pair_tokens().swap_remove(&token);
         self.token_removed_event(&token);
-    }
 
-    /// Pause/unpause contract (emergency stop)
-    #[only_owner]
-    #[endpoint(setPaused)]
-    fn set_paused(&self, paused: bool) {
-        self.paused().set(paused);
-        self.pause_state_changed_event(paused);
+        let open_orders = self.count_pending_orders_for_token(&token);
+        if open_orders > 0 {
+            self.token_delisted_with_open_orders_event(&token, open_orders);
+        }
     }
 
+    // Sensitive setters below go through the timelock queue:
+    // applied immediately while the delay is 0, otherwise via executeQueuedAction
+
     /// Update maximum slippage tolerance
+    /// Capped at 10000 bps (100%) so min-output math can never underflow
     #[only_owner]
     #[endpoint(setMaxSlippage)]
-    fn set_max_slippage(&self, max_slippage_bp: u64) {
-        self.max_slippage().set(max_slippage_bp);
+    fn set_max_slippage(&self, max_slippage_bp: u64) -> u64 {
+        self.queue_action(timelock::TimelockAction::SetMaxSlippage(max_slippage_bp))
     }
 
     /// Set xExchange pair address (owner only)
     /// For WEGLD/USDC pair: erd1qqqqqqqqqqqqqpgqeel2kumf0r8ffyhth7pqdujjat9nx0862jpsg2pqaq
     #[only_owner]
     #[endpoint(setXExchangePair)]
-    fn set_xexchange_pair(&self, pair: ManagedAddress) {
-        self.xexchange_pair().set(&pair);
+    fn set_xexchange_pair(&self, pair: ManagedAddress) -> u64 {
+        self.queue_action(timelock::TimelockAction::SetXExchangePair(pair))
+    }
+
+    /// Set the pair that swaps `token`, overriding the default pair for
+    /// orders on it (owner only)
+    /// Activates orders created while the token was awaiting its pair
+    #[only_owner]
+    #[endpoint(setTokenPair)]
+    fn set_token_pair(&self, token: TokenIdentifier, pair: ManagedAddress) -> u64 {
+        self.queue_action(timelock::TimelockAction::SetTokenPair(token, pair))
+    }
+
+    /// Call `pair`'s swap as `function(arguments...)` instead of
+    /// `swapTokensFixedInput(tokenOut, minAmountOut)` (owner only)
+    /// An empty `function` restores the default
+    #[only_owner]
+    #[endpoint(setSwapDescriptor)]
+    fn set_swap_descriptor(
+        &self,
+        pair: ManagedAddress,
+        function: ManagedBuffer,
+        arguments: MultiValueEncoded<dex::SwapArgument>,
+    ) -> u64 {
+        let descriptor = dex::SwapCallDescriptor {
+            function,
+            arguments: arguments.to_vec(),
+        };
+        self.queue_action(timelock::TimelockAction::SetSwapDescriptor(pair, descriptor))
+    }
+
+    /// Whitelist a new token and route its orders to `pair` (owner only)
+    #[only_owner]
+    #[endpoint(listToken)]
+    fn list_token(&self, token: TokenIdentifier, pair: ManagedAddress) -> u64 {
+        self.queue_action(timelock::TimelockAction::ListToken(token, pair))
+    }
+
+    /// Set the xExchange router executing routed orders (owner only)
+    #[only_owner]
+    #[endpoint(setXExchangeRouter)]
+    fn set_xexchange_router(&self, router: ManagedAddress) -> u64 {
+        self.queue_action(timelock::TimelockAction::SetXExchangeRouter(router))
+    }
+
+    /// Allow (or stop allowing) orders to forward their output to
+    /// `destination` (owner only)
+    #[only_owner]
+    #[endpoint(setForwardDestination)]
+    fn set_forward_destination(&self, destination: ManagedAddress, allowed: bool) -> u64 {
+        self.queue_action(timelock::TimelockAction::SetForwardDestination(destination, allowed))
+    }
+
+    /// Set executor address (owner only)
+    #[only_owner]
+    #[endpoint(setLimitOrderExecutor)]
+    fn set_limit_order_executor(&self, executor: ManagedAddress) -> u64 {
+        self.queue_action(timelock::TimelockAction::SetLimitOrderExecutor(executor))
+    }
+
+    /// Set execution fee in basis points (owner only)
+    /// Example: 10 = 0.1%, 50 = 0.5%, 100 = 1%
+    #[only_owner]
+    #[endpoint(setExecutionFeeBps)]
+    fn set_execution_fee_bps(&self, fee_bps: u64) -> u64 {
+        self.queue_action(timelock::TimelockAction::SetExecutionFeeBps(fee_bps))
+    }
+
+    /// Set the minimum execution fee of fills paying out `token`, in its
+    /// base units (owner only, 0 removes it)
+    #[only_owner]
+    #[endpoint(setExecutionFeeFloor)]
+    fn set_execution_fee_floor(&self, token: TokenIdentifier, min_fee: BigUint) -> u64 {
+        self.queue_action(timelock::TimelockAction::SetExecutionFeeFloor(token, min_fee))
+    }
+
+    /// Split the output of fills above their target price: `executor_bps`
+    /// to the executor, `treasury_bps` to the treasury, the rest to the
+    /// order owner (owner only)
+    #[only_owner]
+    #[endpoint(setPriceImprovementSharing)]
+    fn set_price_improvement_sharing(&self, executor_bps: u64, treasury_bps: u64) -> u64 {
+        self.queue_action(timelock::TimelockAction::SetPriceImprovementSharing(
+            executor_bps,
+            treasury_bps,
+        ))
+    }
+
+    /// Set protocol fee in basis points, accrued for the treasury (owner only)
+    #[only_owner]
+    #[endpoint(setProtocolFeeBps)]
+    fn set_protocol_fee_bps(&self, fee_bps: u64) -> u64 {
+        self.queue_action(timelock::TimelockAction::SetProtocolFeeBps(fee_bps))
+    }
+
+    /// Set the address receiving claimed protocol fees (owner only)
+    #[only_owner]
+    #[endpoint(setTreasuryAddress)]
+    fn set_treasury_address(&self, treasury: ManagedAddress) -> u64 {
+        self.queue_action(timelock::TimelockAction::SetTreasuryAddress(treasury))
+    }
+
+    /// Set the anti-spoofing cancellation policy (owner only)
+    /// Orders younger than `window_seconds` either cannot be cancelled
+    /// (`fee_bps` = 0) or pay `fee_bps` of the refund as a protocol fee
+    #[only_owner]
+    #[endpoint(setCancellationPolicy)]
+    fn set_cancellation_policy(&self, window_seconds: u64, fee_bps: u64) -> u64 {
+        self.queue_action(timelock::TimelockAction::SetCancellationPolicy(
+            window_seconds,
+            fee_bps,
+        ))
+    }
+
+    /// Set the lending market idle escrow of `token` can be supplied to
+    /// (owner only); the zero address disables lending for the token
+    #[only_owner]
+    #[endpoint(setLendingMarket)]
+    fn set_lending_market(
+        &self,
+        token: TokenIdentifier,
+        market: ManagedAddress,
+        share_token: TokenIdentifier,
+    ) -> u64 {
+        self.queue_action(timelock::TimelockAction::SetLendingMarket(
+            token,
+            market,
+            share_token,
+        ))
+    }
+
+    /// Choose who keeps interest earned on lent escrow (owner only):
+    /// the order (`true`) or the protocol fees (`false`)
+    #[only_owner]
+    #[endpoint(setLendingInterestToUser)]
+    fn set_lending_interest_to_user(&self, to_user: bool) -> u64 {
+        self.queue_action(timelock::TimelockAction::SetLendingInterestToUser(to_user))
+    }
+
+    /// Pay `amount` of the insurance fund to `user` after an incident
+    /// (owner only); capped per token by the compensation cap
+    #[only_owner]
+    #[endpoint(compensate)]
+    fn compensate(&self, user: ManagedAddress, token: TokenIdentifier, amount: BigUint) -> u64 {
+        self.queue_action(timelock::TimelockAction::Compensate(user, token, amount))
+    }
+
+    /// Queue a sweep of the surplus balance of `token` (above escrow and
+    /// protocol pools, see `reconcile`) to the treasury (owner only)
+    /// Waits at least 24 hours like emergency withdrawals
+    #[only_owner]
+    #[endpoint(sweepSurplus)]
+    fn sweep_surplus(&self, token: TokenIdentifier) -> u64 {
+        self.queue_action(timelock::TimelockAction::SweepSurplus(token))
+    }
+
+    /// Queue withdrawal of stray tokens (owner only)
+    /// Only the balance above the tracked escrow total can be withdrawn,
+    /// and the action always waits at least 24 hours
+    #[only_owner]
+    #[endpoint(emergencyWithdraw)]
+    fn emergency_withdraw(&self, token: TokenIdentifier, amount: BigUint) -> u64 {
+        self.queue_action(timelock::TimelockAction::EmergencyWithdraw(token, amount))
     }
 
     /// Upgrade contract code
@@ -116,7 +362,37 @@ pub trait StellarNova:
     #[upgrade]
     fn upgrade(&self) {
         // Storage is automatically preserved during upgrade
-        // No additional migration logic needed for now
+
+        // Deployments before versioning have no stored version
+        let old_version = if self.contract_version().is_empty() {
+            1
+        } else {
+            self.contract_version().get()
+        };
+        require!(
+            CONTRACT_VERSION >= old_version,
+            ERROR_VERSION_DOWNGRADE
+        );
+        self.contract_version().set(CONTRACT_VERSION);
+        self.contract_upgraded_event(old_version, CONTRACT_VERSION);
+
+        // Bring storage to the current layout; order rewrites are batched
+        // and finish through migrateOrders
+        self.run_migrations();
+
+        // Carry the legacy boolean pause flag over to pause levels
+        if !self.legacy_paused().is_empty() {
+            if self.legacy_paused().get() {
+                self.pause_level().set(pause::PauseLevel::FullStop);
+            }
+            self.legacy_paused().clear();
+        }
+        // A FullStop from before wind-down tracking counts from now
+        let level = self.pause_level().get();
+        self.store_pause_level(level);
+
+        self.max_execution_attempts()
+            .set_if_empty(limit_orders::DEFAULT_MAX_EXECUTION_ATTEMPTS);
     }
 
     // ========== VIEW FUNCTIONS ==========
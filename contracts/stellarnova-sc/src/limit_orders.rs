@@ -1,29 +1,96 @@
-/// Limit Order Module for StellarNova
-///
-/// JEXchange-style architecture:
-/// - Orders created with direct ESDT payment
-/// - Tokens held in contract until execution or cancellation
-/// - Synchronous swaps (no async callbacks)
+//! Limit Order Module for StellarNova
+//!
+//! JEXchange-style architecture:
+//! - Orders created with direct ESDT payment
+//! - Tokens held in contract until execution or cancellation
+//! - Synchronous swaps (no async callbacks)
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use crate::dex::{MinOutputBound, MinOutputMode, SwapArgument};
+use crate::errors::*;
+use crate::forwarding::{OutputForward, MAX_FORWARD_ARGUMENTS};
+use crate::managers::{SESSION_PERMISSION_CANCEL, SESSION_PERMISSION_MODIFY};
+use crate::pause::PauseLevel;
+use crate::reasons::ReasonCode;
+use crate::signals::{PriceComparison, PriceCondition};
+use crate::stats::ProtocolStats;
+use crate::streams::MAX_PAYOUT_STREAM_SECONDS;
+use crate::storage::{LentEscrow, RedeemPurpose, SwapExecutionContext, ZapExecution};
+
+/// Basis points denominator (10000 bps = 100%)
+/// Hard upper bound for any slippage or fee expressed in bps
+pub const MAX_BASIS_POINTS: u64 = 10_000;
+
+/// Fixed-point scale for prices expressed as a single integer (1e18)
+pub const PRICE_SCALE: u64 = 1_000_000_000_000_000_000;
+
+/// Maximum bounty for sweeping expired orders (1%)
+pub const MAX_EXPIRY_BOUNTY_BPS: u64 = 100;
+
+/// Longest total lifetime, from creation, an order's expiry can be extended to (1 year)
+pub const MAX_ORDER_LIFETIME_SECONDS: u64 = 365 * 24 * 60 * 60;
+
+/// Width of the buckets indexing orders by creation time (1 day)
+pub const ORDER_HISTORY_BUCKET_SECONDS: u64 = 24 * 60 * 60;
+
+/// Most orders returned by one `getOrdersCreatedBetween` or `getOrdersRange` page
+pub const MAX_ORDER_HISTORY_PAGE: u64 = 100;
+
+/// Price book buckets per doubling of the price, as a power of two (16 buckets)
+pub const PRICE_BUCKET_BITS: usize = 4;
+
+/// Gas for the `getTokenProperties` lookup of `fetchTokenDecimals`
+const FETCH_DECIMALS_GAS: u64 = 10_000_000;
+const FETCH_DECIMALS_CALLBACK_GAS: u64 = 5_000_000;
+
+/// Gas for the router call of a routed order (two swaps)
+const ROUTED_SWAP_GAS: u64 = 50_000_000;
+
+/// Prefix of the decimals entry in `getTokenProperties` results
+const NUM_DECIMALS_PREFIX: &[u8] = b"NumDecimals-";
+
+/// Failed swap attempts after which an order is refunded
+pub const DEFAULT_MAX_EXECUTION_ATTEMPTS: u64 = 3;
+
+/// Failed attempts after which the retry backoff stops doubling
+pub const MAX_RETRY_BACKOFF_DOUBLINGS: u64 = 10;
+
+/// Async executions in flight for longer than this (10 minutes) are
+/// reported as stuck by `getExecutorHealth`
+pub const STUCK_EXECUTION_SECONDS: u64 = 10 * 60;
+
 #[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum OrderStatus {
     Pending,
     Executed,
     Cancelled,
     Expired,
+    Failed,
+    /// Chained order waiting for its parent's first fill to fund it
+    Dormant,
+}
+
+/// Why an order was cancelled; orders cancelled by their owner (or a
+/// manager) keep the default
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CancelReason {
+    User,
+    StrategyKill,
+    TokenDelisted,
+    WindDown,
 }
 
 #[type_abi]
-#[derive(TopEncode, TopDecode)]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct LimitOrder<M: ManagedTypeApi> {
     pub order_id: u64,
     pub user: ManagedAddress<M>,
     pub from_token: TokenIdentifier<M>,
-    pub from_amount: BigUint<M>,
+    pub from_amount: BigUint<M>,                 // remaining escrow; total filled once executed
     pub to_token: TokenIdentifier<M>,
     pub target_price_numerator: BigUint<M>,     // e.g., 50 USDC
     pub target_price_denominator: BigUint<M>,   // e.g., 1 WEGLD
@@ -33,11 +100,97 @@ pub struct LimitOrder<M: ManagedTypeApi> {
     pub created_at: u64,
 }
 
+/// Exit legs of a bracket order, prices in payment token per bought token
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct BracketExits<M: ManagedTypeApi> {
+    pub take_profit_num: BigUint<M>,
+    pub take_profit_denom: BigUint<M>,
+    pub stop_trigger_num: BigUint<M>,
+    pub stop_trigger_denom: BigUint<M>,
+    pub stop_limit_num: BigUint<M>,
+    pub stop_limit_denom: BigUint<M>,
+    pub lifetime_seconds: u64,
+}
+
+/// Mutable terms of an order, used to report modifications
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct OrderTerms<M: ManagedTypeApi> {
+    pub to_token: TokenIdentifier<M>,
+    pub target_price_numerator: BigUint<M>,
+    pub target_price_denominator: BigUint<M>,
+    pub slippage_bp: u64,
+    pub expires_at: u64,
+}
+
+impl<M: ManagedTypeApi> OrderTerms<M> {
+    pub fn from_order(order: &LimitOrder<M>) -> Self {
+        OrderTerms {
+            to_token: order.to_token.clone(),
+            target_price_numerator: order.target_price_numerator.clone(),
+            target_price_denominator: order.target_price_denominator.clone(),
+            slippage_bp: order.slippage_bp,
+            expires_at: order.expires_at,
+        }
+    }
+}
+
+/// Structured intent of the agent placing an order, for post-trade
+/// attribution of fills to model versions
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct OrderIntent<M: ManagedTypeApi> {
+    pub strategy_id: u64, // 0 = none
+    pub confidence_bps: u64,
+    pub model_version: ManagedByteArray<M, 32>,
+}
+
+/// Swap pair status of a whitelisted token
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct TokenPairHealth<M: ManagedTypeApi> {
+    pub token: TokenIdentifier<M>,
+    pub pair_configured: bool,
+    pub pair_tripped: bool,
+}
+
+/// Everything the executor bot and monitoring alert on, in one query
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct ExecutorHealth<M: ManagedTypeApi> {
+    pub pause_level: PauseLevel,
+    pub token_pairs: ManagedVec<M, TokenPairHealth<M>>,
+    pub pending_orders: u64,
+    pub oldest_pending_age: u64,     // seconds, 0 without pending orders
+    pub stuck_executions: u64,
+}
+
 #[multiversx_sc::module]
 pub trait LimitOrdersModule:
     crate::storage::StorageModule
     + crate::events::EventsModule
     + crate::dex::DexModule
+    + crate::pause::PauseModule
+    + crate::blacklist::BlacklistModule
+    + crate::treasury::TreasuryModule
+    + crate::managers::ManagersModule
+    + crate::positions::PositionsModule
+    + crate::strategies::StrategiesModule
+    + crate::signals::SignalsModule
+    + crate::streams::StreamsModule
+    + crate::listings::ListingsModule
+    + crate::decimals::DecimalsModule
+    + crate::caps::CapsModule
+    + crate::breaker::CircuitBreakerModule
+    + crate::staking::StakingModule
+    + crate::insurance::InsuranceModule
+    + crate::stats::StatsModule
+    + crate::clients::ClientsModule
+    + crate::commitments::CommitmentsModule
+    + crate::auctions::AuctionsModule
+    + crate::gas_station::GasStationModule
+    + crate::forwarding::ForwardingModule
 {
     /// Create a limit order with ESDT payment (JEXchange style)
     ///
@@ -52,7 +205,10 @@ pub trait LimitOrdersModule:
     /// * `target_price_denom` - Target price denominator
     /// * `slippage_bp` - Slippage tolerance in basis points
     /// * `expires_in_seconds` - How long until order expires
+    /// * `opt_strategy_id` - Optional strategy to tag the order with (0 = none)
+    /// * `opt_client_tag` - Optional 32-byte client metadata hash
     #[payable("*")]
+    #[allow_multiple_var_args]
     #[endpoint(createLimitOrder)]
     fn create_limit_order(
         &self,
@@ -61,320 +217,4016 @@ pub trait LimitOrdersModule:
         target_price_denom: BigUint,
         slippage_bp: u64,
         expires_in_seconds: u64,
+        opt_strategy_id: OptionalValue<u64>,
+        opt_client_tag: OptionalValue<ManagedByteArray<Self::Api, 32>>,
     ) -> u64 {
-        require!(!self.paused().get(), "Contract is paused");
+        self.require_creation_allowed();
 
         let caller = self.blockchain().get_caller();
-        let (from_token, from_amount) = self.call_value().single_fungible_esdt();
+        self.require_not_blacklisted(&caller);
+        let (from_token, from_amount) = self.receive_fungible_payment();
 
-        // Validate tokens
-        require!(
-            self.whitelisted_tokens().contains(&from_token),
-            "From token not whitelisted"
-        );
-        require!(
-            self.whitelisted_tokens().contains(&to_token),
-            "To token not whitelisted"
+        // Tokens are already in contract
+        self.lock_escrow(&from_token, &from_amount);
+
+        let order_id = self.create_order(
+            &caller,
+            from_token,
+            from_amount,
+            to_token,
+            target_price_num,
+            target_price_denom,
+            slippage_bp,
+            expires_in_seconds,
         );
-        require!(*from_token != to_token, "Cannot swap token to itself");
 
-        // Validate amounts
-        require!(*from_amount > 0u64, "Amount must be greater than zero");
-        require!(target_price_num > 0u64, "Target price numerator must be positive");
-        require!(target_price_denom > 0u64, "Target price denominator must be positive");
+        self.apply_order_tags(order_id, opt_strategy_id, opt_client_tag);
 
-        // Validate slippage
-        let max_slippage = self.max_slippage().get();
-        require!(
-            slippage_bp <= max_slippage,
-            "Slippage exceeds maximum allowed"
-        );
+        order_id
+    }
 
-        // Calculate expiry
-        #[allow(deprecated)]
-        let current_time = self.blockchain().get_block_timestamp();
-        let expires_at = current_time + expires_in_seconds;
+    /// Create a limit order priced with a single scaled price
+    ///
+    /// Same as `createLimitOrder`, but the price is given as whole `to_token`
+    /// per whole payment token, scaled by 1e18; token decimals come from the
+    /// on-chain registry (`getTokenDecimals`)
+    ///
+    /// # Arguments
+    /// * `to_token` - Token to buy
+    /// * `scaled_price` - Target price, scaled by 1e18
+    /// * `slippage_bp` - Slippage tolerance in basis points
+    /// * `expires_in_seconds` - How long until order expires
+    /// * `opt_strategy_id` - Optional strategy to tag the order with (0 = none)
+    /// * `opt_client_tag` - Optional 32-byte client metadata hash
+    #[payable("*")]
+    #[allow_multiple_var_args]
+    #[endpoint(createLimitOrderScaled)]
+    fn create_limit_order_scaled(
+        &self,
+        to_token: TokenIdentifier,
+        scaled_price: BigUint,
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+        opt_strategy_id: OptionalValue<u64>,
+        opt_client_tag: OptionalValue<ManagedByteArray<Self::Api, 32>>,
+    ) -> u64 {
+        self.require_creation_allowed();
 
-        // Create order
-        let order_id = self.next_order_id().get();
-        let order = LimitOrder {
-            order_id,
-            user: caller.clone(),
-            from_token: from_token.clone(),
-            from_amount: from_amount.clone(),
-            to_token: to_token.clone(),
-            target_price_numerator: target_price_num.clone(),
-            target_price_denominator: target_price_denom.clone(),
-            slippage_bp,
-            expires_at,
-            status: OrderStatus::Pending,
-            created_at: current_time,
-        };
+        let caller = self.blockchain().get_caller();
+        self.require_not_blacklisted(&caller);
+        let (from_token, from_amount) = self.receive_fungible_payment();
+        let (target_price_num, target_price_denom) =
+            self.human_to_raw_price(&from_token, &to_token, scaled_price);
 
-        // Store order
-        self.limit_orders(order_id).set(&order);
-        self.user_orders(&caller).insert(order_id);
-        self.next_order_id().set(order_id + 1);
+        self.lock_escrow(&from_token, &from_amount);
 
-        // Emit event (tokens are already in contract)
-        self.limit_order_created_event(
-            order_id,
+        let order_id = self.create_order(
             &caller,
-            &from_token,
-            &from_amount,
-            &to_token,
-            &target_price_num,
-            &target_price_denom,
-            expires_at,
+            from_token,
+            from_amount,
+            to_token,
+            target_price_num,
+            target_price_denom,
+            slippage_bp,
+            expires_in_seconds,
         );
 
+        self.apply_order_tags(order_id, opt_strategy_id, opt_client_tag);
+
         order_id
     }
 
-    /// Execute a limit order (called by backend executor)
+    /// Create a limit order represented by a transferable position NFT
     ///
-    /// Performs SYNC swap on xExchange and sends output tokens to user
+    /// Same as `createLimitOrder`, but the caller receives a position NFT;
+    /// its holder controls the order and claims its payouts
     ///
     /// # Arguments
-    /// * `order_id` - ID of order to execute
-    /// * `current_price_num` - Current price numerator (for verification)
-    /// * `current_price_denom` - Current price denominator
-    #[endpoint(executeLimitOrder)]
-    fn execute_limit_order(
+    /// * `to_token` - Token to buy
+    /// * `target_price_num` - Target price numerator
+    /// * `target_price_denom` - Target price denominator
+    /// * `slippage_bp` - Slippage tolerance in basis points
+    /// * `expires_in_seconds` - How long until order expires
+    /// * `opt_strategy_id` - Optional strategy to tag the order with (0 = none)
+    /// * `opt_client_tag` - Optional 32-byte client metadata hash
+    #[payable("*")]
+    #[allow_multiple_var_args]
+    #[endpoint(createLimitOrderWithPosition)]
+    fn create_limit_order_with_position(
         &self,
-        order_id: u64,
-        current_price_num: BigUint,
-        current_price_denom: BigUint,
-    ) {
-        require!(!self.paused().get(), "Contract is paused");
+        to_token: TokenIdentifier,
+        target_price_num: BigUint,
+        target_price_denom: BigUint,
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+        opt_strategy_id: OptionalValue<u64>,
+        opt_client_tag: OptionalValue<ManagedByteArray<Self::Api, 32>>,
+    ) -> u64 {
+        let order_id = self.create_limit_order(
+            to_token,
+            target_price_num,
+            target_price_denom,
+            slippage_bp,
+            expires_in_seconds,
+            opt_strategy_id,
+            opt_client_tag,
+        );
+        self.mint_position(order_id, &self.blockchain().get_caller());
 
-        let caller = self.blockchain().get_caller();
-        let executor = self.limit_order_executor().get();
+        order_id
+    }
 
-        require!(caller == executor, "Only executor can execute orders");
+    /// Create a limit order that is only executable after `signal_id` fired
+    ///
+    /// A signal that already fired does not hold the order back, it is
+    /// executable right away
+    ///
+    /// # Arguments
+    /// * `signal_id` - Signal the order waits for (see `fireSignal`)
+    /// * `to_token` - Token to buy
+    /// * `target_price_num` - Target price numerator
+    /// * `target_price_denom` - Target price denominator
+    /// * `slippage_bp` - Slippage tolerance in basis points
+    /// * `expires_in_seconds` - How long until order expires
+    #[payable("*")]
+    #[endpoint(createSignalOrder)]
+    fn create_signal_order(
+        &self,
+        signal_id: u64,
+        to_token: TokenIdentifier,
+        target_price_num: BigUint,
+        target_price_denom: BigUint,
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+    ) -> u64 {
+        let order_id = self.create_limit_order(
+            to_token,
+            target_price_num,
+            target_price_denom,
+            slippage_bp,
+            expires_in_seconds,
+            OptionalValue::None,
+            OptionalValue::None,
+        );
+        self.order_signal(order_id).set(signal_id);
 
-        let order = self.limit_orders(order_id).get();
+        order_id
+    }
 
-        require!(
-            matches!(order.status, OrderStatus::Pending),
-            "Order is not pending"
+    /// Create a limit order attributed to a registered client (integrator)
+    ///
+    /// The client's volume counts the order's fills and it earns
+    /// `clientFeeShareBps` of the protocol fees they pay
+    ///
+    /// # Arguments
+    /// * `client_id` - Client the order is created through (see `registerClient`)
+    /// * `to_token` - Token to buy
+    /// * `target_price_num` - Target price numerator
+    /// * `target_price_denom` - Target price denominator
+    /// * `slippage_bp` - Slippage tolerance in basis points
+    /// * `expires_in_seconds` - How long until order expires
+    /// * `opt_strategy_id` - Optional strategy to tag the order with (0 = none)
+    /// * `opt_client_tag` - Optional 32-byte client metadata hash
+    #[payable("*")]
+    #[allow_multiple_var_args]
+    #[endpoint(createClientOrder)]
+    #[allow(clippy::too_many_arguments)]
+    fn create_client_order(
+        &self,
+        client_id: u64,
+        to_token: TokenIdentifier,
+        target_price_num: BigUint,
+        target_price_denom: BigUint,
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+        opt_strategy_id: OptionalValue<u64>,
+        opt_client_tag: OptionalValue<ManagedByteArray<Self::Api, 32>>,
+    ) -> u64 {
+        let order_id = self.create_limit_order(
+            to_token,
+            target_price_num,
+            target_price_denom,
+            slippage_bp,
+            expires_in_seconds,
+            opt_strategy_id,
+            opt_client_tag,
         );
+        self.attribute_order_client(order_id, client_id);
 
-        #[allow(deprecated)]
-        let current_time = self.blockchain().get_block_timestamp();
-        require!(current_time <= order.expires_at, "Order expired");
-
-        // Verify price condition is met
-        let target_price = &order.target_price_numerator * &current_price_denom;
-        let current_price = &current_price_num * &order.target_price_denominator;
+        order_id
+    }
 
+    /// Create a limit order carrying the intent of the agent placing it
+    ///
+    /// The intent is stored with the order and emitted, so fills can be
+    /// attributed to the model version behind them; its strategy (if not 0)
+    /// tags the order like `opt_strategy_id` of `createLimitOrder`
+    ///
+    /// # Arguments
+    /// * `to_token` - Token to buy
+    /// * `target_price_num` - Target price numerator
+    /// * `target_price_denom` - Target price denominator
+    /// * `slippage_bp` - Slippage tolerance in basis points
+    /// * `expires_in_seconds` - How long until order expires
+    /// * `intent` - Strategy, confidence (bps) and model version hash
+    /// * `opt_client_tag` - Optional 32-byte client metadata hash
+    #[payable("*")]
+    #[endpoint(createLimitOrderWithIntent)]
+    fn create_limit_order_with_intent(
+        &self,
+        to_token: TokenIdentifier,
+        target_price_num: BigUint,
+        target_price_denom: BigUint,
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+        intent: OrderIntent<Self::Api>,
+        opt_client_tag: OptionalValue<ManagedByteArray<Self::Api, 32>>,
+    ) -> u64 {
         require!(
-            current_price <= target_price,
-            "Price condition not met"
+            intent.confidence_bps <= MAX_BASIS_POINTS,
+            ERROR_CONFIDENCE_ABOVE_100_PERCENT
         );
 
-        // Calculate minimum output with slippage
-        let min_amount_out = self.calculate_min_output(
-            &order.from_amount,
-            &order.target_price_numerator,
-            &order.target_price_denominator,
-            order.slippage_bp,
+        let order_id = self.create_limit_order(
+            to_token,
+            target_price_num,
+            target_price_denom,
+            slippage_bp,
+            expires_in_seconds,
+            OptionalValue::Some(intent.strategy_id),
+            opt_client_tag,
         );
-
-        // Store execution context for callback
-        let context = crate::storage::SwapExecutionContext {
+        self.order_intent(order_id).set(&intent);
+        self.order_intent_recorded_event(
             order_id,
-            user: order.user.clone(),
-            executor: caller.clone(),
-            to_token: order.to_token.clone(),
-            min_amount_out: min_amount_out.clone(),
-        };
-        self.pending_swap_executions(order_id).set(&context);
-
-        // Execute ASYNC swap on xExchange (works cross-shard!)
-        let pair_address = self.xexchange_pair().get();
+            intent.strategy_id,
+            &intent.model_version,
+            intent.confidence_bps,
+        );
 
-        self.tx()
-            .to(&pair_address)
-            .gas(30_000_000u64)
-            .raw_call("swapTokensFixedInput")
-            .argument(&order.to_token)
-            .argument(&min_amount_out)
-            .single_esdt(&order.from_token, 0u64, &order.from_amount)
-            .with_callback(self.callbacks().swap_callback(order_id))
-            .with_extra_gas_for_callback(10_000_000)
-            .register_promise();
+        order_id
     }
 
-    /// Callback handler for async swap completion (PROMISES API)
-    #[promises_callback]
-    fn swap_callback(
+    /// Create a limit order under a client-chosen reference, unique per user
+    ///
+    /// A retried creation with a reference the caller already used fails
+    /// instead of creating a second order, so a client unsure whether its
+    /// transaction went through can safely send it again; the order is
+    /// found with `getOrderByReference`. References stay used after the
+    /// order is settled
+    ///
+    /// # Arguments
+    /// * `reference` - Client reference, e.g. a request counter
+    /// * `to_token` - Token to buy
+    /// * `target_price_num` - Target price numerator
+    /// * `target_price_denom` - Target price denominator
+    /// * `slippage_bp` - Slippage tolerance in basis points
+    /// * `expires_in_seconds` - How long until order expires
+    /// * `opt_strategy_id` - Optional strategy to tag the order with (0 = none)
+    /// * `opt_client_tag` - Optional 32-byte client metadata hash
+    #[payable("*")]
+    #[allow_multiple_var_args]
+    #[endpoint(createLimitOrderWithReference)]
+    #[allow(clippy::too_many_arguments)]
+    fn create_limit_order_with_reference(
         &self,
-        order_id: u64,
-        #[call_result] result: ManagedAsyncCallResult<MultiValueEncoded<EsdtTokenPayment>>,
-    ) {
-        // Retrieve execution context
-        let context_mapper = self.pending_swap_executions(order_id);
-        require!(!context_mapper.is_empty(), "Execution context not found");
-
-        let context = context_mapper.get();
-        context_mapper.clear();
-
-        match result {
-            ManagedAsyncCallResult::Ok(payments) => {
-                // Extract swap output
-                let mut output_amount = BigUint::zero();
-                for payment in payments.into_iter() {
-                    if payment.token_identifier == context.to_token {
-                        output_amount = payment.amount;
-                        break;
-                    }
-                }
-
-                require!(
-                    output_amount >= context.min_amount_out,
-                    "Swap output below minimum"
-                );
-
-                // Calculate execution fee
-                let fee_bps = self.execution_fee_bps().get();
-                let execution_fee = &output_amount * &BigUint::from(fee_bps) / &BigUint::from(10000u64);
-                let user_amount = &output_amount - &execution_fee;
-
-                // Send tokens
-                if execution_fee > 0u64 {
-                    self.send().direct_esdt(
-                        &context.executor,
-                        &context.to_token,
-                        0u64,
-                        &execution_fee,
-                    );
-                }
-
-                self.send().direct_esdt(
-                    &context.user,
-                    &context.to_token,
-                    0u64,
-                    &user_amount,
-                );
+        reference: u64,
+        to_token: TokenIdentifier,
+        target_price_num: BigUint,
+        target_price_denom: BigUint,
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+        opt_strategy_id: OptionalValue<u64>,
+        opt_client_tag: OptionalValue<ManagedByteArray<Self::Api, 32>>,
+    ) -> u64 {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.order_by_reference(&caller, reference).is_empty(),
+            ERROR_DUPLICATE_REFERENCE
+        );
 
-                // Mark order as executed
-                let mut order = self.limit_orders(order_id).get();
-                order.status = OrderStatus::Executed;
-                self.limit_orders(order_id).set(&order);
+        let order_id = self.create_limit_order(
+            to_token,
+            target_price_num,
+            target_price_denom,
+            slippage_bp,
+            expires_in_seconds,
+            opt_strategy_id,
+            opt_client_tag,
+        );
+        self.order_by_reference(&caller, reference).set(order_id);
+        self.order_reference_recorded_event(&caller, reference, order_id);
 
-                // Emit event
-                #[allow(deprecated)]
-                let current_time = self.blockchain().get_block_timestamp();
-                self.limit_order_executed_event(
-                    order_id,
-                    &context.user,
-                    &order.from_token,
-                    &order.from_amount,
-                    &context.to_token,
-                    &user_amount,
-                    current_time,
-                );
-            }
-            ManagedAsyncCallResult::Err(err) => {
-                // Swap failed - order remains pending for retry
-                sc_panic!("Swap failed: {}", err.err_msg);
-            }
-        }
+        order_id
     }
 
-    /// Cancel a limit order (user can cancel their own orders)
+    /// Create a routed order swapping the payment to `to_token` through
+    /// `via_token`, both legs executed atomically by the swap router
     ///
-    /// Returns tokens to user immediately
+    /// The target price and slippage apply to the final output only; the
+    /// first leg accepts any amount of `via_token`
     ///
     /// # Arguments
-    /// * `order_id` - ID of order to cancel
-    #[endpoint(cancelLimitOrder)]
-    fn cancel_limit_order(&self, order_id: u64) {
+    /// * `via_token` - Intermediate token of the route
+    /// * `to_token` - Token to buy
+    /// * `target_price_num` - Target price numerator
+    /// * `target_price_denom` - Target price denominator
+    /// * `slippage_bp` - Slippage tolerance in basis points
+    /// * `expires_in_seconds` - How long until order expires
+    #[payable("*")]
+    #[endpoint(createRoutedOrder)]
+    fn create_routed_order(
+        &self,
+        via_token: TokenIdentifier,
+        to_token: TokenIdentifier,
+        target_price_num: BigUint,
+        target_price_denom: BigUint,
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+    ) -> u64 {
+        require!(!self.xexchange_router().is_empty(), ERROR_ROUTER_NOT_SET);
+        require!(
+            self.whitelisted_tokens().contains(&via_token),
+            ERROR_NOT_WHITELISTED
+        );
+        let from_token = self.call_value().single_esdt().token_identifier.clone();
+        require!(
+            via_token != from_token && via_token != to_token,
+            ERROR_SAME_TOKEN
+        );
+
+        let order_id = self.create_limit_order(
+            to_token,
+            target_price_num,
+            target_price_denom,
+            slippage_bp,
+            expires_in_seconds,
+            OptionalValue::None,
+            OptionalValue::None,
+        );
+        self.order_route_via(order_id).set(&via_token);
+        self.routed_order_created_event(order_id, &via_token);
+
+        order_id
+    }
+
+    /// Create an order chained to a pending order of the caller: it stays
+    /// Dormant until the parent executes, then the parent's output becomes
+    /// its escrow and it turns Pending (e.g. buy WEGLD, then sell it at a
+    /// take-profit price)
+    ///
+    /// Every later fill of the parent adds its output to the chained order
+    /// while that order is pending. The lifetime starts at activation;
+    /// cancelling or expiring the parent before any fill cancels it
+    ///
+    /// # Arguments
+    /// * `parent_order_id` - Order whose output funds the chained order
+    /// * `to_token` - Token to buy with the parent's output
+    /// * `target_price_num` - Target price numerator
+    /// * `target_price_denom` - Target price denominator
+    /// * `slippage_bp` - Slippage tolerance in basis points
+    /// * `expires_in_seconds` - How long after activation the order expires
+    #[endpoint(createChainedOrder)]
+    fn create_chained_order(
+        &self,
+        parent_order_id: u64,
+        to_token: TokenIdentifier,
+        target_price_num: BigUint,
+        target_price_denom: BigUint,
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+    ) -> u64 {
+        self.require_creation_allowed();
+
+        let caller = self.blockchain().get_caller();
+        let parent = self.require_modifiable_order(parent_order_id, &caller);
+        require!(
+            self.chained_order(parent_order_id).is_empty(),
+            ERROR_ALREADY_CHAINED
+        );
+        require!(
+            !self.zap_order(parent_order_id).get(),
+            ERROR_ZAP_ORDER_NOT_CHAINABLE
+        );
+
+        let order_id = self.create_dormant_order(
+            &parent.user,
+            parent.to_token,
+            to_token,
+            target_price_num,
+            target_price_denom,
+            slippage_bp,
+            expires_in_seconds,
+        );
+        self.chained_order(parent_order_id).set(order_id);
+        self.chain_parent(order_id).set(parent_order_id);
+        self.chained_order_created_event(order_id, parent_order_id, &parent.user);
+
+        order_id
+    }
+
+    /// Create a bracket: an entry order buying `to_token`, a take-profit
+    /// selling the entry's output back and a stop-loss guarding it
+    ///
+    /// The take-profit is chained to the entry (see `createChainedOrder`).
+    /// The stop-loss stays Dormant with an oracle condition on the
+    /// `to_token`/payment price falling to `stop_trigger`; once it holds,
+    /// `triggerStopLoss` moves the take-profit's remaining escrow to the
+    /// stop-loss, which then sells at `stop_limit` or better. Whichever
+    /// exit closes first cancels the other
+    ///
+    /// # Arguments
+    /// * `to_token` - Token the entry buys
+    /// * `target_price_num` - Entry target price numerator
+    /// * `target_price_denom` - Entry target price denominator
+    /// * `slippage_bp` - Slippage tolerance of all three orders
+    /// * `expires_in_seconds` - How long until the entry expires
+    /// * `exits` - Take-profit and stop prices and the exits' lifetime
+    ///
+    /// Returns the entry, take-profit and stop-loss order ids
+    #[payable("*")]
+    #[endpoint(createBracketOrder)]
+    fn create_bracket_order(
+        &self,
+        to_token: TokenIdentifier,
+        target_price_num: BigUint,
+        target_price_denom: BigUint,
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+        exits: BracketExits<Self::Api>,
+    ) -> MultiValue3<u64, u64, u64> {
+        let from_token = self.call_value().single_esdt().token_identifier.clone();
+        self.require_price_pair(&to_token, &from_token);
+        require!(exits.stop_trigger_denom > 0u64, ERROR_PRICE_DENOM_ZERO);
+
+        let entry_id = self.create_limit_order(
+            to_token.clone(),
+            target_price_num,
+            target_price_denom,
+            slippage_bp,
+            expires_in_seconds,
+            OptionalValue::None,
+            OptionalValue::None,
+        );
+        let take_profit_id = self.create_chained_order(
+            entry_id,
+            from_token.clone(),
+            exits.take_profit_num,
+            exits.take_profit_denom,
+            slippage_bp,
+            exits.lifetime_seconds,
+        );
+
+        let user = self.blockchain().get_caller();
+        let stop_loss_id = self.create_dormant_order(
+            &user,
+            to_token.clone(),
+            from_token.clone(),
+            exits.stop_limit_num,
+            exits.stop_limit_denom,
+            slippage_bp,
+            exits.lifetime_seconds,
+        );
+        let condition = PriceCondition {
+            base_token: to_token,
+            quote_token: from_token,
+            comparison: PriceComparison::Below,
+            threshold_num: exits.stop_trigger_num,
+            threshold_denom: exits.stop_trigger_denom,
+        };
+        self.order_condition(stop_loss_id).set(&condition);
+        self.order_condition_set_event(stop_loss_id, &condition);
+        self.order_stop_loss(take_profit_id).set(stop_loss_id);
+        self.stop_loss_target(stop_loss_id).set(take_profit_id);
+        self.bracket_order_created_event(entry_id, take_profit_id, stop_loss_id);
+
+        (entry_id, take_profit_id, stop_loss_id).into()
+    }
+
+    /// Activate a stop-loss whose trigger price has been reached: the
+    /// remaining escrow of its take-profit moves to it and the take-profit
+    /// is cancelled (anyone can call)
+    #[endpoint(triggerStopLoss)]
+    fn trigger_stop_loss(&self, order_id: u64) {
+        require!(!self.limit_orders(order_id).is_empty(), ERROR_ORDER_NOT_FOUND);
+        let mut stop_loss = self.limit_orders(order_id).get();
+        require!(
+            matches!(stop_loss.status, OrderStatus::Dormant)
+                && !self.stop_loss_target(order_id).is_empty(),
+            ERROR_NOT_STOP_LOSS
+        );
+        self.require_order_condition_met(order_id);
+
+        let take_profit_id = self.stop_loss_target(order_id).get();
+        let mut take_profit = self.limit_orders(take_profit_id).get();
+        require!(
+            matches!(take_profit.status, OrderStatus::Pending),
+            ERROR_ORDER_NOT_PENDING
+        );
+        self.require_not_executing(take_profit_id);
+        require!(!self.is_tokenized(take_profit_id), ERROR_ORDER_TOKENIZED);
+        require!(!self.is_escrow_lent(take_profit_id), ERROR_ESCROW_LENT);
+
+        // The escrow changes hands without leaving the contract
+        let amount = take_profit.from_amount.clone();
+        self.record_order_cancelled(&take_profit);
+        take_profit.from_amount = BigUint::zero();
+        take_profit.status = OrderStatus::Cancelled;
+        self.limit_orders(take_profit_id).set(&take_profit);
+        self.unindex_pending_order(&take_profit);
+        self.order_stop_loss(take_profit_id).clear();
+        self.limit_order_cancelled_event(
+            crate::CONTRACT_VERSION,
+            take_profit_id,
+            &take_profit.user,
+            &take_profit.from_token,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_round(),
+            &BigUint::zero(),
+        );
+
+        // Triggered, it sells like a plain limit order at the stop limit
+        self.order_condition(order_id).clear();
+        self.activate_dormant_order(&mut stop_loss, &amount);
+        self.stop_loss_triggered_event(order_id, take_profit_id, &amount);
+    }
+
+    /// Create a zap order: on execution half of the input is swapped and
+    /// both halves are added as liquidity to the xExchange pair, so the
+    /// owner receives LP tokens
+    ///
+    /// The price condition applies to the swapped half. If adding
+    /// liquidity fails, the swap output is paid out and the unswapped
+    /// half stays in the order
+    ///
+    /// # Arguments
+    /// * `to_token` - Other token of the pair
+    /// * `target_price_num` - Target price numerator
+    /// * `target_price_denom` - Target price denominator
+    /// * `slippage_bp` - Slippage tolerance for the swap and the liquidity amounts
+    /// * `expires_in_seconds` - How long until order expires
+    #[payable("*")]
+    #[endpoint(createZapOrder)]
+    fn create_zap_order(
+        &self,
+        to_token: TokenIdentifier,
+        target_price_num: BigUint,
+        target_price_denom: BigUint,
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+    ) -> u64 {
+        let order_id = self.create_limit_order(
+            to_token,
+            target_price_num,
+            target_price_denom,
+            slippage_bp,
+            expires_in_seconds,
+            OptionalValue::None,
+            OptionalValue::None,
+        );
+        self.require_zap_pair(&self.limit_orders(order_id).get());
+        self.zap_order(order_id).set(true);
+
+        order_id
+    }
+
+    /// Create an exact-output order: buy exactly `amount_out` of `to_token`
+    /// (after fees) for at most the payment
+    ///
+    /// Executes in full through the pair's `swapTokensFixedOutput`; the
+    /// input the swap did not need is refunded to the owner. The target
+    /// price is `amount_out` per payment, without slippage
+    ///
+    /// # Arguments
+    /// * `to_token` - Token to buy
+    /// * `amount_out` - Amount of `to_token` the owner receives
+    /// * `expires_in_seconds` - How long until order expires
+    #[payable("*")]
+    #[endpoint(createExactOutputOrder)]
+    fn create_exact_output_order(
+        &self,
+        to_token: TokenIdentifier,
+        amount_out: BigUint,
+        expires_in_seconds: u64,
+    ) -> u64 {
+        require!(amount_out > 0u64, ERROR_INVALID_AMOUNT);
+        let (_, max_amount_in) = self.receive_fungible_payment();
+
+        let order_id = self.create_limit_order(
+            to_token,
+            amount_out.clone(),
+            max_amount_in,
+            0u64,
+            expires_in_seconds,
+            OptionalValue::None,
+            OptionalValue::None,
+        );
+        self.exact_output_amount(order_id).set(&amount_out);
+        self.exact_output_order_created_event(order_id, &amount_out);
+
+        order_id
+    }
+
+    /// Create a limit order whose target price stays hidden until execution
+    ///
+    /// Only `price_commitment` (see `computePriceCommitment`) is stored; the
+    /// order shows a 1/1 placeholder price and stays out of `getOrderBook`
+    /// until the executor reveals the real one with `executeCommittedOrder`
+    ///
+    /// # Arguments
+    /// * `to_token` - Token to buy
+    /// * `price_commitment` - keccak256 of the target price and a salt
+    /// * `slippage_bp` - Slippage tolerance in basis points
+    /// * `expires_in_seconds` - How long until order expires
+    #[payable("*")]
+    #[endpoint(createCommittedOrder)]
+    fn create_committed_order(
+        &self,
+        to_token: TokenIdentifier,
+        price_commitment: ManagedByteArray<Self::Api, 32>,
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+    ) -> u64 {
+        let order_id = self.create_limit_order(
+            to_token,
+            BigUint::from(1u64),
+            BigUint::from(1u64),
+            slippage_bp,
+            expires_in_seconds,
+            OptionalValue::None,
+            OptionalValue::None,
+        );
+        self.order_price_commitment(order_id).set(&price_commitment);
+        // The placeholder price is no price level: book it once revealed
+        self.remove_from_price_book(&self.limit_orders(order_id).get());
+        self.order_price_committed_event(order_id, &price_commitment);
+
+        order_id
+    }
+
+    /// Create a limit order for `user`, paid by one of their session keys
+    ///
+    /// The payment counts against the key's spend cap; the order, refunds
+    /// and swap output all belong to `user`
+    ///
+    /// # Arguments
+    /// * `user` - Owner of the new order (the session key grantor)
+    /// * `to_token` - Token to buy
+    /// * `target_price_num` - Target price numerator
+    /// * `target_price_denom` - Target price denominator
+    /// * `slippage_bp` - Slippage tolerance in basis points
+    /// * `expires_in_seconds` - How long until order expires
+    #[payable("*")]
+    #[endpoint(createLimitOrderWithSessionKey)]
+    fn create_limit_order_with_session_key(
+        &self,
+        user: ManagedAddress,
+        to_token: TokenIdentifier,
+        target_price_num: BigUint,
+        target_price_denom: BigUint,
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+    ) -> u64 {
+        self.require_creation_allowed();
+
+        let caller = self.blockchain().get_caller();
+        self.require_not_blacklisted(&user);
+        self.require_not_blacklisted(&caller);
+        let (from_token, from_amount) = self.receive_fungible_payment();
+        self.spend_session_allowance(&user, &caller, &from_token, &from_amount);
+
+        self.lock_escrow(&from_token, &from_amount);
+
+        self.create_order(
+            &user,
+            from_token,
+            from_amount,
+            to_token,
+            target_price_num,
+            target_price_denom,
+            slippage_bp,
+            expires_in_seconds,
+        )
+    }
+
+    /// Modify price, slippage and expiry of a pending order in place
+    ///
+    /// Escrow and order id are kept; the creator, an approved manager or a
+    /// session key with modify permission can modify
+    ///
+    /// # Arguments
+    /// * `order_id` - ID of order to modify
+    /// * `target_price_num` - New target price numerator
+    /// * `target_price_denom` - New target price denominator
+    /// * `slippage_bp` - New slippage tolerance in basis points
+    /// * `expires_in_seconds` - New lifetime, counted from now
+    #[endpoint(modifyLimitOrder)]
+    fn modify_limit_order(
+        &self,
+        order_id: u64,
+        target_price_num: BigUint,
+        target_price_denom: BigUint,
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+    ) {
+        self.require_creation_allowed();
+
+        let caller = self.blockchain().get_caller();
+        let mut order = self.require_modifiable_order(order_id, &caller);
+        self.modify_order(
+            &mut order,
+            target_price_num,
+            target_price_denom,
+            slippage_bp,
+            expires_in_seconds,
+        );
+    }
+
+    /// Push back the expiry of a pending order
+    ///
+    /// Keeps the order id, escrow and fill history, unlike cancelling and
+    /// creating a new order. The expiry can be extended up to
+    /// `MAX_ORDER_LIFETIME_SECONDS` after the order's creation
+    ///
+    /// # Arguments
+    /// * `order_id` - ID of order to extend
+    /// * `extra_seconds` - Seconds to add to the current expiry
+    #[endpoint(extendOrderExpiry)]
+    fn extend_order_expiry(&self, order_id: u64, extra_seconds: u64) {
+        self.require_creation_allowed();
+        require!(extra_seconds > 0, ERROR_INVALID_AMOUNT);
+
+        let caller = self.blockchain().get_caller();
+        let mut order = self.require_modifiable_order(order_id, &caller);
+        let previous = OrderTerms::from_order(&order);
+
+        let expires_at = order
+            .expires_at
+            .checked_add(extra_seconds)
+            .unwrap_or_else(|| sc_panic!(ERROR_EXPIRY_TOO_FAR));
+        require!(
+            expires_at - order.created_at <= MAX_ORDER_LIFETIME_SECONDS,
+            ERROR_EXPIRY_TOO_FAR
+        );
+        order.expires_at = expires_at;
+        self.limit_orders(order_id).set(&order);
+
+        self.limit_order_modified_event(
+            crate::CONTRACT_VERSION,
+            order_id,
+            &order.user,
+            &previous,
+            &OrderTerms::from_order(&order),
+        );
+    }
+
+    /// Modify a tokenized order; send its position NFT with the call
+    ///
+    /// The NFT is returned to the caller
+    ///
+    /// # Arguments
+    /// * `target_price_num` - New target price numerator
+    /// * `target_price_denom` - New target price denominator
+    /// * `slippage_bp` - New slippage tolerance in basis points
+    /// * `expires_in_seconds` - New lifetime, counted from now
+    #[payable("*")]
+    #[endpoint(modifyWithPosition)]
+    fn modify_with_position(
+        &self,
+        target_price_num: BigUint,
+        target_price_denom: BigUint,
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+    ) {
+        self.require_creation_allowed();
+
+        let order_id = self.receive_position();
+        let caller = self.blockchain().get_caller();
+        self.require_not_blacklisted(&caller);
+
+        let mut order = self.require_open_order(order_id);
+        self.modify_order(
+            &mut order,
+            target_price_num,
+            target_price_denom,
+            slippage_bp,
+            expires_in_seconds,
+        );
+
+        self.return_position(order_id, &caller);
+    }
+
+    /// Replace a pending order with a new one funded by the same escrow
+    ///
+    /// The old order is marked Cancelled and a new order id is returned;
+    /// unlike cancel + create, no tokens leave the contract
+    ///
+    /// # Arguments
+    /// * `order_id` - ID of order to replace
+    /// * `to_token` - Token to buy with the new order
+    /// * `target_price_num` - Target price numerator
+    /// * `target_price_denom` - Target price denominator
+    /// * `slippage_bp` - Slippage tolerance in basis points
+    /// * `expires_in_seconds` - How long until the new order expires
+    #[endpoint(replaceLimitOrder)]
+    fn replace_limit_order(
+        &self,
+        order_id: u64,
+        to_token: TokenIdentifier,
+        target_price_num: BigUint,
+        target_price_denom: BigUint,
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+    ) -> u64 {
+        self.require_creation_allowed();
+
+        let caller = self.blockchain().get_caller();
+        let mut old_order = self.require_modifiable_order(order_id, &caller);
+        require!(!self.is_escrow_lent(order_id), ERROR_ESCROW_LENT);
+
+        // Escrow stays locked and moves to the new order
+        // (unmigrated orders were never counted, so count them now)
+        if !self.unindex_pending_order(&old_order) {
+            self.lock_escrow(&old_order.from_token, &old_order.from_amount);
+        }
+        old_order.status = OrderStatus::Cancelled;
+        self.limit_orders(order_id).set(&old_order);
+        self.record_order_cancelled(&old_order);
+
+        // The new order belongs to the creator, even when a manager replaces it
+        let new_order_id = self.create_order(
+            &old_order.user,
+            old_order.from_token.clone(),
+            old_order.from_amount.clone(),
+            to_token,
+            target_price_num,
+            target_price_denom,
+            slippage_bp,
+            expires_in_seconds,
+        );
+        let new_order = self.limit_orders(new_order_id).get();
+
+        if !self.order_strategy(order_id).is_empty() {
+            self.link_order_strategy(new_order_id, self.order_strategy(order_id).get());
+        }
+        if !self.order_signal(order_id).is_empty() {
+            self.order_signal(new_order_id).set(self.order_signal(order_id).get());
+        }
+        if !self.order_client_tag(order_id).is_empty() {
+            self.order_client_tag(new_order_id)
+                .set(self.order_client_tag(order_id).get());
+        }
+        if !self.order_intent(order_id).is_empty() {
+            self.order_intent(new_order_id)
+                .set(self.order_intent(order_id).get());
+        }
+        self.move_chained_order(order_id, &new_order);
+        self.cancel_dormant_stop_loss(order_id);
+        // Kept unless the new order buys the intermediate token itself
+        if !self.order_route_via(order_id).is_empty()
+            && self.order_route_via(order_id).get() != new_order.to_token
+        {
+            self.order_route_via(new_order_id)
+                .set(self.order_route_via(order_id).get());
+        }
+        if !self.order_client(order_id).is_empty() {
+            self.order_client(new_order_id)
+                .set(self.order_client(order_id).get());
+        }
+        if !self.order_condition(order_id).is_empty() {
+            self.order_condition(new_order_id)
+                .set(self.order_condition(order_id).get());
+        }
+        if self.is_streamed(order_id) {
+            self.payout_stream_duration(new_order_id)
+                .set(self.payout_stream_duration(order_id).get());
+        }
+        if !self.order_forward(order_id).is_empty() {
+            self.order_forward(new_order_id)
+                .set(self.order_forward(order_id).get());
+        }
+        if self.zap_order(order_id).get() {
+            self.require_zap_pair(&new_order);
+            self.zap_order(new_order_id).set(true);
+        }
+
+        self.limit_order_replaced_event(
+            crate::CONTRACT_VERSION,
+            order_id,
+            new_order_id,
+            &old_order.user,
+            &OrderTerms::from_order(&old_order),
+            &OrderTerms::from_order(&new_order),
+        );
+
+        new_order_id
+    }
+
+    /// Execute a limit order (called by backend executor)
+    ///
+    /// Performs SYNC swap on xExchange and sends output tokens to user
+    /// If the order has expired it is refunded and marked Expired instead
+    ///
+    /// # Arguments
+    /// * `order_id` - ID of order to execute
+    /// * `current_price_num` - Current price numerator (for verification)
+    /// * `current_price_denom` - Current price denominator
+    #[endpoint(executeLimitOrder)]
+    fn execute_limit_order(
+        &self,
+        order_id: u64,
+        current_price_num: BigUint,
+        current_price_denom: BigUint,
+    ) {
+        self.execute_order(order_id, None, false, current_price_num, current_price_denom);
+    }
+
+    /// Execute a limit order as the owner, when the executor is offline
+    /// (owner only)
+    ///
+    /// Same checks as `executeLimitOrder` (price, expiry, pause...) except
+    /// the executor check; emits `order_force_executed` so these executions
+    /// can be told apart
+    ///
+    /// # Arguments
+    /// * `order_id` - ID of order to execute
+    /// * `current_price_num` - Current price numerator (for verification)
+    /// * `current_price_denom` - Current price denominator
+    #[only_owner]
+    #[endpoint(forceExecute)]
+    fn force_execute(
+        &self,
+        order_id: u64,
+        current_price_num: BigUint,
+        current_price_denom: BigUint,
+    ) {
+        self.execute_order(order_id, None, true, current_price_num, current_price_denom);
+    }
+
+    /// Reveal the committed price of an order and execute it (called by
+    /// backend executor)
+    ///
+    /// The revealed price must match the order's commitment; it becomes the
+    /// order's target price and the execution proceeds as `executeLimitOrder`.
+    /// Nothing is revealed unless the swap goes ahead: expired orders are
+    /// refunded through `executeLimitOrder`, which keeps the price hidden
+    ///
+    /// # Arguments
+    /// * `order_id` - ID of order to execute
+    /// * `target_price_num` - Committed target price numerator
+    /// * `target_price_denom` - Committed target price denominator
+    /// * `salt` - Salt of the commitment
+    /// * `current_price_num` - Current price numerator (for verification)
+    /// * `current_price_denom` - Current price denominator
+    #[endpoint(executeCommittedOrder)]
+    fn execute_committed_order(
+        &self,
+        order_id: u64,
+        target_price_num: BigUint,
+        target_price_denom: BigUint,
+        salt: ManagedByteArray<Self::Api, 32>,
+        current_price_num: BigUint,
+        current_price_denom: BigUint,
+    ) {
+        // Rights, order state and pair health before the reveal is stored
+        self.require_execution_allowed();
+        let caller = self.blockchain().get_caller();
+        let reason =
+            self.execution_rights_reason(order_id, &caller, self.limit_order_executor().get());
+        require!(reason.is_ok(), (reason.error_message()));
+        let mut order = self.require_open_order(order_id);
+
+        let swap_amount = if self.zap_order(order_id).get() {
+            &order.from_amount / 2u64
+        } else {
+            order.from_amount.clone()
+        };
+        let pair_address = self.entry_pair(&order);
+        if !self.check_pair_health(order_id, &pair_address, &order.from_token, &swap_amount) {
+            return;
+        }
+
+        self.open_price_commitment(order_id, &target_price_num, &target_price_denom, salt);
+        self.validate_order_terms(&target_price_num, &target_price_denom, order.slippage_bp);
+        order.target_price_numerator = target_price_num;
+        order.target_price_denominator = target_price_denom;
+        self.limit_orders(order_id).set(&order);
+        self.store_normalized_price(&order);
+
+        self.execute_order(order_id, None, false, current_price_num, current_price_denom);
+    }
+
+    /// Execute part of a limit order (called by backend executor)
+    ///
+    /// Swaps `fill_amount` of the remaining escrow; the order stays pending
+    /// until the remaining amount reaches zero
+    ///
+    /// # Arguments
+    /// * `order_id` - ID of order to execute
+    /// * `fill_amount` - Amount of from_token to swap in this chunk
+    /// * `current_price_num` - Current price numerator (for verification)
+    /// * `current_price_denom` - Current price denominator
+    #[endpoint(executeLimitOrderPartial)]
+    fn execute_limit_order_partial(
+        &self,
+        order_id: u64,
+        fill_amount: BigUint,
+        current_price_num: BigUint,
+        current_price_denom: BigUint,
+    ) {
+        self.execute_order(
+            order_id,
+            Some(fill_amount),
+            false,
+            current_price_num,
+            current_price_denom,
+        );
+    }
+
+    /// Execute a limit order if it is executable, without reverting otherwise
+    ///
+    /// Same as `executeLimitOrder`, but returns why the order was not
+    /// executed instead of failing, so the executor can batch attempts
+    /// Returns `OrderExpired` when the order was refunded instead
+    ///
+    /// # Arguments
+    /// * `order_id` - ID of order to execute
+    /// * `current_price_num` - Current price numerator (for verification)
+    /// * `current_price_denom` - Current price denominator
+    #[endpoint(tryExecuteLimitOrder)]
+    fn try_execute_limit_order(
+        &self,
+        order_id: u64,
+        current_price_num: BigUint,
+        current_price_denom: BigUint,
+    ) -> ReasonCode {
+        let caller = self.blockchain().get_caller();
+        let reason = self.execution_reason(
+            order_id,
+            &caller,
+            false,
+            &None,
+            &current_price_num,
+            &current_price_denom,
+        );
+        if matches!(reason, ReasonCode::Ok | ReasonCode::OrderExpired) {
+            self.execute_order(order_id, None, false, current_price_num, current_price_denom);
+        }
+        reason
+    }
+
+    /// Auction the right to execute a large order (executor or order owner)
+    ///
+    /// Keepers bid the execution fee they accept with `bidForExecution`
+    /// until the window closes; the best bidder then executes alone, at its
+    /// bid, for another window
+    ///
+    /// # Arguments
+    /// * `order_id` - Order of at least `executionAuctionMinAmount` of its token
+    #[endpoint(openExecutionAuction)]
+    fn open_execution_auction(&self, order_id: u64) {
+        let order = self.require_open_order(order_id);
+        let caller = self.blockchain().get_caller();
+        let executor = self.limit_order_executor().get();
+        require!(
+            caller == executor || caller == order.user,
+            ERROR_NOT_AUCTION_OPENER
+        );
+
+        let min_amount = self.execution_auction_min_amount(&order.from_token).get();
+        require!(
+            self.execution_auction_window().get() > 0
+                && min_amount > 0u64
+                && order.from_amount >= min_amount,
+            ERROR_AUCTION_NOT_ELIGIBLE
+        );
+
+        self.start_execution_auction(order_id, executor);
+    }
+
+    /// Settle two crossing orders on opposite sides of a pair against each
+    /// other, without the DEX (called by backend executor)
+    ///
+    /// Trades at the midpoint of the two limit prices, so both orders get
+    /// half of the price improvement; the smaller side is filled completely
+    ///
+    /// # Arguments
+    /// * `buy_id` - Order selling token A for token B
+    /// * `sell_id` - Order selling token B for token A
+    #[endpoint(matchOrders)]
+    fn match_orders(&self, buy_id: u64, sell_id: u64) {
+        self.require_execution_allowed();
+
+        let caller = self.blockchain().get_caller();
+        require!(caller == self.limit_order_executor().get(), ERROR_ONLY_EXECUTOR);
+        self.require_not_blacklisted(&caller);
+
+        let buy = self.require_matchable_order(buy_id);
+        let sell = self.require_matchable_order(sell_id);
+        require!(
+            buy_id != sell_id
+                && buy.from_token == sell.to_token
+                && buy.to_token == sell.from_token,
+            ERROR_ORDERS_NOT_OPPOSITE
+        );
+        self.require_no_self_trade(&buy.user, &sell.user);
+
+        // buy wants >= num_b/denom_b of B per A, sell gives <= denom_s/num_s
+        let buy_limit = &buy.target_price_numerator * &sell.target_price_numerator;
+        let sell_limit = &buy.target_price_denominator * &sell.target_price_denominator;
+        require!(buy_limit <= sell_limit, ERROR_PRICES_DO_NOT_CROSS);
+
+        // Midpoint price of B per A: (buy_limit + sell_limit) / (2 * denom_b * num_s)
+        let price_num = &buy_limit + &sell_limit;
+        let price_denom =
+            &buy.target_price_denominator * &sell.target_price_numerator * 2u64;
+
+        let max_a_for_sell = &sell.from_amount * &price_denom / &price_num;
+        let amount_a = core::cmp::min(buy.from_amount.clone(), max_a_for_sell);
+        let amount_b = &amount_a * &price_num / &price_denom;
+        require!(amount_a > 0u64 && amount_b > 0u64, ERROR_INVALID_AMOUNT);
+        self.spend_executor_volume(&caller, &buy.from_token, &amount_a);
+        self.spend_executor_volume(&caller, &sell.from_token, &amount_b);
+
+        self.settle_fill(buy_id, &caller, &amount_a, &amount_b);
+        self.settle_fill(sell_id, &caller, &amount_b, &amount_a);
+
+        self.orders_matched_event(buy_id, sell_id, &amount_a, &amount_b);
+    }
+
+    /// Refuse to match the caller's orders against each other or fill them
+    /// from their own quotes (off by default)
+    #[endpoint(setSelfTradePrevention)]
+    fn set_self_trade_prevention(&self, enabled: bool) {
+        let caller = self.blockchain().get_caller();
+        self.self_trade_prevention(&caller).set(enabled);
+        self.self_trade_prevention_changed_event(&caller, enabled);
+    }
+
+    /// Callback handler for async swap completion (PROMISES API)
+    #[promises_callback]
+    fn swap_callback(
+        &self,
+        order_id: u64,
+        #[call_result] result: ManagedAsyncCallResult<MultiValueEncoded<EsdtTokenPayment>>,
+    ) {
+        // Retrieve execution context
+        let context_mapper = self.pending_swap_executions(order_id);
+        require!(!context_mapper.is_empty(), ERROR_CONTEXT_NOT_FOUND);
+
+        let context = context_mapper.get();
+        context_mapper.clear();
+        self.end_execution(order_id);
+
+        // Whatever the result, the input is no longer at the pair
+        let order = self.limit_orders(order_id).get();
+        self.escrow_in_flight(&order.from_token)
+            .update(|amount| *amount -= &context.fill_amount);
+
+        match result {
+            ManagedAsyncCallResult::Ok(_) => {
+                let (output_amount, returned_input) = self.split_swap_payments(order_id, &order);
+
+                // Input the pair did not use goes back to the order; a swap
+                // that used none of it is handled like a failed one
+                let is_zap = !self.zap_executions(order_id).is_empty();
+                let credited = if is_zap || returned_input > context.fill_amount {
+                    BigUint::zero()
+                } else {
+                    returned_input.clone()
+                };
+                if credited == context.fill_amount {
+                    self.zap_executions(order_id).clear();
+                    self.handle_failed_swap(
+                        &context,
+                        &credited,
+                        &ManagedBuffer::from(ERROR_SWAP_INPUT_RETURNED),
+                    );
+                    return;
+                }
+                let refunded = &returned_input - &credited;
+                if credited > 0u64 && self.is_exact_output(order_id) {
+                    // Exact-output swaps return the input they did not need:
+                    // refund it so the order closes with this fill
+                    self.release_executor_volume(&context.executor, &order.from_token, &credited);
+                    self.pay_order_owner(order_id, &order.user, &order.from_token, &credited);
+                    let mut order = order.clone();
+                    self.release_escrow(&order, &credited);
+                    order.from_amount -= &credited;
+                    self.limit_orders(order_id).set(&order);
+                    self.exact_output_input_refunded_event(order_id, &order.user, &credited);
+                } else if credited > 0u64 {
+                    self.release_executor_volume(&context.executor, &order.from_token, &credited);
+                    self.unexpected_swap_payment_event(order_id, &order.from_token, 0, true, &credited);
+                }
+                if refunded > 0u64 {
+                    self.pay_order_owner(order_id, &order.user, &order.from_token, &refunded);
+                    self.unexpected_swap_payment_event(order_id, &order.from_token, 0, false, &refunded);
+                }
+                let fill_in = &context.fill_amount - &credited;
+
+                // A routed fill's price spans two pairs, so it feeds neither breaker
+                if self.order_route_via(order_id).is_empty() {
+                    let pair_address = self.swap_pair(&order.from_token, &order.to_token);
+                    let price = self.scaled_price(&output_amount, &fill_in);
+                    self.record_swap_price(&pair_address, &order.from_token, price);
+                }
+
+                // The pair enforces min_amount_out. Reverting here would not
+                // undo the swap, only leave the order stuck in execution with
+                // the output unaccounted, so a short output is settled as is
+                if is_zap {
+                    self.add_zap_liquidity(&context, &output_amount);
+                } else {
+                    self.settle_fill(order_id, &context.executor, &fill_in, &output_amount);
+                }
+            }
+            ManagedAsyncCallResult::Err(err) => {
+                // Swap failed - no panic here, so the guard release is kept
+                self.zap_executions(order_id).clear();
+                let (_, returned_input) = self.split_swap_payments(order_id, &order);
+                self.handle_failed_swap(&context, &returned_input, &err.err_msg);
+            }
+        }
+    }
+
+    /// Callback of the second zap leg (addLiquidity)
+    #[promises_callback]
+    fn add_liquidity_callback(
+        &self,
+        order_id: u64,
+        #[call_result] result: ManagedAsyncCallResult<MultiValueEncoded<EsdtTokenPayment>>,
+    ) {
+        let zap_mapper = self.zap_executions(order_id);
+        require!(!zap_mapper.is_empty(), ERROR_CONTEXT_NOT_FOUND);
+        let zap = zap_mapper.take();
+        self.end_execution(order_id);
+
+        // The half that was not swapped went to the pair with the output
+        let order = self.limit_orders(order_id).get();
+        let liquidity_in = &zap.fill_amount - &(&zap.fill_amount / 2u64);
+        self.escrow_in_flight(&order.from_token)
+            .update(|amount| *amount -= &liquidity_in);
+        let lp_token = self.zap_lp_token().get();
+
+        // LP tokens and unused amounts (on success) or both inputs (on failure)
+        let mut lp_amount = BigUint::zero();
+        let mut returned_from = BigUint::zero();
+        for payment in self.call_value().all_esdt_transfers().iter() {
+            if payment.token_nonce != 0 {
+                continue;
+            }
+            if payment.token_identifier == lp_token {
+                lp_amount += &payment.amount;
+            } else if payment.token_identifier == order.from_token {
+                returned_from += &payment.amount;
+            } else if payment.token_identifier == order.to_token {
+                self.pay_order_owner(order_id, &order.user, &order.to_token, &payment.amount);
+            }
+        }
+
+        match result {
+            ManagedAsyncCallResult::Ok(_) => {
+                // Unused input is paid out, the whole chunk is consumed
+                self.pay_order_owner(order_id, &order.user, &order.from_token, &returned_from);
+                self.pay_order_owner(order_id, &order.user, &lp_token, &lp_amount);
+                self.consume_zap_fill(order, &zap.fill_amount);
+                self.zap_liquidity_added_event(order_id, &zap.executor, &lp_amount);
+            }
+            ManagedAsyncCallResult::Err(err) => {
+                // The unswapped half came back and stays in the order
+                let consumed = &zap.fill_amount - &returned_from;
+                self.consume_zap_fill(order, &consumed);
+                self.zap_liquidity_failed_event(order_id, &err.err_msg);
+            }
+        }
+    }
+
+    /// Supply the escrow of an idle order to the lending market of its
+    /// token (called by backend executor)
+    ///
+    /// Executing or cancelling the order later redeems it just in time
+    #[endpoint(supplyEscrow)]
+    fn supply_escrow(&self, order_id: u64) {
+        self.require_execution_allowed();
+        let caller = self.blockchain().get_caller();
+        require!(caller == self.limit_order_executor().get(), ERROR_ONLY_EXECUTOR);
+
+        let order = self.require_open_order(order_id);
+        require!(!self.is_escrow_lent(order_id), ERROR_ESCROW_ALREADY_LENT);
+        require!(
+            !self.lending_market(&order.from_token).is_empty(),
+            ERROR_LENDING_NOT_CONFIGURED
+        );
+        let (market, share_token) = self.lending_market(&order.from_token).get();
+
+        // Shares are filled in by the callback
+        let lent = LentEscrow {
+            market,
+            share_token,
+            shares: BigUint::zero(),
+            principal: order.from_amount.clone(),
+        };
+        self.lent_escrow(&order.from_token)
+            .update(|total| *total += &lent.principal);
+        self.lent_escrows(order_id).set(&lent);
+        self.begin_execution(order_id);
+
+        self.tx()
+            .to(&lent.market)
+            .gas(20_000_000u64)
+            .raw_call("mint")
+            .single_esdt(&order.from_token, 0u64, &order.from_amount)
+            .with_callback(self.callbacks().supply_callback(order_id))
+            .with_extra_gas_for_callback(10_000_000)
+            .register_promise();
+    }
+
+    /// Bring the lent escrow of an order back from the lending market
+    /// (callable by anyone)
+    #[endpoint(redeemEscrow)]
+    fn redeem_escrow_endpoint(&self, order_id: u64) {
+        require!(self.is_escrow_lent(order_id), ERROR_ESCROW_NOT_LENT);
+        self.require_not_executing(order_id);
+        self.redeem_escrow(order_id, RedeemPurpose::Release);
+    }
+
+    /// Callback of supplying an order's escrow to the lending market
+    #[promises_callback]
+    fn supply_callback(
+        &self,
+        order_id: u64,
+        #[call_result] result: ManagedAsyncCallResult<MultiValueEncoded<EsdtTokenPayment>>,
+    ) {
+        self.end_execution(order_id);
+        let mut lent = self.lent_escrows(order_id).get();
+
+        match result {
+            ManagedAsyncCallResult::Ok(_) => {
+                for payment in self.call_value().all_esdt_transfers().iter() {
+                    if payment.token_identifier == lent.share_token && payment.token_nonce == 0 {
+                        lent.shares += &payment.amount;
+                    }
+                }
+                self.lending_shares(&lent.share_token)
+                    .update(|total| *total += &lent.shares);
+                self.lent_escrows(order_id).set(&lent);
+                self.escrow_supplied_event(order_id, &lent.market, &lent.principal, &lent.shares);
+            }
+            ManagedAsyncCallResult::Err(err) => {
+                // The escrow came back with the callback
+                let order = self.limit_orders(order_id).get();
+                self.lent_escrow(&order.from_token)
+                    .update(|total| *total -= &lent.principal);
+                self.lent_escrows(order_id).clear();
+                self.lending_call_failed_event(order_id, &err.err_msg);
+            }
+        }
+    }
+
+    /// Callback of redeeming a lent escrow; settles interest and resumes
+    /// the execution or cancellation that needed the escrow
+    #[promises_callback]
+    fn redeem_callback(
+        &self,
+        order_id: u64,
+        purpose: RedeemPurpose,
+        #[call_result] result: ManagedAsyncCallResult<MultiValueEncoded<EsdtTokenPayment>>,
+    ) {
+        self.end_execution(order_id);
+
+        if let ManagedAsyncCallResult::Err(err) = result {
+            // Shares came back: the escrow stays lent
+            if purpose == RedeemPurpose::Execute {
+                self.drop_redeemed_execution(order_id);
+            }
+            self.lending_call_failed_event(order_id, &err.err_msg);
+            return;
+        }
+
+        let mut order = self.limit_orders(order_id).get();
+        self.settle_redeemed_escrow(&mut order);
+
+        match purpose {
+            RedeemPurpose::Release => {}
+            RedeemPurpose::Execute => {
+                let context = self.pending_swap_executions(order_id).get();
+                let needed = if self.zap_executions(order_id).is_empty() {
+                    context.fill_amount.clone()
+                } else {
+                    self.zap_executions(order_id).get().fill_amount
+                };
+                if needed > order.from_amount {
+                    // The market returned less than the chunk: drop this attempt
+                    self.drop_redeemed_execution(order_id);
+                    return;
+                }
+                self.begin_execution(order_id);
+                self.dispatch_swap(&context);
+            }
+            RedeemPurpose::Cancel => {
+                self.refund_and_close(&mut order, OrderStatus::Cancelled);
+                self.limit_order_cancelled_event(
+                    crate::CONTRACT_VERSION,
+                    order_id,
+                    &order.user,
+                    &order.from_token,
+                    self.blockchain().get_block_epoch(),
+                    self.blockchain().get_block_round(),
+                    &order.from_amount,
+                );
+            }
+            RedeemPurpose::Expire => {
+                self.refund_and_close(&mut order, OrderStatus::Expired);
+                self.limit_order_expired_event(
+                    order_id,
+                    &order.user,
+                    &order.from_token,
+                    &order.from_amount,
+                );
+            }
+        }
+    }
+
+    /// Read the decimals of a whitelisted token from the ESDT system
+    /// contract (callable by anyone)
+    #[endpoint(fetchTokenDecimals)]
+    fn fetch_token_decimals(&self, token: TokenIdentifier) {
+        require!(
+            self.whitelisted_tokens().contains(&token),
+            ERROR_NOT_WHITELISTED
+        );
+
+        self.send()
+            .esdt_system_sc_tx()
+            .get_token_properties(&token)
+            .gas(FETCH_DECIMALS_GAS)
+            .with_callback(self.callbacks().token_properties_callback(token))
+            .with_extra_gas_for_callback(FETCH_DECIMALS_CALLBACK_GAS)
+            .register_promise();
+    }
+
+    #[promises_callback]
+    fn token_properties_callback(
+        &self,
+        token: TokenIdentifier,
+        #[call_result] result: ManagedAsyncCallResult<MultiValueEncoded<ManagedBuffer>>,
+    ) {
+        // A failed lookup leaves the registry unchanged
+        if let ManagedAsyncCallResult::Ok(properties) = result {
+            let decimals = properties
+                .into_iter()
+                .find_map(|property| parse_num_decimals(&property));
+            if let Some(decimals) = decimals {
+                self.store_token_decimals(&token, decimals);
+            }
+        }
+    }
+
+    /// Gate execution of an order on the oracle price of a registered pair,
+    /// replacing any previous condition (owner, managers or session keys
+    /// with modify permission)
+    #[endpoint(setOrderCondition)]
+    fn set_order_condition(&self, order_id: u64, condition: PriceCondition<Self::Api>) {
+        let caller = self.blockchain().get_caller();
+        self.require_modifiable_order(order_id, &caller);
+        self.require_price_pair(&condition.base_token, &condition.quote_token);
+        require!(condition.threshold_denom > 0u64, ERROR_PRICE_DENOM_ZERO);
+
+        self.order_condition(order_id).set(&condition);
+        self.order_condition_set_event(order_id, &condition);
+    }
+
+    /// Remove the price condition of an order
+    #[endpoint(clearOrderCondition)]
+    fn clear_order_condition(&self, order_id: u64) {
+        let caller = self.blockchain().get_caller();
+        self.require_modifiable_order(order_id, &caller);
+
+        self.order_condition(order_id).clear();
+        self.order_condition_cleared_event(order_id);
+    }
+
+    /// Stream the swap output of an order over `duration_seconds` instead of
+    /// paying each fill at once; 0 switches streaming off (owner, managers
+    /// or session keys with modify permission; not for tokenized orders)
+    #[endpoint(setPayoutStream)]
+    fn set_payout_stream(&self, order_id: u64, duration_seconds: u64) {
+        let caller = self.blockchain().get_caller();
+        self.require_modifiable_order(order_id, &caller);
+        require!(
+            duration_seconds <= MAX_PAYOUT_STREAM_SECONDS,
+            ERROR_STREAM_TOO_LONG
+        );
+
+        self.payout_stream_duration(order_id).set(duration_seconds);
+        self.payout_stream_set_event(order_id, duration_seconds);
+    }
+
+    /// Forward the swap output of an order to a whitelisted destination
+    /// contract as `function(arguments...)` instead of paying it to the
+    /// owner; takes precedence over streaming (owner, managers or session
+    /// keys with modify permission; not for tokenized orders)
+    #[endpoint(setOutputForward)]
+    fn set_output_forward(
+        &self,
+        order_id: u64,
+        destination: ManagedAddress,
+        function: ManagedBuffer,
+        arguments: MultiValueEncoded<ManagedBuffer>,
+    ) {
+        let caller = self.blockchain().get_caller();
+        self.require_modifiable_order(order_id, &caller);
+        require!(
+            self.forward_destinations().contains(&destination),
+            ERROR_DESTINATION_NOT_WHITELISTED
+        );
+        require!(
+            !function.is_empty() && arguments.len() <= MAX_FORWARD_ARGUMENTS,
+            ERROR_INVALID_FORWARD_CALL
+        );
+
+        self.output_forward_set_event(order_id, &destination, &function);
+        self.order_forward(order_id).set(OutputForward {
+            destination,
+            function,
+            arguments: arguments.to_vec(),
+        });
+    }
+
+    /// Pay the swap output of an order to its owner again
+    #[endpoint(clearOutputForward)]
+    fn clear_output_forward(&self, order_id: u64) {
+        let caller = self.blockchain().get_caller();
+        self.require_modifiable_order(order_id, &caller);
+
+        self.order_forward(order_id).clear();
+        self.output_forward_cleared_event(order_id);
+    }
+
+    /// Cancel a limit order (its owner, an approved manager or a session key)
+    ///
+    /// Returns tokens to user immediately
+    /// Never blocked by any pause level or pair pause; young orders are
+    /// subject to the cancellation policy (see `setCancellationPolicy`)
+    /// unless a pause keeps them from executing
+    ///
+    /// # Arguments
+    /// * `order_id` - ID of order to cancel
+    #[endpoint(cancelLimitOrder)]
+    fn cancel_limit_order(&self, order_id: u64) {
+        let caller = self.blockchain().get_caller();
+        require!(!self.limit_orders(order_id).is_empty(), ERROR_ORDER_NOT_FOUND);
+        let mut order = self.limit_orders(order_id).get();
+
+        require!(!self.is_tokenized(order_id), ERROR_ORDER_TOKENIZED);
+        self.require_order_authority(&order.user, &caller, SESSION_PERMISSION_CANCEL);
+
+        // A dormant order holds nothing yet: detach it from the order that
+        // would fund it
+        if matches!(order.status, OrderStatus::Dormant) {
+            if !self.chain_parent(order_id).is_empty() {
+                self.chained_order(self.chain_parent(order_id).get()).clear();
+            }
+            if !self.stop_loss_target(order_id).is_empty() {
+                self.order_stop_loss(self.stop_loss_target(order_id).get()).clear();
+            }
+            self.cancel_dormant_order(&mut order);
+            return;
+        }
+        require!(
+            matches!(order.status, OrderStatus::Pending),
+            ERROR_ORDER_NOT_PENDING
+        );
+        self.require_not_executing(order_id);
+        self.require_not_blacklisted(&order.user);
+        self.require_not_blacklisted(&caller);
+        self.charge_cancellation_fee(&mut order);
+
+        // Lent escrow comes back first; the refund happens in the redeem callback
+        if self.is_escrow_lent(order_id) {
+            self.limit_orders(order_id).set(&order);
+            self.redeem_escrow(order_id, RedeemPurpose::Cancel);
+            return;
+        }
+
+        // Return tokens to user and mark as cancelled
+        self.refund_and_close(&mut order, OrderStatus::Cancelled);
+
+        // Emit event
+        self.limit_order_cancelled_event(
+            crate::CONTRACT_VERSION,
+            order_id,
+            &order.user,
+            &order.from_token,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_round(),
+            &order.from_amount,
+        );
+    }
+
+    /// Cancel a tokenized order; send its position NFT with the call
+    ///
+    /// The NFT is burned and the refund plus any partial-fill output
+    /// goes to the caller. Never blocked by any pause level
+    #[payable("*")]
+    #[endpoint(cancelWithPosition)]
+    fn cancel_with_position(&self) {
+        let order_id = self.receive_position();
+        let caller = self.blockchain().get_caller();
+        self.require_not_blacklisted(&caller);
+
+        let mut order = self.limit_orders(order_id).get();
+        require!(
+            matches!(order.status, OrderStatus::Pending),
+            ERROR_ORDER_NOT_PENDING
+        );
+        self.require_not_executing(order_id);
+        require!(!self.is_escrow_lent(order_id), ERROR_ESCROW_LENT);
+        self.charge_cancellation_fee(&mut order);
+
+        self.refund_and_close(&mut order, OrderStatus::Cancelled);
+        self.limit_order_cancelled_event(
+            crate::CONTRACT_VERSION,
+            order_id,
+            &order.user,
+            &order.from_token,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_round(),
+            &order.from_amount,
+        );
+
+        self.claim_position_payouts(&order, &caller);
+    }
+
+    /// Claim the payouts held for a tokenized order; send its position NFT with the call
+    ///
+    /// Settled orders burn the NFT; pending orders (partial fills so far)
+    /// get it back
+    #[payable("*")]
+    #[endpoint(claimPosition)]
+    fn claim_position(&self) {
+        let order_id = self.receive_position();
+        let caller = self.blockchain().get_caller();
+        self.require_not_blacklisted(&caller);
+
+        let order = self.limit_orders(order_id).get();
+        self.claim_position_payouts(&order, &caller);
+    }
+
+    /// Cancel up to `max_count` pending orders of a strategy and refund their
+    /// owners (strategy owner or contract owner). Orders with a swap in flight
+    /// or a blacklisted owner are skipped. Never blocked by any pause level
+    ///
+    /// Returns the number of cancelled orders
+    #[endpoint(cancelStrategyOrders)]
+    fn cancel_strategy_orders(&self, strategy_id: u64, max_count: u64) -> u64 {
+        self.require_strategy_admin(strategy_id);
+
+        // Collect first: cancelling and pruning remove ids from the set we iterate
+        let mut cancel_ids = ManagedVec::<Self::Api, u64>::new();
+        let mut settled_ids = ManagedVec::<Self::Api, u64>::new();
+        for order_id in self.strategy_orders(strategy_id).iter() {
+            if cancel_ids.len() as u64 >= max_count {
+                break;
+            }
+            if !self.pending_order_ids().contains(&order_id) {
+                settled_ids.push(order_id);
+                continue;
+            }
+            let order = self.limit_orders(order_id).get();
+            if !self.orders_in_execution().contains(&order_id)
+                && !self.is_escrow_lent(order_id)
+                && !self.blacklisted_addresses().contains(&order.user)
+            {
+                cancel_ids.push(order_id);
+            }
+        }
+
+        for order_id in settled_ids.iter() {
+            self.strategy_orders(strategy_id).swap_remove(&order_id);
+        }
+
+        for order_id in cancel_ids.iter() {
+            let mut order = self.limit_orders(order_id).get();
+            self.refund_and_close(&mut order, OrderStatus::Cancelled);
+            self.cancel_reason(order_id).set(CancelReason::StrategyKill);
+            self.strategy_orders(strategy_id).swap_remove(&order_id);
+            self.limit_order_cancelled_event(
+                crate::CONTRACT_VERSION,
+                order_id,
+                &order.user,
+                &order.from_token,
+                self.blockchain().get_block_epoch(),
+                self.blockchain().get_block_round(),
+                &order.from_amount,
+            );
+        }
+
+        let cancelled = cancel_ids.len() as u64;
+        self.strategy_orders_cancelled_event(strategy_id, cancelled);
+        cancelled
+    }
+
+    /// Cancel up to `max_count` pending orders selling or buying a delisted
+    /// token and refund their owners (owner only). Orders with a swap in
+    /// flight or a blacklisted owner are skipped
+    ///
+    /// Returns the number of cancelled orders
+    #[only_owner]
+    #[endpoint(forceRefundOrdersForToken)]
+    fn force_refund_orders_for_token(&self, token: TokenIdentifier, max_count: u64) -> u64 {
+        require!(
+            !self.whitelisted_tokens().contains(&token),
+            ERROR_TOKEN_STILL_WHITELISTED
+        );
+
+        // Collect first: cancelling removes ids from the set we iterate
+        let mut cancel_ids = ManagedVec::<Self::Api, u64>::new();
+        for order_id in self.pending_order_ids().iter() {
+            if cancel_ids.len() as u64 >= max_count {
+                break;
+            }
+            let order = self.limit_orders(order_id).get();
+            if (order.from_token == token || order.to_token == token)
+                && !self.orders_in_execution().contains(&order_id)
+                && !self.is_escrow_lent(order_id)
+                && !self.blacklisted_addresses().contains(&order.user)
+            {
+                cancel_ids.push(order_id);
+            }
+        }
+
+        for order_id in cancel_ids.iter() {
+            let mut order = self.limit_orders(order_id).get();
+            self.refund_and_close(&mut order, OrderStatus::Cancelled);
+            self.cancel_reason(order_id).set(CancelReason::TokenDelisted);
+            self.order_force_cancelled_event(
+                crate::CONTRACT_VERSION,
+                order_id,
+                &order.user,
+                &token,
+                CancelReason::TokenDelisted,
+                &order.from_amount,
+            );
+        }
+
+        cancel_ids.len() as u64
+    }
+
+    /// Refund up to `max_count` of the caller's pending orders once the
+    /// contract is winding down (FullStop for the grace period)
+    ///
+    /// No cancellation fee or window applies. Tokenized orders (cancelled
+    /// with their position) and orders with a swap in flight are skipped;
+    /// lent escrow is redeemed first and refunded by the callback.
+    /// Returns the number of orders refunded or being redeemed
+    #[endpoint(windDownRefund)]
+    fn wind_down_refund(&self, max_count: u64) -> u64 {
+        self.require_wind_down_active();
+        let caller = self.blockchain().get_caller();
+        self.require_not_blacklisted(&caller);
+
+        let mut refund_ids = ManagedVec::<Self::Api, u64>::new();
+        for order_id in self.user_orders(&caller).iter() {
+            if refund_ids.len() as u64 >= max_count {
+                break;
+            }
+            let order = self.limit_orders(order_id).get();
+            if matches!(order.status, OrderStatus::Pending)
+                && !self.is_tokenized(order_id)
+                && !self.orders_in_execution().contains(&order_id)
+            {
+                refund_ids.push(order_id);
+            }
+        }
+
+        for order_id in refund_ids.iter() {
+            self.cancel_reason(order_id).set(CancelReason::WindDown);
+            if self.is_escrow_lent(order_id) {
+                self.redeem_escrow(order_id, RedeemPurpose::Cancel);
+                continue;
+            }
+
+            let mut order = self.limit_orders(order_id).get();
+            self.refund_and_close(&mut order, OrderStatus::Cancelled);
+            self.order_force_cancelled_event(
+                crate::CONTRACT_VERSION,
+                order_id,
+                &order.user,
+                &order.from_token,
+                CancelReason::WindDown,
+                &order.from_amount,
+            );
+        }
+
+        refund_ids.len() as u64
+    }
+
+    /// Emit `order_activated` for up to `max_count` orders created before
+    /// `token` had a pair (callable by anyone)
+    ///
+    /// Setting the pair already activates a first batch; this finishes
+    /// the rest. Returns the number of orders activated
+    #[endpoint(activateDormantOrders)]
+    fn activate_dormant_orders(&self, token: TokenIdentifier, max_count: u64) -> u64 {
+        require!(
+            !self.awaiting_pair_tokens().contains(&token),
+            ERROR_TOKEN_AWAITING_PAIR
+        );
+        self.activate_dormant_batch(&token, max_count)
+    }
+
+    /// Refund expired orders to their owners (callable by anyone, never
+    /// blocked by any pause level)
+    ///
+    /// Examines up to `max_count` pending orders, continuing where the
+    /// previous sweep stopped. The caller earns a bounty (in bps of each
+    /// refunded amount) paid from the expiry bounty pool while it has funds.
+    ///
+    /// Returns the number of orders expired
+    #[endpoint(processExpiredOrders)]
+    fn process_expired_orders(&self, max_count: u64) -> u64 {
+        let caller = self.blockchain().get_caller();
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+
+        let pending_len = self.pending_order_ids().len();
+        if pending_len == 0 {
+            return 0;
+        }
+
+        // Collect first: expiring removes ids from the set we iterate
+        let mut expired_ids = ManagedVec::<Self::Api, u64>::new();
+        let mut index = self.expiry_sweep_cursor().get();
+        let examine_count = core::cmp::min(max_count as usize, pending_len);
+        for _ in 0..examine_count {
+            if index == 0 || index > pending_len {
+                index = 1;
+            }
+            let order_id = self.pending_order_ids().get_by_index(index);
+            let order = self.limit_orders(order_id).get();
+            if order.expires_at < current_time
+                && !self.orders_in_execution().contains(&order_id)
+                && !self.is_escrow_lent(order_id)
+                && !self.blacklisted_addresses().contains(&order.user)
+            {
+                expired_ids.push(order_id);
+            }
+            index += 1;
+        }
+        self.expiry_sweep_cursor().set(index);
+
+        let bounty_bps = self.expiry_bounty_bps().get();
+        for order_id in expired_ids.iter() {
+            let mut order = self.limit_orders(order_id).get();
+            self.refund_and_close(&mut order, OrderStatus::Expired);
+            self.limit_order_expired_event(
+                order_id,
+                &order.user,
+                &order.from_token,
+                &order.from_amount,
+            );
+
+            self.pay_expiry_bounty(&caller, &order.from_token, &order.from_amount, bounty_bps);
+        }
+
+        expired_ids.len() as u64
+    }
+
+    /// Set how many failed swaps an order survives before it is refunded (owner only)
+    #[only_owner]
+    #[endpoint(setMaxExecutionAttempts)]
+    fn set_max_execution_attempts(&self, max_attempts: u64) {
+        require!(max_attempts > 0, ERROR_MAX_ATTEMPTS_ZERO);
+        let old_max_attempts = self.max_execution_attempts().replace(max_attempts);
+        self.max_execution_attempts_changed_event(old_max_attempts, max_attempts);
+    }
+
+    /// Make failed orders wait `base_seconds` before their next attempt,
+    /// doubling with each further failure (owner only, 0 disables)
+    #[only_owner]
+    #[endpoint(setRetryBackoff)]
+    fn set_retry_backoff(&self, base_seconds: u64) {
+        let old_base_seconds = self.retry_backoff_base().replace(base_seconds);
+        self.retry_backoff_changed_event(old_base_seconds, base_seconds);
+    }
+
+    /// Top up the expiry bounty pool with any whitelisted token
+    #[payable("*")]
+    #[endpoint(fundExpiryBountyPool)]
+    fn fund_expiry_bounty_pool(&self) {
+        let (token, amount) = self.receive_fungible_payment();
+        require!(
+            self.whitelisted_tokens().contains(&token),
+            ERROR_TOKEN_NOT_WHITELISTED
+        );
+        require!(amount > 0u64, ERROR_INVALID_AMOUNT);
+
+        self.expiry_bounty_pool(&token)
+            .update(|pool| *pool += &amount);
+    }
+
+    /// Set the expiry bounty in basis points (owner only)
+    #[only_owner]
+    #[endpoint(setExpiryBountyBps)]
+    fn set_expiry_bounty_bps(&self, bounty_bps: u64) {
+        require!(
+            bounty_bps <= MAX_EXPIRY_BOUNTY_BPS,
+            ERROR_BOUNTY_TOO_HIGH
+        );
+        let old_bounty_bps = self.expiry_bounty_bps().replace(bounty_bps);
+        self.expiry_bounty_changed_event(old_bounty_bps, bounty_bps);
+    }
+
+    // ========== VIEW FUNCTIONS ==========
+
+    /// Get all pending orders (for backend executor), in no particular order
+    ///
+    /// Reads the pending index only, so settled orders cost nothing; legacy
+    /// orders appear once `migrateOrders` has indexed them
+    #[view(getPendingOrders)]
+    fn get_pending_orders(&self) -> MultiValueEncoded<LimitOrder<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+
+        for order_id in self.pending_order_ids().iter() {
+            result.push(self.limit_orders(order_id).get());
+        }
+
+        result
+    }
+
+    /// Get user's orders
+    #[view(getUserOrders)]
+    fn get_user_orders(&self, user: ManagedAddress) -> MultiValueEncoded<LimitOrder<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+
+        for order_id in self.user_orders(&user).iter() {
+            if !self.limit_orders(order_id).is_empty() {
+                result.push(self.limit_orders(order_id).get());
+            }
+        }
+
+        result
+    }
+
+    /// Open (pending) orders tagged with a strategy
+    #[view(getStrategyOpenOrders)]
+    fn get_strategy_open_orders(
+        &self,
+        strategy_id: u64,
+    ) -> MultiValueEncoded<LimitOrder<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+
+        for order_id in self.strategy_orders(strategy_id).iter() {
+            if self.pending_order_ids().contains(&order_id) {
+                result.push(self.limit_orders(order_id).get());
+            }
+        }
+
+        result
+    }
+
+    /// Best `depth` unexpired pending orders selling `from_token` for
+    /// `to_token`, lowest target price (closest to execution) first
+    /// Walks the pair's price buckets from the lowest and only sorts the
+    /// buckets it needs
+    #[view(getOrderBook)]
+    fn get_order_book(
+        &self,
+        from_token: TokenIdentifier,
+        to_token: TokenIdentifier,
+        depth: usize,
+    ) -> MultiValueEncoded<LimitOrder<Self::Api>> {
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+
+        let mut result = MultiValueEncoded::new();
+        for bucket in self.pair_price_buckets(&from_token, &to_token).get().iter() {
+            if result.len() >= depth {
+                break;
+            }
+
+            let mut ids = ManagedVec::<Self::Api, u64>::new();
+            let mut prices = ManagedVec::<Self::Api, BigUint>::new();
+            for order_id in self.pair_bucket_orders(&from_token, &to_token, bucket).iter() {
+                let order = self.limit_orders(order_id).get();
+                if order.expires_at < current_time {
+                    continue;
+                }
+                ids.push(order_id);
+                prices.push(self.normalized_price(&order));
+            }
+
+            // Partial selection sort within the bucket
+            while result.len() < depth && !ids.is_empty() {
+                let mut best = 0;
+                for i in 1..ids.len() {
+                    if *prices.get(i) < *prices.get(best) {
+                        best = i;
+                    }
+                }
+                result.push(self.limit_orders(ids.get(best)).get());
+                ids.remove(best);
+                prices.remove(best);
+            }
+        }
+
+        result
+    }
+
+    /// Orders created in `[start_ts, end_ts)`, for exports and backtesting
+    /// Skips the first `from_index` matches and returns at most `size`
+    /// (capped at 100) orders, by creation day
+    #[view(getOrdersCreatedBetween)]
+    fn get_orders_created_between(
+        &self,
+        start_ts: u64,
+        end_ts: u64,
+        from_index: u64,
+        size: u64,
+    ) -> MultiValueEncoded<LimitOrder<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        let limit = core::cmp::min(size, MAX_ORDER_HISTORY_PAGE);
+        let Some(first_indexed) = self.first_history_bucket().get() else {
+            return result;
+        };
+        if start_ts >= end_ts || limit == 0 {
+            return result;
+        }
+
+        // Only buckets that can hold orders: none before the first indexed
+        // one, none in the future
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        let first_bucket = core::cmp::max(start_ts / ORDER_HISTORY_BUCKET_SECONDS, first_indexed);
+        let last_bucket = core::cmp::min(
+            (end_ts - 1) / ORDER_HISTORY_BUCKET_SECONDS,
+            current_time / ORDER_HISTORY_BUCKET_SECONDS,
+        );
+
+        let mut skipped = 0u64;
+        let mut returned = 0u64;
+        for bucket in first_bucket..=last_bucket {
+            for order_id in self.orders_created_in_bucket(bucket).iter() {
+                let order = self.limit_orders(order_id).get();
+                if order.created_at < start_ts || order.created_at >= end_ts {
+                    continue;
+                }
+                if skipped < from_index {
+                    skipped += 1;
+                    continue;
+                }
+                result.push(order);
+                returned += 1;
+                if returned == limit {
+                    return result;
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Number of orders ever created, whatever their status
+    #[view(getOrderCount)]
+    fn get_order_count(&self) -> u64 {
+        self.next_order_id().get().saturating_sub(1)
+    }
+
+    /// Id of the most recent order (0 when none was created); ids are
+    /// sequential from 1 and orders are never deleted
+    #[view(getLastOrderId)]
+    fn get_last_order_id(&self) -> u64 {
+        self.next_order_id().get().saturating_sub(1)
+    }
+
+    /// Lowest id of a pending order (0 when none is pending); orders below
+    /// it are settled for good, as dormant orders always wait on a pending
+    /// order with a lower id
+    #[view(getFirstActiveOrderId)]
+    fn get_first_active_order_id(&self) -> u64 {
+        self.pending_order_ids()
+            .iter()
+            .min()
+            .unwrap_or_default()
+    }
+
+    /// Orders with ids in `[from_id, to_id]`, any status, for indexers
+    /// backfilling state; returns at most 100 orders from `from_id` on
+    #[view(getOrdersRange)]
+    fn get_orders_range(&self, from_id: u64, to_id: u64) -> MultiValueEncoded<LimitOrder<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        let first_id = core::cmp::max(from_id, 1);
+        let last_id = core::cmp::min(
+            to_id,
+            core::cmp::min(
+                self.get_last_order_id(),
+                first_id.saturating_add(MAX_ORDER_HISTORY_PAGE - 1),
+            ),
+        );
+
+        for order_id in first_id..=last_id {
+            result.push(self.limit_orders(order_id).get());
+        }
+
+        result
+    }
+
+    /// Pause level, pair status per whitelisted token, pending order count
+    /// and age, and async executions stuck for over 10 minutes
+    #[view(getExecutorHealth)]
+    fn get_executor_health(&self) -> ExecutorHealth<Self::Api> {
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+
+        let mut token_pairs = ManagedVec::new();
+        for token in self.whitelisted_tokens().iter() {
+            let pair = if !self.token_pair(&token).is_empty() {
+                Some(self.token_pair(&token).get())
+            } else if !self.xexchange_pair().is_empty() {
+                Some(self.xexchange_pair().get())
+            } else {
+                None
+            };
+            let pair_tripped = match &pair {
+                Some(pair) => self.tripped_pairs().contains(pair),
+                None => false,
+            };
+            token_pairs.push(TokenPairHealth {
+                token,
+                pair_configured: pair.is_some(),
+                pair_tripped,
+            });
+        }
+
+        // Order ids grow with creation time, so the oldest is the lowest id
+        let oldest_pending_age = match self.pending_order_ids().iter().min() {
+            Some(order_id) => {
+                current_time.saturating_sub(self.limit_orders(order_id).get().created_at)
+            }
+            None => 0,
+        };
+
+        let stuck_executions = self
+            .orders_in_execution()
+            .iter()
+            .filter(|order_id| {
+                // Executions started before start times were recorded count as stuck
+                let started_at = self.execution_started_at(*order_id).get();
+                current_time.saturating_sub(started_at) > STUCK_EXECUTION_SECONDS
+            })
+            .count() as u64;
+
+        ExecutorHealth {
+            pause_level: self.pause_level().get(),
+            token_pairs,
+            pending_orders: self.pending_order_ids().len() as u64,
+            oldest_pending_age,
+            stuck_executions,
+        }
+    }
+
+    /// Protocol-wide order counts, volume and fees in one call
+    /// Executions, volume and fees count from the release adding statistics
+    #[view(getProtocolStats)]
+    fn get_protocol_stats(&self) -> ProtocolStats<Self::Api> {
+        let (volume, fees) = self.protocol_totals();
+        ProtocolStats {
+            orders_created: self.next_order_id().get().saturating_sub(1),
+            orders_executed: self.total_orders_executed().get(),
+            open_orders: self.pending_order_ids().len() as u64,
+            volume,
+            fees,
+        }
+    }
+
+    /// Get order by ID
+    #[view(getOrder)]
+    fn get_order(&self, order_id: u64) -> LimitOrder<Self::Api> {
+        self.limit_orders(order_id).get()
+    }
+
+    /// Client metadata hash the order was created with, if any
+    #[view(getOrderClientTag)]
+    fn get_order_client_tag(
+        &self,
+        order_id: u64,
+    ) -> OptionalValue<ManagedByteArray<Self::Api, 32>> {
+        if self.order_client_tag(order_id).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.order_client_tag(order_id).get())
+        }
+    }
+
+    /// Agent intent the order was created with, if any
+    #[view(getOrderIntent)]
+    fn get_order_intent(&self, order_id: u64) -> OptionalValue<OrderIntent<Self::Api>> {
+        if self.order_intent(order_id).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.order_intent(order_id).get())
+        }
+    }
+
+    /// Order `user` created under `reference`, if any
+    #[view(getOrderByReference)]
+    fn get_order_by_reference(&self, user: ManagedAddress, reference: u64) -> OptionalValue<u64> {
+        if self.order_by_reference(&user, reference).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.order_by_reference(&user, reference).get())
+        }
+    }
+
+    /// Intermediate token of a routed order, if any
+    #[view(getOrderRouteVia)]
+    fn get_order_route_via(&self, order_id: u64) -> OptionalValue<TokenIdentifier> {
+        if self.order_route_via(order_id).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.order_route_via(order_id).get())
+        }
+    }
+
+    /// Order funded by the output of `order_id`, if any
+    #[view(getChainedOrder)]
+    fn get_chained_order(&self, order_id: u64) -> OptionalValue<u64> {
+        if self.chained_order(order_id).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.chained_order(order_id).get())
+        }
+    }
+
+    /// Order whose output funds the chained order `order_id`, if any
+    #[view(getChainParent)]
+    fn get_chain_parent(&self, order_id: u64) -> OptionalValue<u64> {
+        if self.chain_parent(order_id).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.chain_parent(order_id).get())
+        }
+    }
+
+    /// Dormant stop-loss guarding `order_id`, if any
+    #[view(getOrderStopLoss)]
+    fn get_order_stop_loss(&self, order_id: u64) -> OptionalValue<u64> {
+        if self.order_stop_loss(order_id).is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.order_stop_loss(order_id).get())
+        }
+    }
+
+    /// True while a token of the order has no pair yet
+    #[view(isOrderDormant)]
+    fn is_order_dormant(&self, order_id: u64) -> bool {
+        let order = self.limit_orders(order_id).get();
+        self.is_awaiting_pair(&order.from_token, &order.to_token)
+    }
+
+    /// Why `executeLimitOrder` by the configured executor would not swap
+    /// the whole order at the given price right now (`Ok` if it would)
+    #[view(canExecuteOrder)]
+    fn can_execute_order(
+        &self,
+        order_id: u64,
+        current_price_num: BigUint,
+        current_price_denom: BigUint,
+    ) -> ReasonCode {
+        let executor = self.limit_order_executor().get();
+        self.execution_reason(
+            order_id,
+            &executor,
+            false,
+            &None,
+            &current_price_num,
+            &current_price_denom,
+        )
+    }
+
+    /// Why `createLimitOrder` from `user` with these parameters would fail
+    /// right now (`Ok` if it would succeed)
+    #[allow(clippy::too_many_arguments)]
+    #[view(validateLimitOrder)]
+    fn validate_limit_order(
+        &self,
+        user: ManagedAddress,
+        from_token: TokenIdentifier,
+        from_amount: BigUint,
+        to_token: TokenIdentifier,
+        target_price_num: BigUint,
+        target_price_denom: BigUint,
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+    ) -> ReasonCode {
+        if !self.pause_level().get().allows_creation() {
+            return ReasonCode::CreationPaused;
+        }
+        if self.blacklisted_addresses().contains(&user) {
+            return ReasonCode::AddressBlacklisted;
+        }
+        let reason = self.order_creation_reason(
+            &from_token,
+            &from_amount,
+            &to_token,
+            &target_price_num,
+            &target_price_denom,
+            slippage_bp,
+            expires_in_seconds,
+        );
+        if !reason.is_ok() {
+            return reason;
+        }
+        let open_interest = self.escrow_balance(&from_token).get() + &from_amount;
+        self.risk_caps_reason(&from_token, &from_amount, &open_interest)
+    }
+
+    /// What selling `amount` of `from_token` on its pair would pay out right
+    /// now: the expected output and the minimum after `slippage_bp`, both
+    /// net of execution and protocol fees
+    /// Queries the pair's `getAmountOut` synchronously, so the pair must be
+    /// on the contract's shard
+    #[view(estimateOrderOutput)]
+    fn estimate_order_output(
+        &self,
+        from_token: TokenIdentifier,
+        to_token: TokenIdentifier,
+        amount: BigUint,
+        slippage_bp: u64,
+    ) -> MultiValue2<BigUint, BigUint> {
+        require!(from_token != to_token, ERROR_SAME_TOKEN);
+        require!(amount > 0u64, ERROR_INVALID_AMOUNT);
+        require!(
+            slippage_bp <= MAX_BASIS_POINTS,
+            ERROR_SLIPPAGE_ABOVE_100_PERCENT
+        );
+
+        let pair_address = self.swap_pair(&from_token, &to_token);
+        let amount_out = self.quote_amount_out(&pair_address, &from_token, &amount);
+        let min_amount_out = &amount_out * (MAX_BASIS_POINTS - slippage_bp) / MAX_BASIS_POINTS;
+
+        (
+            self.output_after_fees(&amount_out),
+            self.output_after_fees(&min_amount_out),
+        )
+            .into()
+    }
+
+    /// Price the owner of an order filled exactly at its target receives
+    /// after the current execution and protocol fees, in to_token per
+    /// from_token scaled by PRICE_SCALE (1e18)
+    #[view(getEffectivePrice)]
+    fn get_effective_price(
+        &self,
+        from_amount: BigUint,
+        target_price_num: BigUint,
+        target_price_denom: BigUint,
+    ) -> BigUint {
+        require!(from_amount > 0u64, ERROR_INVALID_AMOUNT);
+        require!(target_price_num > 0u64, ERROR_PRICE_NUM_ZERO);
+        require!(target_price_denom > 0u64, ERROR_PRICE_DENOM_ZERO);
+
+        let target_output = &from_amount * &target_price_num / &target_price_denom;
+        self.scaled_price(&self.output_after_fees(&target_output), &from_amount)
+    }
+
+    /// Minimum swap output an execution of the whole remaining order would
+    /// demand now, under the current `minOutputMode` (gross of fees), and
+    /// the floor that set it
+    #[view(getExecutionMinOutput)]
+    fn get_execution_min_output(&self, order_id: u64) -> MultiValue2<BigUint, MinOutputBound> {
+        let order = self.require_open_order(order_id);
+        let swap_amount = if self.zap_order(order_id).get() {
+            &order.from_amount / 2u64
+        } else {
+            order.from_amount.clone()
+        };
+        self.execution_min_output(&order, &swap_amount).into()
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    /// Shared execution path for full and partial fills
+    fn execute_order(
+        &self,
+        order_id: u64,
+        fill_amount: Option<BigUint>,
+        forced: bool,
+        current_price_num: BigUint,
+        current_price_denom: BigUint,
+    ) {
         let caller = self.blockchain().get_caller();
+        let reason = self.execution_reason(
+            order_id,
+            &caller,
+            forced,
+            &fill_amount,
+            &current_price_num,
+            &current_price_denom,
+        );
+
+        // Expired orders are refunded on the spot instead of reverting,
+        // so normal bot activity cleans them up
+        let order = match reason {
+            ReasonCode::Ok => self.limit_orders(order_id).get(),
+            ReasonCode::OrderExpired => {
+                self.expire_on_execution(order_id);
+                return;
+            },
+            _ => sc_panic!(reason.error_message()),
+        };
+        if forced {
+            self.order_force_executed_event(order_id, &caller);
+        }
+
+        // Whole remaining escrow unless a chunk was requested
+        let fill_amount = fill_amount.unwrap_or_else(|| order.from_amount.clone());
+
+        // Skip the swap (without reverting) on an inactive or shallow pair
+        let is_zap = self.zap_order(order_id).get();
+        let swap_amount = if is_zap {
+            &fill_amount / 2u64
+        } else {
+            fill_amount.clone()
+        };
+        let pair_address = self.entry_pair(&order);
+        if !self.check_pair_health(order_id, &pair_address, &order.from_token, &swap_amount) {
+            return;
+        }
+
+        // Zap orders swap half of the chunk and add liquidity with the rest
+        let fill_amount = if is_zap {
+            let zap = ZapExecution {
+                executor: caller.clone(),
+                fill_amount: fill_amount.clone(),
+            };
+            self.zap_executions(order_id).set(&zap);
+            fill_amount / 2u64
+        } else {
+            fill_amount
+        };
+        require!(fill_amount > 0u64, ERROR_INVALID_AMOUNT);
+        self.spend_executor_volume(&caller, &order.from_token, &fill_amount);
+
+        // Calculate minimum output with slippage
+        let (min_amount_out, bound) = self.execution_min_output(&order, &fill_amount);
+        self.execution_min_output_event(order_id, bound, &min_amount_out);
+
+        // Store execution context for callback
+        let context = SwapExecutionContext {
+            order_id,
+            user: order.user.clone(),
+            executor: caller.clone(),
+            to_token: order.to_token.clone(),
+            min_amount_out: min_amount_out.clone(),
+            fill_amount: fill_amount.clone(),
+        };
+        self.pending_swap_executions(order_id).set(&context);
+
+        // Escrow is in flight until the callback: block cancel/expiry meanwhile
+        self.begin_execution(order_id);
+
+        // Lent escrow comes back first; the swap starts in the redeem callback
+        if self.is_escrow_lent(order_id) {
+            self.redeem_escrow(order_id, RedeemPurpose::Execute);
+            return;
+        }
+
+        self.dispatch_swap(&context);
+    }
+
+    /// First reason an `execute_order` by `caller` would not swap, in the
+    /// order the endpoint checks them; `forced` skips the execution rights
+    /// check (owner backstop)
+    fn execution_reason(
+        &self,
+        order_id: u64,
+        caller: &ManagedAddress,
+        forced: bool,
+        fill_amount: &Option<BigUint>,
+        current_price_num: &BigUint,
+        current_price_denom: &BigUint,
+    ) -> ReasonCode {
+        if !self.pause_level().get().allows_execution() {
+            return ReasonCode::ExecutionPaused;
+        }
+        if !forced {
+            let reason =
+                self.execution_rights_reason(order_id, caller, self.limit_order_executor().get());
+            if !reason.is_ok() {
+                return reason;
+            }
+        }
+        if self.limit_orders(order_id).is_empty() {
+            return ReasonCode::OrderNotFound;
+        }
+        let order = self.limit_orders(order_id).get();
+        if !matches!(order.status, OrderStatus::Pending) {
+            return ReasonCode::OrderNotPending;
+        }
+        if self.orders_in_execution().contains(&order_id) {
+            return ReasonCode::ExecutionInProgress;
+        }
+
+        // No payouts (output, refund or fee) to sanctioned addresses
+        if self.blacklisted_addresses().contains(&order.user)
+            || self.blacklisted_addresses().contains(caller)
+        {
+            return ReasonCode::AddressBlacklisted;
+        }
+
+        // Delisted tokens block execution; cancellation still works
+        if !self.whitelisted_tokens().contains(&order.from_token)
+            || !self.whitelisted_tokens().contains(&order.to_token)
+        {
+            return ReasonCode::TokenDelisted;
+        }
+        if self.is_awaiting_pair(&order.from_token, &order.to_token) {
+            return ReasonCode::OrderDormant;
+        }
+        // No pair lookup while nothing is tripped
+        if !self.tripped_pairs().is_empty()
+            && self.is_pair_tripped(&self.swap_pair(&order.from_token, &order.to_token))
+        {
+            return ReasonCode::PairTripped;
+        }
+        if self.pair_paused(&order.from_token, &order.to_token).get() {
+            return ReasonCode::PairPaused;
+        }
+        let route_reason = self.route_reason(&order);
+        if !route_reason.is_ok() {
+            return route_reason;
+        }
+
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        if current_time > order.expires_at {
+            return ReasonCode::OrderExpired;
+        }
+
+        // The owner's forced execution is not held back by failed attempts
+        if !forced && current_time < self.retry_after(order_id).get() {
+            return ReasonCode::RetryBackoff;
+        }
+
+        let fill_amount = fill_amount.as_ref().unwrap_or(&order.from_amount);
+        if *fill_amount == 0u64 {
+            return ReasonCode::InvalidAmount;
+        }
+        if *fill_amount > order.from_amount {
+            return ReasonCode::FillExceedsRemaining;
+        }
+        if *fill_amount < order.from_amount && self.is_exact_output(order_id) {
+            return ReasonCode::ExactOutputFullFill;
+        }
+
+        // Zap orders swap half of the chunk
+        let swap_amount = if self.zap_order(order_id).get() {
+            fill_amount / 2u64
+        } else {
+            fill_amount.clone()
+        };
+        let reason = self.executor_volume_reason(caller, &order.from_token, &swap_amount);
+        if !reason.is_ok() {
+            return reason;
+        }
+
+        // Signal-gated orders wait for their signal, conditional ones for their price
+        let reason = self.order_signal_reason(order_id);
+        if !reason.is_ok() {
+            return reason;
+        }
+        let reason = self.order_condition_reason(order_id);
+        if !reason.is_ok() {
+            return reason;
+        }
+        let reason = self.strategy_pause_reason(order_id);
+        if !reason.is_ok() {
+            return reason;
+        }
+        let reason = self.price_commitment_reason(order_id);
+        if !reason.is_ok() {
+            return reason;
+        }
+
+        if !self.is_limit_price_met(&order, current_price_num, current_price_denom) {
+            return ReasonCode::PriceNotMet;
+        }
+        ReasonCode::Ok
+    }
+
+    /// Drop up to `max_count` orders from the dormant set of `token`, which
+    /// now has a pair, emitting `order_activated` for the pending ones that
+    /// do not also wait for a pair of their other token
+    fn activate_dormant_batch(&self, token: &TokenIdentifier, max_count: u64) -> u64 {
+        let mut dormant = self.dormant_orders(token);
+        let mut activated = 0u64;
+        for _ in 0..max_count {
+            if dormant.is_empty() {
+                break;
+            }
+            let order_id = dormant.get_by_index(1);
+            dormant.swap_remove(&order_id);
+
+            let order = self.limit_orders(order_id).get();
+            if matches!(order.status, OrderStatus::Pending)
+                && !self.is_awaiting_pair(&order.from_token, &order.to_token)
+            {
+                self.order_activated_event(order_id, token);
+                activated += 1;
+            }
+        }
+        activated
+    }
+
+    /// Refund an expired order found by the executor and mark it Expired
+    fn expire_on_execution(&self, order_id: u64) {
+        if self.is_escrow_lent(order_id) {
+            self.redeem_escrow(order_id, RedeemPurpose::Expire);
+            return;
+        }
+        let mut order = self.limit_orders(order_id).get();
+        self.refund_and_close(&mut order, OrderStatus::Expired);
+        self.limit_order_expired_event(
+            order_id,
+            &order.user,
+            &order.from_token,
+            &order.from_amount,
+        );
+    }
+
+    /// Execute ASYNC swap on xExchange (works cross-shard!)
+    fn dispatch_swap(&self, context: &SwapExecutionContext<Self::Api>) {
+        let order = self.limit_orders(context.order_id).get();
+        self.escrow_in_flight(&order.from_token)
+            .update(|amount| *amount += &context.fill_amount);
+        if !self.order_route_via(context.order_id).is_empty() {
+            let via_token = self.order_route_via(context.order_id).get();
+            let (function, arguments) = self.routed_swap_call(
+                &order.from_token,
+                &via_token,
+                &order.to_token,
+                &context.min_amount_out,
+            );
+            self.tx()
+                .to(self.xexchange_router().get())
+                .gas(ROUTED_SWAP_GAS)
+                .raw_call(function)
+                .arguments_raw(arguments)
+                .single_esdt(&order.from_token, 0u64, &context.fill_amount)
+                .with_callback(self.callbacks().swap_callback(context.order_id))
+                .with_extra_gas_for_callback(10_000_000)
+                .register_promise();
+            return;
+        }
+
+        let pair_address = self.swap_pair(&order.from_token, &order.to_token);
+        let descriptor = if self.is_exact_output(context.order_id) {
+            self.exact_output_call_descriptor()
+        } else {
+            self.swap_call_descriptor(&pair_address)
+        };
+
+        let mut swap_call = self
+            .tx()
+            .to(&pair_address)
+            .gas(30_000_000u64)
+            .raw_call(descriptor.function);
+        for argument in descriptor.arguments.iter() {
+            swap_call = match *argument {
+                SwapArgument::TokenOut => swap_call.argument(&order.to_token),
+                SwapArgument::MinAmountOut => swap_call.argument(&context.min_amount_out),
+                SwapArgument::TokenIn => swap_call.argument(&order.from_token),
+                SwapArgument::AmountIn => swap_call.argument(&context.fill_amount),
+            };
+        }
+        swap_call
+            .single_esdt(&order.from_token, 0u64, &context.fill_amount)
+            .with_callback(self.callbacks().swap_callback(context.order_id))
+            .with_extra_gas_for_callback(10_000_000)
+            .register_promise();
+    }
+
+    /// Sort the payments a swap callback received: the output and the
+    /// returned input of the order are summed, any other token is refunded
+    /// to the order owner with an `unexpected_swap_payment` event
+    /// Returns the output and the returned input amounts
+    fn split_swap_payments(
+        &self,
+        order_id: u64,
+        order: &LimitOrder<Self::Api>,
+    ) -> (BigUint, BigUint) {
+        let mut output_amount = BigUint::zero();
+        let mut returned_input = BigUint::zero();
+        for payment in self.call_value().all_esdt_transfers().iter() {
+            if payment.token_nonce == 0 && payment.token_identifier == order.to_token {
+                output_amount += &payment.amount;
+            } else if payment.token_nonce == 0 && payment.token_identifier == order.from_token {
+                returned_input += &payment.amount;
+            } else {
+                if payment.token_nonce == 0 {
+                    self.pay_order_owner(order_id, &order.user, &payment.token_identifier, &payment.amount);
+                } else {
+                    self.send().direct_esdt(
+                        &order.user,
+                        &payment.token_identifier,
+                        payment.token_nonce,
+                        &payment.amount,
+                    );
+                }
+                self.unexpected_swap_payment_event(
+                    order_id,
+                    &payment.token_identifier,
+                    payment.token_nonce,
+                    false,
+                    &payment.amount,
+                );
+            }
+        }
+        (output_amount, returned_input)
+    }
+
+    /// Attach the optional strategy and client metadata hash of a new order
+    fn apply_order_tags(
+        &self,
+        order_id: u64,
+        opt_strategy_id: OptionalValue<u64>,
+        opt_client_tag: OptionalValue<ManagedByteArray<Self::Api, 32>>,
+    ) {
+        if let OptionalValue::Some(strategy_id) = opt_strategy_id {
+            if strategy_id != 0 {
+                self.tag_order_strategy(order_id, strategy_id);
+            }
+        }
+        if let OptionalValue::Some(client_tag) = opt_client_tag {
+            self.order_client_tag(order_id).set(&client_tag);
+            self.order_client_tagged_event(order_id, &client_tag);
+        }
+    }
+
+    /// Validate, store and index a new pending order, emitting the created event
+    /// Escrow accounting is left to the caller (the funds may already be locked)
+    #[allow(clippy::too_many_arguments)]
+    fn create_order(
+        &self,
+        user: &ManagedAddress,
+        from_token: TokenIdentifier,
+        from_amount: BigUint,
+        to_token: TokenIdentifier,
+        target_price_num: BigUint,
+        target_price_denom: BigUint,
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+    ) -> u64 {
+        let reason = self.order_creation_reason(
+            &from_token,
+            &from_amount,
+            &to_token,
+            &target_price_num,
+            &target_price_denom,
+            slippage_bp,
+            expires_in_seconds,
+        );
+        require!(reason.is_ok(), (reason.error_message()));
+
+        // The order's escrow is already locked, so it counts as open interest
+        let open_interest = self.escrow_balance(&from_token).get();
+        let reason = self.risk_caps_reason(&from_token, &from_amount, &open_interest);
+        require!(reason.is_ok(), (reason.error_message()));
+
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        let expires_at = current_time + expires_in_seconds;
+
+        // Create order
+        let order_id = self.next_order_id().get();
+        let order = LimitOrder {
+            order_id,
+            user: user.clone(),
+            from_token,
+            from_amount,
+            to_token,
+            target_price_numerator: target_price_num,
+            target_price_denominator: target_price_denom,
+            slippage_bp,
+            expires_at,
+            status: OrderStatus::Pending,
+            created_at: current_time,
+        };
+
+        // Store order
+        self.limit_orders(order_id).set(&order);
+        self.user_orders(user).insert(order_id);
+        self.index_pending_order(&order);
+        self.track_dormant_order(order_id, &order.from_token, &order.to_token);
+        self.store_normalized_price(&order);
+        self.next_order_id().set(order_id + 1);
+        self.index_order_history(order_id, current_time);
+        self.record_order_created(&order);
+
+        self.limit_order_created_event(
+            crate::CONTRACT_VERSION,
+            order_id,
+            user,
+            &order.from_token,
+            &order.from_amount,
+            &order.to_token,
+            &order.target_price_numerator,
+            &order.target_price_denominator,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_round(),
+            expires_at,
+        );
+
+        order_id
+    }
+
+    fn validate_order_terms(
+        &self,
+        target_price_num: &BigUint,
+        target_price_denom: &BigUint,
+        slippage_bp: u64,
+    ) {
+        let reason = self.order_terms_reason(target_price_num, target_price_denom, slippage_bp);
+        require!(reason.is_ok(), (reason.error_message()));
+    }
+
+    /// First reason `create_order` would reject these order parameters
+    #[allow(clippy::too_many_arguments)]
+    fn order_creation_reason(
+        &self,
+        from_token: &TokenIdentifier,
+        from_amount: &BigUint,
+        to_token: &TokenIdentifier,
+        target_price_num: &BigUint,
+        target_price_denom: &BigUint,
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+    ) -> ReasonCode {
+        if !self.whitelisted_tokens().contains(from_token) {
+            return ReasonCode::FromTokenNotWhitelisted;
+        }
+        if !self.whitelisted_tokens().contains(to_token) {
+            return ReasonCode::ToTokenNotWhitelisted;
+        }
+        if from_token == to_token {
+            return ReasonCode::SameToken;
+        }
+        if *from_amount == 0u64 {
+            return ReasonCode::InvalidAmount;
+        }
+        let reason = self.order_terms_reason(target_price_num, target_price_denom, slippage_bp);
+        if !reason.is_ok() {
+            return reason;
+        }
+
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        if current_time.checked_add(expires_in_seconds).is_none() {
+            return ReasonCode::ExpiryTooFar;
+        }
+        ReasonCode::Ok
+    }
+
+    fn order_terms_reason(
+        &self,
+        target_price_num: &BigUint,
+        target_price_denom: &BigUint,
+        slippage_bp: u64,
+    ) -> ReasonCode {
+        if *target_price_num == 0u64 {
+            return ReasonCode::PriceNumZero;
+        }
+        if *target_price_denom == 0u64 {
+            return ReasonCode::PriceDenomZero;
+        }
+
+        // Validate slippage (max_slippage is itself capped at MAX_BASIS_POINTS)
+        if slippage_bp > MAX_BASIS_POINTS {
+            return ReasonCode::SlippageAbove100Percent;
+        }
+        if slippage_bp > self.max_slippage().get() {
+            return ReasonCode::SlippageAboveMax;
+        }
+        ReasonCode::Ok
+    }
+
+    /// Load an open order the caller may modify
+    fn require_modifiable_order(
+        &self,
+        order_id: u64,
+        caller: &ManagedAddress,
+    ) -> LimitOrder<Self::Api> {
+        let order = self.require_open_order(order_id);
+
+        require!(!self.is_tokenized(order_id), ERROR_ORDER_TOKENIZED);
+        self.require_order_authority(&order.user, caller, SESSION_PERMISSION_MODIFY);
+        self.require_not_blacklisted(&order.user);
+        self.require_not_blacklisted(caller);
+
+        order
+    }
+
+    /// Load a pending, unexpired order with no swap in flight
+    fn require_open_order(&self, order_id: u64) -> LimitOrder<Self::Api> {
+        require!(!self.limit_orders(order_id).is_empty(), ERROR_ORDER_NOT_FOUND);
+        let order = self.limit_orders(order_id).get();
+
+        require!(
+            matches!(order.status, OrderStatus::Pending),
+            ERROR_ORDER_NOT_PENDING
+        );
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        require!(current_time <= order.expires_at, ERROR_ORDER_EXPIRED);
+        self.require_not_executing(order_id);
+
+        order
+    }
+
+    /// Apply new terms to an order and emit the modification event
+    fn modify_order(
+        &self,
+        order: &mut LimitOrder<Self::Api>,
+        target_price_num: BigUint,
+        target_price_denom: BigUint,
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+    ) {
+        self.validate_order_terms(&target_price_num, &target_price_denom, slippage_bp);
+
+        let previous = OrderTerms::from_order(order);
+
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        order.target_price_numerator = target_price_num;
+        order.target_price_denominator = target_price_denom;
+        order.slippage_bp = slippage_bp;
+        order.expires_at = current_time
+            .checked_add(expires_in_seconds)
+            .unwrap_or_else(|| sc_panic!(ERROR_EXPIRY_TOO_FAR));
+        self.limit_orders(order.order_id).set(&*order);
+        // An explicit new price makes a committed order public
+        self.order_price_commitment(order.order_id).clear();
+        self.store_normalized_price(order);
+
+        self.limit_order_modified_event(
+            crate::CONTRACT_VERSION,
+            order.order_id,
+            &order.user,
+            &previous,
+            &OrderTerms::from_order(order),
+        );
+    }
+
+    /// Pay out everything held for a position; burn the NFT once the order
+    /// is settled, otherwise hand it back to the holder
+    fn claim_position_payouts(&self, order: &LimitOrder<Self::Api>, holder: &ManagedAddress) {
+        let order_id = order.order_id;
+        self.pay_out_position_balance(order_id, holder, &order.from_token);
+        self.pay_out_position_balance(order_id, holder, &order.to_token);
+
+        let settled = !matches!(order.status, OrderStatus::Pending);
+        if settled {
+            self.burn_position(order_id);
+        } else {
+            self.return_position(order_id, holder);
+        }
+        self.position_claimed_event(order_id, holder, settled);
+    }
+
+    /// Expect exactly one fungible ESDT payment
+    /// Nonce-bearing tokens (SFT/MetaESDT) get an explicit error instead of
+    /// the framework's generic one
+    fn receive_fungible_payment(&self) -> (TokenIdentifier, BigUint) {
+        let payment = self.call_value().single_esdt();
+        require!(payment.token_nonce == 0, ERROR_NON_FUNGIBLE_PAYMENT);
+
+        (payment.token_identifier.clone(), payment.amount.clone())
+    }
+
+    /// Number of pending orders selling or buying `token`
+    fn count_pending_orders_for_token(&self, token: &TokenIdentifier) -> u64 {
+        let mut count = 0u64;
+        for order_id in self.pending_order_ids().iter() {
+            let order = self.limit_orders(order_id).get();
+            if order.from_token == *token || order.to_token == *token {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// Reentrancy guard: an order whose swap is in flight cannot be
+    /// executed again, cancelled or expired until its callback has run
+    fn require_not_executing(&self, order_id: u64) {
+        require!(
+            !self.orders_in_execution().contains(&order_id),
+            ERROR_EXECUTION_IN_PROGRESS
+        );
+    }
+
+    /// Apply the cancellation policy to a user cancellation: inside the
+    /// window the order is either not cancellable yet or pays the fee
+    /// out of its escrow into the protocol fees
+    fn charge_cancellation_fee(&self, order: &mut LimitOrder<Self::Api>) {
+        let window = self.cancellation_window().get();
+        if window == 0 {
+            return;
+        }
+        // The window guards executors against spoofing; it must not hold
+        // funds while a pause stops the order from executing anyway
+        if self.is_execution_halted(&order.from_token, &order.to_token) {
+            return;
+        }
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        if current_time >= order.created_at + window {
+            return;
+        }
+
+        let fee_bps = self.cancellation_fee_bps().get();
+        require!(fee_bps > 0, ERROR_ORDER_TOO_YOUNG_TO_CANCEL);
+
+        let fee = &order.from_amount * fee_bps / MAX_BASIS_POINTS;
+        if fee == 0u64 {
+            return;
+        }
+        self.release_escrow(order, &fee);
+        order.from_amount -= &fee;
+        self.accrue_protocol_fee(&order.from_token, &fee);
+        self.fee_collected_event(&order.from_token, order.order_id, &fee);
+        self.record_fees_paid(&order.user, &order.from_token, &fee);
+    }
+
+    /// Settle a successful fill: pay fees and the owner out of `output_amount`,
+    /// consume `fill_in` from the escrow and close the order once fully filled
+    fn settle_fill(
+        &self,
+        order_id: u64,
+        executor: &ManagedAddress,
+        fill_in: &BigUint,
+        output_amount: &BigUint,
+    ) {
         let mut order = self.limit_orders(order_id).get();
 
-        require!(order.user == caller, "Not your order");
-        require!(
-            matches!(order.status, OrderStatus::Pending),
-            "Order is not pending"
+        // Calculate execution fee and protocol fee
+        let (execution_fee, sponsored_fee) =
+            self.split_execution_fee(order_id, executor, &order.to_token, output_amount);
+        let protocol_fee = self.collect_protocol_fee(order_id, &order.to_token, output_amount);
+        let mut user_amount = output_amount - &execution_fee - &protocol_fee;
+
+        // Output above the target price is shared with the executor and treasury
+        let target_output = fill_in * &order.target_price_numerator / &order.target_price_denominator;
+        let improvement = self.price_improvement(order_id, output_amount, &target_output);
+        let (executor_share, treasury_share) = self.price_improvement_shares(&improvement, &user_amount);
+        user_amount -= &(&executor_share + &treasury_share);
+        if treasury_share > 0u64 {
+            self.accrue_protocol_fee(&order.to_token, &treasury_share);
+            self.fee_collected_event(&order.to_token, order_id, &treasury_share);
+        }
+
+        // The order's strategy may give part of the execution fee back
+        user_amount += &self.take_strategy_rebate(order_id, &order.to_token, &execution_fee);
+
+        // Send tokens
+        let executor_amount = &execution_fee + &executor_share;
+        if executor_amount > 0u64 {
+            self.pay_execution_fee(executor, &order.to_token, &executor_amount);
+        }
+        if sponsored_fee > 0u64 {
+            self.pay_sponsored_fee(order_id, executor, &order.to_token, &sponsored_fee);
+        }
+
+        // A chained order takes the output as its escrow instead
+        if !self.fund_chained_order(order_id, &user_amount) {
+            if self.is_forwarded(order_id) {
+                self.forward_output(order_id, &order.user, &order.to_token, &user_amount);
+            } else if self.is_streamed(order_id) {
+                self.stream_payout(order_id, &order.user, &order.to_token, &user_amount);
+            } else {
+                self.pay_order_owner(order_id, &order.user, &order.to_token, &user_amount);
+            }
+        }
+
+        // Consume the filled chunk from the order's escrow
+        order.from_amount -= fill_in;
+        self.release_escrow(&order, fill_in);
+        let completed = order.from_amount == 0u64;
+        self.record_order_fill(&order, fill_in, completed);
+        self.record_client_volume(order_id, &order.from_token, fill_in);
+        self.record_fees_paid(&order.user, &order.to_token, &(&execution_fee + &protocol_fee));
+
+        self.record_strategy_fill(
+            order_id,
+            &order.from_token,
+            fill_in,
+            &order.to_token,
+            output_amount,
+            &target_output,
+            order.from_amount == 0u64,
+        );
+
+        let filled_total = self.filled_input(order_id).update(|filled| {
+            *filled += fill_in;
+            filled.clone()
+        });
+        self.filled_output(order_id)
+            .update(|filled| *filled += &user_amount);
+
+        if order.from_amount > 0u64 {
+            // Partial fill - order stays pending with the remainder
+            self.limit_orders(order_id).set(&order);
+            self.limit_order_partially_filled_event(
+                crate::CONTRACT_VERSION,
+                order_id,
+                &order.user,
+                fill_in,
+                &user_amount,
+                &filled_total,
+                &improvement,
+                &executor_share,
+                &treasury_share,
+                &order.from_amount,
+            );
+            return;
+        }
+
+        // Mark order as executed (from_amount reports the total input again)
+        order.from_amount = filled_total;
+        order.status = OrderStatus::Executed;
+        self.limit_orders(order_id).set(&order);
+        self.unindex_pending_order(&order);
+        self.cancel_dormant_stop_loss(order_id);
+
+        // Emit event for the final chunk (price is gross output per input, before fees)
+        let execution_price = self.scaled_price(output_amount, fill_in);
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        self.limit_order_executed_event(
+            crate::CONTRACT_VERSION,
+            order_id,
+            &order.user,
+            executor,
+            &order.from_token,
+            fill_in,
+            &order.to_token,
+            &user_amount,
+            &execution_price,
+            current_time,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_round(),
+            &improvement,
+            &executor_share,
+            &treasury_share,
+            &execution_fee,
+        );
+    }
+
+    /// Reject a match or quote fill between two sides of `user` when they
+    /// turned self-trade prevention on
+    fn require_no_self_trade(&self, user: &ManagedAddress, counterparty: &ManagedAddress) {
+        require!(
+            user != counterparty || !self.self_trade_prevention(user).get(),
+            ERROR_SELF_TRADE
+        );
+    }
+
+    /// Pending, unexpired and executable order for direct matching
+    fn require_matchable_order(&self, order_id: u64) -> LimitOrder<Self::Api> {
+        let order = self.require_open_order(order_id);
+        require!(!self.zap_order(order_id).get(), ERROR_ZAP_ORDER_POOL_ONLY);
+        require!(!self.is_exact_output(order_id), ERROR_EXACT_OUTPUT_POOL_ONLY);
+        require!(!self.is_escrow_lent(order_id), ERROR_ESCROW_LENT);
+        self.require_not_blacklisted(&order.user);
+        require!(
+            self.whitelisted_tokens().contains(&order.from_token)
+                && self.whitelisted_tokens().contains(&order.to_token),
+            ERROR_TOKEN_DELISTED
+        );
+        require!(
+            !self.is_awaiting_pair(&order.from_token, &order.to_token),
+            ERROR_ORDER_DORMANT
+        );
+        // A tripped pair halts direct fills too; no pair lookup while
+        // nothing is tripped
+        require!(
+            self.tripped_pairs().is_empty()
+                || !self.is_pair_tripped(&self.swap_pair(&order.from_token, &order.to_token)),
+            ERROR_PAIR_TRIPPED
+        );
+        self.require_pair_not_paused(&order.from_token, &order.to_token);
+        self.require_order_signal_fired(order_id);
+        self.require_order_condition_met(order_id);
+        self.require_strategy_not_paused(order_id);
+        self.require_price_revealed(order_id);
+        self.require_not_auctioned(order_id);
+        order
+    }
+
+    fn is_escrow_lent(&self, order_id: u64) -> bool {
+        !self.lent_escrows(order_id).is_empty()
+    }
+
+    /// Send the lending shares of an order back to the market; `purpose`
+    /// continues in the callback
+    fn redeem_escrow(&self, order_id: u64, purpose: RedeemPurpose) {
+        let lent = self.lent_escrows(order_id).get();
+        self.begin_execution(order_id);
+
+        // The execute leg registers the swap from the callback
+        self.tx()
+            .to(&lent.market)
+            .gas(20_000_000u64)
+            .raw_call("redeem")
+            .single_esdt(&lent.share_token, 0u64, &lent.shares)
+            .with_callback(self.callbacks().redeem_callback(order_id, purpose))
+            .with_extra_gas_for_callback(50_000_000)
+            .register_promise();
+    }
+
+    /// Drop an execution that waited for its escrow to be redeemed; the
+    /// swap never started, so its executor volume is given back
+    fn drop_redeemed_execution(&self, order_id: u64) {
+        let context = self.pending_swap_executions(order_id).take();
+        self.zap_executions(order_id).clear();
+        let order = self.limit_orders(order_id).get();
+        self.release_executor_volume(&context.executor, &order.from_token, &context.fill_amount);
+    }
+
+    /// Account for a redeemed escrow: interest goes to the order or the
+    /// protocol fees, a loss reduces the order
+    fn settle_redeemed_escrow(&self, order: &mut LimitOrder<Self::Api>) {
+        let lent = self.lent_escrows(order.order_id).take();
+        self.lending_shares(&lent.share_token)
+            .update(|total| *total -= &lent.shares);
+        self.lent_escrow(&order.from_token)
+            .update(|total| *total -= &lent.principal);
+
+        let mut redeemed = BigUint::zero();
+        for payment in self.call_value().all_esdt_transfers().iter() {
+            if payment.token_identifier == order.from_token && payment.token_nonce == 0 {
+                redeemed += &payment.amount;
+            }
+        }
+
+        let to_user = self.lending_interest_to_user().get();
+        let mut interest = BigUint::zero();
+        if redeemed >= lent.principal {
+            interest = &redeemed - &lent.principal;
+            if interest > 0u64 {
+                if to_user {
+                    self.lock_escrow(&order.from_token, &interest);
+                    order.from_amount += &interest;
+                } else {
+                    self.accrue_protocol_fee(&order.from_token, &interest);
+                    self.fee_collected_event(&order.from_token, order.order_id, &interest);
+                }
+            }
+        } else {
+            let shortfall = &lent.principal - &redeemed;
+            let loss = core::cmp::min(shortfall, order.from_amount.clone());
+            self.release_escrow(order, &loss);
+            order.from_amount -= &loss;
+        }
+        self.limit_orders(order.order_id).set(&*order);
+
+        self.escrow_redeemed_event(order.order_id, to_user, &interest);
+    }
+
+    /// Zap orders need the configured pair and one of its tokens
+    fn require_zap_pair(&self, order: &LimitOrder<Self::Api>) {
+        require!(!self.zap_lp_token().is_empty(), ERROR_ZAP_NOT_CONFIGURED);
+        let first_token = self.zap_first_token().get();
+        require!(
+            order.from_token == first_token || order.to_token == first_token,
+            ERROR_ZAP_TOKEN_NOT_IN_PAIR
+        );
+    }
+
+    /// Second zap leg: take the fees out of the swap output and add
+    /// liquidity with the rest and the unswapped half of the chunk
+    fn add_zap_liquidity(
+        &self,
+        context: &SwapExecutionContext<Self::Api>,
+        output_amount: &BigUint,
+    ) {
+        let order_id = context.order_id;
+        let order = self.limit_orders(order_id).get();
+        let zap = self.zap_executions(order_id).get();
+
+        let (execution_fee, sponsored_fee) = self.split_execution_fee(
+            order_id,
+            &context.executor,
+            &order.to_token,
+            output_amount,
+        );
+        let protocol_fee = self.collect_protocol_fee(order_id, &order.to_token, output_amount);
+        if execution_fee > 0u64 {
+            self.pay_execution_fee(&context.executor, &order.to_token, &execution_fee);
+        }
+        if sponsored_fee > 0u64 {
+            self.pay_sponsored_fee(order_id, &context.executor, &order.to_token, &sponsored_fee);
+        }
+        self.record_fees_paid(&order.user, &order.to_token, &(&execution_fee + &protocol_fee));
+        let liquidity_out = output_amount - &execution_fee - &protocol_fee;
+        let liquidity_in = &zap.fill_amount - &context.fill_amount;
+        self.escrow_in_flight(&order.from_token)
+            .update(|amount| *amount += &liquidity_in);
+
+        let (first, second) = if order.from_token == self.zap_first_token().get() {
+            (
+                EsdtTokenPayment::new(order.from_token.clone(), 0, liquidity_in),
+                EsdtTokenPayment::new(order.to_token.clone(), 0, liquidity_out),
+            )
+        } else {
+            (
+                EsdtTokenPayment::new(order.to_token.clone(), 0, liquidity_out),
+                EsdtTokenPayment::new(order.from_token.clone(), 0, liquidity_in),
+            )
+        };
+        let slippage_factor = MAX_BASIS_POINTS - order.slippage_bp;
+        let first_min = &first.amount * slippage_factor / MAX_BASIS_POINTS;
+        let second_min = &second.amount * slippage_factor / MAX_BASIS_POINTS;
+
+        let mut payments = ManagedVec::new();
+        payments.push(first);
+        payments.push(second);
+
+        // Still in flight until the liquidity callback
+        self.begin_execution(order_id);
+
+        // Same pair as the swap that produced the other half
+        let pair_address = self.swap_pair(&order.from_token, &order.to_token);
+        self.tx()
+            .to(&pair_address)
+            .gas(30_000_000u64)
+            .raw_call("addLiquidity")
+            .argument(&first_min)
+            .argument(&second_min)
+            .payment(payments)
+            .with_callback(self.callbacks().add_liquidity_callback(order_id))
+            .with_extra_gas_for_callback(10_000_000)
+            .register_promise();
+    }
+
+    /// Consume `consumed` of a zap order's escrow and close it once empty
+    fn consume_zap_fill(&self, mut order: LimitOrder<Self::Api>, consumed: &BigUint) {
+        order.from_amount -= consumed;
+        self.release_escrow(&order, consumed);
+        let completed = order.from_amount == 0u64;
+        self.record_order_fill(&order, consumed, completed);
+        self.record_client_volume(order.order_id, &order.from_token, consumed);
+        let filled_total = self.filled_input(order.order_id).update(|filled| {
+            *filled += consumed;
+            filled.clone()
+        });
+
+        if order.from_amount > 0u64 {
+            self.limit_orders(order.order_id).set(&order);
+            return;
+        }
+
+        order.from_amount = filled_total;
+        order.status = OrderStatus::Executed;
+        self.limit_orders(order.order_id).set(&order);
+        self.unindex_pending_order(&order);
+    }
+
+    /// Block cancel/expiry of an order while an async call is in flight
+    fn begin_execution(&self, order_id: u64) {
+        self.orders_in_execution().insert(order_id);
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        self.execution_started_at(order_id).set(current_time);
+    }
+
+    fn end_execution(&self, order_id: u64) {
+        self.orders_in_execution().swap_remove(&order_id);
+        self.execution_started_at(order_id).clear();
+    }
+
+    /// Add an order to the creation-time index of `getOrdersCreatedBetween`
+    fn index_order_history(&self, order_id: u64, created_at: u64) {
+        let bucket = created_at / ORDER_HISTORY_BUCKET_SECONDS;
+        self.orders_created_in_bucket(bucket).push(&order_id);
+        // Migrated orders can be older than the first one indexed
+        self.first_history_bucket().update(|first| match first {
+            Some(first) if *first <= bucket => {}
+            _ => *first = Some(bucket),
+        });
+    }
+
+    /// Add a pending order to the pending index and its pair's price book
+    fn index_pending_order(&self, order: &LimitOrder<Self::Api>) {
+        self.pending_order_ids().insert(order.order_id);
+        self.add_to_price_book(order);
+    }
+
+    /// Remove an order from the pending index and its pair's price book
+    /// Returns false if the order was not indexed
+    fn unindex_pending_order(&self, order: &LimitOrder<Self::Api>) -> bool {
+        self.remove_from_price_book(order);
+        self.pending_order_ids().swap_remove(&order.order_id)
+    }
+
+    /// Put an order in the price bucket of its target price, adding the
+    /// bucket to the pair's sorted bucket list if it was empty
+    fn add_to_price_book(&self, order: &LimitOrder<Self::Api>) {
+        let bucket = self.price_bucket(&self.normalized_price(order));
+        self.order_price_bucket(order.order_id).set(bucket);
+
+        let mut bucket_orders =
+            self.pair_bucket_orders(&order.from_token, &order.to_token, bucket);
+        if bucket_orders.is_empty() {
+            self.pair_price_buckets(&order.from_token, &order.to_token)
+                .update(|buckets| {
+                    let mut sorted = ManagedVec::new();
+                    let mut inserted = false;
+                    for existing in buckets.iter() {
+                        if !inserted && bucket < existing {
+                            sorted.push(bucket);
+                            inserted = true;
+                        }
+                        sorted.push(existing);
+                    }
+                    if !inserted {
+                        sorted.push(bucket);
+                    }
+                    *buckets = sorted;
+                });
+        }
+        bucket_orders.insert(order.order_id);
+    }
+
+    /// Take an order out of its price bucket, dropping the bucket once empty
+    fn remove_from_price_book(&self, order: &LimitOrder<Self::Api>) {
+        let bucket = self.order_price_bucket(order.order_id).take();
+        let mut bucket_orders =
+            self.pair_bucket_orders(&order.from_token, &order.to_token, bucket);
+        if !bucket_orders.swap_remove(&order.order_id) || !bucket_orders.is_empty() {
+            return;
+        }
+
+        self.pair_price_buckets(&order.from_token, &order.to_token)
+            .update(|buckets| {
+                if let Some(index) = buckets.iter().position(|existing| existing == bucket) {
+                    buckets.remove(index);
+                }
+            });
+    }
+
+    /// Price book bucket of a normalized price: its binary magnitude followed
+    /// by the next PRICE_BUCKET_BITS bits, so buckets sort like prices
+    fn price_bucket(&self, price: &BigUint) -> u64 {
+        if *price == 0u64 {
+            return 0;
+        }
+        let magnitude = price.log2() as usize;
+        let mantissa = ((price << PRICE_BUCKET_BITS) >> magnitude)
+            .to_u64()
+            .unwrap_or_default();
+        ((magnitude as u64 + 1) << PRICE_BUCKET_BITS) | (mantissa & ((1 << PRICE_BUCKET_BITS) - 1))
+    }
+
+    /// Return the escrowed input to the order owner and finalize the order
+    fn refund_and_close(&self, order: &mut LimitOrder<Self::Api>, status: OrderStatus) {
+        if order.from_amount > 0u64 {
+            self.pay_order_owner(
+                order.order_id,
+                &order.user,
+                &order.from_token,
+                &order.from_amount,
+            );
+            self.release_escrow(order, &order.from_amount);
+        }
+
+        match status {
+            OrderStatus::Cancelled => self.record_order_cancelled(order),
+            OrderStatus::Expired => self.record_order_expired(order),
+            _ => {}
+        }
+        order.status = status;
+        self.limit_orders(order.order_id).set(&*order);
+        self.unindex_pending_order(order);
+        self.cancel_dormant_chained_order(order.order_id);
+        self.cancel_dormant_stop_loss(order.order_id);
+    }
+
+    /// Move a fill's output of `order_id` into the order chained to it,
+    /// activating it on the first fill
+    /// Returns false when the output is paid out as usual instead: no
+    /// chained order, the parent is tokenized, or the chained order is
+    /// closed, in execution or lent
+    fn fund_chained_order(&self, order_id: u64, amount: &BigUint) -> bool {
+        if self.chained_order(order_id).is_empty()
+            || self.is_tokenized(order_id)
+            || *amount == 0u64
+        {
+            return false;
+        }
+
+        let child_id = self.chained_order(order_id).get();
+        let mut child = self.limit_orders(child_id).get();
+        let activated = match child.status {
+            OrderStatus::Dormant => true,
+            OrderStatus::Pending
+                if !self.orders_in_execution().contains(&child_id)
+                    && !self.is_escrow_lent(child_id)
+                    && !self.is_tokenized(child_id) =>
+            {
+                false
+            },
+            _ => return false,
+        };
+
+        self.lock_escrow(&child.from_token, amount);
+        if activated {
+            self.activate_dormant_order(&mut child, amount);
+        } else {
+            child.from_amount += amount;
+            self.limit_orders(child_id).set(&child);
+        }
+        self.chained_order_funded_event(child_id, order_id, activated, amount);
+        true
+    }
+
+    /// Store a Dormant order of `user` holding no escrow; it is not indexed
+    /// as pending (nor counted in stats) until activated
+    #[allow(clippy::too_many_arguments)]
+    fn create_dormant_order(
+        &self,
+        user: &ManagedAddress,
+        from_token: TokenIdentifier,
+        to_token: TokenIdentifier,
+        target_price_num: BigUint,
+        target_price_denom: BigUint,
+        slippage_bp: u64,
+        expires_in_seconds: u64,
+    ) -> u64 {
+        // Checked as if the order was funded now
+        let reason = self.order_creation_reason(
+            &from_token,
+            &BigUint::from(1u64),
+            &to_token,
+            &target_price_num,
+            &target_price_denom,
+            slippage_bp,
+            expires_in_seconds,
         );
+        require!(reason.is_ok(), (reason.error_message()));
 
-        // Return tokens to user
-        self.send().direct_esdt(&caller, &order.from_token, 0, &order.from_amount);
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        let order_id = self.next_order_id().get();
+        let order = LimitOrder {
+            order_id,
+            user: user.clone(),
+            from_token,
+            from_amount: BigUint::zero(),
+            to_token,
+            target_price_numerator: target_price_num,
+            target_price_denominator: target_price_denom,
+            slippage_bp,
+            expires_at: current_time + expires_in_seconds,
+            status: OrderStatus::Dormant,
+            created_at: current_time,
+        };
 
-        // Mark as cancelled
-        order.status = OrderStatus::Cancelled;
         self.limit_orders(order_id).set(&order);
+        self.user_orders(user).insert(order_id);
+        self.next_order_id().set(order_id + 1);
+        self.index_order_history(order_id, current_time);
 
-        // Emit event
+        order_id
+    }
+
+    /// Turn a Dormant order Pending with `amount` of escrow (already
+    /// accounted by the caller); its lifetime runs from now
+    fn activate_dormant_order(&self, order: &mut LimitOrder<Self::Api>, amount: &BigUint) {
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        order.expires_at = current_time + (order.expires_at - order.created_at);
+        order.from_amount = amount.clone();
+        order.status = OrderStatus::Pending;
+        self.limit_orders(order.order_id).set(&*order);
+
+        self.index_pending_order(order);
+        self.track_dormant_order(order.order_id, &order.from_token, &order.to_token);
+        self.store_normalized_price(order);
+        self.record_order_created(order);
+        self.limit_order_created_event(
+            crate::CONTRACT_VERSION,
+            order.order_id,
+            &order.user,
+            &order.from_token,
+            &order.from_amount,
+            &order.to_token,
+            &order.target_price_numerator,
+            &order.target_price_denominator,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_round(),
+            order.expires_at,
+        );
+    }
+
+    /// Cancel the order chained to `order_id` if it was never funded, along
+    /// with the dormant orders chained to it in turn
+    fn cancel_dormant_chained_order(&self, order_id: u64) {
+        if self.chained_order(order_id).is_empty() {
+            return;
+        }
+
+        let mut child = self.limit_orders(self.chained_order(order_id).get()).get();
+        if matches!(child.status, OrderStatus::Dormant) {
+            self.cancel_dormant_order(&mut child);
+        }
+    }
+
+    /// Cancel the dormant stop-loss guarding `order_id` once that order closes
+    fn cancel_dormant_stop_loss(&self, order_id: u64) {
+        if self.order_stop_loss(order_id).is_empty() {
+            return;
+        }
+
+        let mut stop_loss = self.limit_orders(self.order_stop_loss(order_id).get()).get();
+        if matches!(stop_loss.status, OrderStatus::Dormant) {
+            self.cancel_dormant_order(&mut stop_loss);
+        }
+    }
+
+    /// Cancel a dormant (unfunded) order and the orders chained to it
+    fn cancel_dormant_order(&self, order: &mut LimitOrder<Self::Api>) {
+        order.status = OrderStatus::Cancelled;
+        self.limit_orders(order.order_id).set(&*order);
         self.limit_order_cancelled_event(
-            order_id,
-            &caller,
+            crate::CONTRACT_VERSION,
+            order.order_id,
+            &order.user,
             &order.from_token,
+            self.blockchain().get_block_epoch(),
+            self.blockchain().get_block_round(),
             &order.from_amount,
         );
+        self.cancel_dormant_chained_order(order.order_id);
+        self.cancel_dormant_stop_loss(order.order_id);
     }
 
-    // ========== VIEW FUNCTIONS ==========
+    /// Hand the chained order of a replaced order over to its replacement,
+    /// or cancel it if still dormant when the replacement pays out another token
+    fn move_chained_order(&self, old_order_id: u64, new_order: &LimitOrder<Self::Api>) {
+        if self.chained_order(old_order_id).is_empty() {
+            return;
+        }
 
-    /// Get all pending orders (for backend executor)
-    #[view(getPendingOrders)]
-    fn get_pending_orders(&self) -> MultiValueEncoded<LimitOrder<Self::Api>> {
-        let mut result = MultiValueEncoded::new();
-        let next_id = self.next_order_id().get();
+        let child_id = self.chained_order(old_order_id).get();
+        let child = self.limit_orders(child_id).get();
+        if child.from_token != new_order.to_token {
+            self.cancel_dormant_chained_order(old_order_id);
+            return;
+        }
+        self.chained_order(old_order_id).clear();
+        self.chained_order(new_order.order_id).set(child_id);
+        self.chain_parent(child_id).set(new_order.order_id);
+    }
 
-        for order_id in 1..next_id {
-            if !self.limit_orders(order_id).is_empty() {
-                let order = self.limit_orders(order_id).get();
-                if matches!(order.status, OrderStatus::Pending) {
-                    result.push(order);
-                }
-            }
+    /// A failed swap bounces the input back with the callback.
+    /// Re-credit what actually came back to the order and keep it pending
+    /// for retry, or refund the user once the attempt budget is exhausted.
+    fn handle_failed_swap(
+        &self,
+        context: &SwapExecutionContext<Self::Api>,
+        returned: &BigUint,
+        err_msg: &ManagedBuffer,
+    ) {
+        let order_id = context.order_id;
+        let executor = &context.executor;
+        let mut order = self.limit_orders(order_id).get();
+
+        // Only the swapped chunk left the contract
+        if *returned < context.fill_amount {
+            let shortfall = &context.fill_amount - returned;
+            self.release_escrow(&order, &shortfall);
+            order.from_amount -= &shortfall;
+            self.limit_orders(order_id).set(&order);
         }
 
-        result
+        self.release_executor_volume(executor, &order.from_token, &context.fill_amount);
+
+        let attempts = self.execution_attempts(order_id).update(|attempts| {
+            *attempts += 1;
+            *attempts
+        });
+        self.order_execution_failed_event(
+            crate::CONTRACT_VERSION,
+            order_id,
+            executor,
+            attempts,
+            err_msg,
+        );
+
+        if order.from_amount == 0u64 || attempts >= self.max_execution_attempts().get() {
+            self.refund_and_close(&mut order, OrderStatus::Failed);
+            self.limit_order_failed_event(
+                crate::CONTRACT_VERSION,
+                order_id,
+                &order.user,
+                &order.from_token,
+                &order.from_amount,
+                attempts,
+            );
+        } else {
+            self.schedule_retry(order_id, order.expires_at, attempts);
+        }
     }
 
-    /// Get user's orders
-    #[view(getUserOrders)]
-    fn get_user_orders(&self, user: ManagedAddress) -> MultiValueEncoded<LimitOrder<Self::Api>> {
-        let mut result = MultiValueEncoded::new();
+    /// Hold the next attempt of a failed order back by the backoff of its
+    /// attempt count, but never past halfway to its deadline so the order
+    /// still gets a retry before it expires
+    fn schedule_retry(&self, order_id: u64, expires_at: u64, attempts: u64) {
+        let base = self.retry_backoff_base().get();
+        if base == 0 {
+            return;
+        }
 
-        for order_id in self.user_orders(&user).iter() {
-            if !self.limit_orders(order_id).is_empty() {
-                result.push(self.limit_orders(order_id).get());
-            }
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        let doublings = core::cmp::min(attempts - 1, MAX_RETRY_BACKOFF_DOUBLINGS);
+        let backoff = base.saturating_mul(1u64 << doublings);
+        let halfway = current_time + expires_at.saturating_sub(current_time) / 2;
+        let retry_after = core::cmp::min(current_time.saturating_add(backoff), halfway);
+
+        self.retry_after(order_id).set(retry_after);
+        self.order_retry_scheduled_event(order_id, attempts, retry_after);
+    }
+
+    /// Pay the sweeper from the bounty pool, capped by what the pool holds
+    fn pay_expiry_bounty(
+        &self,
+        caller: &ManagedAddress,
+        token: &TokenIdentifier,
+        refunded_amount: &BigUint,
+        bounty_bps: u64,
+    ) {
+        if bounty_bps == 0 {
+            return;
         }
 
-        result
+        let pool_mapper = self.expiry_bounty_pool(token);
+        let pool = pool_mapper.get();
+        let mut bounty = refunded_amount * bounty_bps / MAX_BASIS_POINTS;
+        if bounty > pool {
+            bounty = pool.clone();
+        }
+        if bounty == 0u64 {
+            return;
+        }
+
+        pool_mapper.set(&pool - &bounty);
+        self.send().direct_esdt(caller, token, 0, &bounty);
     }
 
-    /// Get order by ID
-    #[view(getOrder)]
-    fn get_order(&self, order_id: u64) -> LimitOrder<Self::Api> {
-        self.limit_orders(order_id).get()
+    /// Record the order's target price as a single PRICE_SCALE integer
+    fn store_normalized_price(&self, order: &LimitOrder<Self::Api>) {
+        let price = self.scaled_price(
+            &order.target_price_numerator,
+            &order.target_price_denominator,
+        );
+        self.order_normalized_price(order.order_id).set(&price);
+
+        // A repriced pending order moves to its new bucket; a committed one
+        // is not booked until its price is revealed
+        if !self.pending_order_ids().contains(&order.order_id)
+            || !self.order_price_commitment(order.order_id).is_empty()
+        {
+            return;
+        }
+        if self.order_price_bucket(order.order_id).is_empty() {
+            self.add_to_price_book(order);
+        } else if self.price_bucket(&price) != self.order_price_bucket(order.order_id).get() {
+            self.remove_from_price_book(order);
+            self.add_to_price_book(order);
+        }
     }
 
-    // ========== ADMIN FUNCTIONS ==========
+    /// Target price of an order scaled by PRICE_SCALE (computed for orders
+    /// created before prices were stored)
+    fn normalized_price(&self, order: &LimitOrder<Self::Api>) -> BigUint {
+        let mapper = self.order_normalized_price(order.order_id);
+        if mapper.is_empty() {
+            return self.scaled_price(
+                &order.target_price_numerator,
+                &order.target_price_denominator,
+            );
+        }
+        mapper.get()
+    }
 
-    /// Set executor address (owner only)
-    #[only_owner]
-    #[endpoint(setLimitOrderExecutor)]
-    fn set_limit_order_executor(&self, executor: ManagedAddress) {
-        self.limit_order_executor().set(&executor);
+    /// Price of `amount_in` in units of `amount_out`, scaled by PRICE_SCALE
+    fn scaled_price(&self, amount_out: &BigUint, amount_in: &BigUint) -> BigUint {
+        if *amount_in == 0u64 {
+            return BigUint::zero();
+        }
+        amount_out * PRICE_SCALE / amount_in
     }
 
-    /// Set execution fee in basis points (owner only)
-    /// Example: 10 = 0.1%, 50 = 0.5%, 100 = 1%
-    #[only_owner]
-    #[endpoint(setExecutionFeeBps)]
-    fn set_execution_fee_bps(&self, fee_bps: u64) {
-        require!(fee_bps <= 500, "Fee too high (max 5%)");
-        self.execution_fee_bps().set(fee_bps);
+    fn lock_escrow(&self, token: &TokenIdentifier, amount: &BigUint) {
+        self.escrow_balance(token).update(|escrow| *escrow += amount);
     }
 
-    // ========== HELPER FUNCTIONS ==========
+    /// Only indexed orders are counted in the escrow total; orders created
+    /// before escrow tracking are added by the storage migration
+    fn release_escrow(&self, order: &LimitOrder<Self::Api>, amount: &BigUint) {
+        if !self.pending_order_ids().contains(&order.order_id) {
+            return;
+        }
+        self.escrow_balance(&order.from_token)
+            .update(|escrow| *escrow -= amount);
+    }
+
+    /// True when the current price is at or below the order's limit price
+    fn is_limit_price_met(
+        &self,
+        order: &LimitOrder<Self::Api>,
+        current_price_num: &BigUint,
+        current_price_denom: &BigUint,
+    ) -> bool {
+        let target_price = &order.target_price_numerator * current_price_denom;
+        let current_price = current_price_num * &order.target_price_denominator;
+        current_price <= target_price
+    }
+
+    /// Executor share of a fill's output (rounded down)
+    fn execution_fee_amount(&self, output_amount: &BigUint) -> BigUint {
+        output_amount * self.execution_fee_bps().get() / MAX_BASIS_POINTS
+    }
+
+    /// Execution fee of a fill, split into what the order owner pays out of
+    /// the output and what the gas station sponsors
+    /// The fee is the one `executor` gets for the order (a winning auction
+    /// bid or the default fee), raised to the fee floor of `token`; the
+    /// owner's part never exceeds what the protocol fee leaves
+    fn split_execution_fee(
+        &self,
+        order_id: u64,
+        executor: &ManagedAddress,
+        token: &TokenIdentifier,
+        output_amount: &BigUint,
+    ) -> (BigUint, BigUint) {
+        let fee = output_amount * self.execution_fee_bps_for(order_id, executor) / MAX_BASIS_POINTS;
+        let floor = self.execution_fee_floor(token).get();
+        if fee >= floor {
+            return (fee, BigUint::zero());
+        }
+
+        let sponsored = self.sponsored_fee_amount(token, output_amount, &(&floor - &fee));
+        let owner_fee = floor - &sponsored;
+        let available = output_amount - &self.protocol_fee_amount(output_amount);
+        if owner_fee < available {
+            (owner_fee, sponsored)
+        } else {
+            (available, sponsored)
+        }
+    }
+
+    /// Output of a fill above what its target price promises (zero for
+    /// exact-output orders, whose output is fixed)
+    fn price_improvement(
+        &self,
+        order_id: u64,
+        output_amount: &BigUint,
+        target_output: &BigUint,
+    ) -> BigUint {
+        if output_amount <= target_output || self.is_exact_output(order_id) {
+            return BigUint::zero();
+        }
+        output_amount - target_output
+    }
+
+    /// Executor and treasury shares of a price improvement, taken out of
+    /// what the order owner receives (`user_amount` caps the improvement)
+    fn price_improvement_shares(
+        &self,
+        improvement: &BigUint,
+        user_amount: &BigUint,
+    ) -> (BigUint, BigUint) {
+        let shared = if improvement < user_amount {
+            improvement
+        } else {
+            user_amount
+        };
+        let executor_share =
+            shared * self.improvement_executor_bps().get() / MAX_BASIS_POINTS;
+        let treasury_share =
+            shared * self.improvement_treasury_bps().get() / MAX_BASIS_POINTS;
+        (executor_share, treasury_share)
+    }
+
+    /// What the order owner receives of a fill's output
+    fn output_after_fees(&self, output_amount: &BigUint) -> BigUint {
+        output_amount
+            - &self.execution_fee_amount(output_amount)
+            - &self.protocol_fee_amount(output_amount)
+    }
+
+    /// Minimum output of swapping `swap_amount` of an order and the floor
+    /// that set it: the target price minus slippage, or in `LiveQuote` mode
+    /// the live quote minus slippage when that is stricter
+    /// Exact-output orders ask for their grossed-up output instead
+    fn execution_min_output(
+        &self,
+        order: &LimitOrder<Self::Api>,
+        swap_amount: &BigUint,
+    ) -> (BigUint, MinOutputBound) {
+        if self.is_exact_output(order.order_id) {
+            let amount_out = self.exact_output_amount(order.order_id).get();
+            return (self.exact_output_gross(&amount_out), MinOutputBound::ExactOutput);
+        }
+        let price_floor = self.calculate_min_output(
+            swap_amount,
+            &order.target_price_numerator,
+            &order.target_price_denominator,
+            order.slippage_bp,
+        );
+        if self.min_output_mode().get() != MinOutputMode::LiveQuote {
+            return (price_floor, MinOutputBound::TargetPrice);
+        }
+
+        let quote = self.quote_order_output(order, swap_amount);
+        let quote_floor = quote * (MAX_BASIS_POINTS - order.slippage_bp) / MAX_BASIS_POINTS;
+        if quote_floor > price_floor {
+            (quote_floor, MinOutputBound::LiveQuote)
+        } else {
+            (price_floor, MinOutputBound::TargetPrice)
+        }
+    }
+
+    /// Live quote of swapping `swap_amount` of an order, chained over both
+    /// legs for routed orders
+    fn quote_order_output(&self, order: &LimitOrder<Self::Api>, swap_amount: &BigUint) -> BigUint {
+        if self.order_route_via(order.order_id).is_empty() {
+            let pair_address = self.swap_pair(&order.from_token, &order.to_token);
+            return self.quote_amount_out(&pair_address, &order.from_token, swap_amount);
+        }
+
+        let via_token = self.order_route_via(order.order_id).get();
+        let first_pair = self.swap_pair(&order.from_token, &via_token);
+        let via_amount = self.quote_amount_out(&first_pair, &order.from_token, swap_amount);
+        let second_pair = self.swap_pair(&via_token, &order.to_token);
+        self.quote_amount_out(&second_pair, &via_token, &via_amount)
+    }
+
+    /// Pair an order's input is swapped on: its first leg's for routed orders
+    fn entry_pair(&self, order: &LimitOrder<Self::Api>) -> ManagedAddress {
+        if self.order_route_via(order.order_id).is_empty() {
+            self.swap_pair(&order.from_token, &order.to_token)
+        } else {
+            self.swap_pair(&order.from_token, &self.order_route_via(order.order_id).get())
+        }
+    }
+
+    /// Why a routed order cannot execute on account of its legs: the
+    /// intermediate token was delisted or has no pair yet, or a leg's pair
+    /// is paused or tripped
+    fn route_reason(&self, order: &LimitOrder<Self::Api>) -> ReasonCode {
+        if self.order_route_via(order.order_id).is_empty() {
+            return ReasonCode::Ok;
+        }
+
+        let via_token = self.order_route_via(order.order_id).get();
+        if !self.whitelisted_tokens().contains(&via_token) {
+            return ReasonCode::TokenDelisted;
+        }
+        if self.awaiting_pair_tokens().contains(&via_token) {
+            return ReasonCode::OrderDormant;
+        }
+        for (token_in, token_out) in [(&order.from_token, &via_token), (&via_token, &order.to_token)] {
+            if !self.tripped_pairs().is_empty()
+                && self.is_pair_tripped(&self.swap_pair(token_in, token_out))
+            {
+                return ReasonCode::PairTripped;
+            }
+            if self.pair_paused(token_in, token_out).get() {
+                return ReasonCode::PairPaused;
+            }
+        }
+        ReasonCode::Ok
+    }
+
+    /// Swap output that leaves the owner `amount_out` once the execution
+    /// and protocol fees are taken (rounded up)
+    fn exact_output_gross(&self, amount_out: &BigUint) -> BigUint {
+        let fee_bps = self.execution_fee_bps().get() + self.protocol_fee_bps().get();
+        let net_bps = MAX_BASIS_POINTS - fee_bps;
+        (amount_out * MAX_BASIS_POINTS + (net_bps - 1)) / net_bps
+    }
+
+    fn is_exact_output(&self, order_id: u64) -> bool {
+        !self.exact_output_amount(order_id).is_empty()
+    }
 
     fn calculate_min_output(
         &self,
@@ -383,14 +4235,18 @@ pub trait LimitOrdersModule:
         target_price_denom: &BigUint,
         slippage_bp: u64,
     ) -> BigUint {
+        // Orders are validated at creation, but never let a bad value underflow here
+        require!(
+            slippage_bp <= MAX_BASIS_POINTS,
+            ERROR_SLIPPAGE_ABOVE_100_PERCENT
+        );
+
         // Expected output = from_amount * target_price_num / target_price_denom
         let expected_output = from_amount * target_price_num / target_price_denom;
 
         // Min output = expected - slippage
-        let slippage_factor = 10000u64 - slippage_bp;
-        let min_output = &expected_output * slippage_factor / 10000u64;
-
-        min_output
+        let slippage_factor = MAX_BASIS_POINTS - slippage_bp;
+        &expected_output * slippage_factor / MAX_BASIS_POINTS
     }
 
     // ========== STORAGE ==========
@@ -404,6 +4260,178 @@ pub trait LimitOrdersModule:
     #[storage_mapper("userOrders")]
     fn user_orders(&self, user: &ManagedAddress) -> UnorderedSetMapper<u64>;
 
+    /// Ids of the orders created in each `ORDER_HISTORY_BUCKET_SECONDS` bucket
+    #[storage_mapper("ordersCreatedInBucket")]
+    fn orders_created_in_bucket(&self, bucket: u64) -> VecMapper<u64>;
+
+    /// Earliest bucket holding an order (None until one is indexed)
+    #[storage_mapper("firstHistoryBucket")]
+    fn first_history_bucket(&self) -> SingleValueMapper<Option<u64>>;
+
+    /// Ids of all orders still in Pending status
+    #[storage_mapper("pendingOrderIds")]
+    fn pending_order_ids(&self) -> UnorderedSetMapper<u64>;
+
+    /// Non-empty price buckets of a (from, to) pair, lowest first,
+    /// backing `getOrderBook`
+    #[storage_mapper("pairPriceBuckets")]
+    fn pair_price_buckets(
+        &self,
+        from_token: &TokenIdentifier,
+        to_token: &TokenIdentifier,
+    ) -> SingleValueMapper<ManagedVec<u64>>;
+
+    /// Pending order ids of a (from, to) pair in one price bucket
+    #[storage_mapper("pairBucketOrders")]
+    fn pair_bucket_orders(
+        &self,
+        from_token: &TokenIdentifier,
+        to_token: &TokenIdentifier,
+        bucket: u64,
+    ) -> UnorderedSetMapper<u64>;
+
+    /// Price bucket a pending order is filed under
+    #[storage_mapper("orderPriceBucket")]
+    fn order_price_bucket(&self, order_id: u64) -> SingleValueMapper<u64>;
+
+    /// Escrow of an order currently supplied to a lending market
+    #[view(getOrderLentEscrow)]
+    #[storage_mapper("lentEscrows")]
+    fn lent_escrows(&self, order_id: u64) -> SingleValueMapper<LentEscrow<Self::Api>>;
+
+    /// Orders that add liquidity after their swap (see `createZapOrder`)
+    #[view(isZapOrder)]
+    #[storage_mapper("zapOrder")]
+    fn zap_order(&self, order_id: u64) -> SingleValueMapper<bool>;
+
+    /// Users whose orders are never matched against their own orders or quotes
+    #[view(isSelfTradePrevented)]
+    #[storage_mapper("selfTradePrevention")]
+    fn self_trade_prevention(&self, user: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    /// Amount of `to_token` an exact-output order buys (see `createExactOutputOrder`)
+    #[view(getExactOutputAmount)]
+    #[storage_mapper("exactOutputAmount")]
+    fn exact_output_amount(&self, order_id: u64) -> SingleValueMapper<BigUint>;
+
+    /// Zap executions between the swap and the addLiquidity callback
+    #[storage_mapper("zapExecutions")]
+    fn zap_executions(&self, order_id: u64) -> SingleValueMapper<ZapExecution<Self::Api>>;
+
+    /// Orders with an async swap in flight (set at execute, cleared in callback)
+    #[storage_mapper("ordersInExecution")]
+    fn orders_in_execution(&self) -> UnorderedSetMapper<u64>;
+
+    /// Client metadata hash an order was created with
+    #[storage_mapper("orderClientTag")]
+    fn order_client_tag(
+        &self,
+        order_id: u64,
+    ) -> SingleValueMapper<ManagedByteArray<Self::Api, 32>>;
+
+    /// Agent intent an order was created with
+    #[storage_mapper("orderIntent")]
+    fn order_intent(&self, order_id: u64) -> SingleValueMapper<OrderIntent<Self::Api>>;
+
+    /// Order a user created under a client reference
+    #[storage_mapper("orderByReference")]
+    fn order_by_reference(&self, user: &ManagedAddress, reference: u64) -> SingleValueMapper<u64>;
+
+    /// Order funded by the output of an order
+    #[storage_mapper("chainedOrder")]
+    fn chained_order(&self, order_id: u64) -> SingleValueMapper<u64>;
+
+    /// Order whose output funds a chained order
+    #[storage_mapper("chainParent")]
+    fn chain_parent(&self, order_id: u64) -> SingleValueMapper<u64>;
+
+    /// Dormant stop-loss taking over an order's escrow when triggered
+    #[storage_mapper("orderStopLoss")]
+    fn order_stop_loss(&self, order_id: u64) -> SingleValueMapper<u64>;
+
+    /// Order whose escrow a stop-loss takes over
+    #[view(getStopLossTarget)]
+    #[storage_mapper("stopLossTarget")]
+    fn stop_loss_target(&self, order_id: u64) -> SingleValueMapper<u64>;
+
+    /// Intermediate token a routed order swaps through
+    #[storage_mapper("orderRouteVia")]
+    fn order_route_via(&self, order_id: u64) -> SingleValueMapper<TokenIdentifier>;
+
+    /// When the async call of an order in execution was sent
+    #[storage_mapper("executionStartedAt")]
+    fn execution_started_at(&self, order_id: u64) -> SingleValueMapper<u64>;
+
+    /// Position in pendingOrderIds where the next expiry sweep starts
+    #[storage_mapper("expirySweepCursor")]
+    fn expiry_sweep_cursor(&self) -> SingleValueMapper<usize>;
+
+    /// Cumulative input swapped by (partial) fills
+    #[view(getFilledAmount)]
+    #[storage_mapper("filledInput")]
+    fn filled_input(&self, order_id: u64) -> SingleValueMapper<BigUint>;
+
+    /// Cumulative output delivered to the user by (partial) fills
+    #[view(getFilledOutput)]
+    #[storage_mapper("filledOutput")]
+    fn filled_output(&self, order_id: u64) -> SingleValueMapper<BigUint>;
+
+    /// Why a cancelled order was cancelled (`User` unless cancelled by an admin action)
+    #[view(getCancelReason)]
+    #[storage_mapper("cancelReason")]
+    fn cancel_reason(&self, order_id: u64) -> SingleValueMapper<CancelReason>;
+
+    /// Failed swap attempts per order
+    #[view(getExecutionAttempts)]
+    #[storage_mapper("executionAttempts")]
+    fn execution_attempts(&self, order_id: u64) -> SingleValueMapper<u64>;
+
+    /// Target price in base units of the to token per base unit of the
+    /// from token, scaled by 1e18
+    #[view(getOrderNormalizedPrice)]
+    #[storage_mapper("orderNormalizedPrice")]
+    fn order_normalized_price(&self, order_id: u64) -> SingleValueMapper<BigUint>;
+
+    #[view(getMaxExecutionAttempts)]
+    #[storage_mapper("maxExecutionAttempts")]
+    fn max_execution_attempts(&self) -> SingleValueMapper<u64>;
+
+    /// Seconds a failed order waits before its next attempt, doubled per
+    /// further failure (0 = retry immediately)
+    #[view(getRetryBackoffBase)]
+    #[storage_mapper("retryBackoffBase")]
+    fn retry_backoff_base(&self) -> SingleValueMapper<u64>;
+
+    /// Timestamp before which a failed order is not executed again
+    #[view(getRetryAfter)]
+    #[storage_mapper("retryAfter")]
+    fn retry_after(&self, order_id: u64) -> SingleValueMapper<u64>;
+
+    /// Bounty paid to expiry sweepers (bps of refunded amount)
+    #[view(getExpiryBountyBps)]
+    #[storage_mapper("expiryBountyBps")]
+    fn expiry_bounty_bps(&self) -> SingleValueMapper<u64>;
+
     #[storage_mapper("limitOrderExecutor")]
     fn limit_order_executor(&self) -> SingleValueMapper<ManagedAddress>;
 }
+
+/// Decimals from a `NumDecimals-<n>` token property, `None` for other entries
+fn parse_num_decimals<M: ManagedTypeApi>(property: &ManagedBuffer<M>) -> Option<u8> {
+    let mut bytes = [0u8; 16];
+    let len = property.len();
+    if len <= NUM_DECIMALS_PREFIX.len() || len > bytes.len() {
+        return None;
+    }
+    let bytes = property.load_to_byte_array(&mut bytes);
+    let digits = bytes[..len].strip_prefix(NUM_DECIMALS_PREFIX)?;
+
+    let mut decimals = 0u8;
+    for digit in digits {
+        if !digit.is_ascii_digit() {
+            return None;
+        }
+        decimals = decimals.checked_mul(10)?.checked_add(digit - b'0')?;
+    }
+    Some(decimals)
+}
@@ -0,0 +1,70 @@
+//! Listings Module for StellarNova
+//!
+//! A token can be whitelisted before its xExchange pair exists. Orders on
+//! such a token are accepted but dormant: they cannot be executed, matched
+//! or filled until the owner sets the token's pair (`setTokenPair`, which is
+//! timelocked). Setting the pair activates them with `order_activated`.
+
+multiversx_sc::imports!();
+
+use crate::errors::*;
+
+/// Dormant orders activated by `setTokenPair` itself; `activateDormantOrders`
+/// handles the rest
+pub const LISTING_ACTIVATION_BATCH: u64 = 100;
+
+#[multiversx_sc::module]
+pub trait ListingsModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+{
+    /// Whitelist a token whose pair is not configured yet (owner only)
+    /// Orders on it stay dormant until `setTokenPair` applies
+    #[only_owner]
+    #[endpoint(whitelistUpcomingToken)]
+    fn whitelist_upcoming_token(&self, token: TokenIdentifier) {
+        require!(
+            !self.whitelisted_tokens().contains(&token),
+            ERROR_ALREADY_WHITELISTED
+        );
+
+        self.whitelisted_tokens().insert(token.clone());
+        self.awaiting_pair_tokens().insert(token.clone());
+        self.token_whitelisted_event(&token);
+        self.token_awaiting_pair_event(&token);
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    /// Orders on a token without a pair are dormant
+    fn is_awaiting_pair(&self, from_token: &TokenIdentifier, to_token: &TokenIdentifier) -> bool {
+        self.awaiting_pair_tokens().contains(from_token)
+            || self.awaiting_pair_tokens().contains(to_token)
+    }
+
+    /// Record a new order on a token that has no pair yet
+    fn track_dormant_order(
+        &self,
+        order_id: u64,
+        from_token: &TokenIdentifier,
+        to_token: &TokenIdentifier,
+    ) {
+        for token in [from_token, to_token] {
+            if self.awaiting_pair_tokens().contains(token) {
+                self.dormant_orders(token).insert(order_id);
+            }
+        }
+    }
+
+    // ========== STORAGE ==========
+
+    /// Whitelisted tokens still waiting for their pair
+    #[view(getAwaitingPairTokens)]
+    #[storage_mapper("awaitingPairTokens")]
+    fn awaiting_pair_tokens(&self) -> UnorderedSetMapper<TokenIdentifier>;
+
+    /// Orders created while `token` had no pair, not activated yet
+    #[view(getDormantOrders)]
+    #[storage_mapper("dormantOrders")]
+    fn dormant_orders(&self, token: &TokenIdentifier) -> UnorderedSetMapper<u64>;
+}
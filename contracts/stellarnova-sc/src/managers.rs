@@ -0,0 +1,336 @@
+//! Order Managers Module for StellarNova
+//!
+//! A user can approve other addresses (e.g. an agent wallet) to cancel, modify
+//! or replace their orders. Managers only act on the orders; refunds and swap
+//! output always go to the order creator.
+//!
+//! Session keys are a scoped alternative for hot automation keys: each key is
+//! limited to a set of actions, a spend cap for order creation and an expiry.
+//!
+//! Managers can also create orders from the user's credit, limited per token
+//! by a daily (per epoch) notional cap and optionally by an order rate. Without
+//! a cap for a token, a manager cannot spend that token at all.
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::errors::*;
+
+/// Session key may create orders for the user (within its spend cap)
+pub const SESSION_PERMISSION_CREATE: u32 = 1;
+
+/// Session key may cancel the user's orders
+pub const SESSION_PERMISSION_CANCEL: u32 = 1 << 1;
+
+/// Session key may modify and replace the user's orders
+pub const SESSION_PERMISSION_MODIFY: u32 = 1 << 2;
+
+const SESSION_PERMISSION_ALL: u32 =
+    SESSION_PERMISSION_CREATE | SESSION_PERMISSION_CANCEL | SESSION_PERMISSION_MODIFY;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct SessionKey<M: ManagedTypeApi> {
+    pub permissions: u32,
+    pub spend_token: TokenIdentifier<M>,
+    pub spend_cap: BigUint<M>,
+    pub spent: BigUint<M>,
+    pub expires_at: u64,
+}
+
+/// Amount a manager spent in one epoch
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Default)]
+pub struct EpochUsage<M: ManagedTypeApi> {
+    pub epoch: u64,
+    pub amount: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait ManagersModule: crate::events::EventsModule {
+    /// Authorize `manager` to cancel, modify and replace the caller's orders
+    #[endpoint(approveOrderManager)]
+    fn approve_order_manager(&self, manager: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        require!(manager != caller, ERROR_SELF_MANAGER);
+        require!(
+            self.order_managers(&caller).insert(manager.clone()),
+            ERROR_MANAGER_ALREADY_APPROVED
+        );
+        self.order_manager_approved_event(&caller, &manager);
+    }
+
+    /// Revoke a previously approved manager
+    ///
+    /// Its daily caps and order rate are cleared too, so approving it again
+    /// starts without any spending allowance
+    #[endpoint(revokeOrderManager)]
+    fn revoke_order_manager(&self, manager: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.order_managers(&caller).swap_remove(&manager),
+            ERROR_MANAGER_NOT_APPROVED
+        );
+
+        for token in self.manager_cap_tokens(&caller, &manager).iter() {
+            self.manager_daily_cap(&caller, &manager, &token).clear();
+            self.manager_spend(&caller, &manager, &token).clear();
+        }
+        self.manager_cap_tokens(&caller, &manager).clear();
+        self.manager_order_rate(&caller, &manager).clear();
+        self.manager_order_count(&caller, &manager).clear();
+
+        self.order_manager_revoked_event(&caller, &manager);
+    }
+
+    /// Daily (per epoch) cap on how much of `token` an approved manager may
+    /// escrow into new orders for the caller; zero disables spending
+    #[endpoint(setManagerDailyCap)]
+    fn set_manager_daily_cap(
+        &self,
+        manager: ManagedAddress,
+        token: TokenIdentifier,
+        cap: BigUint,
+    ) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.order_managers(&caller).contains(&manager),
+            ERROR_MANAGER_NOT_APPROVED
+        );
+        self.manager_daily_cap(&caller, &manager, &token).set(&cap);
+        self.manager_cap_tokens(&caller, &manager).insert(token.clone());
+        self.manager_daily_cap_set_event(&caller, &manager, &token, &cap);
+    }
+
+    /// Maximum orders an approved manager may create per epoch (0 = no limit)
+    #[endpoint(setManagerOrderRate)]
+    fn set_manager_order_rate(&self, manager: ManagedAddress, max_orders_per_epoch: u64) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.order_managers(&caller).contains(&manager),
+            ERROR_MANAGER_NOT_APPROVED
+        );
+        self.manager_order_rate(&caller, &manager).set(max_orders_per_epoch);
+        self.manager_order_rate_set_event(&caller, &manager, max_orders_per_epoch);
+    }
+
+    /// Grant (or replace) a scoped session key for the caller's orders
+    ///
+    /// # Arguments
+    /// * `key` - Address allowed to act
+    /// * `permissions` - Bitmask of SESSION_PERMISSION_* flags
+    /// * `spend_token` - Token the key may spend when creating orders
+    /// * `spend_cap` - Total amount of `spend_token` the key may escrow
+    /// * `expires_at` - Timestamp after which the key is no longer valid
+    #[endpoint(grantSessionKey)]
+    fn grant_session_key(
+        &self,
+        key: ManagedAddress,
+        permissions: u32,
+        spend_token: TokenIdentifier,
+        spend_cap: BigUint,
+        expires_at: u64,
+    ) {
+        let caller = self.blockchain().get_caller();
+        require!(key != caller, ERROR_SELF_MANAGER);
+        require!(
+            permissions != 0 && permissions & !SESSION_PERMISSION_ALL == 0,
+            ERROR_INVALID_SESSION_PERMISSIONS
+        );
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        require!(expires_at > current_time, ERROR_SESSION_KEY_EXPIRED);
+
+        let session = SessionKey {
+            permissions,
+            spend_token,
+            spend_cap,
+            spent: BigUint::zero(),
+            expires_at,
+        };
+        self.session_key(&caller, &key).set(&session);
+        self.session_keys(&caller).insert(key.clone());
+
+        self.session_key_granted_event(&caller, &key, &session);
+    }
+
+    /// Revoke a session key before its expiry
+    #[endpoint(revokeSessionKey)]
+    fn revoke_session_key(&self, key: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.session_keys(&caller).swap_remove(&key),
+            ERROR_SESSION_KEY_NOT_FOUND
+        );
+        self.session_key(&caller, &key).clear();
+        self.session_key_revoked_event(&caller, &key);
+    }
+
+    /// Check if `manager` may act on the orders of `user`
+    #[view(isOrderManager)]
+    fn is_order_manager(&self, user: ManagedAddress, manager: ManagedAddress) -> bool {
+        self.order_managers(&user).contains(&manager)
+    }
+
+    /// Session keys granted by `user` (expired ones included until revoked)
+    #[view(getSessionKeys)]
+    fn get_session_keys(
+        &self,
+        user: ManagedAddress,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, SessionKey<Self::Api>>> {
+        let mut result = MultiValueEncoded::new();
+        for key in self.session_keys(&user).iter() {
+            let session = self.session_key(&user, &key).get();
+            result.push((key, session).into());
+        }
+        result
+    }
+
+    // ========== GUARDS ==========
+
+    /// Caller must be the order owner, one of their approved managers,
+    /// or a live session key holding `permission`
+    fn require_order_authority(
+        &self,
+        owner: &ManagedAddress,
+        caller: &ManagedAddress,
+        permission: u32,
+    ) {
+        if owner == caller || self.order_managers(owner).contains(caller) {
+            return;
+        }
+        self.require_session_permission(owner, caller, permission);
+    }
+
+    /// Session key of `owner` must be live and hold `permission`
+    fn require_session_permission(
+        &self,
+        owner: &ManagedAddress,
+        key: &ManagedAddress,
+        permission: u32,
+    ) -> SessionKey<Self::Api> {
+        let mapper = self.session_key(owner, key);
+        require!(!mapper.is_empty(), ERROR_NOT_YOUR_ORDER);
+
+        let session = mapper.get();
+        require!(session.permissions & permission != 0, ERROR_SESSION_PERMISSION_DENIED);
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        require!(current_time <= session.expires_at, ERROR_SESSION_KEY_EXPIRED);
+
+        session
+    }
+
+    /// Charge an order creation against the session key's spend cap
+    fn spend_session_allowance(
+        &self,
+        owner: &ManagedAddress,
+        key: &ManagedAddress,
+        token: &TokenIdentifier,
+        amount: &BigUint,
+    ) {
+        let mut session = self.require_session_permission(owner, key, SESSION_PERMISSION_CREATE);
+        require!(session.spend_token == *token, ERROR_SESSION_WRONG_TOKEN);
+
+        session.spent += amount;
+        require!(session.spent <= session.spend_cap, ERROR_SESSION_CAP_EXCEEDED);
+        self.session_key(owner, key).set(&session);
+    }
+
+    /// Charge an order a manager creates for `owner` against the daily cap
+    /// and the order rate limit; both reset every epoch
+    fn spend_manager_allowance(
+        &self,
+        owner: &ManagedAddress,
+        manager: &ManagedAddress,
+        token: &TokenIdentifier,
+        amount: &BigUint,
+    ) {
+        require!(
+            self.order_managers(owner).contains(manager),
+            ERROR_NOT_YOUR_ORDER
+        );
+        let cap = self.manager_daily_cap(owner, manager, token).get();
+        require!(cap > 0u64, ERROR_MANAGER_NO_CAP);
+
+        let epoch = self.blockchain().get_block_epoch();
+
+        let mut usage = self.manager_spend(owner, manager, token).get();
+        if usage.epoch != epoch {
+            usage = EpochUsage { epoch, amount: BigUint::zero() };
+        }
+        usage.amount += amount;
+        require!(usage.amount <= cap, ERROR_MANAGER_CAP_EXCEEDED);
+        self.manager_spend(owner, manager, token).set(&usage);
+
+        let max_orders = self.manager_order_rate(owner, manager).get();
+        if max_orders > 0 {
+            let mut orders = self.manager_order_count(owner, manager).get();
+            if orders.0 != epoch {
+                orders = (epoch, 0);
+            }
+            orders.1 += 1;
+            require!(orders.1 <= max_orders, ERROR_MANAGER_RATE_LIMITED);
+            self.manager_order_count(owner, manager).set(orders);
+        }
+    }
+
+    // ========== STORAGE ==========
+
+    #[view(getOrderManagers)]
+    #[storage_mapper("orderManagers")]
+    fn order_managers(&self, user: &ManagedAddress) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getManagerDailyCap)]
+    #[storage_mapper("managerDailyCap")]
+    fn manager_daily_cap(
+        &self,
+        user: &ManagedAddress,
+        manager: &ManagedAddress,
+        token: &TokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    /// Tokens `user` set a daily cap for, cleared when the manager is revoked
+    #[storage_mapper("managerCapTokens")]
+    fn manager_cap_tokens(
+        &self,
+        user: &ManagedAddress,
+        manager: &ManagedAddress,
+    ) -> UnorderedSetMapper<TokenIdentifier>;
+
+    /// Amount of `token` a manager escrowed for `user` in the current epoch
+    #[view(getManagerSpend)]
+    #[storage_mapper("managerSpend")]
+    fn manager_spend(
+        &self,
+        user: &ManagedAddress,
+        manager: &ManagedAddress,
+        token: &TokenIdentifier,
+    ) -> SingleValueMapper<EpochUsage<Self::Api>>;
+
+    #[view(getManagerOrderRate)]
+    #[storage_mapper("managerOrderRate")]
+    fn manager_order_rate(
+        &self,
+        user: &ManagedAddress,
+        manager: &ManagedAddress,
+    ) -> SingleValueMapper<u64>;
+
+    /// (epoch, orders created in that epoch) per manager
+    #[storage_mapper("managerOrderCount")]
+    fn manager_order_count(
+        &self,
+        user: &ManagedAddress,
+        manager: &ManagedAddress,
+    ) -> SingleValueMapper<(u64, u64)>;
+
+    #[storage_mapper("sessionKey")]
+    fn session_key(
+        &self,
+        user: &ManagedAddress,
+        key: &ManagedAddress,
+    ) -> SingleValueMapper<SessionKey<Self::Api>>;
+
+    #[storage_mapper("sessionKeys")]
+    fn session_keys(&self, user: &ManagedAddress) -> UnorderedSetMapper<ManagedAddress>;
+}
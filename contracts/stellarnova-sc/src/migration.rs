@@ -0,0 +1,240 @@
+//! Migration Module for StellarNova
+//!
+//! Storage layouts are tracked with `storageVersion`, independently of the code
+//! version. `upgrade()` runs every registered migration between the stored and
+//! the current storage version. Migrations that touch every order are batched:
+//! they record the id range to rewrite and complete through `migrateOrders`.
+//!
+//! Registry:
+//! - v1 -> v2: re-encode orders created before versioning into the current
+//!   `LimitOrder` layout and add pending ones to the pending index and escrow totals
+//! - v2 -> v3: add pending orders to the per-pair price book (`getOrderBook`)
+//! - v3 -> v4: add every order to the creation-time index
+//!   (`getOrdersCreatedBetween`)
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::errors::*;
+use crate::limit_orders::{LimitOrder, OrderStatus};
+
+/// Current storage layout version
+pub const STORAGE_VERSION: u64 = 4;
+
+/// Order layout of storage version 1 (original deployment)
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct LegacyLimitOrderV1<M: ManagedTypeApi> {
+    pub order_id: u64,
+    pub user: ManagedAddress<M>,
+    pub from_token: TokenIdentifier<M>,
+    pub from_amount: BigUint<M>,
+    pub to_token: TokenIdentifier<M>,
+    pub target_price_numerator: BigUint<M>,
+    pub target_price_denominator: BigUint<M>,
+    pub slippage_bp: u64,
+    pub expires_at: u64,
+    pub status: OrderStatus,
+    pub created_at: u64,
+}
+
+impl<M: ManagedTypeApi> LegacyLimitOrderV1<M> {
+    pub fn into_current(self) -> LimitOrder<M> {
+        LimitOrder {
+            order_id: self.order_id,
+            user: self.user,
+            from_token: self.from_token,
+            from_amount: self.from_amount,
+            to_token: self.to_token,
+            target_price_numerator: self.target_price_numerator,
+            target_price_denominator: self.target_price_denominator,
+            slippage_bp: self.slippage_bp,
+            expires_at: self.expires_at,
+            status: self.status,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[multiversx_sc::module]
+pub trait MigrationModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::limit_orders::LimitOrdersModule
+    + crate::pause::PauseModule
+    + crate::dex::DexModule
+    + crate::blacklist::BlacklistModule
+    + crate::treasury::TreasuryModule
+    + crate::managers::ManagersModule
+    + crate::positions::PositionsModule
+    + crate::strategies::StrategiesModule
+    + crate::signals::SignalsModule
+    + crate::streams::StreamsModule
+    + crate::listings::ListingsModule
+    + crate::decimals::DecimalsModule
+    + crate::caps::CapsModule
+    + crate::breaker::CircuitBreakerModule
+    + crate::staking::StakingModule
+    + crate::insurance::InsuranceModule
+    + crate::stats::StatsModule
+    + crate::clients::ClientsModule
+    + crate::commitments::CommitmentsModule
+    + crate::auctions::AuctionsModule
+    + crate::gas_station::GasStationModule
+    + crate::forwarding::ForwardingModule
+{
+    /// Re-encode up to `max_count` legacy orders (owner only)
+    /// Completes the pending order migration once the last order is done
+    /// Returns the number of orders still to migrate
+    #[only_owner]
+    #[endpoint(migrateOrders)]
+    fn migrate_orders(&self, max_count: u64) -> u64 {
+        require!(!self.order_migration_end().is_empty(), ERROR_NO_MIGRATION_PENDING);
+
+        let version = self.storage_version().get();
+        let end = self.order_migration_end().get();
+        let mut cursor = self.order_migration_cursor().get();
+        let mut processed = 0u64;
+
+        while cursor < end && processed < max_count {
+            self.migrate_order(version, cursor);
+            cursor += 1;
+            processed += 1;
+        }
+
+        if cursor < end {
+            self.order_migration_cursor().set(cursor);
+            return end - cursor;
+        }
+
+        self.order_migration_cursor().clear();
+        self.order_migration_end().clear();
+
+        self.complete_migration(version);
+        self.run_migrations();
+
+        // The next migration may itself be batched
+        if self.is_migration_pending() {
+            return self.order_migration_end().get() - self.order_migration_cursor().get();
+        }
+        0
+    }
+
+    /// True while a batched migration waits for `migrateOrders`
+    #[view(isMigrationPending)]
+    fn is_migration_pending(&self) -> bool {
+        !self.order_migration_end().is_empty()
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    /// Run registered migrations from the stored to the current storage version
+    /// Stops at the first batched migration, which completes via `migrateOrders`
+    fn run_migrations(&self) {
+        // Deployments before storage versioning are on version 1
+        if self.storage_version().is_empty() {
+            self.storage_version().set(1u64);
+        }
+
+        let mut version = self.storage_version().get();
+        require!(version <= STORAGE_VERSION, ERROR_STORAGE_VERSION_TOO_NEW);
+
+        while version < STORAGE_VERSION {
+            if !self.start_migration(version) {
+                return;
+            }
+            self.complete_migration(version);
+            version += 1;
+        }
+    }
+
+    /// Apply the migration from `from_version` to `from_version + 1`
+    /// Returns false when the migration is batched and still has work left
+    fn start_migration(&self, from_version: u64) -> bool {
+        match from_version {
+            // All of them rewrite every order in batches
+            1..=3 => {
+                let next_order_id = self.next_order_id().get();
+                if next_order_id <= 1 {
+                    return true;
+                }
+                if self.order_migration_end().is_empty() {
+                    self.order_migration_cursor().set(1u64);
+                    self.order_migration_end().set(next_order_id);
+                }
+                false
+            }
+            _ => sc_panic!(ERROR_UNKNOWN_MIGRATION),
+        }
+    }
+
+    /// Apply the batched part of the migration from `from_version` to one order
+    fn migrate_order(&self, from_version: u64, order_id: u64) {
+        match from_version {
+            1 => self.migrate_order_v1(order_id),
+            2 => self.index_order_pair(order_id),
+            3 => self.index_order_created_at(order_id),
+            _ => sc_panic!(ERROR_UNKNOWN_MIGRATION),
+        }
+    }
+
+    fn complete_migration(&self, from_version: u64) {
+        self.storage_version().set(from_version + 1);
+        self.storage_migrated_event(from_version, from_version + 1);
+    }
+
+    /// Rewrite one v1 order in the current layout and index it if still pending
+    fn migrate_order_v1(&self, order_id: u64) {
+        if self.legacy_limit_orders(order_id).is_empty() {
+            return;
+        }
+
+        let order = self.legacy_limit_orders(order_id).get().into_current();
+
+        if matches!(order.status, OrderStatus::Pending)
+            && !self.pending_order_ids().contains(&order_id)
+        {
+            self.index_pending_order(&order);
+            self.lock_escrow(&order.from_token, &order.from_amount);
+        }
+
+        self.limit_orders(order_id).set(&order);
+    }
+
+    /// Add an already pending order to its pair's price book
+    fn index_order_pair(&self, order_id: u64) {
+        if !self.pending_order_ids().contains(&order_id) {
+            return;
+        }
+        let order = self.limit_orders(order_id).get();
+        self.add_to_price_book(&order);
+    }
+
+    /// Add an order created before the creation-time index to it
+    fn index_order_created_at(&self, order_id: u64) {
+        if self.limit_orders(order_id).is_empty() {
+            return;
+        }
+        let order = self.limit_orders(order_id).get();
+        self.index_order_history(order_id, order.created_at);
+    }
+
+    // ========== STORAGE ==========
+
+    /// Storage layout version, bumped by completed migrations
+    #[view(getStorageVersion)]
+    #[storage_mapper("storageVersion")]
+    fn storage_version(&self) -> SingleValueMapper<u64>;
+
+    /// Orders as written by storage version 1 (same key as `limitOrders`)
+    #[storage_mapper("limitOrders")]
+    fn legacy_limit_orders(&self, order_id: u64) -> SingleValueMapper<LegacyLimitOrderV1<Self::Api>>;
+
+    /// Next order id to migrate
+    #[storage_mapper("orderMigrationCursor")]
+    fn order_migration_cursor(&self) -> SingleValueMapper<u64>;
+
+    /// First order id not covered by the migration (next order id at upgrade time)
+    #[storage_mapper("orderMigrationEnd")]
+    fn order_migration_end(&self) -> SingleValueMapper<u64>;
+}
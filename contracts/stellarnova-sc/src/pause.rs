@@ -0,0 +1,170 @@
+//! Pause Module for StellarNova
+//!
+//! Granular emergency controls:
+//! - CreationPaused: no new orders, executions and cancellations continue
+//! - ExecutionPaused: no executions, orders can still be created and cancelled
+//! - FullStop: no creation, no execution
+//!
+//! Cancellation and expiry refunds are never blocked by any pause level or
+//! per-pair pause: escrowed funds stay reclaimable whatever the owner does.
+//! While a pause keeps an order from executing, the cancellation window
+//! (`setCancellationPolicy`) is waived as well.
+//!
+//! Per-pair pause: `setPairPaused` freezes executions between two tokens (in
+//! both directions, through the DEX or matching) without touching the rest
+//! of the protocol, e.g. while a pool is being exploited.
+//!
+//! Wind-down: once a FullStop has lasted `WIND_DOWN_GRACE_SECONDS`, any user
+//! can refund all of their pending orders with `windDownRefund`, whatever the
+//! rest of the configuration says, so funds are never trapped behind an
+//! abandoned owner key.
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::errors::*;
+
+/// FullStop duration after which wind-down refunds open up (30 days)
+pub const WIND_DOWN_GRACE_SECONDS: u64 = 30 * 24 * 60 * 60;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PauseLevel {
+    Active,
+    CreationPaused,
+    ExecutionPaused,
+    FullStop,
+}
+
+impl PauseLevel {
+    pub fn allows_creation(&self) -> bool {
+        matches!(self, PauseLevel::Active | PauseLevel::ExecutionPaused)
+    }
+
+    pub fn allows_execution(&self) -> bool {
+        matches!(self, PauseLevel::Active | PauseLevel::CreationPaused)
+    }
+}
+
+#[multiversx_sc::module]
+pub trait PauseModule: crate::events::EventsModule {
+    /// Pause/unpause contract (emergency stop)
+    /// `true` = FullStop, `false` = Active
+    #[only_owner]
+    #[endpoint(setPaused)]
+    fn set_paused(&self, paused: bool) {
+        let level = if paused {
+            PauseLevel::FullStop
+        } else {
+            PauseLevel::Active
+        };
+        self.store_pause_level(level);
+        self.pause_state_changed_event(paused);
+    }
+
+    /// Set a granular pause level (owner only)
+    #[only_owner]
+    #[endpoint(setPauseLevel)]
+    fn set_pause_level(&self, level: PauseLevel) {
+        self.store_pause_level(level);
+        self.pause_level_changed_event(level);
+    }
+
+    /// Freeze or resume executions between two tokens, in both directions
+    /// (owner only); their orders can still be cancelled
+    #[only_owner]
+    #[endpoint(setPairPaused)]
+    fn set_pair_paused(&self, from_token: TokenIdentifier, to_token: TokenIdentifier, paused: bool) {
+        require!(from_token != to_token, ERROR_SAME_TOKEN);
+
+        self.pair_paused(&from_token, &to_token).set(paused);
+        self.pair_paused(&to_token, &from_token).set(paused);
+        self.pair_paused_event(&from_token, &to_token, paused);
+    }
+
+    /// True when any pause level is active
+    #[view(isPaused)]
+    fn is_paused(&self) -> bool {
+        self.pause_level().get() != PauseLevel::Active
+    }
+
+    /// True once a FullStop has lasted the wind-down grace period
+    #[view(isWindDownActive)]
+    fn is_wind_down_active(&self) -> bool {
+        if self.full_stop_since().is_empty() {
+            return false;
+        }
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        current_time >= self.full_stop_since().get() + WIND_DOWN_GRACE_SECONDS
+    }
+
+    // ========== GUARDS ==========
+
+    fn require_creation_allowed(&self) {
+        require!(
+            self.pause_level().get().allows_creation(),
+            ERROR_CREATION_PAUSED
+        );
+    }
+
+    fn require_execution_allowed(&self) {
+        require!(
+            self.pause_level().get().allows_execution(),
+            ERROR_EXECUTION_PAUSED
+        );
+    }
+
+    fn require_pair_not_paused(&self, from_token: &TokenIdentifier, to_token: &TokenIdentifier) {
+        require!(!self.pair_paused(from_token, to_token).get(), ERROR_PAIR_PAUSED);
+    }
+
+    fn require_wind_down_active(&self) {
+        require!(self.is_wind_down_active(), ERROR_WIND_DOWN_NOT_ACTIVE);
+    }
+
+    /// True when a pause level or the per-pair pause keeps orders between
+    /// the two tokens from executing
+    fn is_execution_halted(&self, from_token: &TokenIdentifier, to_token: &TokenIdentifier) -> bool {
+        !self.pause_level().get().allows_execution() || self.pair_paused(from_token, to_token).get()
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    /// Set the pause level, tracking since when a FullStop lasts
+    fn store_pause_level(&self, level: PauseLevel) {
+        self.pause_level().set(level);
+        if level == PauseLevel::FullStop {
+            #[allow(deprecated)]
+            let current_time = self.blockchain().get_block_timestamp();
+            self.full_stop_since().set_if_empty(current_time);
+        } else {
+            self.full_stop_since().clear();
+        }
+    }
+
+    // ========== STORAGE ==========
+
+    /// Current pause level (empty storage decodes as Active)
+    #[view(getPauseLevel)]
+    #[storage_mapper("pauseLevel")]
+    fn pause_level(&self) -> SingleValueMapper<PauseLevel>;
+
+    /// Start of the current FullStop (empty when not fully stopped)
+    #[view(getFullStopSince)]
+    #[storage_mapper("fullStopSince")]
+    fn full_stop_since(&self) -> SingleValueMapper<u64>;
+
+    /// Executions between the two tokens are frozen (stored both ways)
+    #[view(isPairPaused)]
+    #[storage_mapper("pairPaused")]
+    fn pair_paused(
+        &self,
+        from_token: &TokenIdentifier,
+        to_token: &TokenIdentifier,
+    ) -> SingleValueMapper<bool>;
+
+    /// Legacy boolean pause flag (pre pause-levels), only read during upgrade
+    #[storage_mapper("paused")]
+    fn legacy_paused(&self) -> SingleValueMapper<bool>;
+}
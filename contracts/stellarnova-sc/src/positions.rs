@@ -0,0 +1,136 @@
+//! Positions Module for StellarNova
+//!
+//! Orders can be tokenized: a position NFT (attributes = order id) is minted
+//! to the creator, and whoever holds it controls the order. Payouts of a
+//! tokenized order (swap output, refunds) are held by the contract until the
+//! holder claims them by sending the NFT; the NFT is burned once the order
+//! is settled.
+//!
+//! The collection is issued off-chain with NFTCreate/NFTBurn roles for this
+//! contract and registered via `setPositionToken`.
+
+multiversx_sc::imports!();
+
+use crate::errors::*;
+
+#[multiversx_sc::module]
+pub trait PositionsModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+{
+    /// Register the NFT collection used for order positions (owner only, once)
+    #[only_owner]
+    #[endpoint(setPositionToken)]
+    fn set_position_token(&self, token: TokenIdentifier) {
+        require!(self.position_token().is_empty(), ERROR_POSITION_TOKEN_ALREADY_SET);
+        self.position_token().set_token_id(token.clone());
+        self.position_token_set_event(&token);
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    fn is_tokenized(&self, order_id: u64) -> bool {
+        !self.order_position(order_id).is_empty()
+    }
+
+    /// Mint the position NFT of `order_id` and send it to `holder`
+    fn mint_position(&self, order_id: u64, holder: &ManagedAddress) -> u64 {
+        require!(!self.position_token().is_empty(), ERROR_POSITION_TOKEN_NOT_SET);
+
+        let payment = self
+            .position_token()
+            .nft_create_and_send(holder, BigUint::from(1u64), &order_id);
+        self.order_position(order_id).set(payment.token_nonce);
+        self.position_order(payment.token_nonce).set(order_id);
+
+        self.position_minted_event(order_id, holder, payment.token_nonce);
+        payment.token_nonce
+    }
+
+    /// Order id of the position NFT sent with the call
+    fn receive_position(&self) -> u64 {
+        let payment = self.call_value().single_esdt();
+        require!(!self.position_token().is_empty(), ERROR_POSITION_TOKEN_NOT_SET);
+        self.position_token().require_same_token(&payment.token_identifier);
+        require!(
+            !self.position_order(payment.token_nonce).is_empty(),
+            ERROR_POSITION_NOT_FOUND
+        );
+
+        self.position_order(payment.token_nonce).get()
+    }
+
+    /// Send the NFT of `order_id` back to `holder`
+    fn return_position(&self, order_id: u64, holder: &ManagedAddress) {
+        let nonce = self.order_position(order_id).get();
+        let token = self.position_token().get_token_id();
+        self.send()
+            .direct_esdt(holder, &token, nonce, &BigUint::from(1u64));
+    }
+
+    /// Burn the NFT of a settled order and forget the position
+    fn burn_position(&self, order_id: u64) {
+        let nonce = self.order_position(order_id).take();
+        self.position_order(nonce).clear();
+        self.position_token().nft_burn(nonce, &BigUint::from(1u64));
+    }
+
+    /// Pay an order's owner: tokenized orders hold the amount for the position holder
+    fn pay_order_owner(
+        &self,
+        order_id: u64,
+        owner: &ManagedAddress,
+        token: &TokenIdentifier,
+        amount: &BigUint,
+    ) {
+        if *amount == 0u64 {
+            return;
+        }
+
+        if self.is_tokenized(order_id) {
+            self.position_balance(order_id, token)
+                .update(|balance| *balance += amount);
+            self.position_held_balance(token)
+                .update(|held| *held += amount);
+        } else {
+            self.send().direct_esdt(owner, token, 0, amount);
+        }
+    }
+
+    /// Send everything held for a position in `token` to `holder`
+    fn pay_out_position_balance(
+        &self,
+        order_id: u64,
+        holder: &ManagedAddress,
+        token: &TokenIdentifier,
+    ) -> BigUint {
+        let amount = self.position_balance(order_id, token).take();
+        if amount > 0u64 {
+            self.position_held_balance(token)
+                .update(|held| *held -= &amount);
+            self.send().direct_esdt(holder, token, 0, &amount);
+        }
+        amount
+    }
+
+    // ========== STORAGE ==========
+
+    #[view(getPositionToken)]
+    #[storage_mapper("positionToken")]
+    fn position_token(&self) -> NonFungibleTokenMapper;
+
+    /// Position NFT nonce of a tokenized order
+    #[view(getOrderPosition)]
+    #[storage_mapper("orderPosition")]
+    fn order_position(&self, order_id: u64) -> SingleValueMapper<u64>;
+
+    /// Order id represented by a position NFT nonce
+    #[view(getPositionOrder)]
+    #[storage_mapper("positionOrder")]
+    fn position_order(&self, nonce: u64) -> SingleValueMapper<u64>;
+
+    /// Payouts held for the position holder of an order, per token
+    #[view(getPositionBalance)]
+    #[storage_mapper("positionBalance")]
+    fn position_balance(&self, order_id: u64, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+}
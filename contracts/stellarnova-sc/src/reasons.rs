@@ -0,0 +1,109 @@
+//! Reason codes for StellarNova views
+//!
+//! `canExecuteOrder`, `tryExecuteLimitOrder` and `validateLimitOrder` report
+//! why an order operation would be rejected as a `ReasonCode` instead of an
+//! error string, so SDKs and the executor bot can branch on it.
+//!
+//! The codes are part of the ABI: variants are only ever appended, never
+//! reordered or removed. Each code except `Ok` maps to the error the matching
+//! endpoint fails with.
+
+multiversx_sc::derive_imports!();
+
+use crate::errors::*;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReasonCode {
+    Ok,
+    CreationPaused,
+    ExecutionPaused,
+    AddressBlacklisted,
+    NotExecutor,
+    OrderNotFound,
+    OrderNotPending,
+    ExecutionInProgress,
+    TokenDelisted,
+    /// Executing refunds the order instead of swapping
+    OrderExpired,
+    InvalidAmount,
+    FillExceedsRemaining,
+    SignalNotFired,
+    ConditionPriceStale,
+    ConditionNotMet,
+    StrategyPaused,
+    PriceNotMet,
+    FromTokenNotWhitelisted,
+    ToTokenNotWhitelisted,
+    SameToken,
+    PriceNumZero,
+    PriceDenomZero,
+    SlippageAbove100Percent,
+    SlippageAboveMax,
+    ExpiryTooFar,
+    /// A token of the order has no pair yet
+    OrderDormant,
+    OrderTooLarge,
+    OpenInterestCapReached,
+    /// The order's pair is halted by the circuit breaker
+    PairTripped,
+    /// The caller executed its epoch limit of the from token
+    ExecutorVolumeExceeded,
+    /// Executions on the order's pair are paused by the owner
+    PairPaused,
+    /// Exact-output orders cannot be filled in chunks
+    ExactOutputFullFill,
+    /// The order's price is committed and not revealed yet
+    PriceNotRevealed,
+    /// Bids for the order's execution rights are still open
+    ExecutionAuctionOpen,
+    /// The order failed recently and waits for its retry time
+    RetryBackoff,
+}
+
+impl ReasonCode {
+    pub fn is_ok(self) -> bool {
+        self == ReasonCode::Ok
+    }
+
+    /// Error message of the rejected operation (empty for `Ok`)
+    pub fn error_message(self) -> &'static str {
+        match self {
+            ReasonCode::Ok => "",
+            ReasonCode::CreationPaused => ERROR_CREATION_PAUSED,
+            ReasonCode::ExecutionPaused => ERROR_EXECUTION_PAUSED,
+            ReasonCode::AddressBlacklisted => ERROR_ADDRESS_BLACKLISTED,
+            ReasonCode::NotExecutor => ERROR_ONLY_EXECUTOR,
+            ReasonCode::OrderNotFound => ERROR_ORDER_NOT_FOUND,
+            ReasonCode::OrderNotPending => ERROR_ORDER_NOT_PENDING,
+            ReasonCode::ExecutionInProgress => ERROR_EXECUTION_IN_PROGRESS,
+            ReasonCode::TokenDelisted => ERROR_TOKEN_DELISTED,
+            ReasonCode::OrderExpired => ERROR_ORDER_EXPIRED,
+            ReasonCode::InvalidAmount => ERROR_INVALID_AMOUNT,
+            ReasonCode::FillExceedsRemaining => ERROR_FILL_EXCEEDS_REMAINING,
+            ReasonCode::SignalNotFired => ERROR_SIGNAL_NOT_FIRED,
+            ReasonCode::ConditionPriceStale => ERROR_CONDITION_PRICE_STALE,
+            ReasonCode::ConditionNotMet => ERROR_CONDITION_NOT_MET,
+            ReasonCode::StrategyPaused => ERROR_STRATEGY_PAUSED,
+            ReasonCode::PriceNotMet => ERROR_PRICE_NOT_MET,
+            ReasonCode::FromTokenNotWhitelisted => ERROR_FROM_TOKEN_NOT_WHITELISTED,
+            ReasonCode::ToTokenNotWhitelisted => ERROR_TO_TOKEN_NOT_WHITELISTED,
+            ReasonCode::SameToken => ERROR_SAME_TOKEN,
+            ReasonCode::PriceNumZero => ERROR_PRICE_NUM_ZERO,
+            ReasonCode::PriceDenomZero => ERROR_PRICE_DENOM_ZERO,
+            ReasonCode::SlippageAbove100Percent => ERROR_SLIPPAGE_ABOVE_100_PERCENT,
+            ReasonCode::SlippageAboveMax => ERROR_SLIPPAGE_ABOVE_MAX,
+            ReasonCode::ExpiryTooFar => ERROR_EXPIRY_TOO_FAR,
+            ReasonCode::OrderDormant => ERROR_ORDER_DORMANT,
+            ReasonCode::OrderTooLarge => ERROR_ORDER_TOO_LARGE,
+            ReasonCode::OpenInterestCapReached => ERROR_OPEN_INTEREST_CAP,
+            ReasonCode::PairTripped => ERROR_PAIR_TRIPPED,
+            ReasonCode::ExecutorVolumeExceeded => ERROR_EXECUTOR_VOLUME_EXCEEDED,
+            ReasonCode::PairPaused => ERROR_PAIR_PAUSED,
+            ReasonCode::ExactOutputFullFill => ERROR_EXACT_OUTPUT_FULL_FILL,
+            ReasonCode::PriceNotRevealed => ERROR_PRICE_NOT_REVEALED,
+            ReasonCode::ExecutionAuctionOpen => ERROR_EXECUTION_AUCTION_OPEN,
+            ReasonCode::RetryBackoff => ERROR_RETRY_BACKOFF,
+        }
+    }
+}
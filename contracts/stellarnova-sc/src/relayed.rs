@@ -0,0 +1,122 @@
+//! Relayed Orders Module for StellarNova
+//!
+//! A relayer pays the gas for `createLimitOrderFor`; the user only signs the
+//! order parameters off-chain. The escrow is taken from the user's deposited
+//! credit, and a per-user nonce prevents replaying a signature.
+//!
+//! Signed message: top-encoded `RelayedOrderMessage` (contract address, user,
+//! nonce, order parameters), verified with the user's address as ed25519 key.
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::errors::*;
+
+/// Order parameters signed by the user
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct RelayedOrder<M: ManagedTypeApi> {
+    pub from_token: TokenIdentifier<M>,
+    pub from_amount: BigUint<M>,
+    pub to_token: TokenIdentifier<M>,
+    pub target_price_numerator: BigUint<M>,
+    pub target_price_denominator: BigUint<M>,
+    pub slippage_bp: u64,
+    pub expires_in_seconds: u64,
+}
+
+/// Payload the user signs; binding the contract address keeps signatures
+/// from being replayed on another deployment
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct RelayedOrderMessage<M: ManagedTypeApi> {
+    pub contract: ManagedAddress<M>,
+    pub user: ManagedAddress<M>,
+    pub nonce: u64,
+    pub order: RelayedOrder<M>,
+}
+
+#[multiversx_sc::module]
+pub trait RelayedModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::limit_orders::LimitOrdersModule
+    + crate::pause::PauseModule
+    + crate::dex::DexModule
+    + crate::blacklist::BlacklistModule
+    + crate::treasury::TreasuryModule
+    + crate::managers::ManagersModule
+    + crate::positions::PositionsModule
+    + crate::strategies::StrategiesModule
+    + crate::signals::SignalsModule
+    + crate::streams::StreamsModule
+    + crate::listings::ListingsModule
+    + crate::decimals::DecimalsModule
+    + crate::caps::CapsModule
+    + crate::breaker::CircuitBreakerModule
+    + crate::staking::StakingModule
+    + crate::insurance::InsuranceModule
+    + crate::stats::StatsModule
+    + crate::clients::ClientsModule
+    + crate::commitments::CommitmentsModule
+    + crate::auctions::AuctionsModule
+    + crate::gas_station::GasStationModule
+    + crate::forwarding::ForwardingModule
+    + crate::credit::CreditModule
+{
+    /// Create a limit order on behalf of `user`, funded from their credit
+    ///
+    /// # Arguments
+    /// * `user` - Order owner and signer
+    /// * `order` - Signed order parameters
+    /// * `nonce` - Must equal `getRelayNonce(user)`
+    /// * `signature` - ed25519 signature of the user over the message
+    ///
+    /// Returns the new order id
+    #[endpoint(createLimitOrderFor)]
+    fn create_limit_order_for(
+        &self,
+        user: ManagedAddress,
+        order: RelayedOrder<Self::Api>,
+        nonce: u64,
+        signature: ManagedBuffer,
+    ) -> u64 {
+        self.require_creation_allowed();
+        self.require_not_blacklisted(&user);
+        self.require_not_blacklisted(&self.blockchain().get_caller());
+
+        require!(nonce == self.relay_nonce(&user).get(), ERROR_INVALID_RELAY_NONCE);
+        self.relay_nonce(&user).set(nonce + 1);
+
+        let message = RelayedOrderMessage {
+            contract: self.blockchain().get_sc_address(),
+            user: user.clone(),
+            nonce,
+            order: order.clone(),
+        };
+        let message_bytes = self.serializer().top_encode_to_managed_buffer(&message);
+        self.crypto()
+            .verify_ed25519(user.as_managed_buffer(), &message_bytes, &signature);
+
+        self.debit_credit(&user, &order.from_token, &order.from_amount);
+        self.lock_escrow(&order.from_token, &order.from_amount);
+
+        self.create_order(
+            &user,
+            order.from_token,
+            order.from_amount,
+            order.to_token,
+            order.target_price_numerator,
+            order.target_price_denominator,
+            order.slippage_bp,
+            order.expires_in_seconds,
+        )
+    }
+
+    // ========== STORAGE ==========
+
+    /// Next nonce a relayed order of `user` must be signed with
+    #[view(getRelayNonce)]
+    #[storage_mapper("relayNonce")]
+    fn relay_nonce(&self, user: &ManagedAddress) -> SingleValueMapper<u64>;
+}
@@ -0,0 +1,234 @@
+//! RFQ Module for StellarNova
+//!
+//! Approved market makers post on-chain quotes backed by a deposit: the maker
+//! offers `give_token` at `price_num / price_denom` units per unit of
+//! `take_token`, until the quote expires or its deposit runs out. The executor
+//! fills resting limit orders against a quote when it beats the pool; the
+//! order still has to get at least its limit price.
+//!
+//! Makers get unused deposits back with `cancelQuote`.
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::errors::*;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct Quote<M: ManagedTypeApi> {
+    pub quote_id: u64,
+    pub maker: ManagedAddress<M>,
+    pub give_token: TokenIdentifier<M>,
+    pub give_amount: BigUint<M>, // remaining deposit
+    pub take_token: TokenIdentifier<M>,
+    pub price_num: BigUint<M>,   // give_token per take_token
+    pub price_denom: BigUint<M>,
+    pub expires_at: u64,
+}
+
+#[multiversx_sc::module]
+pub trait RfqModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::limit_orders::LimitOrdersModule
+    + crate::pause::PauseModule
+    + crate::dex::DexModule
+    + crate::blacklist::BlacklistModule
+    + crate::treasury::TreasuryModule
+    + crate::managers::ManagersModule
+    + crate::positions::PositionsModule
+    + crate::strategies::StrategiesModule
+    + crate::signals::SignalsModule
+    + crate::streams::StreamsModule
+    + crate::listings::ListingsModule
+    + crate::decimals::DecimalsModule
+    + crate::caps::CapsModule
+    + crate::breaker::CircuitBreakerModule
+    + crate::staking::StakingModule
+    + crate::insurance::InsuranceModule
+    + crate::stats::StatsModule
+    + crate::clients::ClientsModule
+    + crate::commitments::CommitmentsModule
+    + crate::auctions::AuctionsModule
+    + crate::gas_station::GasStationModule
+    + crate::forwarding::ForwardingModule
+{
+    /// Allow `maker` to post quotes (owner only)
+    #[only_owner]
+    #[endpoint(addMarketMaker)]
+    fn add_market_maker(&self, maker: ManagedAddress) {
+        require!(
+            self.market_makers().insert(maker.clone()),
+            ERROR_MARKET_MAKER_ALREADY_APPROVED
+        );
+        self.market_maker_added_event(&maker);
+    }
+
+    /// Stop `maker` from posting quotes; its open quotes can no longer be
+    /// filled but stay cancellable (owner only)
+    #[only_owner]
+    #[endpoint(removeMarketMaker)]
+    fn remove_market_maker(&self, maker: ManagedAddress) {
+        require!(
+            self.market_makers().swap_remove(&maker),
+            ERROR_NOT_MARKET_MAKER
+        );
+        self.market_maker_removed_event(&maker);
+    }
+
+    /// Post a quote giving the deposited token for `take_token`
+    ///
+    /// # Payment
+    /// The `give_token` deposit backing the quote
+    ///
+    /// # Arguments
+    /// * `take_token` - Token the maker wants
+    /// * `price_num` - give_token per take_token numerator
+    /// * `price_denom` - give_token per take_token denominator
+    /// * `expires_at` - Timestamp after which the quote cannot be filled
+    ///
+    /// Returns the quote id
+    #[payable("*")]
+    #[endpoint(postQuote)]
+    fn post_quote(
+        &self,
+        take_token: TokenIdentifier,
+        price_num: BigUint,
+        price_denom: BigUint,
+        expires_at: u64,
+    ) -> u64 {
+        self.require_creation_allowed();
+
+        let maker = self.blockchain().get_caller();
+        require!(self.market_makers().contains(&maker), ERROR_NOT_MARKET_MAKER);
+        self.require_not_blacklisted(&maker);
+
+        let (give_token, give_amount) = self.receive_fungible_payment();
+        require!(give_amount > 0u64, ERROR_INVALID_AMOUNT);
+        require!(give_token != take_token, ERROR_SAME_TOKEN);
+        require!(price_num > 0u64, ERROR_PRICE_NUM_ZERO);
+        require!(price_denom > 0u64, ERROR_PRICE_DENOM_ZERO);
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        require!(expires_at > current_time, ERROR_QUOTE_EXPIRED);
+
+        let quote_id = self.next_quote_id().get() + 1;
+        self.next_quote_id().set(quote_id);
+
+        let quote = Quote {
+            quote_id,
+            maker,
+            give_token,
+            give_amount,
+            take_token,
+            price_num,
+            price_denom,
+            expires_at,
+        };
+        self.quotes(quote_id).set(&quote);
+        self.quote_deposits(&quote.give_token)
+            .update(|total| *total += &quote.give_amount);
+
+        self.quote_posted_event(quote_id, &quote.maker, &quote);
+        quote_id
+    }
+
+    /// Withdraw the unused deposit of a quote and close it (maker only)
+    /// Never blocked by any pause level
+    #[endpoint(cancelQuote)]
+    fn cancel_quote(&self, quote_id: u64) {
+        require!(!self.quotes(quote_id).is_empty(), ERROR_QUOTE_NOT_FOUND);
+        let quote = self.quotes(quote_id).take();
+        let caller = self.blockchain().get_caller();
+        require!(quote.maker == caller, ERROR_NOT_MARKET_MAKER);
+        self.require_not_blacklisted(&caller);
+
+        if quote.give_amount > 0u64 {
+            self.quote_deposits(&quote.give_token)
+                .update(|total| *total -= &quote.give_amount);
+            self.send()
+                .direct_esdt(&caller, &quote.give_token, 0, &quote.give_amount);
+        }
+
+        self.quote_cancelled_event(quote_id, &caller, &quote.give_amount);
+    }
+
+    /// Fill `fill_amount` of a resting order against a quote (called by
+    /// backend executor)
+    ///
+    /// The order's input goes to the maker, the quoted output (fees
+    /// deducted as for swaps) to the order owner
+    ///
+    /// # Arguments
+    /// * `order_id` - Order to fill
+    /// * `quote_id` - Quote selling the order's `to_token` for its `from_token`
+    /// * `fill_amount` - Amount of the order's from_token to fill
+    #[endpoint(fillOrderWithQuote)]
+    fn fill_order_with_quote(&self, order_id: u64, quote_id: u64, fill_amount: BigUint) {
+        self.require_execution_allowed();
+
+        let executor = self.blockchain().get_caller();
+        require!(executor == self.limit_order_executor().get(), ERROR_ONLY_EXECUTOR);
+        self.require_not_blacklisted(&executor);
+
+        let order = self.require_matchable_order(order_id);
+        require!(fill_amount > 0u64, ERROR_INVALID_AMOUNT);
+        require!(fill_amount <= order.from_amount, ERROR_FILL_EXCEEDS_REMAINING);
+        self.spend_executor_volume(&executor, &order.from_token, &fill_amount);
+
+        require!(!self.quotes(quote_id).is_empty(), ERROR_QUOTE_NOT_FOUND);
+        let mut quote = self.quotes(quote_id).get();
+        require!(self.market_makers().contains(&quote.maker), ERROR_NOT_MARKET_MAKER);
+        self.require_not_blacklisted(&quote.maker);
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        require!(current_time <= quote.expires_at, ERROR_QUOTE_EXPIRED);
+        require!(
+            quote.give_token == order.to_token && quote.take_token == order.from_token,
+            ERROR_QUOTE_MISMATCH
+        );
+        self.require_no_self_trade(&order.user, &quote.maker);
+
+        let output = &fill_amount * &quote.price_num / &quote.price_denom;
+        let limit_output =
+            &fill_amount * &order.target_price_numerator / &order.target_price_denominator;
+        require!(output > 0u64 && output >= limit_output, ERROR_PRICE_NOT_MET);
+        require!(output <= quote.give_amount, ERROR_QUOTE_INSUFFICIENT);
+
+        // The quoted output leaves the deposit and settles the order
+        quote.give_amount -= &output;
+        self.quotes(quote_id).set(&quote);
+        self.quote_deposits(&quote.give_token)
+            .update(|total| *total -= &output);
+        self.settle_fill(order_id, &executor, &fill_amount, &output);
+
+        // The order's input (released from escrow by the settlement) pays the maker
+        self.send()
+            .direct_esdt(&quote.maker, &order.from_token, 0, &fill_amount);
+
+        self.quote_filled_event(quote_id, order_id, &quote.maker, &fill_amount, &output);
+    }
+
+    #[view(isMarketMaker)]
+    fn is_market_maker(&self, address: ManagedAddress) -> bool {
+        self.market_makers().contains(&address)
+    }
+
+    #[view(getQuote)]
+    fn get_quote(&self, quote_id: u64) -> Quote<Self::Api> {
+        require!(!self.quotes(quote_id).is_empty(), ERROR_QUOTE_NOT_FOUND);
+        self.quotes(quote_id).get()
+    }
+
+    // ========== STORAGE ==========
+
+    #[view(getMarketMakers)]
+    #[storage_mapper("marketMakers")]
+    fn market_makers(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("nextQuoteId")]
+    fn next_quote_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("quotes")]
+    fn quotes(&self, quote_id: u64) -> SingleValueMapper<Quote<Self::Api>>;
+}
@@ -0,0 +1,259 @@
+//! Signals Module for StellarNova
+//!
+//! Bridges off-chain AI signals into the execution conditions: the owner
+//! registers a signal oracle, the oracle marks signals as fired, and orders
+//! referencing a signal can only be executed once it has fired (the price
+//! condition still applies on top).
+//!
+//! Cross-pair conditions work the same way with prices: a price oracle posts
+//! prices for pairs the owner registered, and an order can require the price
+//! of any registered pair to be above or below a threshold (e.g. sell MEX for
+//! USDC only while WEGLD/USDC is above X). Stale prices never satisfy a condition.
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::errors::*;
+use crate::reasons::ReasonCode;
+
+/// Default maximum age of an oracle price used by conditions (5 minutes)
+pub const DEFAULT_PRICE_MAX_AGE_SECONDS: u64 = 5 * 60;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PriceComparison {
+    Above,
+    Below,
+}
+
+/// Quote per base price posted by the price oracle
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct OraclePrice<M: ManagedTypeApi> {
+    pub price_num: BigUint<M>,
+    pub price_denom: BigUint<M>,
+    pub updated_at: u64,
+}
+
+/// Order gate on the price of another pair
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct PriceCondition<M: ManagedTypeApi> {
+    pub base_token: TokenIdentifier<M>,
+    pub quote_token: TokenIdentifier<M>,
+    pub comparison: PriceComparison,
+    pub threshold_num: BigUint<M>,
+    pub threshold_denom: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait SignalsModule: crate::events::EventsModule {
+    /// Set the address allowed to fire signals (owner only)
+    #[only_owner]
+    #[endpoint(setSignalOracle)]
+    fn set_signal_oracle(&self, oracle: ManagedAddress) {
+        require!(!oracle.is_zero(), ERROR_ORACLE_ZERO);
+        self.signal_oracle().set(&oracle);
+        self.signal_oracle_changed_event(&oracle);
+    }
+
+    /// Mark a signal as fired (signal oracle only)
+    #[endpoint(fireSignal)]
+    fn fire_signal(&self, signal_id: u64) {
+        require!(
+            !self.signal_oracle().is_empty()
+                && self.blockchain().get_caller() == self.signal_oracle().get(),
+            ERROR_ONLY_SIGNAL_ORACLE
+        );
+        require!(self.signal_fired_at(signal_id).is_empty(), ERROR_SIGNAL_ALREADY_FIRED);
+
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        self.signal_fired_at(signal_id).set(current_time);
+
+        self.signal_fired_event(signal_id, current_time);
+    }
+
+    /// Set the address allowed to post condition prices (owner only)
+    #[only_owner]
+    #[endpoint(setPriceOracle)]
+    fn set_price_oracle(&self, oracle: ManagedAddress) {
+        require!(!oracle.is_zero(), ERROR_ORACLE_ZERO);
+        self.price_oracle().set(&oracle);
+        self.price_oracle_changed_event(&oracle);
+    }
+
+    /// Allow conditions and oracle prices for a pair (owner only)
+    #[only_owner]
+    #[endpoint(registerPricePair)]
+    fn register_price_pair(&self, base_token: TokenIdentifier, quote_token: TokenIdentifier) {
+        require!(
+            self.price_pairs().insert((base_token.clone(), quote_token.clone())),
+            ERROR_PRICE_PAIR_ALREADY_REGISTERED
+        );
+        self.price_pair_registered_event(&base_token, &quote_token);
+    }
+
+    /// Maximum age of an oracle price for conditions (owner only)
+    #[only_owner]
+    #[endpoint(setPriceMaxAge)]
+    fn set_price_max_age(&self, max_age_seconds: u64) {
+        self.price_max_age().set(max_age_seconds);
+        self.price_max_age_changed_event(max_age_seconds);
+    }
+
+    /// Post the current price of a registered pair (price oracle only)
+    #[endpoint(postPrice)]
+    fn post_price(
+        &self,
+        base_token: TokenIdentifier,
+        quote_token: TokenIdentifier,
+        price_num: BigUint,
+        price_denom: BigUint,
+    ) {
+        require!(
+            !self.price_oracle().is_empty()
+                && self.blockchain().get_caller() == self.price_oracle().get(),
+            ERROR_ONLY_PRICE_ORACLE
+        );
+        self.require_price_pair(&base_token, &quote_token);
+        require!(price_denom > 0u64, ERROR_PRICE_DENOM_ZERO);
+
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        let price = OraclePrice {
+            price_num,
+            price_denom,
+            updated_at: current_time,
+        };
+        self.oracle_price(&base_token, &quote_token).set(&price);
+
+        self.oracle_price_posted_event(&base_token, &quote_token, &price);
+    }
+
+    /// True once the oracle has fired `signal_id`
+    #[view(isSignalFired)]
+    fn is_signal_fired(&self, signal_id: u64) -> bool {
+        !self.signal_fired_at(signal_id).is_empty()
+    }
+
+    // ========== GUARDS ==========
+
+    /// Orders gated on a signal are only executable after it fired
+    fn require_order_signal_fired(&self, order_id: u64) {
+        let reason = self.order_signal_reason(order_id);
+        require!(reason.is_ok(), (reason.error_message()));
+    }
+
+    fn order_signal_reason(&self, order_id: u64) -> ReasonCode {
+        if self.order_signal(order_id).is_empty() {
+            return ReasonCode::Ok;
+        }
+        let signal_id = self.order_signal(order_id).get();
+        if !self.is_signal_fired(signal_id) {
+            return ReasonCode::SignalNotFired;
+        }
+        ReasonCode::Ok
+    }
+
+    fn require_price_pair(&self, base_token: &TokenIdentifier, quote_token: &TokenIdentifier) {
+        require!(
+            self.price_pairs()
+                .contains(&(base_token.clone(), quote_token.clone())),
+            ERROR_PRICE_PAIR_NOT_REGISTERED
+        );
+    }
+
+    /// Orders with a price condition are only executable while it holds
+    /// on a fresh oracle price
+    fn require_order_condition_met(&self, order_id: u64) {
+        let reason = self.order_condition_reason(order_id);
+        require!(reason.is_ok(), (reason.error_message()));
+    }
+
+    fn order_condition_reason(&self, order_id: u64) -> ReasonCode {
+        if self.order_condition(order_id).is_empty() {
+            return ReasonCode::Ok;
+        }
+        let condition = self.order_condition(order_id).get();
+
+        let price_mapper = self.oracle_price(&condition.base_token, &condition.quote_token);
+        if price_mapper.is_empty() {
+            return ReasonCode::ConditionPriceStale;
+        }
+        let price = price_mapper.get();
+
+        let max_age = if self.price_max_age().is_empty() {
+            DEFAULT_PRICE_MAX_AGE_SECONDS
+        } else {
+            self.price_max_age().get()
+        };
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        if current_time > price.updated_at + max_age {
+            return ReasonCode::ConditionPriceStale;
+        }
+
+        if !self.is_price_condition_met(&condition, &price) {
+            return ReasonCode::ConditionNotMet;
+        }
+        ReasonCode::Ok
+    }
+
+    /// Compare an oracle price with a condition threshold (bounds inclusive)
+    fn is_price_condition_met(
+        &self,
+        condition: &PriceCondition<Self::Api>,
+        price: &OraclePrice<Self::Api>,
+    ) -> bool {
+        let current = &price.price_num * &condition.threshold_denom;
+        let threshold = &condition.threshold_num * &price.price_denom;
+        match condition.comparison {
+            PriceComparison::Above => current >= threshold,
+            PriceComparison::Below => current <= threshold,
+        }
+    }
+
+    // ========== STORAGE ==========
+
+    #[view(getSignalOracle)]
+    #[storage_mapper("signalOracle")]
+    fn signal_oracle(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Timestamp a signal was fired at
+    #[view(getSignalFiredAt)]
+    #[storage_mapper("signalFiredAt")]
+    fn signal_fired_at(&self, signal_id: u64) -> SingleValueMapper<u64>;
+
+    #[view(getPriceOracle)]
+    #[storage_mapper("priceOracle")]
+    fn price_oracle(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Pairs conditions may reference (base, quote)
+    #[view(getPricePairs)]
+    #[storage_mapper("pricePairs")]
+    fn price_pairs(&self) -> UnorderedSetMapper<(TokenIdentifier, TokenIdentifier)>;
+
+    #[view(getPriceMaxAge)]
+    #[storage_mapper("priceMaxAge")]
+    fn price_max_age(&self) -> SingleValueMapper<u64>;
+
+    /// Latest oracle price of a registered pair
+    #[view(getOraclePrice)]
+    #[storage_mapper("oraclePrice")]
+    fn oracle_price(
+        &self,
+        base_token: &TokenIdentifier,
+        quote_token: &TokenIdentifier,
+    ) -> SingleValueMapper<OraclePrice<Self::Api>>;
+
+    /// Cross-pair price condition an order waits for
+    #[view(getOrderCondition)]
+    #[storage_mapper("orderCondition")]
+    fn order_condition(&self, order_id: u64) -> SingleValueMapper<PriceCondition<Self::Api>>;
+
+    /// Signal an order waits for
+    #[view(getOrderSignal)]
+    #[storage_mapper("orderSignal")]
+    fn order_signal(&self, order_id: u64) -> SingleValueMapper<u64>;
+}
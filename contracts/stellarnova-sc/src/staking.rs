@@ -0,0 +1,247 @@
+//! Staking Module for StellarNova
+//!
+//! Users lock the protocol token and earn a pro-rata share of protocol fees:
+//! `stakingFeeShareBps` of every fee accrued in any token is credited to the
+//! stakers of that moment (reward per staked unit, scaled by
+//! `REWARD_PER_SHARE_SCALE`) and claimed per token. Unstaked tokens unbond for
+//! `unbondEpochs` epochs before they can be withdrawn, so stake cannot be
+//! moved in and out around a large fee.
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::errors::*;
+use crate::limit_orders::MAX_BASIS_POINTS;
+
+/// Fixed-point scale of the per-token reward per staked unit
+pub const REWARD_PER_SHARE_SCALE: u64 = 1_000_000_000_000_000_000;
+
+/// Unstaked tokens waiting for their unbonding period
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct Unbonding<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,
+    pub unlock_epoch: u64,
+}
+
+#[multiversx_sc::module]
+pub trait StakingModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+{
+    /// Configure staking (owner only); the token cannot change once set
+    ///
+    /// # Arguments
+    /// * `fee_share_bps` - Share of every protocol fee paid to stakers
+    /// * `unbond_epochs` - Epochs between unstaking and withdrawing
+    #[only_owner]
+    #[endpoint(setStakingConfig)]
+    fn set_staking_config(&self, token: TokenIdentifier, fee_share_bps: u64, unbond_epochs: u64) {
+        require!(
+            self.staking_token().is_empty() || self.staking_token().get() == token,
+            ERROR_STAKING_TOKEN_LOCKED
+        );
+        require!(fee_share_bps <= MAX_BASIS_POINTS, ERROR_STAKING_SHARE_TOO_HIGH);
+
+        self.staking_token().set(&token);
+        self.staking_fee_share_bps().set(fee_share_bps);
+        self.unbond_epochs().set(unbond_epochs);
+        self.staking_config_changed_event(&token, fee_share_bps, unbond_epochs);
+    }
+
+    /// Stake the protocol tokens sent with the call
+    #[payable("*")]
+    #[endpoint(stake)]
+    fn stake(&self) {
+        require!(!self.staking_token().is_empty(), ERROR_STAKING_NOT_CONFIGURED);
+        let payment = self.call_value().single_esdt();
+        let token = self.staking_token().get();
+        require!(
+            payment.token_identifier == token && payment.token_nonce == 0,
+            ERROR_WRONG_STAKING_TOKEN
+        );
+        require!(payment.amount > 0u64, ERROR_INVALID_AMOUNT);
+
+        let caller = self.blockchain().get_caller();
+        self.settle_staking_rewards(&caller);
+        let amount = payment.amount.clone();
+        self.staked_amount(&caller).update(|staked| *staked += &amount);
+        self.total_staked().update(|total| *total += &amount);
+        self.staking_deposits(&token).update(|total| *total += &amount);
+        self.reset_reward_debt(&caller);
+
+        self.staked_event(&caller, &amount);
+    }
+
+    /// Stop earning on `amount` of the caller's stake; it can be withdrawn
+    /// after the unbonding period (a new unstake restarts it)
+    #[endpoint(unstake)]
+    fn unstake(&self, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        require!(amount > 0u64, ERROR_INVALID_AMOUNT);
+        require!(
+            amount <= self.staked_amount(&caller).get(),
+            ERROR_INSUFFICIENT_STAKE
+        );
+
+        self.settle_staking_rewards(&caller);
+        self.staked_amount(&caller).update(|staked| *staked -= &amount);
+        self.total_staked().update(|total| *total -= &amount);
+        self.reset_reward_debt(&caller);
+
+        let unlock_epoch = self.blockchain().get_block_epoch() + self.unbond_epochs().get();
+        let mapper = self.unbonding(&caller);
+        let unbonding_amount = if mapper.is_empty() {
+            amount.clone()
+        } else {
+            mapper.get().amount + &amount
+        };
+        mapper.set(Unbonding {
+            amount: unbonding_amount,
+            unlock_epoch,
+        });
+
+        self.unstaked_event(&caller, unlock_epoch, &amount);
+    }
+
+    /// Withdraw unstaked tokens once they finished unbonding
+    #[endpoint(withdrawUnstaked)]
+    fn withdraw_unstaked(&self) -> BigUint {
+        let caller = self.blockchain().get_caller();
+        let mapper = self.unbonding(&caller);
+        require!(!mapper.is_empty(), ERROR_NOTHING_UNBONDING);
+        let unbonding = mapper.get();
+        require!(
+            self.blockchain().get_block_epoch() >= unbonding.unlock_epoch,
+            ERROR_STILL_UNBONDING
+        );
+        mapper.clear();
+
+        let token = self.staking_token().get();
+        self.staking_deposits(&token)
+            .update(|total| *total -= &unbonding.amount);
+        self.send().direct_esdt(&caller, &token, 0, &unbonding.amount);
+        self.unstaked_withdrawn_event(&caller, &unbonding.amount);
+
+        unbonding.amount
+    }
+
+    /// Claim the caller's staking rewards in `token`
+    #[endpoint(claimStakingRewards)]
+    fn claim_staking_rewards(&self, token: TokenIdentifier) -> BigUint {
+        let caller = self.blockchain().get_caller();
+        self.settle_staking_rewards(&caller);
+        self.reset_reward_debt(&caller);
+
+        let amount = self.pending_staking_rewards(&caller, &token).take();
+        require!(amount > 0u64, ERROR_NO_STAKING_REWARDS);
+
+        self.staking_reward_pool(&token)
+            .update(|pool| *pool -= &amount);
+        self.send().direct_esdt(&caller, &token, 0, &amount);
+        self.staking_rewards_claimed_event(&caller, &token, &amount);
+
+        amount
+    }
+
+    /// Rewards in `token` the user can claim now
+    #[view(getClaimableStakingRewards)]
+    fn get_claimable_staking_rewards(&self, user: ManagedAddress, token: TokenIdentifier) -> BigUint {
+        self.pending_staking_rewards(&user, &token).get() + self.unsettled_rewards(&user, &token)
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    /// Credit `amount` of a protocol fee in `token` to the current stakers
+    /// Returns the part distributed (zero when staking is off or empty)
+    fn distribute_staking_rewards(&self, token: &TokenIdentifier, fee: &BigUint) -> BigUint {
+        let total_staked = self.total_staked().get();
+        if total_staked == 0u64 {
+            return BigUint::zero();
+        }
+        let share = fee * self.staking_fee_share_bps().get() / MAX_BASIS_POINTS;
+        if share == 0u64 {
+            return share;
+        }
+
+        self.staking_reward_tokens().insert(token.clone());
+        self.reward_per_share(token)
+            .update(|per_share| *per_share += &share * REWARD_PER_SHARE_SCALE / &total_staked);
+        self.staking_reward_pool(token)
+            .update(|pool| *pool += &share);
+        share
+    }
+
+    /// Rewards earned by the user's stake since its last settlement
+    fn unsettled_rewards(&self, user: &ManagedAddress, token: &TokenIdentifier) -> BigUint {
+        let earned =
+            self.staked_amount(user).get() * self.reward_per_share(token).get() / REWARD_PER_SHARE_SCALE;
+        earned - self.reward_debt(user, token).get()
+    }
+
+    /// Move rewards earned so far into the user's pending balances
+    fn settle_staking_rewards(&self, user: &ManagedAddress) {
+        for token in self.staking_reward_tokens().iter() {
+            let unsettled = self.unsettled_rewards(user, &token);
+            if unsettled > 0u64 {
+                self.pending_staking_rewards(user, &token)
+                    .update(|pending| *pending += unsettled);
+            }
+        }
+    }
+
+    /// Start counting rewards from now on, after a settlement
+    fn reset_reward_debt(&self, user: &ManagedAddress) {
+        let staked = self.staked_amount(user).get();
+        for token in self.staking_reward_tokens().iter() {
+            let debt = &staked * &self.reward_per_share(&token).get() / REWARD_PER_SHARE_SCALE;
+            self.reward_debt(user, &token).set(debt);
+        }
+    }
+
+    // ========== STORAGE ==========
+
+    #[view(getStakingToken)]
+    #[storage_mapper("stakingToken")]
+    fn staking_token(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    #[view(getStakingFeeShareBps)]
+    #[storage_mapper("stakingFeeShareBps")]
+    fn staking_fee_share_bps(&self) -> SingleValueMapper<u64>;
+
+    #[view(getUnbondEpochs)]
+    #[storage_mapper("unbondEpochs")]
+    fn unbond_epochs(&self) -> SingleValueMapper<u64>;
+
+    #[view(getStakedAmount)]
+    #[storage_mapper("stakedAmount")]
+    fn staked_amount(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalStaked)]
+    #[storage_mapper("totalStaked")]
+    fn total_staked(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getUnbonding)]
+    #[storage_mapper("unbonding")]
+    fn unbonding(&self, user: &ManagedAddress) -> SingleValueMapper<Unbonding<Self::Api>>;
+
+    /// Tokens stakers ever earned rewards in
+    #[view(getStakingRewardTokens)]
+    #[storage_mapper("stakingRewardTokens")]
+    fn staking_reward_tokens(&self) -> UnorderedSetMapper<TokenIdentifier>;
+
+    /// Accumulated reward per staked unit, scaled by REWARD_PER_SHARE_SCALE
+    #[view(getRewardPerShare)]
+    #[storage_mapper("rewardPerShare")]
+    fn reward_per_share(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("rewardDebt")]
+    fn reward_debt(&self, user: &ManagedAddress, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("pendingStakingRewards")]
+    fn pending_staking_rewards(
+        &self,
+        user: &ManagedAddress,
+        token: &TokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+}
@@ -0,0 +1,278 @@
+//! Statistics Module for StellarNova
+//!
+//! On-chain counters kept up to date by the order paths, so the UI can show
+//! account history and the AI layer can personalize strategies without an
+//! indexer. Volume is the input an account sold (filled from escrow), fees
+//! are everything it paid: execution, protocol and cancellation fees.
+//!
+//! The same counters are kept per pair (from token, to token), so the
+//! operator can judge from on-chain data which pairs are worth listing, and
+//! protocol wide for `getProtocolStats`.
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::limit_orders::{LimitOrder, MAX_BASIS_POINTS};
+
+/// Order counters of one account
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Default, PartialEq, Debug)]
+pub struct UserStats {
+    pub orders_created: u64,
+    pub orders_executed: u64,
+    pub orders_cancelled: u64,
+    pub orders_expired: u64,
+}
+
+/// Protocol-wide totals for a single stats call; volume and fees are listed
+/// per token
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct ProtocolStats<M: ManagedTypeApi> {
+    pub orders_created: u64,
+    pub orders_executed: u64,
+    pub open_orders: u64,
+    pub volume: ManagedVec<M, EsdtTokenPayment<M>>,
+    pub fees: ManagedVec<M, EsdtTokenPayment<M>>,
+}
+
+/// Order lifecycle steps counted by the statistics
+#[derive(Clone, Copy)]
+pub enum OrderCount {
+    Created,
+    Executed,
+    Cancelled,
+    Expired,
+}
+
+impl UserStats {
+    fn count(&mut self, step: OrderCount) {
+        match step {
+            OrderCount::Created => self.orders_created += 1,
+            OrderCount::Executed => self.orders_executed += 1,
+            OrderCount::Cancelled => self.orders_cancelled += 1,
+            OrderCount::Expired => self.orders_expired += 1,
+        }
+    }
+}
+
+/// Order counters and executed volume (in the from token) of one pair
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct PairStats<M: ManagedTypeApi> {
+    pub orders_created: u64,
+    pub orders_executed: u64,
+    pub orders_cancelled: u64,
+    pub orders_expired: u64,
+    pub fill_count: u64,
+    pub volume: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> PairStats<M> {
+    fn count(&mut self, step: OrderCount) {
+        match step {
+            OrderCount::Created => self.orders_created += 1,
+            OrderCount::Executed => self.orders_executed += 1,
+            OrderCount::Cancelled => self.orders_cancelled += 1,
+            OrderCount::Expired => self.orders_expired += 1,
+        }
+    }
+}
+
+/// Ratios derived from the pair counters; rates are in bps of the closed
+/// (executed, cancelled or expired) orders
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct PairAnalytics<M: ManagedTypeApi> {
+    pub volume: BigUint<M>,
+    pub average_fill_size: BigUint<M>,
+    pub fill_rate_bps: u64,
+    pub expiry_rate_bps: u64,
+}
+
+#[multiversx_sc::module]
+pub trait StatsModule: crate::storage::StorageModule {
+    /// Order counters of `user`
+    #[view(getUserStats)]
+    fn get_user_stats(&self, user: ManagedAddress) -> UserStats {
+        self.load_user_stats(&user)
+    }
+
+    /// Order counters and executed volume of orders selling `from_token`
+    /// for `to_token`
+    #[view(getPairStats)]
+    fn get_pair_stats(
+        &self,
+        from_token: TokenIdentifier,
+        to_token: TokenIdentifier,
+    ) -> PairStats<Self::Api> {
+        self.load_pair_stats(&from_token, &to_token)
+    }
+
+    /// Executed volume, average fill size, fill rate and expiry rate of a pair
+    #[view(getPairAnalytics)]
+    fn get_pair_analytics(
+        &self,
+        from_token: TokenIdentifier,
+        to_token: TokenIdentifier,
+    ) -> PairAnalytics<Self::Api> {
+        let stats = self.load_pair_stats(&from_token, &to_token);
+
+        let average_fill_size = if stats.fill_count == 0 {
+            BigUint::zero()
+        } else {
+            &stats.volume / stats.fill_count
+        };
+        let closed = stats.orders_executed + stats.orders_cancelled + stats.orders_expired;
+        let rate_bps = |count: u64| (count * MAX_BASIS_POINTS).checked_div(closed).unwrap_or(0);
+
+        PairAnalytics {
+            fill_rate_bps: rate_bps(stats.orders_executed),
+            expiry_rate_bps: rate_bps(stats.orders_expired),
+            volume: stats.volume,
+            average_fill_size,
+        }
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    /// Non-zero volume and fee totals per token
+    fn protocol_totals(
+        &self,
+    ) -> (
+        ManagedVec<EsdtTokenPayment<Self::Api>>,
+        ManagedVec<EsdtTokenPayment<Self::Api>>,
+    ) {
+        let mut volume = ManagedVec::new();
+        let mut fees = ManagedVec::new();
+        for token in self.stats_tokens().iter() {
+            let token_volume = self.total_volume(&token).get();
+            if token_volume > 0u64 {
+                volume.push(EsdtTokenPayment::new(token.clone(), 0, token_volume));
+            }
+            let token_fees = self.total_fees_paid(&token).get();
+            if token_fees > 0u64 {
+                fees.push(EsdtTokenPayment::new(token, 0, token_fees));
+            }
+        }
+        (volume, fees)
+    }
+
+    fn load_user_stats(&self, user: &ManagedAddress) -> UserStats {
+        if self.user_stats(user).is_empty() {
+            UserStats::default()
+        } else {
+            self.user_stats(user).get()
+        }
+    }
+
+    fn load_pair_stats(
+        &self,
+        from_token: &TokenIdentifier,
+        to_token: &TokenIdentifier,
+    ) -> PairStats<Self::Api> {
+        if self.pair_stats(from_token, to_token).is_empty() {
+            PairStats {
+                orders_created: 0,
+                orders_executed: 0,
+                orders_cancelled: 0,
+                orders_expired: 0,
+                fill_count: 0,
+                volume: BigUint::zero(),
+            }
+        } else {
+            self.pair_stats(from_token, to_token).get()
+        }
+    }
+
+    /// Count a lifecycle step for the order's owner and pair
+    fn count_order(&self, order: &LimitOrder<Self::Api>, step: OrderCount) {
+        let mut stats = self.load_user_stats(&order.user);
+        stats.count(step);
+        self.user_stats(&order.user).set(&stats);
+
+        let mut pair = self.load_pair_stats(&order.from_token, &order.to_token);
+        pair.count(step);
+        self.pair_stats(&order.from_token, &order.to_token).set(&pair);
+
+        if let OrderCount::Executed = step {
+            self.total_orders_executed().update(|count| *count += 1);
+        }
+    }
+
+    fn record_order_created(&self, order: &LimitOrder<Self::Api>) {
+        self.count_order(order, OrderCount::Created);
+    }
+
+    /// Count `fill_in` as sold, and the order as executed once it is fully
+    /// filled
+    fn record_order_fill(&self, order: &LimitOrder<Self::Api>, fill_in: &BigUint, completed: bool) {
+        self.user_volume(&order.user, &order.from_token)
+            .update(|volume| *volume += fill_in);
+        let mut pair = self.load_pair_stats(&order.from_token, &order.to_token);
+        pair.fill_count += 1;
+        pair.volume += fill_in;
+        self.pair_stats(&order.from_token, &order.to_token).set(&pair);
+        self.stats_tokens().insert(order.from_token.clone());
+        self.total_volume(&order.from_token)
+            .update(|volume| *volume += fill_in);
+
+        if completed {
+            self.count_order(order, OrderCount::Executed);
+        }
+    }
+
+    fn record_fees_paid(&self, user: &ManagedAddress, token: &TokenIdentifier, fees: &BigUint) {
+        if *fees > 0u64 {
+            self.user_fees_paid(user, token)
+                .update(|paid| *paid += fees);
+            self.stats_tokens().insert(token.clone());
+            self.total_fees_paid(token)
+                .update(|paid| *paid += fees);
+        }
+    }
+
+    fn record_order_cancelled(&self, order: &LimitOrder<Self::Api>) {
+        self.count_order(order, OrderCount::Cancelled);
+    }
+
+    fn record_order_expired(&self, order: &LimitOrder<Self::Api>) {
+        self.count_order(order, OrderCount::Expired);
+    }
+
+    // ========== STORAGE ==========
+
+    #[storage_mapper("userStats")]
+    fn user_stats(&self, user: &ManagedAddress) -> SingleValueMapper<UserStats>;
+
+    /// Input of `token` the user sold through executed fills
+    #[view(getUserVolume)]
+    #[storage_mapper("userVolume")]
+    fn user_volume(&self, user: &ManagedAddress, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Fees in `token` the user paid
+    #[view(getUserFeesPaid)]
+    #[storage_mapper("userFeesPaid")]
+    fn user_fees_paid(&self, user: &ManagedAddress, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("pairStats")]
+    fn pair_stats(
+        &self,
+        from_token: &TokenIdentifier,
+        to_token: &TokenIdentifier,
+    ) -> SingleValueMapper<PairStats<Self::Api>>;
+
+    /// Orders fully executed since statistics were introduced
+    #[storage_mapper("totalOrdersExecuted")]
+    fn total_orders_executed(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("totalVolume")]
+    fn total_volume(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("totalFeesPaid")]
+    fn total_fees_paid(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Tokens with a recorded volume or fee
+    #[storage_mapper("statsTokens")]
+    fn stats_tokens(&self) -> UnorderedSetMapper<TokenIdentifier>;
+}
@@ -0,0 +1,6023 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct StellarNovaProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for StellarNovaProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = StellarNovaProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        StellarNovaProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct StellarNovaProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> StellarNovaProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Initialize the contract 
+    ///  
+    /// # Arguments 
+    /// * `max_slippage_bp` - Maximum slippage in basis points (e.g., 500 = 5%) 
+    /// * `initial_tokens` - List of tokens to whitelist (WEGLD, USDC) 
+    pub fn init<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, EsdtTokenIdentifier<Env::Api>>>,
+    >(
+        self,
+        max_slippage_bp: Arg0,
+        initial_tokens: Arg1,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .argument(&max_slippage_bp)
+            .argument(&initial_tokens)
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> StellarNovaProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Upgrade contract code 
+    /// Allows owner to upgrade contract without redeployment 
+    /// Preserves all storage (user balances, orders, etc.) 
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> StellarNovaProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    /// Add token to whitelist 
+    pub fn whitelist_token<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("whitelistToken")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Configure zap orders: LP token of the xExchange pair and the pair's 
+    /// first token, which decides the addLiquidity payment order 
+    pub fn set_zap_config<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        lp_token: Arg0,
+        first_token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setZapConfig")
+            .argument(&lp_token)
+            .argument(&first_token)
+            .original_result()
+    }
+
+    /// Remove token from whitelist 
+    /// Pending orders on the token can no longer execute but stay cancellable 
+    pub fn remove_token<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeToken")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Update maximum slippage tolerance 
+    /// Capped at 10000 bps (100%) so min-output math can never underflow 
+    pub fn set_max_slippage<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        max_slippage_bp: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxSlippage")
+            .argument(&max_slippage_bp)
+            .original_result()
+    }
+
+    /// Set xExchange pair address (owner only) 
+    /// For WEGLD/USDC pair: erd1qqqqqqqqqqqqqpgqeel2kumf0r8ffyhth7pqdujjat9nx0862jpsg2pqaq 
+    pub fn set_xexchange_pair<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        pair: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setXExchangePair")
+            .argument(&pair)
+            .original_result()
+    }
+
+    /// Set the pair that swaps `token`, overriding the default pair for 
+    /// orders on it (owner only) 
+    /// Activates orders created while the token was awaiting its pair 
+    pub fn set_token_pair<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+        pair: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTokenPair")
+            .argument(&token)
+            .argument(&pair)
+            .original_result()
+    }
+
+    /// Call `pair`'s swap as `function(arguments...)` instead of 
+    /// `swapTokensFixedInput(tokenOut, minAmountOut)` (owner only) 
+    /// An empty `function` restores the default 
+    pub fn set_swap_descriptor<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg2: ProxyArg<MultiValueEncoded<Env::Api, SwapArgument>>,
+    >(
+        self,
+        pair: Arg0,
+        function: Arg1,
+        arguments: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setSwapDescriptor")
+            .argument(&pair)
+            .argument(&function)
+            .argument(&arguments)
+            .original_result()
+    }
+
+    /// Whitelist a new token and route its orders to `pair` (owner only) 
+    pub fn list_token<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+        pair: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("listToken")
+            .argument(&token)
+            .argument(&pair)
+            .original_result()
+    }
+
+    /// Set the xExchange router executing routed orders (owner only) 
+    pub fn set_xexchange_router<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        router: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setXExchangeRouter")
+            .argument(&router)
+            .original_result()
+    }
+
+    /// Allow (or stop allowing) orders to forward their output to 
+    /// `destination` (owner only) 
+    pub fn set_forward_destination<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        destination: Arg0,
+        allowed: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setForwardDestination")
+            .argument(&destination)
+            .argument(&allowed)
+            .original_result()
+    }
+
+    /// Set executor address (owner only) 
+    pub fn set_limit_order_executor<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        executor: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setLimitOrderExecutor")
+            .argument(&executor)
+            .original_result()
+    }
+
+    /// Set execution fee in basis points (owner only) 
+    /// Example: 10 = 0.1%, 50 = 0.5%, 100 = 1% 
+    pub fn set_execution_fee_bps<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        fee_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setExecutionFeeBps")
+            .argument(&fee_bps)
+            .original_result()
+    }
+
+    /// Set the minimum execution fee of fills paying out `token`, in its 
+    /// base units (owner only, 0 removes it) 
+    pub fn set_execution_fee_floor<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+        min_fee: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setExecutionFeeFloor")
+            .argument(&token)
+            .argument(&min_fee)
+            .original_result()
+    }
+
+    /// Split the output of fills above their target price: `executor_bps` 
+    /// to the executor, `treasury_bps` to the treasury, the rest to the 
+    /// order owner (owner only) 
+    pub fn set_price_improvement_sharing<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        executor_bps: Arg0,
+        treasury_bps: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPriceImprovementSharing")
+            .argument(&executor_bps)
+            .argument(&treasury_bps)
+            .original_result()
+    }
+
+    /// Set protocol fee in basis points, accrued for the treasury (owner only) 
+    pub fn set_protocol_fee_bps<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        fee_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setProtocolFeeBps")
+            .argument(&fee_bps)
+            .original_result()
+    }
+
+    /// Set the address receiving claimed protocol fees (owner only) 
+    pub fn set_treasury_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        treasury: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTreasuryAddress")
+            .argument(&treasury)
+            .original_result()
+    }
+
+    /// Set the anti-spoofing cancellation policy (owner only) 
+    /// Orders younger than `window_seconds` either cannot be cancelled 
+    /// (`fee_bps` = 0) or pay `fee_bps` of the refund as a protocol fee 
+    pub fn set_cancellation_policy<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        window_seconds: Arg0,
+        fee_bps: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setCancellationPolicy")
+            .argument(&window_seconds)
+            .argument(&fee_bps)
+            .original_result()
+    }
+
+    /// Set the lending market idle escrow of `token` can be supplied to 
+    /// (owner only); the zero address disables lending for the token 
+    pub fn set_lending_market<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+        market: Arg1,
+        share_token: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setLendingMarket")
+            .argument(&token)
+            .argument(&market)
+            .argument(&share_token)
+            .original_result()
+    }
+
+    /// Choose who keeps interest earned on lent escrow (owner only): 
+    /// the order (`true`) or the protocol fees (`false`) 
+    pub fn set_lending_interest_to_user<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        to_user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setLendingInterestToUser")
+            .argument(&to_user)
+            .original_result()
+    }
+
+    /// Pay `amount` of the insurance fund to `user` after an incident 
+    /// (owner only); capped per token by the compensation cap 
+    pub fn compensate<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+        token: Arg1,
+        amount: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("compensate")
+            .argument(&user)
+            .argument(&token)
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Queue a sweep of the surplus balance of `token` (above escrow and 
+    /// protocol pools, see `reconcile`) to the treasury (owner only) 
+    /// Waits at least 24 hours like emergency withdrawals 
+    pub fn sweep_surplus<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("sweepSurplus")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Queue withdrawal of stray tokens (owner only) 
+    /// Only the balance above the tracked escrow total can be withdrawn, 
+    /// and the action always waits at least 24 hours 
+    pub fn emergency_withdraw<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("emergencyWithdraw")
+            .argument(&token)
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Check if token is whitelisted 
+    pub fn is_token_whitelisted<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isTokenWhitelisted")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Whitelisted tokens that can be traded 
+    /// Only these tokens are allowed in the system 
+    pub fn whitelisted_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EsdtTokenIdentifier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getWhitelistedTokens")
+            .original_result()
+    }
+
+    /// xExchange WEGLD/USDC pair address for direct swaps 
+    /// Address: erd1qqqqqqqqqqqqqpgqeel2kumf0r8ffyhth7pqdujjat9nx0862jpsg2pqaq 
+    /// IMPORTANT: Deploy contract to Shard 1 (same as this pair) for sync calls! 
+    pub fn xexchange_pair(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getXExchangePair")
+            .original_result()
+    }
+
+    /// Pair that swaps a token, overriding the default pair for its orders 
+    pub fn token_pair<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTokenPair")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// xExchange router executing the two legs of routed orders in one call 
+    pub fn xexchange_router(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getXExchangeRouter")
+            .original_result()
+    }
+
+    /// Version of the deployed code, set by init and upgrade 
+    pub fn contract_version(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getContractVersion")
+            .original_result()
+    }
+
+    /// Contract owner (for admin functions) 
+    pub fn owner(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOwner")
+            .original_result()
+    }
+
+    /// Maximum slippage tolerance (basis points, e.g., 500 = 5%) 
+    pub fn max_slippage(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxSlippage")
+            .original_result()
+    }
+
+    /// Total amount of each token currently escrowed by pending orders 
+    pub fn escrow_balance<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEscrowBalance")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Protocol pool paying bounties to expired-order sweepers 
+    pub fn expiry_bounty_pool<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getExpiryBountyPool")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Protocol fees accrued per token, waiting to be claimed by the treasury 
+    pub fn protocol_fees<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProtocolFees")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Total deposited credit per token, not yet escrowed or withdrawn 
+    pub fn total_credit<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalCredit")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Total payouts held for position NFT holders per token 
+    pub fn position_held_balance<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPositionHeldBalance")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Total deposits backing open RFQ quotes per token 
+    pub fn quote_deposits<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getQuoteDeposits")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Streamed swap output not claimed yet per token 
+    pub fn streamed_balance<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStreamedBalance")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Governance tokens locked in proposal votes 
+    pub fn governance_deposits<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGovernanceDeposits")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Staked and unbonding protocol tokens 
+    pub fn staking_deposits<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStakingDeposits")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Protocol fees credited to stakers and not claimed yet per token 
+    pub fn staking_reward_pool<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStakingRewardPool")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Protocol fees credited to clients and not claimed yet per token 
+    pub fn client_fee_balance<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClientFeeBalance")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Execution fee slices kept to compensate users after incidents 
+    pub fn insurance_fund<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getInsuranceFund")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Rebate pools of all strategies per token 
+    pub fn strategy_rebate_deposits<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStrategyRebateDeposits")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Tokens paying the sponsored part of small fills' execution fees 
+    pub fn gas_station_pool<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGasStationPool")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Escrow principal currently supplied to lending markets per token 
+    pub fn lent_escrow<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLentEscrow")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Escrow sent to a pair by a swap or zap liquidity call whose 
+    /// callback has not come back yet, per token 
+    pub fn escrow_in_flight<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEscrowInFlight")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Lending market share tokens held for lent escrow 
+    pub fn lending_shares<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        share_token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLendingShares")
+            .argument(&share_token)
+            .original_result()
+    }
+
+    /// Execution fee (in bps, e.g., 10 = 0.1%) 
+    /// Bot gets this percentage of output tokens as reward 
+    pub fn execution_fee_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getExecutionFeeBps")
+            .original_result()
+    }
+
+    /// Minimum execution fee per output token, in its base units, for fills 
+    /// whose bps fee would be lower (0 = none) 
+    pub fn execution_fee_floor<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getExecutionFeeFloor")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Share (in bps) of a fill's output above the target price paid to the executor 
+    pub fn improvement_executor_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getImprovementExecutorBps")
+            .original_result()
+    }
+
+    /// Share (in bps) of a fill's output above the target price accrued for the treasury 
+    pub fn improvement_treasury_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getImprovementTreasuryBps")
+            .original_result()
+    }
+
+    /// Protocol fee (in bps) taken from output tokens for the treasury 
+    pub fn protocol_fee_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProtocolFeeBps")
+            .original_result()
+    }
+
+    /// Lending market (money market address, share token) per underlying token 
+    pub fn lending_market<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, (ManagedAddress<Env::Api>, EsdtTokenIdentifier<Env::Api>)> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLendingMarket")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Whether interest on lent escrow goes to the order (true) or to 
+    /// the protocol fees (false) 
+    pub fn lending_interest_to_user(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLendingInterestToUser")
+            .original_result()
+    }
+
+    /// LP token of the xExchange pair, delivered by zap orders 
+    pub fn zap_lp_token(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getZapLpToken")
+            .original_result()
+    }
+
+    /// First token of the xExchange pair (addLiquidity payment order) 
+    pub fn zap_first_token(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getZapFirstToken")
+            .original_result()
+    }
+
+    /// Seconds after creation during which cancelling is restricted (0 = off) 
+    pub fn cancellation_window(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCancellationWindow")
+            .original_result()
+    }
+
+    /// Fee (in bps of the refund) for cancelling inside the window; 
+    /// 0 means such cancellations are rejected instead 
+    pub fn cancellation_fee_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCancellationFeeBps")
+            .original_result()
+    }
+
+    /// Receiver of claimed protocol fees (owner when unset) 
+    pub fn treasury_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTreasuryAddress")
+            .original_result()
+    }
+
+    /// Require the pair to be active and to hold at least `multiple` times the 
+    /// fill size of the input token before swapping (owner only, 0 disables) 
+    pub fn set_pair_health_check<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        min_reserve_multiple: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPairHealthCheck")
+            .argument(&min_reserve_multiple)
+            .original_result()
+    }
+
+    /// Choose how the swap's minimum output is derived (owner only) 
+    pub fn set_min_output_mode<
+        Arg0: ProxyArg<MinOutputMode>,
+    >(
+        self,
+        mode: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMinOutputMode")
+            .argument(&mode)
+            .original_result()
+    }
+
+    /// Pair that swaps `from_token` for `to_token` 
+    pub fn swap_pair<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        from_token: Arg0,
+        to_token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSwapPair")
+            .argument(&from_token)
+            .argument(&to_token)
+            .original_result()
+    }
+
+    /// How swaps on `pair` are called (the xExchange layout by default) 
+    pub fn swap_call_descriptor<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        pair: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, SwapCallDescriptor<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSwapDescriptor")
+            .argument(&pair)
+            .original_result()
+    }
+
+    /// Quote swapping `amount_in` of the first token of `route` along it 
+    /// Returns the output of every hop, the last one being the route's output 
+    pub fn quote_route<
+        Arg0: ProxyArg<ManagedVec<Env::Api, EsdtTokenIdentifier<Env::Api>>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        route: Arg0,
+        amount_in: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("quoteRoute")
+            .argument(&route)
+            .argument(&amount_in)
+            .original_result()
+    }
+
+    /// Minimum pair reserve of the input token, as a multiple of the fill 
+    pub fn min_reserve_multiple(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinReserveMultiple")
+            .original_result()
+    }
+
+    /// Source of the swap's minimum output (empty storage decodes as TargetPrice) 
+    pub fn min_output_mode(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MinOutputMode> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinOutputMode")
+            .original_result()
+    }
+
+    /// Create a limit order with ESDT payment (JEXchange style) 
+    ///  
+    /// User sends tokens directly, contract holds them until execution/cancellation 
+    ///  
+    /// # Payment 
+    /// User must send the tokens they want to sell 
+    ///  
+    /// # Arguments 
+    /// * `to_token` - Token to buy 
+    /// * `target_price_num` - Target price numerator 
+    /// * `target_price_denom` - Target price denominator 
+    /// * `slippage_bp` - Slippage tolerance in basis points 
+    /// * `expires_in_seconds` - How long until order expires 
+    /// * `opt_strategy_id` - Optional strategy to tag the order with (0 = none) 
+    /// * `opt_client_tag` - Optional 32-byte client metadata hash 
+    pub fn create_limit_order<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<u64>,
+        Arg5: ProxyArg<OptionalValue<u64>>,
+        Arg6: ProxyArg<OptionalValue<ManagedByteArray<Env::Api, 32usize>>>,
+    >(
+        self,
+        to_token: Arg0,
+        target_price_num: Arg1,
+        target_price_denom: Arg2,
+        slippage_bp: Arg3,
+        expires_in_seconds: Arg4,
+        opt_strategy_id: Arg5,
+        opt_client_tag: Arg6,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("createLimitOrder")
+            .argument(&to_token)
+            .argument(&target_price_num)
+            .argument(&target_price_denom)
+            .argument(&slippage_bp)
+            .argument(&expires_in_seconds)
+            .argument(&opt_strategy_id)
+            .argument(&opt_client_tag)
+            .original_result()
+    }
+
+    /// Create a limit order priced with a single scaled price 
+    ///  
+    /// Same as `createLimitOrder`, but the price is given as whole `to_token` 
+    /// per whole payment token, scaled by 1e18; token decimals come from the 
+    /// on-chain registry (`getTokenDecimals`) 
+    ///  
+    /// # Arguments 
+    /// * `to_token` - Token to buy 
+    /// * `scaled_price` - Target price, scaled by 1e18 
+    /// * `slippage_bp` - Slippage tolerance in basis points 
+    /// * `expires_in_seconds` - How long until order expires 
+    /// * `opt_strategy_id` - Optional strategy to tag the order with (0 = none) 
+    /// * `opt_client_tag` - Optional 32-byte client metadata hash 
+    pub fn create_limit_order_scaled<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<OptionalValue<u64>>,
+        Arg5: ProxyArg<OptionalValue<ManagedByteArray<Env::Api, 32usize>>>,
+    >(
+        self,
+        to_token: Arg0,
+        scaled_price: Arg1,
+        slippage_bp: Arg2,
+        expires_in_seconds: Arg3,
+        opt_strategy_id: Arg4,
+        opt_client_tag: Arg5,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("createLimitOrderScaled")
+            .argument(&to_token)
+            .argument(&scaled_price)
+            .argument(&slippage_bp)
+            .argument(&expires_in_seconds)
+            .argument(&opt_strategy_id)
+            .argument(&opt_client_tag)
+            .original_result()
+    }
+
+    /// Create a limit order represented by a transferable position NFT 
+    ///  
+    /// Same as `createLimitOrder`, but the caller receives a position NFT; 
+    /// its holder controls the order and claims its payouts 
+    ///  
+    /// # Arguments 
+    /// * `to_token` - Token to buy 
+    /// * `target_price_num` - Target price numerator 
+    /// * `target_price_denom` - Target price denominator 
+    /// * `slippage_bp` - Slippage tolerance in basis points 
+    /// * `expires_in_seconds` - How long until order expires 
+    /// * `opt_strategy_id` - Optional strategy to tag the order with (0 = none) 
+    /// * `opt_client_tag` - Optional 32-byte client metadata hash 
+    pub fn create_limit_order_with_position<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<u64>,
+        Arg5: ProxyArg<OptionalValue<u64>>,
+        Arg6: ProxyArg<OptionalValue<ManagedByteArray<Env::Api, 32usize>>>,
+    >(
+        self,
+        to_token: Arg0,
+        target_price_num: Arg1,
+        target_price_denom: Arg2,
+        slippage_bp: Arg3,
+        expires_in_seconds: Arg4,
+        opt_strategy_id: Arg5,
+        opt_client_tag: Arg6,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("createLimitOrderWithPosition")
+            .argument(&to_token)
+            .argument(&target_price_num)
+            .argument(&target_price_denom)
+            .argument(&slippage_bp)
+            .argument(&expires_in_seconds)
+            .argument(&opt_strategy_id)
+            .argument(&opt_client_tag)
+            .original_result()
+    }
+
+    /// Create a limit order that is only executable after `signal_id` fired 
+    ///  
+    /// A signal that already fired does not hold the order back, it is 
+    /// executable right away 
+    ///  
+    /// # Arguments 
+    /// * `signal_id` - Signal the order waits for (see `fireSignal`) 
+    /// * `to_token` - Token to buy 
+    /// * `target_price_num` - Target price numerator 
+    /// * `target_price_denom` - Target price denominator 
+    /// * `slippage_bp` - Slippage tolerance in basis points 
+    /// * `expires_in_seconds` - How long until order expires 
+    pub fn create_signal_order<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<u64>,
+        Arg5: ProxyArg<u64>,
+    >(
+        self,
+        signal_id: Arg0,
+        to_token: Arg1,
+        target_price_num: Arg2,
+        target_price_denom: Arg3,
+        slippage_bp: Arg4,
+        expires_in_seconds: Arg5,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("createSignalOrder")
+            .argument(&signal_id)
+            .argument(&to_token)
+            .argument(&target_price_num)
+            .argument(&target_price_denom)
+            .argument(&slippage_bp)
+            .argument(&expires_in_seconds)
+            .original_result()
+    }
+
+    /// Create a limit order attributed to a registered client (integrator) 
+    ///  
+    /// The client's volume counts the order's fills and it earns 
+    /// `clientFeeShareBps` of the protocol fees they pay 
+    ///  
+    /// # Arguments 
+    /// * `client_id` - Client the order is created through (see `registerClient`) 
+    /// * `to_token` - Token to buy 
+    /// * `target_price_num` - Target price numerator 
+    /// * `target_price_denom` - Target price denominator 
+    /// * `slippage_bp` - Slippage tolerance in basis points 
+    /// * `expires_in_seconds` - How long until order expires 
+    /// * `opt_strategy_id` - Optional strategy to tag the order with (0 = none) 
+    /// * `opt_client_tag` - Optional 32-byte client metadata hash 
+    pub fn create_client_order<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<u64>,
+        Arg5: ProxyArg<u64>,
+        Arg6: ProxyArg<OptionalValue<u64>>,
+        Arg7: ProxyArg<OptionalValue<ManagedByteArray<Env::Api, 32usize>>>,
+    >(
+        self,
+        client_id: Arg0,
+        to_token: Arg1,
+        target_price_num: Arg2,
+        target_price_denom: Arg3,
+        slippage_bp: Arg4,
+        expires_in_seconds: Arg5,
+        opt_strategy_id: Arg6,
+        opt_client_tag: Arg7,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("createClientOrder")
+            .argument(&client_id)
+            .argument(&to_token)
+            .argument(&target_price_num)
+            .argument(&target_price_denom)
+            .argument(&slippage_bp)
+            .argument(&expires_in_seconds)
+            .argument(&opt_strategy_id)
+            .argument(&opt_client_tag)
+            .original_result()
+    }
+
+    /// Create a limit order carrying the intent of the agent placing it 
+    ///  
+    /// The intent is stored with the order and emitted, so fills can be 
+    /// attributed to the model version behind them; its strategy (if not 0) 
+    /// tags the order like `opt_strategy_id` of `createLimitOrder` 
+    ///  
+    /// # Arguments 
+    /// * `to_token` - Token to buy 
+    /// * `target_price_num` - Target price numerator 
+    /// * `target_price_denom` - Target price denominator 
+    /// * `slippage_bp` - Slippage tolerance in basis points 
+    /// * `expires_in_seconds` - How long until order expires 
+    /// * `intent` - Strategy, confidence (bps) and model version hash 
+    /// * `opt_client_tag` - Optional 32-byte client metadata hash 
+    pub fn create_limit_order_with_intent<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<u64>,
+        Arg5: ProxyArg<OrderIntent<Env::Api>>,
+        Arg6: ProxyArg<OptionalValue<ManagedByteArray<Env::Api, 32usize>>>,
+    >(
+        self,
+        to_token: Arg0,
+        target_price_num: Arg1,
+        target_price_denom: Arg2,
+        slippage_bp: Arg3,
+        expires_in_seconds: Arg4,
+        intent: Arg5,
+        opt_client_tag: Arg6,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("createLimitOrderWithIntent")
+            .argument(&to_token)
+            .argument(&target_price_num)
+            .argument(&target_price_denom)
+            .argument(&slippage_bp)
+            .argument(&expires_in_seconds)
+            .argument(&intent)
+            .argument(&opt_client_tag)
+            .original_result()
+    }
+
+    /// Create a limit order under a client-chosen reference, unique per user 
+    ///  
+    /// A retried creation with a reference the caller already used fails 
+    /// instead of creating a second order, so a client unsure whether its 
+    /// transaction went through can safely send it again; the order is 
+    /// found with `getOrderByReference`. References stay used after the 
+    /// order is settled 
+    ///  
+    /// # Arguments 
+    /// * `reference` - Client reference, e.g. a request counter 
+    /// * `to_token` - Token to buy 
+    /// * `target_price_num` - Target price numerator 
+    /// * `target_price_denom` - Target price denominator 
+    /// * `slippage_bp` - Slippage tolerance in basis points 
+    /// * `expires_in_seconds` - How long until order expires 
+    /// * `opt_strategy_id` - Optional strategy to tag the order with (0 = none) 
+    /// * `opt_client_tag` - Optional 32-byte client metadata hash 
+    pub fn create_limit_order_with_reference<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<u64>,
+        Arg5: ProxyArg<u64>,
+        Arg6: ProxyArg<OptionalValue<u64>>,
+        Arg7: ProxyArg<OptionalValue<ManagedByteArray<Env::Api, 32usize>>>,
+    >(
+        self,
+        reference: Arg0,
+        to_token: Arg1,
+        target_price_num: Arg2,
+        target_price_denom: Arg3,
+        slippage_bp: Arg4,
+        expires_in_seconds: Arg5,
+        opt_strategy_id: Arg6,
+        opt_client_tag: Arg7,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("createLimitOrderWithReference")
+            .argument(&reference)
+            .argument(&to_token)
+            .argument(&target_price_num)
+            .argument(&target_price_denom)
+            .argument(&slippage_bp)
+            .argument(&expires_in_seconds)
+            .argument(&opt_strategy_id)
+            .argument(&opt_client_tag)
+            .original_result()
+    }
+
+    /// Create a routed order swapping the payment to `to_token` through 
+    /// `via_token`, both legs executed atomically by the swap router 
+    ///  
+    /// The target price and slippage apply to the final output only; the 
+    /// first leg accepts any amount of `via_token` 
+    ///  
+    /// # Arguments 
+    /// * `via_token` - Intermediate token of the route 
+    /// * `to_token` - Token to buy 
+    /// * `target_price_num` - Target price numerator 
+    /// * `target_price_denom` - Target price denominator 
+    /// * `slippage_bp` - Slippage tolerance in basis points 
+    /// * `expires_in_seconds` - How long until order expires 
+    pub fn create_routed_order<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<u64>,
+        Arg5: ProxyArg<u64>,
+    >(
+        self,
+        via_token: Arg0,
+        to_token: Arg1,
+        target_price_num: Arg2,
+        target_price_denom: Arg3,
+        slippage_bp: Arg4,
+        expires_in_seconds: Arg5,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("createRoutedOrder")
+            .argument(&via_token)
+            .argument(&to_token)
+            .argument(&target_price_num)
+            .argument(&target_price_denom)
+            .argument(&slippage_bp)
+            .argument(&expires_in_seconds)
+            .original_result()
+    }
+
+    /// Create an order chained to a pending order of the caller: it stays 
+    /// Dormant until the parent executes, then the parent's output becomes 
+    /// its escrow and it turns Pending (e.g. buy WEGLD, then sell it at a 
+    /// take-profit price) 
+    ///  
+    /// Every later fill of the parent adds its output to the chained order 
+    /// while that order is pending. The lifetime starts at activation; 
+    /// cancelling or expiring the parent before any fill cancels it 
+    ///  
+    /// # Arguments 
+    /// * `parent_order_id` - Order whose output funds the chained order 
+    /// * `to_token` - Token to buy with the parent's output 
+    /// * `target_price_num` - Target price numerator 
+    /// * `target_price_denom` - Target price denominator 
+    /// * `slippage_bp` - Slippage tolerance in basis points 
+    /// * `expires_in_seconds` - How long after activation the order expires 
+    pub fn create_chained_order<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<u64>,
+        Arg5: ProxyArg<u64>,
+    >(
+        self,
+        parent_order_id: Arg0,
+        to_token: Arg1,
+        target_price_num: Arg2,
+        target_price_denom: Arg3,
+        slippage_bp: Arg4,
+        expires_in_seconds: Arg5,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("createChainedOrder")
+            .argument(&parent_order_id)
+            .argument(&to_token)
+            .argument(&target_price_num)
+            .argument(&target_price_denom)
+            .argument(&slippage_bp)
+            .argument(&expires_in_seconds)
+            .original_result()
+    }
+
+    /// Create a bracket: an entry order buying `to_token`, a take-profit 
+    /// selling the entry's output back and a stop-loss guarding it 
+    ///  
+    /// The take-profit is chained to the entry (see `createChainedOrder`). 
+    /// The stop-loss stays Dormant with an oracle condition on the 
+    /// `to_token`/payment price falling to `stop_trigger`; once it holds, 
+    /// `triggerStopLoss` moves the take-profit's remaining escrow to the 
+    /// stop-loss, which then sells at `stop_limit` or better. Whichever 
+    /// exit closes first cancels the other 
+    ///  
+    /// # Arguments 
+    /// * `to_token` - Token the entry buys 
+    /// * `target_price_num` - Entry target price numerator 
+    /// * `target_price_denom` - Entry target price denominator 
+    /// * `slippage_bp` - Slippage tolerance of all three orders 
+    /// * `expires_in_seconds` - How long until the entry expires 
+    /// * `exits` - Take-profit and stop prices and the exits' lifetime 
+    ///  
+    /// Returns the entry, take-profit and stop-loss order ids 
+    pub fn create_bracket_order<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<u64>,
+        Arg5: ProxyArg<BracketExits<Env::Api>>,
+    >(
+        self,
+        to_token: Arg0,
+        target_price_num: Arg1,
+        target_price_denom: Arg2,
+        slippage_bp: Arg3,
+        expires_in_seconds: Arg4,
+        exits: Arg5,
+    ) -> TxTypedCall<Env, From, To, (), Gas, MultiValue3<u64, u64, u64>> {
+        self.wrapped_tx
+            .raw_call("createBracketOrder")
+            .argument(&to_token)
+            .argument(&target_price_num)
+            .argument(&target_price_denom)
+            .argument(&slippage_bp)
+            .argument(&expires_in_seconds)
+            .argument(&exits)
+            .original_result()
+    }
+
+    /// Activate a stop-loss whose trigger price has been reached: the 
+    /// remaining escrow of its take-profit moves to it and the take-profit 
+    /// is cancelled (anyone can call) 
+    pub fn trigger_stop_loss<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("triggerStopLoss")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Create a zap order: on execution half of the input is swapped and 
+    /// both halves are added as liquidity to the xExchange pair, so the 
+    /// owner receives LP tokens 
+    ///  
+    /// The price condition applies to the swapped half. If adding 
+    /// liquidity fails, the swap output is paid out and the unswapped 
+    /// half stays in the order 
+    ///  
+    /// # Arguments 
+    /// * `to_token` - Other token of the pair 
+    /// * `target_price_num` - Target price numerator 
+    /// * `target_price_denom` - Target price denominator 
+    /// * `slippage_bp` - Slippage tolerance for the swap and the liquidity amounts 
+    /// * `expires_in_seconds` - How long until order expires 
+    pub fn create_zap_order<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<u64>,
+    >(
+        self,
+        to_token: Arg0,
+        target_price_num: Arg1,
+        target_price_denom: Arg2,
+        slippage_bp: Arg3,
+        expires_in_seconds: Arg4,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("createZapOrder")
+            .argument(&to_token)
+            .argument(&target_price_num)
+            .argument(&target_price_denom)
+            .argument(&slippage_bp)
+            .argument(&expires_in_seconds)
+            .original_result()
+    }
+
+    /// Create an exact-output order: buy exactly `amount_out` of `to_token` 
+    /// (after fees) for at most the payment 
+    ///  
+    /// Executes in full through the pair's `swapTokensFixedOutput`; the 
+    /// input the swap did not need is refunded to the owner. The target 
+    /// price is `amount_out` per payment, without slippage 
+    ///  
+    /// # Arguments 
+    /// * `to_token` - Token to buy 
+    /// * `amount_out` - Amount of `to_token` the owner receives 
+    /// * `expires_in_seconds` - How long until order expires 
+    pub fn create_exact_output_order<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        to_token: Arg0,
+        amount_out: Arg1,
+        expires_in_seconds: Arg2,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("createExactOutputOrder")
+            .argument(&to_token)
+            .argument(&amount_out)
+            .argument(&expires_in_seconds)
+            .original_result()
+    }
+
+    /// Create a limit order whose target price stays hidden until execution 
+    ///  
+    /// Only `price_commitment` (see `computePriceCommitment`) is stored; the 
+    /// order shows a 1/1 placeholder price and stays out of `getOrderBook` 
+    /// until the executor reveals the real one with `executeCommittedOrder` 
+    ///  
+    /// # Arguments 
+    /// * `to_token` - Token to buy 
+    /// * `price_commitment` - keccak256 of the target price and a salt 
+    /// * `slippage_bp` - Slippage tolerance in basis points 
+    /// * `expires_in_seconds` - How long until order expires 
+    pub fn create_committed_order<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<u64>,
+    >(
+        self,
+        to_token: Arg0,
+        price_commitment: Arg1,
+        slippage_bp: Arg2,
+        expires_in_seconds: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("createCommittedOrder")
+            .argument(&to_token)
+            .argument(&price_commitment)
+            .argument(&slippage_bp)
+            .argument(&expires_in_seconds)
+            .original_result()
+    }
+
+    /// Create a limit order for `user`, paid by one of their session keys 
+    ///  
+    /// The payment counts against the key's spend cap; the order, refunds 
+    /// and swap output all belong to `user` 
+    ///  
+    /// # Arguments 
+    /// * `user` - Owner of the new order (the session key grantor) 
+    /// * `to_token` - Token to buy 
+    /// * `target_price_num` - Target price numerator 
+    /// * `target_price_denom` - Target price denominator 
+    /// * `slippage_bp` - Slippage tolerance in basis points 
+    /// * `expires_in_seconds` - How long until order expires 
+    pub fn create_limit_order_with_session_key<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<u64>,
+        Arg5: ProxyArg<u64>,
+    >(
+        self,
+        user: Arg0,
+        to_token: Arg1,
+        target_price_num: Arg2,
+        target_price_denom: Arg3,
+        slippage_bp: Arg4,
+        expires_in_seconds: Arg5,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("createLimitOrderWithSessionKey")
+            .argument(&user)
+            .argument(&to_token)
+            .argument(&target_price_num)
+            .argument(&target_price_denom)
+            .argument(&slippage_bp)
+            .argument(&expires_in_seconds)
+            .original_result()
+    }
+
+    /// Modify price, slippage and expiry of a pending order in place 
+    ///  
+    /// Escrow and order id are kept; the creator, an approved manager or a 
+    /// session key with modify permission can modify 
+    ///  
+    /// # Arguments 
+    /// * `order_id` - ID of order to modify 
+    /// * `target_price_num` - New target price numerator 
+    /// * `target_price_denom` - New target price denominator 
+    /// * `slippage_bp` - New slippage tolerance in basis points 
+    /// * `expires_in_seconds` - New lifetime, counted from now 
+    pub fn modify_limit_order<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+        target_price_num: Arg1,
+        target_price_denom: Arg2,
+        slippage_bp: Arg3,
+        expires_in_seconds: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("modifyLimitOrder")
+            .argument(&order_id)
+            .argument(&target_price_num)
+            .argument(&target_price_denom)
+            .argument(&slippage_bp)
+            .argument(&expires_in_seconds)
+            .original_result()
+    }
+
+    /// Push back the expiry of a pending order 
+    ///  
+    /// Keeps the order id, escrow and fill history, unlike cancelling and 
+    /// creating a new order. The expiry can be extended up to 
+    /// `MAX_ORDER_LIFETIME_SECONDS` after the order's creation 
+    ///  
+    /// # Arguments 
+    /// * `order_id` - ID of order to extend 
+    /// * `extra_seconds` - Seconds to add to the current expiry 
+    pub fn extend_order_expiry<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+        extra_seconds: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("extendOrderExpiry")
+            .argument(&order_id)
+            .argument(&extra_seconds)
+            .original_result()
+    }
+
+    /// Modify a tokenized order; send its position NFT with the call 
+    ///  
+    /// The NFT is returned to the caller 
+    ///  
+    /// # Arguments 
+    /// * `target_price_num` - New target price numerator 
+    /// * `target_price_denom` - New target price denominator 
+    /// * `slippage_bp` - New slippage tolerance in basis points 
+    /// * `expires_in_seconds` - New lifetime, counted from now 
+    pub fn modify_with_position<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<u64>,
+    >(
+        self,
+        target_price_num: Arg0,
+        target_price_denom: Arg1,
+        slippage_bp: Arg2,
+        expires_in_seconds: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("modifyWithPosition")
+            .argument(&target_price_num)
+            .argument(&target_price_denom)
+            .argument(&slippage_bp)
+            .argument(&expires_in_seconds)
+            .original_result()
+    }
+
+    /// Replace a pending order with a new one funded by the same escrow 
+    ///  
+    /// The old order is marked Cancelled and a new order id is returned; 
+    /// unlike cancel + create, no tokens leave the contract 
+    ///  
+    /// # Arguments 
+    /// * `order_id` - ID of order to replace 
+    /// * `to_token` - Token to buy with the new order 
+    /// * `target_price_num` - Target price numerator 
+    /// * `target_price_denom` - Target price denominator 
+    /// * `slippage_bp` - Slippage tolerance in basis points 
+    /// * `expires_in_seconds` - How long until the new order expires 
+    pub fn replace_limit_order<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<u64>,
+        Arg5: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+        to_token: Arg1,
+        target_price_num: Arg2,
+        target_price_denom: Arg3,
+        slippage_bp: Arg4,
+        expires_in_seconds: Arg5,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("replaceLimitOrder")
+            .argument(&order_id)
+            .argument(&to_token)
+            .argument(&target_price_num)
+            .argument(&target_price_denom)
+            .argument(&slippage_bp)
+            .argument(&expires_in_seconds)
+            .original_result()
+    }
+
+    /// Execute a limit order (called by backend executor) 
+    ///  
+    /// Performs SYNC swap on xExchange and sends output tokens to user 
+    /// If the order has expired it is refunded and marked Expired instead 
+    ///  
+    /// # Arguments 
+    /// * `order_id` - ID of order to execute 
+    /// * `current_price_num` - Current price numerator (for verification) 
+    /// * `current_price_denom` - Current price denominator 
+    pub fn execute_limit_order<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        order_id: Arg0,
+        current_price_num: Arg1,
+        current_price_denom: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("executeLimitOrder")
+            .argument(&order_id)
+            .argument(&current_price_num)
+            .argument(&current_price_denom)
+            .original_result()
+    }
+
+    /// Execute a limit order as the owner, when the executor is offline 
+    /// (owner only) 
+    ///  
+    /// Same checks as `executeLimitOrder` (price, expiry, pause...) except 
+    /// the executor check; emits `order_force_executed` so these executions 
+    /// can be told apart 
+    ///  
+    /// # Arguments 
+    /// * `order_id` - ID of order to execute 
+    /// * `current_price_num` - Current price numerator (for verification) 
+    /// * `current_price_denom` - Current price denominator 
+    pub fn force_execute<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        order_id: Arg0,
+        current_price_num: Arg1,
+        current_price_denom: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("forceExecute")
+            .argument(&order_id)
+            .argument(&current_price_num)
+            .argument(&current_price_denom)
+            .original_result()
+    }
+
+    /// Reveal the committed price of an order and execute it (called by 
+    /// backend executor) 
+    ///  
+    /// The revealed price must match the order's commitment; it becomes the 
+    /// order's target price and the execution proceeds as `executeLimitOrder` 
+    ///  
+    /// # Arguments 
+    /// * `order_id` - ID of order to execute 
+    /// * `target_price_num` - Committed target price numerator 
+    /// * `target_price_denom` - Committed target price denominator 
+    /// * `salt` - Salt of the commitment 
+    /// * `current_price_num` - Current price numerator (for verification) 
+    /// * `current_price_denom` - Current price denominator 
+    pub fn execute_committed_order<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+        Arg4: ProxyArg<BigUint<Env::Api>>,
+        Arg5: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        order_id: Arg0,
+        target_price_num: Arg1,
+        target_price_denom: Arg2,
+        salt: Arg3,
+        current_price_num: Arg4,
+        current_price_denom: Arg5,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("executeCommittedOrder")
+            .argument(&order_id)
+            .argument(&target_price_num)
+            .argument(&target_price_denom)
+            .argument(&salt)
+            .argument(&current_price_num)
+            .argument(&current_price_denom)
+            .original_result()
+    }
+
+    /// Execute part of a limit order (called by backend executor) 
+    ///  
+    /// Swaps `fill_amount` of the remaining escrow; the order stays pending 
+    /// until the remaining amount reaches zero 
+    ///  
+    /// # Arguments 
+    /// * `order_id` - ID of order to execute 
+    /// * `fill_amount` - Amount of from_token to swap in this chunk 
+    /// * `current_price_num` - Current price numerator (for verification) 
+    /// * `current_price_denom` - Current price denominator 
+    pub fn execute_limit_order_partial<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        order_id: Arg0,
+        fill_amount: Arg1,
+        current_price_num: Arg2,
+        current_price_denom: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("executeLimitOrderPartial")
+            .argument(&order_id)
+            .argument(&fill_amount)
+            .argument(&current_price_num)
+            .argument(&current_price_denom)
+            .original_result()
+    }
+
+    /// Execute a limit order if it is executable, without reverting otherwise 
+    ///  
+    /// Same as `executeLimitOrder`, but returns why the order was not 
+    /// executed instead of failing, so the executor can batch attempts 
+    /// Returns `OrderExpired` when the order was refunded instead 
+    ///  
+    /// # Arguments 
+    /// * `order_id` - ID of order to execute 
+    /// * `current_price_num` - Current price numerator (for verification) 
+    /// * `current_price_denom` - Current price denominator 
+    pub fn try_execute_limit_order<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        order_id: Arg0,
+        current_price_num: Arg1,
+        current_price_denom: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ReasonCode> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("tryExecuteLimitOrder")
+            .argument(&order_id)
+            .argument(&current_price_num)
+            .argument(&current_price_denom)
+            .original_result()
+    }
+
+    /// Auction the right to execute a large order (executor or order owner) 
+    ///  
+    /// Keepers bid the execution fee they accept with `bidForExecution` 
+    /// until the window closes; the best bidder then executes alone, at its 
+    /// bid, for another window 
+    ///  
+    /// # Arguments 
+    /// * `order_id` - Order of at least `executionAuctionMinAmount` of its token 
+    pub fn open_execution_auction<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("openExecutionAuction")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Settle two crossing orders on opposite sides of a pair against each 
+    /// other, without the DEX (called by backend executor) 
+    ///  
+    /// Trades at the midpoint of the two limit prices, so both orders get 
+    /// half of the price improvement; the smaller side is filled completely 
+    ///  
+    /// # Arguments 
+    /// * `buy_id` - Order selling token A for token B 
+    /// * `sell_id` - Order selling token B for token A 
+    pub fn match_orders<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        buy_id: Arg0,
+        sell_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("matchOrders")
+            .argument(&buy_id)
+            .argument(&sell_id)
+            .original_result()
+    }
+
+    /// Refuse to match the caller's orders against each other or fill them 
+    /// from their own quotes (off by default) 
+    pub fn set_self_trade_prevention<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        enabled: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setSelfTradePrevention")
+            .argument(&enabled)
+            .original_result()
+    }
+
+    /// Supply the escrow of an idle order to the lending market of its 
+    /// token (called by backend executor) 
+    ///  
+    /// Executing or cancelling the order later redeems it just in time 
+    pub fn supply_escrow<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("supplyEscrow")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Bring the lent escrow of an order back from the lending market 
+    /// (callable by anyone) 
+    pub fn redeem_escrow_endpoint<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("redeemEscrow")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Read the decimals of a whitelisted token from the ESDT system 
+    /// contract (callable by anyone) 
+    pub fn fetch_token_decimals<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("fetchTokenDecimals")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Gate execution of an order on the oracle price of a registered pair, 
+    /// replacing any previous condition (owner, managers or session keys 
+    /// with modify permission) 
+    pub fn set_order_condition<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<PriceCondition<Env::Api>>,
+    >(
+        self,
+        order_id: Arg0,
+        condition: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setOrderCondition")
+            .argument(&order_id)
+            .argument(&condition)
+            .original_result()
+    }
+
+    /// Remove the price condition of an order 
+    pub fn clear_order_condition<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearOrderCondition")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Stream the swap output of an order over `duration_seconds` instead of 
+    /// paying each fill at once; 0 switches streaming off (owner, managers 
+    /// or session keys with modify permission; not for tokenized orders) 
+    pub fn set_payout_stream<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+        duration_seconds: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPayoutStream")
+            .argument(&order_id)
+            .argument(&duration_seconds)
+            .original_result()
+    }
+
+    /// Forward the swap output of an order to a whitelisted destination 
+    /// contract as `function(arguments...)` instead of paying it to the 
+    /// owner; takes precedence over streaming (owner, managers or session 
+    /// keys with modify permission; not for tokenized orders) 
+    pub fn set_output_forward<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        order_id: Arg0,
+        destination: Arg1,
+        function: Arg2,
+        arguments: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setOutputForward")
+            .argument(&order_id)
+            .argument(&destination)
+            .argument(&function)
+            .argument(&arguments)
+            .original_result()
+    }
+
+    /// Pay the swap output of an order to its owner again 
+    pub fn clear_output_forward<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearOutputForward")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Cancel a limit order (its owner, an approved manager or a session key) 
+    ///  
+    /// Returns tokens to user immediately 
+    /// Never blocked by any pause level or pair pause; young orders are 
+    /// subject to the cancellation policy (see `setCancellationPolicy`) 
+    /// unless a pause keeps them from executing 
+    ///  
+    /// # Arguments 
+    /// * `order_id` - ID of order to cancel 
+    pub fn cancel_limit_order<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelLimitOrder")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Cancel a tokenized order; send its position NFT with the call 
+    ///  
+    /// The NFT is burned and the refund plus any partial-fill output 
+    /// goes to the caller. Never blocked by any pause level 
+    pub fn cancel_with_position(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("cancelWithPosition")
+            .original_result()
+    }
+
+    /// Claim the payouts held for a tokenized order; send its position NFT with the call 
+    ///  
+    /// Settled orders burn the NFT; pending orders (partial fills so far) 
+    /// get it back 
+    pub fn claim_position(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("claimPosition")
+            .original_result()
+    }
+
+    /// Cancel up to `max_count` pending orders of a strategy and refund their 
+    /// owners (strategy owner or contract owner). Orders with a swap in flight 
+    /// or a blacklisted owner are skipped. Never blocked by any pause level 
+    ///  
+    /// Returns the number of cancelled orders 
+    pub fn cancel_strategy_orders<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        strategy_id: Arg0,
+        max_count: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelStrategyOrders")
+            .argument(&strategy_id)
+            .argument(&max_count)
+            .original_result()
+    }
+
+    /// Cancel up to `max_count` pending orders selling or buying a delisted 
+    /// token and refund their owners (owner only). Orders with a swap in 
+    /// flight or a blacklisted owner are skipped 
+    ///  
+    /// Returns the number of cancelled orders 
+    pub fn force_refund_orders_for_token<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        token: Arg0,
+        max_count: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("forceRefundOrdersForToken")
+            .argument(&token)
+            .argument(&max_count)
+            .original_result()
+    }
+
+    /// Refund up to `max_count` of the caller's pending orders once the 
+    /// contract is winding down (FullStop for the grace period) 
+    ///  
+    /// No cancellation fee or window applies. Tokenized orders (cancelled 
+    /// with their position) and orders with a swap in flight are skipped; 
+    /// lent escrow is redeemed first and refunded by the callback. 
+    /// Returns the number of orders refunded or being redeemed 
+    pub fn wind_down_refund<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        max_count: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("windDownRefund")
+            .argument(&max_count)
+            .original_result()
+    }
+
+    /// Emit `order_activated` for up to `max_count` orders created before 
+    /// `token` had a pair (callable by anyone) 
+    ///  
+    /// Setting the pair already activates a first batch; this finishes 
+    /// the rest. Returns the number of orders activated 
+    pub fn activate_dormant_orders<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        token: Arg0,
+        max_count: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("activateDormantOrders")
+            .argument(&token)
+            .argument(&max_count)
+            .original_result()
+    }
+
+    /// Refund expired orders to their owners (callable by anyone, never 
+    /// blocked by any pause level) 
+    ///  
+    /// Examines up to `max_count` pending orders, continuing where the 
+    /// previous sweep stopped. The caller earns a bounty (in bps of each 
+    /// refunded amount) paid from the expiry bounty pool while it has funds. 
+    ///  
+    /// Returns the number of orders expired 
+    pub fn process_expired_orders<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        max_count: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("processExpiredOrders")
+            .argument(&max_count)
+            .original_result()
+    }
+
+    /// Set how many failed swaps an order survives before it is refunded (owner only) 
+    pub fn set_max_execution_attempts<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        max_attempts: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMaxExecutionAttempts")
+            .argument(&max_attempts)
+            .original_result()
+    }
+
+    /// Make failed orders wait `base_seconds` before their next attempt, 
+    /// doubling with each further failure (owner only, 0 disables) 
+    pub fn set_retry_backoff<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        base_seconds: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setRetryBackoff")
+            .argument(&base_seconds)
+            .original_result()
+    }
+
+    /// Top up the expiry bounty pool with any whitelisted token 
+    pub fn fund_expiry_bounty_pool(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("fundExpiryBountyPool")
+            .original_result()
+    }
+
+    /// Set the expiry bounty in basis points (owner only) 
+    pub fn set_expiry_bounty_bps<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        bounty_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setExpiryBountyBps")
+            .argument(&bounty_bps)
+            .original_result()
+    }
+
+    /// Get all pending orders (for backend executor), in no particular order 
+    ///  
+    /// Reads the pending index only, so settled orders cost nothing; legacy 
+    /// orders appear once `migrateOrders` has indexed them 
+    pub fn get_pending_orders(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, LimitOrder<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingOrders")
+            .original_result()
+    }
+
+    /// Get user's orders 
+    pub fn get_user_orders<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, LimitOrder<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserOrders")
+            .argument(&user)
+            .original_result()
+    }
+
+    /// Open (pending) orders tagged with a strategy 
+    pub fn get_strategy_open_orders<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        strategy_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, LimitOrder<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStrategyOpenOrders")
+            .argument(&strategy_id)
+            .original_result()
+    }
+
+    /// Get order by ID 
+    /// Best `depth` unexpired pending orders selling `from_token` for 
+    /// `to_token`, lowest target price (closest to execution) first 
+    pub fn get_order_book<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<usize>,
+    >(
+        self,
+        from_token: Arg0,
+        to_token: Arg1,
+        depth: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, LimitOrder<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOrderBook")
+            .argument(&from_token)
+            .argument(&to_token)
+            .argument(&depth)
+            .original_result()
+    }
+
+    /// Orders created in `[start_ts, end_ts)`, for exports and backtesting 
+    /// Skips the first `from_index` matches and returns at most `size` 
+    /// (capped at 100) orders, by creation day 
+    pub fn get_orders_created_between<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<u64>,
+    >(
+        self,
+        start_ts: Arg0,
+        end_ts: Arg1,
+        from_index: Arg2,
+        size: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, LimitOrder<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOrdersCreatedBetween")
+            .argument(&start_ts)
+            .argument(&end_ts)
+            .argument(&from_index)
+            .argument(&size)
+            .original_result()
+    }
+
+    /// Number of orders ever created, whatever their status 
+    pub fn get_order_count(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOrderCount")
+            .original_result()
+    }
+
+    /// Id of the most recent order (0 when none was created); ids are 
+    /// sequential from 1 and orders are never deleted 
+    pub fn get_last_order_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastOrderId")
+            .original_result()
+    }
+
+    /// Lowest id of a pending order (0 when none is pending); orders below 
+    /// it are settled for good, as dormant orders always wait on a pending 
+    /// order with a lower id 
+    pub fn get_first_active_order_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFirstActiveOrderId")
+            .original_result()
+    }
+
+    /// Orders with ids in `[from_id, to_id]`, any status, for indexers 
+    /// backfilling state; returns at most 100 orders from `from_id` on 
+    pub fn get_orders_range<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        from_id: Arg0,
+        to_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, LimitOrder<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOrdersRange")
+            .argument(&from_id)
+            .argument(&to_id)
+            .original_result()
+    }
+
+    /// Pause level, pair status per whitelisted token, pending order count 
+    /// and age, and async executions stuck for over 10 minutes 
+    pub fn get_executor_health(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ExecutorHealth<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getExecutorHealth")
+            .original_result()
+    }
+
+    /// Protocol-wide order counts, volume and fees in one call 
+    /// Executions, volume and fees count from the release adding statistics 
+    pub fn get_protocol_stats(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ProtocolStats<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProtocolStats")
+            .original_result()
+    }
+
+    pub fn get_order<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, LimitOrder<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOrder")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Client metadata hash the order was created with, if any 
+    pub fn get_order_client_tag<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedByteArray<Env::Api, 32usize>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOrderClientTag")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Agent intent the order was created with, if any 
+    pub fn get_order_intent<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<OrderIntent<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOrderIntent")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Order `user` created under `reference`, if any 
+    pub fn get_order_by_reference<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        user: Arg0,
+        reference: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOrderByReference")
+            .argument(&user)
+            .argument(&reference)
+            .original_result()
+    }
+
+    /// Intermediate token of a routed order, if any 
+    pub fn get_order_route_via<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<EsdtTokenIdentifier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOrderRouteVia")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Order funded by the output of `order_id`, if any 
+    pub fn get_chained_order<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getChainedOrder")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Order whose output funds the chained order `order_id`, if any 
+    pub fn get_chain_parent<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getChainParent")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Dormant stop-loss guarding `order_id`, if any 
+    pub fn get_order_stop_loss<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOrderStopLoss")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// True while a token of the order has no pair yet 
+    pub fn is_order_dormant<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isOrderDormant")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Why `executeLimitOrder` by the configured executor would not swap 
+    /// the whole order at the given price right now (`Ok` if it would) 
+    pub fn can_execute_order<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        order_id: Arg0,
+        current_price_num: Arg1,
+        current_price_denom: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ReasonCode> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("canExecuteOrder")
+            .argument(&order_id)
+            .argument(&current_price_num)
+            .argument(&current_price_denom)
+            .original_result()
+    }
+
+    /// Why `createLimitOrder` from `user` with these parameters would fail 
+    /// right now (`Ok` if it would succeed) 
+    pub fn validate_limit_order<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg4: ProxyArg<BigUint<Env::Api>>,
+        Arg5: ProxyArg<BigUint<Env::Api>>,
+        Arg6: ProxyArg<u64>,
+        Arg7: ProxyArg<u64>,
+    >(
+        self,
+        user: Arg0,
+        from_token: Arg1,
+        from_amount: Arg2,
+        to_token: Arg3,
+        target_price_num: Arg4,
+        target_price_denom: Arg5,
+        slippage_bp: Arg6,
+        expires_in_seconds: Arg7,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ReasonCode> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("validateLimitOrder")
+            .argument(&user)
+            .argument(&from_token)
+            .argument(&from_amount)
+            .argument(&to_token)
+            .argument(&target_price_num)
+            .argument(&target_price_denom)
+            .argument(&slippage_bp)
+            .argument(&expires_in_seconds)
+            .original_result()
+    }
+
+    /// What selling `amount` of `from_token` on its pair would pay out right 
+    /// now: the expected output and the minimum after `slippage_bp`, both 
+    /// net of execution and protocol fees 
+    /// Queries the pair's `getAmountOut` synchronously, so the pair must be 
+    /// on the contract's shard 
+    pub fn estimate_order_output<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+    >(
+        self,
+        from_token: Arg0,
+        to_token: Arg1,
+        amount: Arg2,
+        slippage_bp: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("estimateOrderOutput")
+            .argument(&from_token)
+            .argument(&to_token)
+            .argument(&amount)
+            .argument(&slippage_bp)
+            .original_result()
+    }
+
+    /// Price the owner of an order filled exactly at its target receives 
+    /// after the current execution and protocol fees, in to_token per 
+    /// from_token scaled by PRICE_SCALE (1e18) 
+    pub fn get_effective_price<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        from_amount: Arg0,
+        target_price_num: Arg1,
+        target_price_denom: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getEffectivePrice")
+            .argument(&from_amount)
+            .argument(&target_price_num)
+            .argument(&target_price_denom)
+            .original_result()
+    }
+
+    /// Minimum swap output an execution of the whole remaining order would 
+    /// demand now, under the current `minOutputMode` (gross of fees), and 
+    /// the floor that set it 
+    pub fn get_execution_min_output<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, MinOutputBound>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getExecutionMinOutput")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Escrow of an order currently supplied to a lending market 
+    pub fn lent_escrows<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, LentEscrow<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOrderLentEscrow")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Orders that add liquidity after their swap (see `createZapOrder`) 
+    pub fn zap_order<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isZapOrder")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Users whose orders are never matched against their own orders or quotes 
+    pub fn self_trade_prevention<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isSelfTradePrevented")
+            .argument(&user)
+            .original_result()
+    }
+
+    /// Amount of `to_token` an exact-output order buys (see `createExactOutputOrder`) 
+    pub fn exact_output_amount<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getExactOutputAmount")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Order whose escrow a stop-loss takes over 
+    pub fn stop_loss_target<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStopLossTarget")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Cumulative input swapped by (partial) fills 
+    pub fn filled_input<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFilledAmount")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Cumulative output delivered to the user by (partial) fills 
+    pub fn filled_output<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFilledOutput")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Why a cancelled order was cancelled (`User` unless cancelled by an admin action) 
+    pub fn cancel_reason<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, CancelReason> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCancelReason")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Failed swap attempts per order 
+    pub fn execution_attempts<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getExecutionAttempts")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Target price in base units of the to token per base unit of the 
+    /// from token, scaled by 1e18 
+    pub fn order_normalized_price<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOrderNormalizedPrice")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    pub fn max_execution_attempts(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxExecutionAttempts")
+            .original_result()
+    }
+
+    /// Seconds a failed order waits before its next attempt, doubled per 
+    /// further failure (0 = retry immediately) 
+    pub fn retry_backoff_base(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRetryBackoffBase")
+            .original_result()
+    }
+
+    /// Timestamp before which a failed order is not executed again 
+    pub fn retry_after<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRetryAfter")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Bounty paid to expiry sweepers (bps of refunded amount) 
+    pub fn expiry_bounty_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getExpiryBountyBps")
+            .original_result()
+    }
+
+    /// Pause/unpause contract (emergency stop) 
+    /// `true` = FullStop, `false` = Active 
+    pub fn set_paused<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        paused: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPaused")
+            .argument(&paused)
+            .original_result()
+    }
+
+    /// Set a granular pause level (owner only) 
+    pub fn set_pause_level<
+        Arg0: ProxyArg<PauseLevel>,
+    >(
+        self,
+        level: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPauseLevel")
+            .argument(&level)
+            .original_result()
+    }
+
+    /// Freeze or resume executions between two tokens, in both directions 
+    /// (owner only); their orders can still be cancelled 
+    pub fn set_pair_paused<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<bool>,
+    >(
+        self,
+        from_token: Arg0,
+        to_token: Arg1,
+        paused: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPairPaused")
+            .argument(&from_token)
+            .argument(&to_token)
+            .argument(&paused)
+            .original_result()
+    }
+
+    /// True when any pause level is active 
+    pub fn is_paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPaused")
+            .original_result()
+    }
+
+    /// True once a FullStop has lasted the wind-down grace period 
+    pub fn is_wind_down_active(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isWindDownActive")
+            .original_result()
+    }
+
+    /// Current pause level (empty storage decodes as Active) 
+    pub fn pause_level(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PauseLevel> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPauseLevel")
+            .original_result()
+    }
+
+    /// Start of the current FullStop (empty when not fully stopped) 
+    pub fn full_stop_since(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getFullStopSince")
+            .original_result()
+    }
+
+    /// Executions between the two tokens are frozen (stored both ways) 
+    pub fn pair_paused<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        from_token: Arg0,
+        to_token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPairPaused")
+            .argument(&from_token)
+            .argument(&to_token)
+            .original_result()
+    }
+
+    /// Queue a change of the timelock delay itself (owner only) 
+    /// Lowering the delay has to wait out the current delay 
+    pub fn set_timelock_delay<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        delay_seconds: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTimelockDelay")
+            .argument(&delay_seconds)
+            .original_result()
+    }
+
+    /// Apply a queued action once its delay has elapsed (callable by anyone) 
+    pub fn execute_queued_action<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("executeQueuedAction")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    /// Drop a queued action without applying it (owner only) 
+    pub fn cancel_queued_action<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelQueuedAction")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    /// All actions waiting for their delay to elapse 
+    pub fn get_queued_actions(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, QueuedAction<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getQueuedActions")
+            .original_result()
+    }
+
+    pub fn get_queued_action<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, QueuedAction<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getQueuedAction")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    /// Queued actions whose delay has elapsed 
+    pub fn get_executable_actions(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, QueuedAction<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getExecutableActions")
+            .original_result()
+    }
+
+    /// Id the next queued action will get 
+    pub fn get_next_action_id(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNextActionId")
+            .original_result()
+    }
+
+    /// Delay (seconds) between queueing and applying a sensitive action 
+    pub fn timelock_delay(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTimelockDelay")
+            .original_result()
+    }
+
+    /// Add address to blacklist (owner only) 
+    pub fn add_to_blacklist<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addToBlacklist")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// Remove address from blacklist (owner only) 
+    pub fn remove_from_blacklist<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeFromBlacklist")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// Check if address is blacklisted 
+    pub fn is_blacklisted<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isBlacklisted")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn blacklisted_addresses(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBlacklistedAddresses")
+            .original_result()
+    }
+
+    /// Send all accrued protocol fees in `token` to the treasury (callable 
+    /// by anyone: the receiver does not depend on the caller) 
+    pub fn claim_protocol_fees<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimProtocolFees")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Compare the contract's balance of `token` with what it owes (escrow 
+    /// and protocol pools) and emit the difference (callable by anyone) 
+    /// Returns the delta: positive for a surplus such as tokens sent to the 
+    /// contract by mistake (see `sweepSurplus`), negative for a shortfall 
+    pub fn reconcile<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigInt<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("reconcile")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Token the protocol fees are converted into (owner only) 
+    pub fn set_treasury_token<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTreasuryToken")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Swap the accrued protocol fees of each listed token into the 
+    /// treasury token, for at least the paired minimum output (owner only) 
+    ///  
+    /// # Arguments 
+    /// * `conversions` - (token, min_amount_out) pairs; tokens without 
+    ///   accrued fees are skipped 
+    pub fn convert_protocol_fees<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<EsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>>,
+    >(
+        self,
+        conversions: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("convertProtocolFees")
+            .argument(&conversions)
+            .original_result()
+    }
+
+    /// Token the protocol fees are converted into 
+    pub fn treasury_token(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTreasuryToken")
+            .original_result()
+    }
+
+    /// Re-encode up to `max_count` legacy orders (owner only) 
+    /// Completes the pending order migration once the last order is done 
+    /// Returns the number of orders still to migrate 
+    pub fn migrate_orders<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        max_count: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("migrateOrders")
+            .argument(&max_count)
+            .original_result()
+    }
+
+    /// True while a batched migration waits for `migrateOrders` 
+    pub fn is_migration_pending(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isMigrationPending")
+            .original_result()
+    }
+
+    /// Storage layout version, bumped by completed migrations 
+    pub fn storage_version(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStorageVersion")
+            .original_result()
+    }
+
+    /// Authorize `manager` to cancel, modify and replace the caller's orders 
+    pub fn approve_order_manager<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        manager: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("approveOrderManager")
+            .argument(&manager)
+            .original_result()
+    }
+
+    /// Revoke a previously approved manager 
+    ///  
+    /// Its daily caps and order rate are cleared too, so approving it again 
+    /// starts without any spending allowance 
+    pub fn revoke_order_manager<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        manager: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revokeOrderManager")
+            .argument(&manager)
+            .original_result()
+    }
+
+    /// Daily (per epoch) cap on how much of `token` an approved manager may 
+    /// escrow into new orders for the caller; zero disables spending 
+    pub fn set_manager_daily_cap<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        manager: Arg0,
+        token: Arg1,
+        cap: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setManagerDailyCap")
+            .argument(&manager)
+            .argument(&token)
+            .argument(&cap)
+            .original_result()
+    }
+
+    /// Maximum orders an approved manager may create per epoch (0 = no limit) 
+    pub fn set_manager_order_rate<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        manager: Arg0,
+        max_orders_per_epoch: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setManagerOrderRate")
+            .argument(&manager)
+            .argument(&max_orders_per_epoch)
+            .original_result()
+    }
+
+    /// Grant (or replace) a scoped session key for the caller's orders 
+    ///  
+    /// # Arguments 
+    /// * `key` - Address allowed to act 
+    /// * `permissions` - Bitmask of SESSION_PERMISSION_* flags 
+    /// * `spend_token` - Token the key may spend when creating orders 
+    /// * `spend_cap` - Total amount of `spend_token` the key may escrow 
+    /// * `expires_at` - Timestamp after which the key is no longer valid 
+    pub fn grant_session_key<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<u32>,
+        Arg2: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<u64>,
+    >(
+        self,
+        key: Arg0,
+        permissions: Arg1,
+        spend_token: Arg2,
+        spend_cap: Arg3,
+        expires_at: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("grantSessionKey")
+            .argument(&key)
+            .argument(&permissions)
+            .argument(&spend_token)
+            .argument(&spend_cap)
+            .argument(&expires_at)
+            .original_result()
+    }
+
+    /// Revoke a session key before its expiry 
+    pub fn revoke_session_key<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        key: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revokeSessionKey")
+            .argument(&key)
+            .original_result()
+    }
+
+    /// Check if `manager` may act on the orders of `user` 
+    pub fn is_order_manager<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+        manager: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isOrderManager")
+            .argument(&user)
+            .argument(&manager)
+            .original_result()
+    }
+
+    /// Session keys granted by `user` (expired ones included until revoked) 
+    pub fn get_session_keys<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, SessionKey<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSessionKeys")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn order_managers<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOrderManagers")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn manager_daily_cap<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+        manager: Arg1,
+        token: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getManagerDailyCap")
+            .argument(&user)
+            .argument(&manager)
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Amount of `token` a manager escrowed for `user` in the current epoch 
+    pub fn manager_spend<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+        manager: Arg1,
+        token: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EpochUsage<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getManagerSpend")
+            .argument(&user)
+            .argument(&manager)
+            .argument(&token)
+            .original_result()
+    }
+
+    pub fn manager_order_rate<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+        manager: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getManagerOrderRate")
+            .argument(&user)
+            .argument(&manager)
+            .original_result()
+    }
+
+    /// Deposit a fungible ESDT as credit for the caller 
+    pub fn deposit(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("deposit")
+            .original_result()
+    }
+
+    /// Withdraw unused credit back to the caller 
+    /// Never blocked by any pause level 
+    pub fn withdraw_credit<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawCredit")
+            .argument(&token)
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Create a limit order funded from the caller's credit 
+    ///  
+    /// Same as `createLimitOrder`, without a token transfer; refunds on 
+    /// cancellation or expiry go to the wallet, not back to credit 
+    ///  
+    /// # Arguments 
+    /// * `from_token` - Credited token to sell 
+    /// * `from_amount` - Amount of credit to escrow 
+    /// * `to_token` - Token to buy 
+    /// * `target_price_num` - Target price numerator 
+    /// * `target_price_denom` - Target price denominator 
+    /// * `slippage_bp` - Slippage tolerance in basis points 
+    /// * `expires_in_seconds` - How long until order expires 
+    pub fn create_limit_order_from_credit<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+        Arg4: ProxyArg<BigUint<Env::Api>>,
+        Arg5: ProxyArg<u64>,
+        Arg6: ProxyArg<u64>,
+    >(
+        self,
+        from_token: Arg0,
+        from_amount: Arg1,
+        to_token: Arg2,
+        target_price_num: Arg3,
+        target_price_denom: Arg4,
+        slippage_bp: Arg5,
+        expires_in_seconds: Arg6,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("createLimitOrderFromCredit")
+            .argument(&from_token)
+            .argument(&from_amount)
+            .argument(&to_token)
+            .argument(&target_price_num)
+            .argument(&target_price_denom)
+            .argument(&slippage_bp)
+            .argument(&expires_in_seconds)
+            .original_result()
+    }
+
+    /// Create a limit order for `user` from their credit (approved managers only) 
+    ///  
+    /// The amount counts against the manager's daily cap for `from_token` 
+    /// and the manager's order rate; the order belongs to `user` 
+    ///  
+    /// # Arguments 
+    /// * `user` - Owner of the new order 
+    /// * `from_token` - Credited token to sell 
+    /// * `from_amount` - Amount of credit to escrow 
+    /// * `to_token` - Token to buy 
+    /// * `target_price_num` - Target price numerator 
+    /// * `target_price_denom` - Target price denominator 
+    /// * `slippage_bp` - Slippage tolerance in basis points 
+    /// * `expires_in_seconds` - How long until order expires 
+    pub fn create_limit_order_as_manager<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg4: ProxyArg<BigUint<Env::Api>>,
+        Arg5: ProxyArg<BigUint<Env::Api>>,
+        Arg6: ProxyArg<u64>,
+        Arg7: ProxyArg<u64>,
+    >(
+        self,
+        user: Arg0,
+        from_token: Arg1,
+        from_amount: Arg2,
+        to_token: Arg3,
+        target_price_num: Arg4,
+        target_price_denom: Arg5,
+        slippage_bp: Arg6,
+        expires_in_seconds: Arg7,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("createLimitOrderAsManager")
+            .argument(&user)
+            .argument(&from_token)
+            .argument(&from_amount)
+            .argument(&to_token)
+            .argument(&target_price_num)
+            .argument(&target_price_denom)
+            .argument(&slippage_bp)
+            .argument(&expires_in_seconds)
+            .original_result()
+    }
+
+    /// Deposited credit per user and token 
+    pub fn user_credit<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+        token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserCredit")
+            .argument(&user)
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Create a limit order on behalf of `user`, funded from their credit 
+    ///  
+    /// # Arguments 
+    /// * `user` - Order owner and signer 
+    /// * `order` - Signed order parameters 
+    /// * `nonce` - Must equal `getRelayNonce(user)` 
+    /// * `signature` - ed25519 signature of the user over the message 
+    ///  
+    /// Returns the new order id 
+    pub fn create_limit_order_for<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<RelayedOrder<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+        order: Arg1,
+        nonce: Arg2,
+        signature: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("createLimitOrderFor")
+            .argument(&user)
+            .argument(&order)
+            .argument(&nonce)
+            .argument(&signature)
+            .original_result()
+    }
+
+    /// Next nonce a relayed order of `user` must be signed with 
+    pub fn relay_nonce<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRelayNonce")
+            .argument(&user)
+            .original_result()
+    }
+
+    /// Register the NFT collection used for order positions (owner only, once) 
+    pub fn set_position_token<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPositionToken")
+            .argument(&token)
+            .original_result()
+    }
+
+    pub fn position_token(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPositionToken")
+            .original_result()
+    }
+
+    /// Position NFT nonce of a tokenized order 
+    pub fn order_position<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOrderPosition")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Order id represented by a position NFT nonce 
+    pub fn position_order<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPositionOrder")
+            .argument(&nonce)
+            .original_result()
+    }
+
+    /// Payouts held for the position holder of an order, per token 
+    pub fn position_balance<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        order_id: Arg0,
+        token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPositionBalance")
+            .argument(&order_id)
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Register a strategy owned by the caller 
+    /// Returns the strategy id 
+    pub fn register_strategy<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        metadata_hash: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("registerStrategy")
+            .argument(&metadata_hash)
+            .original_result()
+    }
+
+    /// Point a strategy at new metadata (strategy owner only) 
+    pub fn update_strategy_metadata<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        strategy_id: Arg0,
+        metadata_hash: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("updateStrategyMetadata")
+            .argument(&strategy_id)
+            .argument(&metadata_hash)
+            .original_result()
+    }
+
+    /// Stop new orders from being tagged with a strategy (strategy owner only) 
+    pub fn deactivate_strategy<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        strategy_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("deactivateStrategy")
+            .argument(&strategy_id)
+            .original_result()
+    }
+
+    /// Pause or resume execution of every order tagged with a strategy 
+    /// (strategy owner or contract owner) 
+    pub fn set_strategy_paused<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        strategy_id: Arg0,
+        paused: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setStrategyPaused")
+            .argument(&strategy_id)
+            .argument(&paused)
+            .original_result()
+    }
+
+    /// Reimburse `rebate_bps` of the execution fee of the strategy's fills 
+    /// from its rebate pool (strategy owner only, 0 disables) 
+    pub fn set_strategy_rebate_bps<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        strategy_id: Arg0,
+        rebate_bps: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setStrategyRebateBps")
+            .argument(&strategy_id)
+            .argument(&rebate_bps)
+            .original_result()
+    }
+
+    /// Add the fungible tokens sent with the call to the strategy's rebate 
+    /// pool (strategy owner only) 
+    pub fn fund_strategy_rebates<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        strategy_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("fundStrategyRebates")
+            .argument(&strategy_id)
+            .original_result()
+    }
+
+    /// Take unused tokens back from the strategy's rebate pool (strategy 
+    /// owner only) 
+    pub fn withdraw_strategy_rebates<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        strategy_id: Arg0,
+        token: Arg1,
+        amount: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawStrategyRebates")
+            .argument(&strategy_id)
+            .argument(&token)
+            .argument(&amount)
+            .original_result()
+    }
+
+    pub fn get_strategy<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        strategy_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Strategy<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStrategy")
+            .argument(&strategy_id)
+            .original_result()
+    }
+
+    /// Strategy an order was tagged with 
+    pub fn order_strategy<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOrderStrategy")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Kill switch: execution of the strategy's orders is blocked while set 
+    pub fn strategy_paused<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        strategy_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isStrategyPaused")
+            .argument(&strategy_id)
+            .original_result()
+    }
+
+    pub fn strategy_stats<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        strategy_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, StrategyStats> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStrategyStats")
+            .argument(&strategy_id)
+            .original_result()
+    }
+
+    /// Executed input volume of a strategy per token 
+    pub fn strategy_volume_in<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        strategy_id: Arg0,
+        token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStrategyVolumeIn")
+            .argument(&strategy_id)
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Executed gross output volume of a strategy per token 
+    pub fn strategy_volume_out<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        strategy_id: Arg0,
+        token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStrategyVolumeOut")
+            .argument(&strategy_id)
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Output above (or below, within slippage) the limit price, per token 
+    pub fn strategy_price_improvement<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        strategy_id: Arg0,
+        token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigInt<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStrategyPriceImprovement")
+            .argument(&strategy_id)
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Share (in bps) of the execution fee of the strategy's fills rebated 
+    pub fn strategy_rebate_bps<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        strategy_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStrategyRebateBps")
+            .argument(&strategy_id)
+            .original_result()
+    }
+
+    /// Tokens funded by the strategy owner to pay rebates 
+    pub fn strategy_rebate_pool<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        strategy_id: Arg0,
+        token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStrategyRebatePool")
+            .argument(&strategy_id)
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Set the address allowed to fire signals (owner only) 
+    pub fn set_signal_oracle<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        oracle: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setSignalOracle")
+            .argument(&oracle)
+            .original_result()
+    }
+
+    /// Mark a signal as fired (signal oracle only) 
+    pub fn fire_signal<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        signal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("fireSignal")
+            .argument(&signal_id)
+            .original_result()
+    }
+
+    /// Set the address allowed to post condition prices (owner only) 
+    pub fn set_price_oracle<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        oracle: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPriceOracle")
+            .argument(&oracle)
+            .original_result()
+    }
+
+    /// Allow conditions and oracle prices for a pair (owner only) 
+    pub fn register_price_pair<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        base_token: Arg0,
+        quote_token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("registerPricePair")
+            .argument(&base_token)
+            .argument(&quote_token)
+            .original_result()
+    }
+
+    /// Maximum age of an oracle price for conditions (owner only) 
+    pub fn set_price_max_age<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        max_age_seconds: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPriceMaxAge")
+            .argument(&max_age_seconds)
+            .original_result()
+    }
+
+    /// Post the current price of a registered pair (price oracle only) 
+    pub fn post_price<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        base_token: Arg0,
+        quote_token: Arg1,
+        price_num: Arg2,
+        price_denom: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("postPrice")
+            .argument(&base_token)
+            .argument(&quote_token)
+            .argument(&price_num)
+            .argument(&price_denom)
+            .original_result()
+    }
+
+    /// True once the oracle has fired `signal_id` 
+    pub fn is_signal_fired<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        signal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isSignalFired")
+            .argument(&signal_id)
+            .original_result()
+    }
+
+    pub fn signal_oracle(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSignalOracle")
+            .original_result()
+    }
+
+    /// Timestamp a signal was fired at 
+    pub fn signal_fired_at<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        signal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSignalFiredAt")
+            .argument(&signal_id)
+            .original_result()
+    }
+
+    pub fn price_oracle(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPriceOracle")
+            .original_result()
+    }
+
+    /// Pairs conditions may reference (base, quote) 
+    pub fn price_pairs(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, (EsdtTokenIdentifier<Env::Api>, EsdtTokenIdentifier<Env::Api>)>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPricePairs")
+            .original_result()
+    }
+
+    pub fn price_max_age(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPriceMaxAge")
+            .original_result()
+    }
+
+    /// Latest oracle price of a registered pair 
+    pub fn oracle_price<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        base_token: Arg0,
+        quote_token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OraclePrice<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOraclePrice")
+            .argument(&base_token)
+            .argument(&quote_token)
+            .original_result()
+    }
+
+    /// Cross-pair price condition an order waits for 
+    pub fn order_condition<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PriceCondition<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOrderCondition")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Signal an order waits for 
+    pub fn order_signal<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOrderSignal")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Allow `maker` to post quotes (owner only) 
+    pub fn add_market_maker<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        maker: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addMarketMaker")
+            .argument(&maker)
+            .original_result()
+    }
+
+    /// Stop `maker` from posting quotes; its open quotes can no longer be 
+    /// filled but stay cancellable (owner only) 
+    pub fn remove_market_maker<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        maker: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeMarketMaker")
+            .argument(&maker)
+            .original_result()
+    }
+
+    /// Post a quote giving the deposited token for `take_token` 
+    ///  
+    /// # Payment 
+    /// The `give_token` deposit backing the quote 
+    ///  
+    /// # Arguments 
+    /// * `take_token` - Token the maker wants 
+    /// * `price_num` - give_token per take_token numerator 
+    /// * `price_denom` - give_token per take_token denominator 
+    /// * `expires_at` - Timestamp after which the quote cannot be filled 
+    ///  
+    /// Returns the quote id 
+    pub fn post_quote<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+    >(
+        self,
+        take_token: Arg0,
+        price_num: Arg1,
+        price_denom: Arg2,
+        expires_at: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("postQuote")
+            .argument(&take_token)
+            .argument(&price_num)
+            .argument(&price_denom)
+            .argument(&expires_at)
+            .original_result()
+    }
+
+    /// Withdraw the unused deposit of a quote and close it (maker only) 
+    /// Never blocked by any pause level 
+    pub fn cancel_quote<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        quote_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelQuote")
+            .argument(&quote_id)
+            .original_result()
+    }
+
+    /// Fill `fill_amount` of a resting order against a quote (called by 
+    /// backend executor) 
+    ///  
+    /// The order's input goes to the maker, the quoted output (fees 
+    /// deducted as for swaps) to the order owner 
+    ///  
+    /// # Arguments 
+    /// * `order_id` - Order to fill 
+    /// * `quote_id` - Quote selling the order's `to_token` for its `from_token` 
+    /// * `fill_amount` - Amount of the order's from_token to fill 
+    pub fn fill_order_with_quote<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        order_id: Arg0,
+        quote_id: Arg1,
+        fill_amount: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("fillOrderWithQuote")
+            .argument(&order_id)
+            .argument(&quote_id)
+            .argument(&fill_amount)
+            .original_result()
+    }
+
+    pub fn is_market_maker<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isMarketMaker")
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_quote<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        quote_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Quote<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getQuote")
+            .argument(&quote_id)
+            .original_result()
+    }
+
+    pub fn market_makers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMarketMakers")
+            .original_result()
+    }
+
+    /// Send everything vested so far to the order owner 
+    /// Returns the claimed amount 
+    pub fn claim_payout_stream<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimPayoutStream")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Amount of an order's stream that can be claimed right now 
+    pub fn get_claimable_stream<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClaimableStream")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Vesting duration for the output of an order (0 = paid out directly) 
+    pub fn payout_stream_duration<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPayoutStreamDuration")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    pub fn payout_streams<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PayoutStream<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPayoutStream")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Whitelist a token whose pair is not configured yet (owner only) 
+    /// Orders on it stay dormant until `setTokenPair` applies 
+    pub fn whitelist_upcoming_token<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("whitelistUpcomingToken")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Whitelisted tokens still waiting for their pair 
+    pub fn awaiting_pair_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EsdtTokenIdentifier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAwaitingPairTokens")
+            .original_result()
+    }
+
+    /// Orders created while `token` had no pair, not activated yet 
+    pub fn dormant_orders<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDormantOrders")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Set the decimals of a whitelisted token (owner only) 
+    pub fn set_token_decimals<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u8>,
+    >(
+        self,
+        token: Arg0,
+        decimals: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTokenDecimals")
+            .argument(&token)
+            .argument(&decimals)
+            .original_result()
+    }
+
+    /// Human price (1e18-scaled whole to tokens per whole from token) of a 
+    /// raw `price_num / price_denom` order price, rounded down 
+    pub fn to_human_price<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        from_token: Arg0,
+        to_token: Arg1,
+        price_num: Arg2,
+        price_denom: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("toHumanPrice")
+            .argument(&from_token)
+            .argument(&to_token)
+            .argument(&price_num)
+            .argument(&price_denom)
+            .original_result()
+    }
+
+    /// Raw `(price_num, price_denom)` order price for a human price 
+    /// (1e18-scaled whole to tokens per whole from token) 
+    pub fn from_human_price<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        from_token: Arg0,
+        to_token: Arg1,
+        human_price: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("fromHumanPrice")
+            .argument(&from_token)
+            .argument(&to_token)
+            .argument(&human_price)
+            .original_result()
+    }
+
+    pub fn token_decimals<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u8> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTokenDecimals")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Set the max single order amount and the max open interest of a 
+    /// token (owner only, 0 = no cap) 
+    pub fn set_risk_caps<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+        max_order_amount: Arg1,
+        max_open_interest: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setRiskCaps")
+            .argument(&token)
+            .argument(&max_order_amount)
+            .argument(&max_open_interest)
+            .original_result()
+    }
+
+    /// Max amount of `token` a single executor may execute per epoch 
+    /// (owner only, 0 = no limit) 
+    pub fn set_executor_daily_limit<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+        limit: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setExecutorDailyLimit")
+            .argument(&token)
+            .argument(&limit)
+            .original_result()
+    }
+
+    /// Amount of `token` `executor` executed in the current epoch 
+    pub fn get_executor_volume<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        executor: Arg0,
+        token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getExecutorVolume")
+            .argument(&executor)
+            .argument(&token)
+            .original_result()
+    }
+
+    pub fn max_order_amount<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxOrderAmount")
+            .argument(&token)
+            .original_result()
+    }
+
+    pub fn max_open_interest<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMaxOpenInterest")
+            .argument(&token)
+            .original_result()
+    }
+
+    pub fn executor_daily_limit<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getExecutorDailyLimit")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Trip a pair when consecutive swaps within `window_seconds` move the 
+    /// price by more than `max_deviation_bp` (owner only, 0 disables) 
+    pub fn set_circuit_breaker<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        max_deviation_bp: Arg0,
+        window_seconds: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setCircuitBreaker")
+            .argument(&max_deviation_bp)
+            .argument(&window_seconds)
+            .original_result()
+    }
+
+    /// Resume executions on a tripped pair (owner only) 
+    pub fn clear_circuit_breaker<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        pair: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clearCircuitBreaker")
+            .argument(&pair)
+            .original_result()
+    }
+
+    /// True while executions on `pair` are halted 
+    pub fn is_pair_tripped<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        pair: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPairTripped")
+            .argument(&pair)
+            .original_result()
+    }
+
+    /// Max price move between consecutive swaps, in basis points (0 = off) 
+    pub fn breaker_max_deviation_bp(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBreakerMaxDeviationBp")
+            .original_result()
+    }
+
+    /// Only swaps at most this far apart are compared 
+    pub fn breaker_window_seconds(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBreakerWindowSeconds")
+            .original_result()
+    }
+
+    /// Last realized price of swapping `token_in` on `pair` 
+    pub fn last_swap_price<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        pair: Arg0,
+        token_in: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PriceSnapshot<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLastSwapPrice")
+            .argument(&pair)
+            .argument(&token_in)
+            .original_result()
+    }
+
+    /// Pairs whose executions are halted 
+    pub fn tripped_pairs(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTrippedPairs")
+            .original_result()
+    }
+
+    /// Configure governance (owner only); the token cannot change once set 
+    ///  
+    /// # Arguments 
+    /// * `proposal_threshold` - Minimum deposit to open a proposal 
+    /// * `quorum` - Minimum total votes for a proposal to pass 
+    /// * `voting_period_seconds` - How long proposals are open for votes 
+    pub fn set_governance_config<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<BigUint<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+    >(
+        self,
+        token: Arg0,
+        proposal_threshold: Arg1,
+        quorum: Arg2,
+        voting_period_seconds: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setGovernanceConfig")
+            .argument(&token)
+            .argument(&proposal_threshold)
+            .argument(&quorum)
+            .argument(&voting_period_seconds)
+            .original_result()
+    }
+
+    /// Open a proposal; the governance tokens sent with the call must reach 
+    /// the proposal threshold and count as the proposer's yes vote 
+    ///  
+    /// Returns the proposal id 
+    pub fn propose<
+        Arg0: ProxyArg<TimelockAction<Env::Api>>,
+    >(
+        self,
+        action: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, u64> {
+        self.wrapped_tx
+            .raw_call("propose")
+            .argument(&action)
+            .original_result()
+    }
+
+    /// Vote with the governance tokens sent with the call; they stay locked 
+    /// until the voting period ends 
+    pub fn vote<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        proposal_id: Arg0,
+        support: Arg1,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("vote")
+            .argument(&proposal_id)
+            .argument(&support)
+            .original_result()
+    }
+
+    /// Close the vote of a proposal once its period has ended (callable by 
+    /// anyone). A passed proposal is queued in the timelock 
+    pub fn finalize_proposal<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ProposalStatus> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("finalizeProposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    /// Take back the governance tokens locked in a proposal after its 
+    /// voting period 
+    pub fn withdraw_vote_deposit<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawVoteDeposit")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn get_proposal<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        proposal_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Proposal<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposal")
+            .argument(&proposal_id)
+            .original_result()
+    }
+
+    pub fn governance_token(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGovernanceToken")
+            .original_result()
+    }
+
+    pub fn proposal_threshold(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getProposalThreshold")
+            .original_result()
+    }
+
+    pub fn governance_quorum(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGovernanceQuorum")
+            .original_result()
+    }
+
+    pub fn voting_period(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVotingPeriod")
+            .original_result()
+    }
+
+    /// Governance tokens a voter locked in a proposal 
+    pub fn vote_deposit<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        proposal_id: Arg0,
+        voter: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getVoteDeposit")
+            .argument(&proposal_id)
+            .argument(&voter)
+            .original_result()
+    }
+
+    /// Configure staking (owner only); the token cannot change once set 
+    ///  
+    /// # Arguments 
+    /// * `fee_share_bps` - Share of every protocol fee paid to stakers 
+    /// * `unbond_epochs` - Epochs between unstaking and withdrawing 
+    pub fn set_staking_config<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        token: Arg0,
+        fee_share_bps: Arg1,
+        unbond_epochs: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setStakingConfig")
+            .argument(&token)
+            .argument(&fee_share_bps)
+            .argument(&unbond_epochs)
+            .original_result()
+    }
+
+    /// Stake the protocol tokens sent with the call 
+    pub fn stake(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("stake")
+            .original_result()
+    }
+
+    /// Stop earning on `amount` of the caller's stake; it can be withdrawn 
+    /// after the unbonding period (a new unstake restarts it) 
+    pub fn unstake<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unstake")
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Withdraw unstaked tokens once they finished unbonding 
+    pub fn withdraw_unstaked(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawUnstaked")
+            .original_result()
+    }
+
+    /// Claim the caller's staking rewards in `token` 
+    pub fn claim_staking_rewards<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimStakingRewards")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Rewards in `token` the user can claim now 
+    pub fn get_claimable_staking_rewards<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+        token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClaimableStakingRewards")
+            .argument(&user)
+            .argument(&token)
+            .original_result()
+    }
+
+    pub fn staking_token(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenIdentifier<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStakingToken")
+            .original_result()
+    }
+
+    pub fn staking_fee_share_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStakingFeeShareBps")
+            .original_result()
+    }
+
+    pub fn unbond_epochs(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnbondEpochs")
+            .original_result()
+    }
+
+    pub fn staked_amount<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStakedAmount")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn total_staked(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalStaked")
+            .original_result()
+    }
+
+    pub fn unbonding<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Unbonding<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUnbonding")
+            .argument(&user)
+            .original_result()
+    }
+
+    /// Tokens stakers ever earned rewards in 
+    pub fn staking_reward_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EsdtTokenIdentifier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStakingRewardTokens")
+            .original_result()
+    }
+
+    /// Accumulated reward per staked unit, scaled by REWARD_PER_SHARE_SCALE 
+    pub fn reward_per_share<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRewardPerShare")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Set the share of every execution fee kept for the insurance fund 
+    /// (owner only) 
+    pub fn set_insurance_fee_bps<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        fee_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setInsuranceFeeBps")
+            .argument(&fee_bps)
+            .original_result()
+    }
+
+    /// Set the largest single compensation in `token` (owner only) 
+    /// 0 disables compensations in the token 
+    pub fn set_compensation_cap<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+        cap: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setCompensationCap")
+            .argument(&token)
+            .argument(&cap)
+            .original_result()
+    }
+
+    pub fn insurance_fee_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getInsuranceFeeBps")
+            .original_result()
+    }
+
+    pub fn compensation_cap<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCompensationCap")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Order counters of `user` 
+    pub fn get_user_stats<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, UserStats> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserStats")
+            .argument(&user)
+            .original_result()
+    }
+
+    /// Order counters and executed volume of orders selling `from_token` 
+    /// for `to_token` 
+    pub fn get_pair_stats<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        from_token: Arg0,
+        to_token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PairStats<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPairStats")
+            .argument(&from_token)
+            .argument(&to_token)
+            .original_result()
+    }
+
+    /// Executed volume, average fill size, fill rate and expiry rate of a pair 
+    pub fn get_pair_analytics<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        from_token: Arg0,
+        to_token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, PairAnalytics<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPairAnalytics")
+            .argument(&from_token)
+            .argument(&to_token)
+            .original_result()
+    }
+
+    /// Input of `token` the user sold through executed fills 
+    pub fn user_volume<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+        token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserVolume")
+            .argument(&user)
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Fees in `token` the user paid 
+    pub fn user_fees_paid<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+        token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserFeesPaid")
+            .argument(&user)
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Register an integrator paid at `payout_address` (owner only) 
+    /// Returns the client id 
+    pub fn register_client<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        payout_address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("registerClient")
+            .argument(&payout_address)
+            .original_result()
+    }
+
+    /// Move a client's payouts to a new address (client or contract owner) 
+    pub fn set_client_payout_address<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        client_id: Arg0,
+        payout_address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setClientPayoutAddress")
+            .argument(&client_id)
+            .argument(&payout_address)
+            .original_result()
+    }
+
+    /// Allow or stop attributing new orders to a client (owner only) 
+    /// Orders already attributed keep earning the client its share 
+    pub fn set_client_active<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<bool>,
+    >(
+        self,
+        client_id: Arg0,
+        active: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setClientActive")
+            .argument(&client_id)
+            .argument(&active)
+            .original_result()
+    }
+
+    /// Set the share of protocol fees paid to the client of an order (owner only) 
+    pub fn set_client_fee_share_bps<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        fee_share_bps: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setClientFeeShareBps")
+            .argument(&fee_share_bps)
+            .original_result()
+    }
+
+    /// Send a client's accrued fees in `token` to its payout address 
+    /// (callable by anyone: the receiver does not depend on the caller) 
+    pub fn claim_client_fees<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        client_id: Arg0,
+        token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("claimClientFees")
+            .argument(&client_id)
+            .argument(&token)
+            .original_result()
+    }
+
+    pub fn get_client<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        client_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Client<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClient")
+            .argument(&client_id)
+            .original_result()
+    }
+
+    /// Client an order is attributed to 
+    pub fn order_client<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOrderClient")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Share of protocol fees (in bps) paid to the client of an order 
+    pub fn client_fee_share_bps(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClientFeeShareBps")
+            .original_result()
+    }
+
+    /// Executed input volume of a client per token 
+    pub fn client_volume<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        client_id: Arg0,
+        token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClientVolume")
+            .argument(&client_id)
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Fees a client can claim per token 
+    pub fn client_fees<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        client_id: Arg0,
+        token: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getClientFees")
+            .argument(&client_id)
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Commitment of a target price (see the module docs for the encoding) 
+    pub fn compute_price_commitment<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<ManagedByteArray<Env::Api, 32usize>>,
+    >(
+        self,
+        price_num: Arg0,
+        price_denom: Arg1,
+        salt: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedByteArray<Env::Api, 32usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("computePriceCommitment")
+            .argument(&price_num)
+            .argument(&price_denom)
+            .argument(&salt)
+            .original_result()
+    }
+
+    /// Hidden target price of an order, until revealed at execution 
+    pub fn order_price_commitment<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedByteArray<Env::Api, 32usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOrderPriceCommitment")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    /// Length of the bidding window and of the winner's execution rights 
+    /// (owner only, 0 disables new auctions) 
+    pub fn set_execution_auction_window<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        window_seconds: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setExecutionAuctionWindow")
+            .argument(&window_seconds)
+            .original_result()
+    }
+
+    /// Smallest order of `token` whose execution can be auctioned 
+    /// (owner only, 0 disables auctions for the token) 
+    pub fn set_execution_auction_min_amount<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+        min_amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setExecutionAuctionMinAmount")
+            .argument(&token)
+            .argument(&min_amount)
+            .original_result()
+    }
+
+    /// Bid the execution fee (in bps) the caller accepts for executing an 
+    /// order; the lowest bid when the window closes wins 
+    pub fn bid_for_execution<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+        fee_bps: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("bidForExecution")
+            .argument(&order_id)
+            .argument(&fee_bps)
+            .original_result()
+    }
+
+    pub fn get_execution_auction<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ExecutionAuction<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getExecutionAuction")
+            .argument(&order_id)
+            .original_result()
+    }
+
+    pub fn execution_auction_window(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getExecutionAuctionWindow")
+            .original_result()
+    }
+
+    pub fn execution_auction_min_amount<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getExecutionAuctionMinAmount")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Sponsor fills paying out at most `max_output` of `token` (owner 
+    /// only, 0 disables sponsoring for the token) 
+    pub fn set_gas_station_max_output<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+        max_output: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setGasStationMaxOutput")
+            .argument(&token)
+            .argument(&max_output)
+            .original_result()
+    }
+
+    /// Add the fungible tokens sent with the call to the gas station pool 
+    pub fn top_up_gas_station(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("topUpGasStation")
+            .original_result()
+    }
+
+    /// Move `amount` of the accrued protocol fees in `token` to the gas 
+    /// station pool (owner only) 
+    pub fn fund_gas_station_from_fees<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("fundGasStationFromFees")
+            .argument(&token)
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Largest fill output in a token whose execution fee is sponsored 
+    pub fn gas_station_max_output<
+        Arg0: ProxyArg<EsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getGasStationMaxOutput")
+            .argument(&token)
+            .original_result()
+    }
+
+    /// Contracts order outputs may be forwarded to 
+    pub fn forward_destinations(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getForwardDestinations")
+            .original_result()
+    }
+
+    pub fn order_forward<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        order_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OutputForward<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOrderForward")
+            .argument(&order_id)
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SwapArgument {
+    TokenOut,
+    MinAmountOut,
+    TokenIn,
+    AmountIn,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct SessionKey<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub permissions: u32,
+    pub spend_token: EsdtTokenIdentifier<Api>,
+    pub spend_cap: BigUint<Api>,
+    pub spent: BigUint<Api>,
+    pub expires_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct Quote<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub quote_id: u64,
+    pub maker: ManagedAddress<Api>,
+    pub give_token: EsdtTokenIdentifier<Api>,
+    pub give_amount: BigUint<Api>,
+    pub take_token: EsdtTokenIdentifier<Api>,
+    pub price_num: BigUint<Api>,
+    pub price_denom: BigUint<Api>,
+    pub expires_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct OraclePrice<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub price_num: BigUint<Api>,
+    pub price_denom: BigUint<Api>,
+    pub updated_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct PriceCondition<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub base_token: EsdtTokenIdentifier<Api>,
+    pub quote_token: EsdtTokenIdentifier<Api>,
+    pub comparison: PriceComparison,
+    pub threshold_num: BigUint<Api>,
+    pub threshold_denom: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PriceComparison {
+    Above,
+    Below,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MinOutputMode {
+    TargetPrice,
+    LiveQuote,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MinOutputBound {
+    TargetPrice,
+    LiveQuote,
+    ExactOutput,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PairHealthIssue {
+    Inactive,
+    LowReserves,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CancelReason {
+    User,
+    StrategyKill,
+    TokenDelisted,
+    WindDown,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PauseLevel {
+    Active,
+    CreationPaused,
+    ExecutionPaused,
+    FullStop,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct Proposal<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub proposal_id: u64,
+    pub proposer: ManagedAddress<Api>,
+    pub action: TimelockAction<Api>,
+    pub voting_ends_at: u64,
+    pub yes_votes: BigUint<Api>,
+    pub no_votes: BigUint<Api>,
+    pub status: ProposalStatus,
+    pub action_id: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub enum TimelockAction<Api>
+where
+    Api: ManagedTypeApi,
+{
+    SetXExchangePair(ManagedAddress<Api>),
+    SetLimitOrderExecutor(ManagedAddress<Api>),
+    SetExecutionFeeBps(u64),
+    SetMaxSlippage(u64),
+    SetTimelockDelay(u64),
+    EmergencyWithdraw(EsdtTokenIdentifier<Api>, BigUint<Api>),
+    SetProtocolFeeBps(u64),
+    SetTreasuryAddress(ManagedAddress<Api>),
+    SetCancellationPolicy(u64, u64),
+    SetLendingMarket(EsdtTokenIdentifier<Api>, ManagedAddress<Api>, EsdtTokenIdentifier<Api>),
+    SetLendingInterestToUser(bool),
+    SetTokenPair(EsdtTokenIdentifier<Api>, ManagedAddress<Api>),
+    ListToken(EsdtTokenIdentifier<Api>, ManagedAddress<Api>),
+    Compensate(ManagedAddress<Api>, EsdtTokenIdentifier<Api>, BigUint<Api>),
+    SetSwapDescriptor(ManagedAddress<Api>, SwapCallDescriptor<Api>),
+    SetExecutionFeeFloor(EsdtTokenIdentifier<Api>, BigUint<Api>),
+    SetPriceImprovementSharing(u64, u64),
+    SetXExchangeRouter(ManagedAddress<Api>),
+    SetForwardDestination(ManagedAddress<Api>, bool),
+    SweepSurplus(EsdtTokenIdentifier<Api>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct SwapCallDescriptor<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub function: ManagedBuffer<Api>,
+    pub arguments: ManagedVec<Api, SwapArgument>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalStatus {
+    Active,
+    Queued,
+    Defeated,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct OrderTerms<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub to_token: EsdtTokenIdentifier<Api>,
+    pub target_price_numerator: BigUint<Api>,
+    pub target_price_denominator: BigUint<Api>,
+    pub slippage_bp: u64,
+    pub expires_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct OrderIntent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub strategy_id: u64,
+    pub confidence_bps: u64,
+    pub model_version: ManagedByteArray<Api, 32usize>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct BracketExits<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub take_profit_num: BigUint<Api>,
+    pub take_profit_denom: BigUint<Api>,
+    pub stop_trigger_num: BigUint<Api>,
+    pub stop_trigger_denom: BigUint<Api>,
+    pub stop_limit_num: BigUint<Api>,
+    pub stop_limit_denom: BigUint<Api>,
+    pub lifetime_seconds: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReasonCode {
+    Ok,
+    CreationPaused,
+    ExecutionPaused,
+    AddressBlacklisted,
+    NotExecutor,
+    OrderNotFound,
+    OrderNotPending,
+    ExecutionInProgress,
+    TokenDelisted,
+    OrderExpired,
+    InvalidAmount,
+    FillExceedsRemaining,
+    SignalNotFired,
+    ConditionPriceStale,
+    ConditionNotMet,
+    StrategyPaused,
+    PriceNotMet,
+    FromTokenNotWhitelisted,
+    ToTokenNotWhitelisted,
+    SameToken,
+    PriceNumZero,
+    PriceDenomZero,
+    SlippageAbove100Percent,
+    SlippageAboveMax,
+    ExpiryTooFar,
+    OrderDormant,
+    OrderTooLarge,
+    OpenInterestCapReached,
+    PairTripped,
+    ExecutorVolumeExceeded,
+    PairPaused,
+    ExactOutputFullFill,
+    PriceNotRevealed,
+    ExecutionAuctionOpen,
+    RetryBackoff,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RedeemPurpose {
+    Release,
+    Execute,
+    Cancel,
+    Expire,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct LimitOrder<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub order_id: u64,
+    pub user: ManagedAddress<Api>,
+    pub from_token: EsdtTokenIdentifier<Api>,
+    pub from_amount: BigUint<Api>,
+    pub to_token: EsdtTokenIdentifier<Api>,
+    pub target_price_numerator: BigUint<Api>,
+    pub target_price_denominator: BigUint<Api>,
+    pub slippage_bp: u64,
+    pub expires_at: u64,
+    pub status: OrderStatus,
+    pub created_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OrderStatus {
+    Pending,
+    Executed,
+    Cancelled,
+    Expired,
+    Failed,
+    Dormant,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct ExecutorHealth<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub pause_level: PauseLevel,
+    pub token_pairs: ManagedVec<Api, TokenPairHealth<Api>>,
+    pub pending_orders: u64,
+    pub oldest_pending_age: u64,
+    pub stuck_executions: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct TokenPairHealth<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token: EsdtTokenIdentifier<Api>,
+    pub pair_configured: bool,
+    pub pair_tripped: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct ProtocolStats<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub orders_created: u64,
+    pub orders_executed: u64,
+    pub open_orders: u64,
+    pub volume: ManagedVec<Api, EsdtTokenPayment<Api>>,
+    pub fees: ManagedVec<Api, EsdtTokenPayment<Api>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct LentEscrow<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub market: ManagedAddress<Api>,
+    pub share_token: EsdtTokenIdentifier<Api>,
+    pub shares: BigUint<Api>,
+    pub principal: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct QueuedAction<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub action_id: u64,
+    pub action: TimelockAction<Api>,
+    pub queued_at: u64,
+    pub executable_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Default)]
+pub struct EpochUsage<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub epoch: u64,
+    pub amount: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct RelayedOrder<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub from_token: EsdtTokenIdentifier<Api>,
+    pub from_amount: BigUint<Api>,
+    pub to_token: EsdtTokenIdentifier<Api>,
+    pub target_price_numerator: BigUint<Api>,
+    pub target_price_denominator: BigUint<Api>,
+    pub slippage_bp: u64,
+    pub expires_in_seconds: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct Strategy<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub strategy_id: u64,
+    pub owner: ManagedAddress<Api>,
+    pub metadata_hash: ManagedBuffer<Api>,
+    pub created_at: u64,
+    pub active: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Default)]
+pub struct StrategyStats {
+    pub order_count: u64,
+    pub fill_count: u64,
+    pub executed_order_count: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct PayoutStream<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub user: ManagedAddress<Api>,
+    pub token: EsdtTokenIdentifier<Api>,
+    pub locked: BigUint<Api>,
+    pub withdrawable: BigUint<Api>,
+    pub start: u64,
+    pub end: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, Debug)]
+pub struct PriceSnapshot<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub price: BigUint<Api>,
+    pub timestamp: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct Unbonding<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub amount: BigUint<Api>,
+    pub unlock_epoch: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Default, PartialEq, Debug)]
+pub struct UserStats {
+    pub orders_created: u64,
+    pub orders_executed: u64,
+    pub orders_cancelled: u64,
+    pub orders_expired: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct PairStats<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub orders_created: u64,
+    pub orders_executed: u64,
+    pub orders_cancelled: u64,
+    pub orders_expired: u64,
+    pub fill_count: u64,
+    pub volume: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct PairAnalytics<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub volume: BigUint<Api>,
+    pub average_fill_size: BigUint<Api>,
+    pub fill_rate_bps: u64,
+    pub expiry_rate_bps: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct Client<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub client_id: u64,
+    pub payout_address: ManagedAddress<Api>,
+    pub created_at: u64,
+    pub active: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct ExecutionAuction<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub ends_at: u64,
+    pub rights_until: u64,
+    pub best_bidder: ManagedAddress<Api>,
+    pub best_fee_bps: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct OutputForward<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub destination: ManagedAddress<Api>,
+    pub function: ManagedBuffer<Api>,
+    pub arguments: ManagedVec<Api, ManagedBuffer<Api>>,
+}
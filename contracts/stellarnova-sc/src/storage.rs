@@ -22,23 +22,165 @@ pub trait StorageModule {
     #[storage_mapper("xExchangePair")]
     fn xexchange_pair(&self) -> SingleValueMapper<ManagedAddress>;
 
+    /// Pair that swaps a token, overriding the default pair for its orders
+    #[view(getTokenPair)]
+    #[storage_mapper("tokenPair")]
+    fn token_pair(&self, token: &TokenIdentifier) -> SingleValueMapper<ManagedAddress>;
+
+    /// xExchange router executing the two legs of routed orders in one call
+    #[view(getXExchangeRouter)]
+    #[storage_mapper("xExchangeRouter")]
+    fn xexchange_router(&self) -> SingleValueMapper<ManagedAddress>;
+
     // ========== CONFIGURATION ==========
 
+    /// Version of the deployed code, set by init and upgrade
+    #[view(getContractVersion)]
+    #[storage_mapper("contractVersion")]
+    fn contract_version(&self) -> SingleValueMapper<u64>;
+
     /// Contract owner (for admin functions)
     #[view(getOwner)]
     #[storage_mapper("owner")]
     fn owner(&self) -> SingleValueMapper<ManagedAddress>;
 
-    /// Contract paused state (emergency stop)
-    #[view(isPaused)]
-    #[storage_mapper("paused")]
-    fn paused(&self) -> SingleValueMapper<bool>;
-
     /// Maximum slippage tolerance (basis points, e.g., 500 = 5%)
     #[view(getMaxSlippage)]
     #[storage_mapper("maxSlippage")]
     fn max_slippage(&self) -> SingleValueMapper<u64>;
 
+    // ========== ESCROW ACCOUNTING ==========
+
+    /// Total amount of each token currently escrowed by pending orders
+    #[view(getEscrowBalance)]
+    #[storage_mapper("escrowBalance")]
+    fn escrow_balance(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Protocol pool paying bounties to expired-order sweepers
+    #[view(getExpiryBountyPool)]
+    #[storage_mapper("expiryBountyPool")]
+    fn expiry_bounty_pool(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Protocol fees accrued per token, waiting to be claimed by the treasury
+    #[view(getProtocolFees)]
+    #[storage_mapper("protocolFees")]
+    fn protocol_fees(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Total deposited credit per token, not yet escrowed or withdrawn
+    #[view(getTotalCredit)]
+    #[storage_mapper("totalCredit")]
+    fn total_credit(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Total payouts held for position NFT holders per token
+    #[view(getPositionHeldBalance)]
+    #[storage_mapper("positionHeldBalance")]
+    fn position_held_balance(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Total deposits backing open RFQ quotes per token
+    #[view(getQuoteDeposits)]
+    #[storage_mapper("quoteDeposits")]
+    fn quote_deposits(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Streamed swap output not claimed yet per token
+    #[view(getStreamedBalance)]
+    #[storage_mapper("streamedBalance")]
+    fn streamed_balance(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Governance tokens locked in proposal votes
+    #[view(getGovernanceDeposits)]
+    #[storage_mapper("governanceDeposits")]
+    fn governance_deposits(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Staked and unbonding protocol tokens
+    #[view(getStakingDeposits)]
+    #[storage_mapper("stakingDeposits")]
+    fn staking_deposits(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Protocol fees credited to stakers and not claimed yet per token
+    #[view(getStakingRewardPool)]
+    #[storage_mapper("stakingRewardPool")]
+    fn staking_reward_pool(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Protocol fees credited to clients and not claimed yet per token
+    #[view(getClientFeeBalance)]
+    #[storage_mapper("clientFeeBalance")]
+    fn client_fee_balance(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Execution fee slices kept to compensate users after incidents
+    #[view(getInsuranceFund)]
+    #[storage_mapper("insuranceFund")]
+    fn insurance_fund(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Rebate pools of all strategies per token
+    #[view(getStrategyRebateDeposits)]
+    #[storage_mapper("strategyRebateDeposits")]
+    fn strategy_rebate_deposits(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Tokens paying the sponsored part of small fills' execution fees
+    #[view(getGasStationPool)]
+    #[storage_mapper("gasStationPool")]
+    fn gas_station_pool(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Escrow principal currently supplied to lending markets per token
+    #[view(getLentEscrow)]
+    #[storage_mapper("lentEscrow")]
+    fn lent_escrow(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Escrow sent to a pair by a swap or zap liquidity call whose
+    /// callback has not come back yet, per token
+    #[view(getEscrowInFlight)]
+    #[storage_mapper("escrowInFlight")]
+    fn escrow_in_flight(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Lending market share tokens held for lent escrow
+    #[view(getLendingShares)]
+    #[storage_mapper("lendingShares")]
+    fn lending_shares(&self, share_token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Balance the contract owes to users or protocol pools for a token and
+    /// should hold (lent escrow is held as lending shares instead, escrow in
+    /// flight is out until its callback)
+    ///
+    /// Every balance held for users or pools (escrow, credit, quote deposits,
+    /// locked votes, stakes, rewards...) is tracked per token and counted
+    /// here, so emergency withdrawals and surplus sweeps, which only move
+    /// `surplus_balance`, never touch it
+    fn accounted_balance(&self, token: &TokenIdentifier) -> BigUint {
+        let owed = self.escrow_balance(token).get()
+            + self.lending_shares(token).get()
+            + self.total_credit(token).get()
+            + self.position_held_balance(token).get()
+            + self.quote_deposits(token).get()
+            + self.streamed_balance(token).get()
+            + self.expiry_bounty_pool(token).get()
+            + self.protocol_fees(token).get()
+            + self.governance_deposits(token).get()
+            + self.staking_deposits(token).get()
+            + self.staking_reward_pool(token).get()
+            + self.insurance_fund(token).get()
+            + self.gas_station_pool(token).get()
+            + self.strategy_rebate_deposits(token).get()
+            + self.client_fee_balance(token).get();
+        owed - self.lent_escrow(token).get() - self.escrow_in_flight(token).get()
+    }
+
+    /// Balance the contract actually holds of a fungible token
+    fn contract_balance(&self, token: &TokenIdentifier) -> BigUint {
+        self.blockchain()
+            .get_sc_balance(EgldOrEsdtTokenIdentifier::esdt(token.clone()), 0)
+    }
+
+    /// Balance held above what the contract owes for a token (0 on a shortfall)
+    fn surplus_balance(&self, token: &TokenIdentifier) -> BigUint {
+        let balance = self.contract_balance(token);
+        let accounted = self.accounted_balance(token);
+        if balance > accounted {
+            balance - accounted
+        } else {
+            BigUint::zero()
+        }
+    }
+
     // ========== ASYNC EXECUTION CONTEXT ==========
 
     /// Track pending async swap executions
@@ -51,6 +193,67 @@ pub trait StorageModule {
     #[view(getExecutionFeeBps)]
     #[storage_mapper("executionFeeBps")]
     fn execution_fee_bps(&self) -> SingleValueMapper<u64>;
+
+    /// Minimum execution fee per output token, in its base units, for fills
+    /// whose bps fee would be lower (0 = none)
+    #[view(getExecutionFeeFloor)]
+    #[storage_mapper("executionFeeFloor")]
+    fn execution_fee_floor(&self, token: &TokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    /// Share (in bps) of a fill's output above the target price paid to the executor
+    #[view(getImprovementExecutorBps)]
+    #[storage_mapper("improvementExecutorBps")]
+    fn improvement_executor_bps(&self) -> SingleValueMapper<u64>;
+
+    /// Share (in bps) of a fill's output above the target price accrued for the treasury
+    #[view(getImprovementTreasuryBps)]
+    #[storage_mapper("improvementTreasuryBps")]
+    fn improvement_treasury_bps(&self) -> SingleValueMapper<u64>;
+
+    /// Protocol fee (in bps) taken from output tokens for the treasury
+    #[view(getProtocolFeeBps)]
+    #[storage_mapper("protocolFeeBps")]
+    fn protocol_fee_bps(&self) -> SingleValueMapper<u64>;
+
+    /// Lending market (money market address, share token) per underlying token
+    #[view(getLendingMarket)]
+    #[storage_mapper("lendingMarket")]
+    fn lending_market(
+        &self,
+        token: &TokenIdentifier,
+    ) -> SingleValueMapper<(ManagedAddress, TokenIdentifier)>;
+
+    /// Whether interest on lent escrow goes to the order (true) or to
+    /// the protocol fees (false)
+    #[view(getLendingInterestToUser)]
+    #[storage_mapper("lendingInterestToUser")]
+    fn lending_interest_to_user(&self) -> SingleValueMapper<bool>;
+
+    /// LP token of the xExchange pair, delivered by zap orders
+    #[view(getZapLpToken)]
+    #[storage_mapper("zapLpToken")]
+    fn zap_lp_token(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    /// First token of the xExchange pair (addLiquidity payment order)
+    #[view(getZapFirstToken)]
+    #[storage_mapper("zapFirstToken")]
+    fn zap_first_token(&self) -> SingleValueMapper<TokenIdentifier>;
+
+    /// Seconds after creation during which cancelling is restricted (0 = off)
+    #[view(getCancellationWindow)]
+    #[storage_mapper("cancellationWindow")]
+    fn cancellation_window(&self) -> SingleValueMapper<u64>;
+
+    /// Fee (in bps of the refund) for cancelling inside the window;
+    /// 0 means such cancellations are rejected instead
+    #[view(getCancellationFeeBps)]
+    #[storage_mapper("cancellationFeeBps")]
+    fn cancellation_fee_bps(&self) -> SingleValueMapper<u64>;
+
+    /// Receiver of claimed protocol fees (owner when unset)
+    #[view(getTreasuryAddress)]
+    #[storage_mapper("treasuryAddress")]
+    fn treasury_address(&self) -> SingleValueMapper<ManagedAddress>;
 }
 
 use multiversx_sc::derive_imports::*;
@@ -64,4 +267,33 @@ pub struct SwapExecutionContext<M: ManagedTypeApi> {
     pub executor: ManagedAddress<M>,
     pub to_token: TokenIdentifier<M>,
     pub min_amount_out: BigUint<M>,
+    pub fill_amount: BigUint<M>,
+}
+
+/// Escrow of an order supplied to a lending market
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct LentEscrow<M: ManagedTypeApi> {
+    pub market: ManagedAddress<M>,
+    pub share_token: TokenIdentifier<M>,
+    pub shares: BigUint<M>,
+    pub principal: BigUint<M>,
+}
+
+/// What to do once a lent escrow is back from the lending market
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RedeemPurpose {
+    Release,
+    Execute,
+    Cancel,
+    Expire,
+}
+
+/// Context of a zap execution between the swap and the addLiquidity call
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ZapExecution<M: ManagedTypeApi> {
+    pub executor: ManagedAddress<M>,
+    pub fill_amount: BigUint<M>, // whole chunk, swapped half included
 }
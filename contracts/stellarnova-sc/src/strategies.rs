@@ -0,0 +1,344 @@
+//! Strategies Module for StellarNova
+//!
+//! Registry of AI strategies: each strategy has an owner and a metadata hash
+//! pointing to its off-chain description. Orders can be tagged with a strategy
+//! at creation, and fills update per-strategy statistics:
+//! - executed volume in (per input token) and out (per output token)
+//! - price improvement: gross output minus the output at the limit price,
+//!   per output token, used as an on-chain PnL proxy
+//!
+//! Tags are set by the order creator; statistics are informational only.
+//!
+//! Kill switch: the strategy owner or the contract owner can pause a strategy,
+//! blocking execution of all its orders, and cancel them in batches.
+//!
+//! Fee rebates: a strategy owner can pre-fund a per-token rebate pool; fills
+//! of orders tagged with the strategy then get `rebate_bps` of their
+//! execution fee back from the pool, as long as it lasts.
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::errors::*;
+use crate::limit_orders::MAX_BASIS_POINTS;
+use crate::reasons::ReasonCode;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct Strategy<M: ManagedTypeApi> {
+    pub strategy_id: u64,
+    pub owner: ManagedAddress<M>,
+    pub metadata_hash: ManagedBuffer<M>,
+    pub created_at: u64,
+    pub active: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Default)]
+pub struct StrategyStats {
+    pub order_count: u64,
+    pub fill_count: u64,
+    pub executed_order_count: u64,
+}
+
+#[multiversx_sc::module]
+pub trait StrategiesModule: crate::storage::StorageModule + crate::events::EventsModule {
+    /// Register a strategy owned by the caller
+    /// Returns the strategy id
+    #[endpoint(registerStrategy)]
+    fn register_strategy(&self, metadata_hash: ManagedBuffer) -> u64 {
+        let caller = self.blockchain().get_caller();
+        let strategy_id = self.next_strategy_id().get() + 1;
+        self.next_strategy_id().set(strategy_id);
+
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        let strategy = Strategy {
+            strategy_id,
+            owner: caller.clone(),
+            metadata_hash,
+            created_at: current_time,
+            active: true,
+        };
+        self.strategies(strategy_id).set(&strategy);
+
+        self.strategy_registered_event(strategy_id, &caller, &strategy.metadata_hash);
+        strategy_id
+    }
+
+    /// Point a strategy at new metadata (strategy owner only)
+    #[endpoint(updateStrategyMetadata)]
+    fn update_strategy_metadata(&self, strategy_id: u64, metadata_hash: ManagedBuffer) {
+        let mut strategy = self.require_strategy_owner(strategy_id);
+        strategy.metadata_hash = metadata_hash;
+        self.strategies(strategy_id).set(&strategy);
+
+        self.strategy_updated_event(strategy_id, &strategy.metadata_hash);
+    }
+
+    /// Stop new orders from being tagged with a strategy (strategy owner only)
+    #[endpoint(deactivateStrategy)]
+    fn deactivate_strategy(&self, strategy_id: u64) {
+        let mut strategy = self.require_strategy_owner(strategy_id);
+        strategy.active = false;
+        self.strategies(strategy_id).set(&strategy);
+
+        self.strategy_deactivated_event(strategy_id);
+    }
+
+    /// Pause or resume execution of every order tagged with a strategy
+    /// (strategy owner or contract owner)
+    #[endpoint(setStrategyPaused)]
+    fn set_strategy_paused(&self, strategy_id: u64, paused: bool) {
+        self.require_strategy_admin(strategy_id);
+        self.strategy_paused(strategy_id).set(paused);
+        self.strategy_paused_event(strategy_id, paused);
+    }
+
+    /// Reimburse `rebate_bps` of the execution fee of the strategy's fills
+    /// from its rebate pool (strategy owner only, 0 disables)
+    #[endpoint(setStrategyRebateBps)]
+    fn set_strategy_rebate_bps(&self, strategy_id: u64, rebate_bps: u64) {
+        self.require_strategy_owner(strategy_id);
+        require!(rebate_bps <= MAX_BASIS_POINTS, ERROR_REBATE_TOO_HIGH);
+
+        self.strategy_rebate_bps(strategy_id).set(rebate_bps);
+        self.strategy_rebate_changed_event(strategy_id, rebate_bps);
+    }
+
+    /// Add the fungible tokens sent with the call to the strategy's rebate
+    /// pool (strategy owner only)
+    #[payable("*")]
+    #[endpoint(fundStrategyRebates)]
+    fn fund_strategy_rebates(&self, strategy_id: u64) {
+        self.require_strategy_owner(strategy_id);
+        let payment = self.call_value().single_esdt();
+        require!(payment.token_nonce == 0, ERROR_NON_FUNGIBLE_PAYMENT);
+        require!(payment.amount > 0u64, ERROR_INVALID_AMOUNT);
+
+        let token = &payment.token_identifier;
+        self.strategy_rebate_pool(strategy_id, token)
+            .update(|pool| *pool += &payment.amount);
+        self.strategy_rebate_deposits(token)
+            .update(|total| *total += &payment.amount);
+        self.strategy_rebates_funded_event(strategy_id, token, &payment.amount);
+    }
+
+    /// Take unused tokens back from the strategy's rebate pool (strategy
+    /// owner only)
+    #[endpoint(withdrawStrategyRebates)]
+    fn withdraw_strategy_rebates(&self, strategy_id: u64, token: TokenIdentifier, amount: BigUint) {
+        let strategy = self.require_strategy_owner(strategy_id);
+        require!(amount > 0u64, ERROR_INVALID_AMOUNT);
+        require!(
+            amount <= self.strategy_rebate_pool(strategy_id, &token).get(),
+            ERROR_REBATE_POOL_INSUFFICIENT
+        );
+
+        self.strategy_rebate_pool(strategy_id, &token)
+            .update(|pool| *pool -= &amount);
+        self.strategy_rebate_deposits(&token)
+            .update(|total| *total -= &amount);
+        self.send().direct_esdt(&strategy.owner, &token, 0u64, &amount);
+        self.strategy_rebates_withdrawn_event(strategy_id, &token, &amount);
+    }
+
+    #[view(getStrategy)]
+    fn get_strategy(&self, strategy_id: u64) -> Strategy<Self::Api> {
+        require!(!self.strategies(strategy_id).is_empty(), ERROR_STRATEGY_NOT_FOUND);
+        self.strategies(strategy_id).get()
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    fn require_strategy_owner(&self, strategy_id: u64) -> Strategy<Self::Api> {
+        let strategy = self.get_strategy(strategy_id);
+        require!(
+            strategy.owner == self.blockchain().get_caller(),
+            ERROR_NOT_STRATEGY_OWNER
+        );
+        strategy
+    }
+
+    /// Caller must own the strategy or the contract
+    fn require_strategy_admin(&self, strategy_id: u64) -> Strategy<Self::Api> {
+        let strategy = self.get_strategy(strategy_id);
+        let caller = self.blockchain().get_caller();
+        require!(
+            strategy.owner == caller || self.blockchain().get_owner_address() == caller,
+            ERROR_NOT_STRATEGY_OWNER
+        );
+        strategy
+    }
+
+    /// Orders of a paused strategy cannot be executed
+    fn require_strategy_not_paused(&self, order_id: u64) {
+        let reason = self.strategy_pause_reason(order_id);
+        require!(reason.is_ok(), (reason.error_message()));
+    }
+
+    fn strategy_pause_reason(&self, order_id: u64) -> ReasonCode {
+        if self.order_strategy(order_id).is_empty() {
+            return ReasonCode::Ok;
+        }
+        let strategy_id = self.order_strategy(order_id).get();
+        if self.strategy_paused(strategy_id).get() {
+            return ReasonCode::StrategyPaused;
+        }
+        ReasonCode::Ok
+    }
+
+    /// Rebate owed to the owner of a filled order out of its strategy's
+    /// pool, taken from the pool (zero for untagged orders)
+    fn take_strategy_rebate(
+        &self,
+        order_id: u64,
+        token: &TokenIdentifier,
+        execution_fee: &BigUint,
+    ) -> BigUint {
+        if self.order_strategy(order_id).is_empty() {
+            return BigUint::zero();
+        }
+        let strategy_id = self.order_strategy(order_id).get();
+        let rebate_bps = self.strategy_rebate_bps(strategy_id).get();
+        if rebate_bps == 0 {
+            return BigUint::zero();
+        }
+
+        let pool = self.strategy_rebate_pool(strategy_id, token).get();
+        let mut rebate = execution_fee * rebate_bps / MAX_BASIS_POINTS;
+        if rebate > pool {
+            rebate = pool;
+        }
+        if rebate == 0u64 {
+            return rebate;
+        }
+
+        self.strategy_rebate_pool(strategy_id, token)
+            .update(|pool| *pool -= &rebate);
+        self.strategy_rebate_deposits(token)
+            .update(|total| *total -= &rebate);
+        self.strategy_rebate_paid_event(order_id, strategy_id, token, &rebate);
+        rebate
+    }
+
+    /// Tag a new order with an active strategy
+    fn tag_order_strategy(&self, order_id: u64, strategy_id: u64) {
+        let strategy = self.get_strategy(strategy_id);
+        require!(strategy.active, ERROR_STRATEGY_INACTIVE);
+
+        self.link_order_strategy(order_id, strategy_id);
+    }
+
+    /// Attach an order to a strategy without checking it is still active
+    /// (used when a replaced order carries its tag over)
+    fn link_order_strategy(&self, order_id: u64, strategy_id: u64) {
+        self.order_strategy(order_id).set(strategy_id);
+        self.strategy_orders(strategy_id).insert(order_id);
+        self.strategy_stats(strategy_id)
+            .update(|stats| stats.order_count += 1);
+
+        self.order_strategy_tagged_event(order_id, strategy_id);
+    }
+
+    /// Record a fill of a tagged order; `target_output` is the output the
+    /// fill would have had exactly at the limit price
+    #[allow(clippy::too_many_arguments)]
+    fn record_strategy_fill(
+        &self,
+        order_id: u64,
+        from_token: &TokenIdentifier,
+        amount_in: &BigUint,
+        to_token: &TokenIdentifier,
+        amount_out: &BigUint,
+        target_output: &BigUint,
+        order_completed: bool,
+    ) {
+        if self.order_strategy(order_id).is_empty() {
+            return;
+        }
+        let strategy_id = self.order_strategy(order_id).get();
+
+        self.strategy_volume_in(strategy_id, from_token)
+            .update(|volume| *volume += amount_in);
+        self.strategy_volume_out(strategy_id, to_token)
+            .update(|volume| *volume += amount_out);
+
+        let improvement = BigInt::from(amount_out.clone()) - BigInt::from(target_output.clone());
+        self.strategy_price_improvement(strategy_id, to_token)
+            .update(|total| *total += improvement);
+
+        self.strategy_stats(strategy_id).update(|stats| {
+            stats.fill_count += 1;
+            if order_completed {
+                stats.executed_order_count += 1;
+            }
+        });
+    }
+
+    // ========== STORAGE ==========
+
+    #[storage_mapper("nextStrategyId")]
+    fn next_strategy_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("strategies")]
+    fn strategies(&self, strategy_id: u64) -> SingleValueMapper<Strategy<Self::Api>>;
+
+    /// Strategy an order was tagged with
+    #[view(getOrderStrategy)]
+    #[storage_mapper("orderStrategy")]
+    fn order_strategy(&self, order_id: u64) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("strategyOrders")]
+    fn strategy_orders(&self, strategy_id: u64) -> UnorderedSetMapper<u64>;
+
+    /// Kill switch: execution of the strategy's orders is blocked while set
+    #[view(isStrategyPaused)]
+    #[storage_mapper("strategyPaused")]
+    fn strategy_paused(&self, strategy_id: u64) -> SingleValueMapper<bool>;
+
+    #[view(getStrategyStats)]
+    #[storage_mapper("strategyStats")]
+    fn strategy_stats(&self, strategy_id: u64) -> SingleValueMapper<StrategyStats>;
+
+    /// Executed input volume of a strategy per token
+    #[view(getStrategyVolumeIn)]
+    #[storage_mapper("strategyVolumeIn")]
+    fn strategy_volume_in(
+        &self,
+        strategy_id: u64,
+        token: &TokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    /// Executed gross output volume of a strategy per token
+    #[view(getStrategyVolumeOut)]
+    #[storage_mapper("strategyVolumeOut")]
+    fn strategy_volume_out(
+        &self,
+        strategy_id: u64,
+        token: &TokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+
+    /// Output above (or below, within slippage) the limit price, per token
+    #[view(getStrategyPriceImprovement)]
+    #[storage_mapper("strategyPriceImprovement")]
+    fn strategy_price_improvement(
+        &self,
+        strategy_id: u64,
+        token: &TokenIdentifier,
+    ) -> SingleValueMapper<BigInt>;
+
+    /// Share (in bps) of the execution fee of the strategy's fills rebated
+    #[view(getStrategyRebateBps)]
+    #[storage_mapper("strategyRebateBps")]
+    fn strategy_rebate_bps(&self, strategy_id: u64) -> SingleValueMapper<u64>;
+
+    /// Tokens funded by the strategy owner to pay rebates
+    #[view(getStrategyRebatePool)]
+    #[storage_mapper("strategyRebatePool")]
+    fn strategy_rebate_pool(
+        &self,
+        strategy_id: u64,
+        token: &TokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+}
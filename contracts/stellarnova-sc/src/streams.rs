@@ -0,0 +1,146 @@
+//! Payout Streams Module for StellarNova
+//!
+//! An order can opt into streaming: instead of one transfer per fill, the
+//! swap output vests linearly over a fixed duration and the owner claims what
+//! has vested with `claimPayoutStream`. A new fill restarts the schedule for
+//! the part still locked, so vested amounts never decrease.
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::errors::*;
+
+/// Longest payout stream (90 days)
+pub const MAX_PAYOUT_STREAM_SECONDS: u64 = 90 * 24 * 60 * 60;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct PayoutStream<M: ManagedTypeApi> {
+    pub user: ManagedAddress<M>,
+    pub token: TokenIdentifier<M>,
+    pub locked: BigUint<M>,       // vesting between `start` and `end`
+    pub withdrawable: BigUint<M>, // vested, not claimed yet
+    pub start: u64,
+    pub end: u64,
+}
+
+#[multiversx_sc::module]
+pub trait StreamsModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::blacklist::BlacklistModule
+{
+    /// Send everything vested so far to the order owner
+    /// Returns the claimed amount
+    #[endpoint(claimPayoutStream)]
+    fn claim_payout_stream(&self, order_id: u64) -> BigUint {
+        require!(!self.payout_streams(order_id).is_empty(), ERROR_STREAM_NOT_FOUND);
+        let mut stream = self.payout_streams(order_id).get();
+        let caller = self.blockchain().get_caller();
+        require!(stream.user == caller, ERROR_NOT_YOUR_ORDER);
+        self.require_not_blacklisted(&caller);
+
+        self.vest_stream(&mut stream);
+        let amount = core::mem::replace(&mut stream.withdrawable, BigUint::zero());
+        if stream.locked == 0u64 {
+            self.payout_streams(order_id).clear();
+        } else {
+            self.payout_streams(order_id).set(&stream);
+        }
+
+        if amount > 0u64 {
+            self.streamed_balance(&stream.token)
+                .update(|total| *total -= &amount);
+            self.send().direct_esdt(&caller, &stream.token, 0, &amount);
+        }
+
+        self.payout_stream_claimed_event(order_id, &caller, &amount);
+        amount
+    }
+
+    /// Amount of an order's stream that can be claimed right now
+    #[view(getClaimableStream)]
+    fn get_claimable_stream(&self, order_id: u64) -> BigUint {
+        if self.payout_streams(order_id).is_empty() {
+            return BigUint::zero();
+        }
+        let mut stream = self.payout_streams(order_id).get();
+        self.vest_stream(&mut stream);
+        stream.withdrawable
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    fn is_streamed(&self, order_id: u64) -> bool {
+        self.payout_stream_duration(order_id).get() > 0
+    }
+
+    /// Lock a fill's output in the order's stream
+    fn stream_payout(
+        &self,
+        order_id: u64,
+        user: &ManagedAddress,
+        token: &TokenIdentifier,
+        amount: &BigUint,
+    ) {
+        if *amount == 0u64 {
+            return;
+        }
+
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        let duration = self.payout_stream_duration(order_id).get();
+
+        let mut stream = if self.payout_streams(order_id).is_empty() {
+            PayoutStream {
+                user: user.clone(),
+                token: token.clone(),
+                locked: BigUint::zero(),
+                withdrawable: BigUint::zero(),
+                start: current_time,
+                end: current_time,
+            }
+        } else {
+            self.payout_streams(order_id).get()
+        };
+
+        self.vest_stream(&mut stream);
+        stream.locked += amount;
+        stream.start = current_time;
+        stream.end = current_time + duration;
+        self.payout_streams(order_id).set(&stream);
+
+        self.streamed_balance(token)
+            .update(|total| *total += amount);
+        self.payout_streamed_event(order_id, stream.end, amount);
+    }
+
+    /// Move what vested since `start` from `locked` to `withdrawable`
+    fn vest_stream(&self, stream: &mut PayoutStream<Self::Api>) {
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        if current_time <= stream.start {
+            return;
+        }
+
+        let vested = if current_time >= stream.end {
+            stream.locked.clone()
+        } else {
+            &stream.locked * (current_time - stream.start) / (stream.end - stream.start)
+        };
+        stream.locked -= &vested;
+        stream.withdrawable += &vested;
+        stream.start = core::cmp::min(current_time, stream.end);
+    }
+
+    // ========== STORAGE ==========
+
+    /// Vesting duration for the output of an order (0 = paid out directly)
+    #[view(getPayoutStreamDuration)]
+    #[storage_mapper("payoutStreamDuration")]
+    fn payout_stream_duration(&self, order_id: u64) -> SingleValueMapper<u64>;
+
+    #[view(getPayoutStream)]
+    #[storage_mapper("payoutStreams")]
+    fn payout_streams(&self, order_id: u64) -> SingleValueMapper<PayoutStream<Self::Api>>;
+}
@@ -0,0 +1,509 @@
+//! Timelock Module for StellarNova
+//!
+//! Sensitive admin changes (pair, swap call, executor, fees and fee floors, slippage bounds, forward destinations,
+//! stray-token withdrawals and surplus sweeps, insurance compensations) are queued and can only be applied once the configured delay
+//! has elapsed, giving order creators time to exit before parameters change.
+//! With a zero delay actions are applied immediately.
+//!
+//! Proposing (queueing) is owner only; applying an elapsed action is open to
+//! anyone, since its effect was fixed when it was queued. A multisig owner
+//! therefore needs a single proposal per action, and its signers can check
+//! what they approve with `getQueuedAction` and `getNextActionId`.
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::dex::SwapCallDescriptor;
+use crate::errors::*;
+use crate::limit_orders::MAX_BASIS_POINTS;
+use crate::listings::LISTING_ACTIVATION_BATCH;
+
+/// Upper bound for the timelock delay (30 days)
+pub const MAX_TIMELOCK_DELAY_SECONDS: u64 = 30 * 24 * 60 * 60;
+
+/// Maximum execution fee (5%)
+pub const MAX_EXECUTION_FEE_BPS: u64 = 500;
+
+/// Maximum protocol fee (5%)
+pub const MAX_PROTOCOL_FEE_BPS: u64 = 500;
+
+/// Maximum cancellation fee for orders cancelled inside the window (1%)
+pub const MAX_CANCELLATION_FEE_BPS: u64 = 100;
+
+/// Upper bound for the cancellation window (1 hour)
+pub const MAX_CANCELLATION_WINDOW_SECONDS: u64 = 60 * 60;
+
+/// Emergency withdrawals and surplus sweeps always wait at least this long (24 hours),
+/// even when the general timelock delay is lower
+pub const EMERGENCY_WITHDRAW_MIN_DELAY_SECONDS: u64 = 24 * 60 * 60;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub enum TimelockAction<M: ManagedTypeApi> {
+    SetXExchangePair(ManagedAddress<M>),
+    SetLimitOrderExecutor(ManagedAddress<M>),
+    SetExecutionFeeBps(u64),
+    SetMaxSlippage(u64),
+    SetTimelockDelay(u64),
+    EmergencyWithdraw(TokenIdentifier<M>, BigUint<M>),
+    SetProtocolFeeBps(u64),
+    SetTreasuryAddress(ManagedAddress<M>),
+    SetCancellationPolicy(u64, u64),
+    SetLendingMarket(TokenIdentifier<M>, ManagedAddress<M>, TokenIdentifier<M>),
+    SetLendingInterestToUser(bool),
+    SetTokenPair(TokenIdentifier<M>, ManagedAddress<M>),
+    ListToken(TokenIdentifier<M>, ManagedAddress<M>),
+    Compensate(ManagedAddress<M>, TokenIdentifier<M>, BigUint<M>),
+    SetSwapDescriptor(ManagedAddress<M>, SwapCallDescriptor<M>),
+    SetExecutionFeeFloor(TokenIdentifier<M>, BigUint<M>),
+    SetPriceImprovementSharing(u64, u64),
+    SetXExchangeRouter(ManagedAddress<M>),
+    SetForwardDestination(ManagedAddress<M>, bool),
+    SweepSurplus(TokenIdentifier<M>),
+}
+
+impl<M: ManagedTypeApi> TimelockAction<M> {
+    /// Parameters governance proposals may change
+    pub fn is_governable(&self) -> bool {
+        matches!(
+            self,
+            TimelockAction::SetExecutionFeeBps(_)
+                | TimelockAction::SetExecutionFeeFloor(..)
+                | TimelockAction::SetPriceImprovementSharing(..)
+                | TimelockAction::SetProtocolFeeBps(_)
+                | TimelockAction::SetMaxSlippage(_)
+                | TimelockAction::SetTokenPair(..)
+                | TimelockAction::ListToken(..)
+                | TimelockAction::Compensate(..)
+        )
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct QueuedAction<M: ManagedTypeApi> {
+    pub action_id: u64,
+    pub action: TimelockAction<M>,
+    pub queued_at: u64,
+    pub executable_at: u64,
+}
+
+#[multiversx_sc::module]
+pub trait TimelockModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::limit_orders::LimitOrdersModule
+    + crate::pause::PauseModule
+    + crate::dex::DexModule
+    + crate::blacklist::BlacklistModule
+    + crate::treasury::TreasuryModule
+    + crate::managers::ManagersModule
+    + crate::positions::PositionsModule
+    + crate::strategies::StrategiesModule
+    + crate::signals::SignalsModule
+    + crate::streams::StreamsModule
+    + crate::listings::ListingsModule
+    + crate::decimals::DecimalsModule
+    + crate::caps::CapsModule
+    + crate::breaker::CircuitBreakerModule
+    + crate::staking::StakingModule
+    + crate::insurance::InsuranceModule
+    + crate::stats::StatsModule
+    + crate::clients::ClientsModule
+    + crate::commitments::CommitmentsModule
+    + crate::auctions::AuctionsModule
+    + crate::gas_station::GasStationModule
+    + crate::forwarding::ForwardingModule
+{
+    /// Queue a change of the timelock delay itself (owner only)
+    /// Lowering the delay has to wait out the current delay
+    #[only_owner]
+    #[endpoint(setTimelockDelay)]
+    fn set_timelock_delay(&self, delay_seconds: u64) -> u64 {
+        self.queue_action(TimelockAction::SetTimelockDelay(delay_seconds))
+    }
+
+    /// Apply a queued action once its delay has elapsed (callable by anyone)
+    #[endpoint(executeQueuedAction)]
+    fn execute_queued_action(&self, action_id: u64) {
+        let mapper = self.queued_action(action_id);
+        require!(!mapper.is_empty(), ERROR_ACTION_NOT_FOUND);
+
+        let queued = mapper.get();
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        require!(
+            current_time >= queued.executable_at,
+            ERROR_TIMELOCK_NOT_ELAPSED
+        );
+
+        mapper.clear();
+        self.queued_action_ids().swap_remove(&action_id);
+
+        self.apply_action(&queued.action);
+        self.timelock_action_executed_event(action_id, &queued.action);
+    }
+
+    /// Drop a queued action without applying it (owner only)
+    #[only_owner]
+    #[endpoint(cancelQueuedAction)]
+    fn cancel_queued_action(&self, action_id: u64) {
+        let mapper = self.queued_action(action_id);
+        require!(!mapper.is_empty(), ERROR_ACTION_NOT_FOUND);
+
+        let queued = mapper.get();
+        mapper.clear();
+        self.queued_action_ids().swap_remove(&action_id);
+
+        self.timelock_action_cancelled_event(action_id, &queued.action);
+    }
+
+    // ========== VIEW FUNCTIONS ==========
+
+    /// All actions waiting for their delay to elapse
+    #[view(getQueuedActions)]
+    fn get_queued_actions(&self) -> MultiValueEncoded<QueuedAction<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for action_id in self.queued_action_ids().iter() {
+            result.push(self.queued_action(action_id).get());
+        }
+        result
+    }
+
+    #[view(getQueuedAction)]
+    fn get_queued_action(&self, action_id: u64) -> QueuedAction<Self::Api> {
+        let mapper = self.queued_action(action_id);
+        require!(!mapper.is_empty(), ERROR_ACTION_NOT_FOUND);
+        mapper.get()
+    }
+
+    /// Queued actions whose delay has elapsed
+    #[view(getExecutableActions)]
+    fn get_executable_actions(&self) -> MultiValueEncoded<QueuedAction<Self::Api>> {
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        let mut result = MultiValueEncoded::new();
+        for action_id in self.queued_action_ids().iter() {
+            let queued = self.queued_action(action_id).get();
+            if current_time >= queued.executable_at {
+                result.push(queued);
+            }
+        }
+        result
+    }
+
+    /// Id the next queued action will get
+    #[view(getNextActionId)]
+    fn get_next_action_id(&self) -> u64 {
+        self.next_action_id().get() + 1
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    /// Validate and queue an action, applying it right away when no delay is set
+    /// Returns the action id
+    fn queue_action(&self, action: TimelockAction<Self::Api>) -> u64 {
+        self.validate_action(&action);
+
+        let action_id = self.next_action_id().get() + 1;
+        self.next_action_id().set(action_id);
+
+        let delay = self.action_delay(&action);
+        if delay == 0 {
+            self.apply_action(&action);
+            self.timelock_action_executed_event(action_id, &action);
+            return action_id;
+        }
+
+        #[allow(deprecated)]
+        let current_time = self.blockchain().get_block_timestamp();
+        let queued = QueuedAction {
+            action_id,
+            action,
+            queued_at: current_time,
+            executable_at: current_time + delay,
+        };
+
+        self.queued_action(action_id).set(&queued);
+        self.queued_action_ids().insert(action_id);
+        self.timelock_action_queued_event(action_id, queued.executable_at, &queued.action);
+
+        action_id
+    }
+
+    fn action_delay(&self, action: &TimelockAction<Self::Api>) -> u64 {
+        let delay = self.timelock_delay().get();
+        match action {
+            TimelockAction::EmergencyWithdraw(..) | TimelockAction::SweepSurplus(_) => {
+                core::cmp::max(delay, EMERGENCY_WITHDRAW_MIN_DELAY_SECONDS)
+            }
+            _ => delay,
+        }
+    }
+
+    fn validate_action(&self, action: &TimelockAction<Self::Api>) {
+        match action {
+            TimelockAction::SetXExchangePair(pair) => {
+                require!(
+                    self.blockchain().is_smart_contract(pair),
+                    ERROR_PAIR_NOT_CONTRACT
+                );
+            }
+            TimelockAction::SetLimitOrderExecutor(executor) => {
+                require!(!executor.is_zero(), ERROR_EXECUTOR_ZERO);
+            }
+            TimelockAction::SetExecutionFeeBps(fee_bps) => {
+                require!(*fee_bps <= MAX_EXECUTION_FEE_BPS, ERROR_FEE_TOO_HIGH);
+            }
+            TimelockAction::SetMaxSlippage(max_slippage_bp) => {
+                require!(
+                    *max_slippage_bp <= MAX_BASIS_POINTS,
+                    ERROR_MAX_SLIPPAGE_ABOVE_100_PERCENT
+                );
+            }
+            TimelockAction::SetTimelockDelay(delay_seconds) => {
+                require!(
+                    *delay_seconds <= MAX_TIMELOCK_DELAY_SECONDS,
+                    ERROR_TIMELOCK_DELAY_TOO_LONG
+                );
+            }
+            TimelockAction::EmergencyWithdraw(_, amount) => {
+                require!(*amount > 0u64, ERROR_INVALID_AMOUNT);
+            }
+            TimelockAction::SetProtocolFeeBps(fee_bps) => {
+                require!(
+                    *fee_bps <= MAX_PROTOCOL_FEE_BPS,
+                    ERROR_PROTOCOL_FEE_TOO_HIGH
+                );
+            }
+            TimelockAction::SetTreasuryAddress(treasury) => {
+                require!(!treasury.is_zero(), ERROR_TREASURY_ZERO);
+            }
+            TimelockAction::SetCancellationPolicy(window_seconds, fee_bps) => {
+                require!(
+                    *window_seconds <= MAX_CANCELLATION_WINDOW_SECONDS,
+                    ERROR_CANCELLATION_WINDOW_TOO_LONG
+                );
+                require!(
+                    *fee_bps <= MAX_CANCELLATION_FEE_BPS,
+                    ERROR_CANCELLATION_FEE_TOO_HIGH
+                );
+            }
+            TimelockAction::SetLendingMarket(_, market, _) => {
+                // The zero address disables lending for the token
+                require!(
+                    market.is_zero() || self.blockchain().is_smart_contract(market),
+                    ERROR_MARKET_NOT_CONTRACT
+                );
+            }
+            TimelockAction::SetLendingInterestToUser(_) => {}
+            TimelockAction::SetTokenPair(token, pair) => {
+                require!(
+                    self.whitelisted_tokens().contains(token),
+                    ERROR_NOT_WHITELISTED
+                );
+                require!(
+                    self.blockchain().is_smart_contract(pair),
+                    ERROR_PAIR_NOT_CONTRACT
+                );
+            }
+            TimelockAction::ListToken(token, pair) => {
+                require!(
+                    !self.whitelisted_tokens().contains(token),
+                    ERROR_ALREADY_WHITELISTED
+                );
+                require!(
+                    self.blockchain().is_smart_contract(pair),
+                    ERROR_PAIR_NOT_CONTRACT
+                );
+            }
+            TimelockAction::Compensate(_, token, amount) => {
+                self.require_compensation_allowed(token, amount);
+            }
+            TimelockAction::SetSwapDescriptor(pair, _) => {
+                require!(
+                    self.blockchain().is_smart_contract(pair),
+                    ERROR_PAIR_NOT_CONTRACT
+                );
+            }
+            TimelockAction::SetExecutionFeeFloor(token, _) => {
+                require!(
+                    self.whitelisted_tokens().contains(token),
+                    ERROR_NOT_WHITELISTED
+                );
+            }
+            TimelockAction::SetPriceImprovementSharing(executor_bps, treasury_bps) => {
+                require!(
+                    executor_bps + treasury_bps <= MAX_BASIS_POINTS,
+                    ERROR_IMPROVEMENT_SHARES_TOO_HIGH
+                );
+            }
+            TimelockAction::SetXExchangeRouter(router) => {
+                require!(
+                    self.blockchain().is_smart_contract(router),
+                    ERROR_PAIR_NOT_CONTRACT
+                );
+            }
+            TimelockAction::SetForwardDestination(destination, allowed) => {
+                require!(
+                    !*allowed || self.blockchain().is_smart_contract(destination),
+                    ERROR_DESTINATION_NOT_CONTRACT
+                );
+            }
+            TimelockAction::SweepSurplus(token) => {
+                require!(self.surplus_balance(token) > 0u64, ERROR_NO_SURPLUS);
+            }
+        }
+    }
+
+    fn apply_action(&self, action: &TimelockAction<Self::Api>) {
+        match action {
+            TimelockAction::SetXExchangePair(pair) => {
+                let old_pair = self.address_or_zero(&self.xexchange_pair());
+                self.xexchange_pair().set(pair);
+                self.xexchange_pair_changed_event(&old_pair, pair);
+            }
+            TimelockAction::SetLimitOrderExecutor(executor) => {
+                let old_executor = self.address_or_zero(&self.limit_order_executor());
+                self.limit_order_executor().set(executor);
+                self.limit_order_executor_changed_event(&old_executor, executor);
+            }
+            TimelockAction::SetExecutionFeeBps(fee_bps) => {
+                let old_fee_bps = self.execution_fee_bps().replace(*fee_bps);
+                self.execution_fee_changed_event(old_fee_bps, *fee_bps);
+            }
+            TimelockAction::SetMaxSlippage(max_slippage_bp) => {
+                let old_max_slippage = self.max_slippage().replace(*max_slippage_bp);
+                self.max_slippage_changed_event(old_max_slippage, *max_slippage_bp);
+            }
+            TimelockAction::SetTimelockDelay(delay_seconds) => {
+                let old_delay = self.timelock_delay().replace(*delay_seconds);
+                self.timelock_delay_changed_event(old_delay, *delay_seconds);
+            }
+            TimelockAction::EmergencyWithdraw(token, amount) => {
+                self.withdraw_surplus(token, amount);
+            }
+            TimelockAction::SetProtocolFeeBps(fee_bps) => {
+                let old_fee_bps = self.protocol_fee_bps().replace(*fee_bps);
+                self.protocol_fee_changed_event(old_fee_bps, *fee_bps);
+            }
+            TimelockAction::SetTreasuryAddress(treasury) => {
+                let old_treasury = self.address_or_zero(&self.treasury_address());
+                self.treasury_address().set(treasury);
+                self.treasury_address_changed_event(&old_treasury, treasury);
+            }
+            TimelockAction::SetCancellationPolicy(window_seconds, fee_bps) => {
+                self.cancellation_window().set(*window_seconds);
+                self.cancellation_fee_bps().set(*fee_bps);
+                self.cancellation_policy_changed_event(*window_seconds, *fee_bps);
+            }
+            TimelockAction::SetLendingMarket(token, market, share_token) => {
+                if market.is_zero() {
+                    self.lending_market(token).clear();
+                } else {
+                    self.lending_market(token)
+                        .set((market.clone(), share_token.clone()));
+                }
+                self.lending_market_changed_event(token, market, share_token);
+            }
+            TimelockAction::SetLendingInterestToUser(to_user) => {
+                self.lending_interest_to_user().set(*to_user);
+                self.lending_interest_policy_changed_event(*to_user);
+            }
+            TimelockAction::SetTokenPair(token, pair) => {
+                let old_pair = self.address_or_zero(&self.token_pair(token));
+                self.token_pair(token).set(pair);
+                self.token_pair_changed_event(token, &old_pair, pair);
+
+                // Orders waiting for this pair become executable now
+                if self.awaiting_pair_tokens().swap_remove(token) {
+                    self.activate_dormant_batch(token, LISTING_ACTIVATION_BATCH);
+                }
+            }
+            TimelockAction::ListToken(token, pair) => {
+                // Whitelisted by the owner while the action was queued
+                if self.whitelisted_tokens().insert(token.clone()) {
+                    self.token_whitelisted_event(token);
+                }
+                self.apply_action(&TimelockAction::SetTokenPair(token.clone(), pair.clone()));
+            }
+            TimelockAction::Compensate(user, token, amount) => {
+                // Re-checked: the fund may have paid other claims meanwhile
+                self.pay_compensation(user, token, amount);
+            }
+            TimelockAction::SetSwapDescriptor(pair, descriptor) => {
+                // An empty function restores the xExchange layout
+                if descriptor.function.is_empty() {
+                    self.swap_descriptor(pair).clear();
+                } else {
+                    self.swap_descriptor(pair).set(descriptor);
+                }
+                let descriptor = self.swap_call_descriptor(pair);
+                self.swap_descriptor_changed_event(pair, &descriptor.function);
+            }
+            TimelockAction::SetExecutionFeeFloor(token, min_fee) => {
+                let old_min_fee = self.execution_fee_floor(token).replace(min_fee.clone());
+                self.execution_fee_floor_changed_event(token, &old_min_fee, min_fee);
+            }
+            TimelockAction::SetPriceImprovementSharing(executor_bps, treasury_bps) => {
+                self.improvement_executor_bps().set(*executor_bps);
+                self.improvement_treasury_bps().set(*treasury_bps);
+                self.price_improvement_sharing_changed_event(*executor_bps, *treasury_bps);
+            }
+            TimelockAction::SetXExchangeRouter(router) => {
+                let old_router = self.address_or_zero(&self.xexchange_router());
+                self.xexchange_router().set(router);
+                self.xexchange_router_changed_event(&old_router, router);
+            }
+            TimelockAction::SetForwardDestination(destination, allowed) => {
+                if *allowed {
+                    self.forward_destinations().insert(destination.clone());
+                } else {
+                    self.forward_destinations().swap_remove(destination);
+                }
+                self.forward_destination_changed_event(destination, *allowed);
+            }
+            TimelockAction::SweepSurplus(token) => {
+                // Recomputed: the surplus may have changed while queued
+                self.sweep_surplus_to_treasury(token);
+            }
+        }
+    }
+
+    fn address_or_zero(&self, mapper: &SingleValueMapper<ManagedAddress>) -> ManagedAddress {
+        if mapper.is_empty() {
+            ManagedAddress::zero()
+        } else {
+            mapper.get()
+        }
+    }
+
+    /// Send stray tokens (balance above accounted escrow and pools) to the owner
+    /// Escrowed user funds can never be moved by this path
+    fn withdraw_surplus(&self, token: &TokenIdentifier, amount: &BigUint) {
+        let balance = self.contract_balance(token);
+        let escrow = self.accounted_balance(token);
+        require!(*amount <= self.surplus_balance(token), ERROR_EXCEEDS_SURPLUS);
+
+        let receiver = self.blockchain().get_owner_address();
+        self.send().direct_esdt(&receiver, token, 0, amount);
+
+        self.emergency_withdrawal_event(&receiver, token, amount, &balance, &escrow);
+    }
+
+    // ========== STORAGE ==========
+
+    /// Delay (seconds) between queueing and applying a sensitive action
+    #[view(getTimelockDelay)]
+    #[storage_mapper("timelockDelay")]
+    fn timelock_delay(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("nextActionId")]
+    fn next_action_id(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("queuedAction")]
+    fn queued_action(&self, action_id: u64) -> SingleValueMapper<QueuedAction<Self::Api>>;
+
+    #[storage_mapper("queuedActionIds")]
+    fn queued_action_ids(&self) -> UnorderedSetMapper<u64>;
+}
@@ -0,0 +1,226 @@
+//! Treasury Module for StellarNova
+//!
+//! Protocol fees: a bps share of every fill's output accrues here per token
+//! and is paid out to the treasury address on claim. The client an order was
+//! created through gets its share of that order's fees first, then stakers
+//! get theirs of every accrued fee (see the clients and staking modules).
+//!
+//! Fee conversion: with a treasury token set (`setTreasuryToken`), the owner
+//! swaps the fees accrued in other tokens into it with `convertProtocolFees`,
+//! one swap per token on the pair `getSwapPair` picks, each with its own
+//! minimum output. The swapped fees are out of `getProtocolFees` while the
+//! swap is in flight; the callback books the output (and anything returned)
+//! as protocol fees again, or the whole amount back if the swap failed.
+
+multiversx_sc::imports!();
+
+use crate::dex::SwapArgument;
+use crate::errors::*;
+use crate::limit_orders::MAX_BASIS_POINTS;
+
+/// Gas of one fee conversion swap
+const FEE_CONVERSION_GAS: u64 = 30_000_000;
+
+/// Gas reserved for the fee conversion callback
+const FEE_CONVERSION_CALLBACK_GAS: u64 = 10_000_000;
+
+#[multiversx_sc::module]
+pub trait TreasuryModule:
+    crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::dex::DexModule
+    + crate::staking::StakingModule
+    + crate::clients::ClientsModule
+{
+    /// Send all accrued protocol fees in `token` to the treasury (callable
+    /// by anyone: the receiver does not depend on the caller)
+    #[endpoint(claimProtocolFees)]
+    fn claim_protocol_fees(&self, token: TokenIdentifier) -> BigUint {
+        let amount = self.protocol_fees(&token).take();
+        require!(amount > 0u64, ERROR_NO_PROTOCOL_FEES);
+
+        let receiver = self.treasury_receiver();
+        self.send().direct_esdt(&receiver, &token, 0, &amount);
+        self.fees_claimed_event(&receiver, &token, &amount);
+
+        amount
+    }
+
+    /// Compare the contract's balance of `token` with what it owes (escrow
+    /// and protocol pools) and emit the difference (callable by anyone)
+    /// Returns the delta: positive for a surplus such as tokens sent to the
+    /// contract by mistake (see `sweepSurplus`), negative for a shortfall
+    #[endpoint(reconcile)]
+    fn reconcile(&self, token: TokenIdentifier) -> BigInt {
+        let balance = self.contract_balance(&token);
+        let accounted = self.accounted_balance(&token);
+        let delta = BigInt::from(balance.clone()) - BigInt::from(accounted.clone());
+
+        self.escrow_reconciled_event(&token, &balance, &accounted, &delta);
+        delta
+    }
+
+    /// Token the protocol fees are converted into (owner only)
+    #[only_owner]
+    #[endpoint(setTreasuryToken)]
+    fn set_treasury_token(&self, token: TokenIdentifier) {
+        require!(
+            self.whitelisted_tokens().contains(&token),
+            ERROR_TOKEN_NOT_WHITELISTED
+        );
+        self.treasury_token().set(&token);
+        self.treasury_token_changed_event(&token);
+    }
+
+    /// Swap the accrued protocol fees of each listed token into the
+    /// treasury token, for at least the paired minimum output (owner only)
+    ///
+    /// # Arguments
+    /// * `conversions` - (token, min_amount_out) pairs; tokens without
+    ///   accrued fees are skipped
+    #[only_owner]
+    #[endpoint(convertProtocolFees)]
+    fn convert_protocol_fees(
+        &self,
+        conversions: MultiValueEncoded<MultiValue2<TokenIdentifier, BigUint>>,
+    ) {
+        require!(!self.treasury_token().is_empty(), ERROR_TREASURY_TOKEN_NOT_SET);
+        let treasury_token = self.treasury_token().get();
+
+        for conversion in conversions {
+            let (token, min_amount_out) = conversion.into_tuple();
+            require!(token != treasury_token, ERROR_SAME_TOKEN);
+            let amount = self.protocol_fees(&token).take();
+            if amount == 0u64 {
+                continue;
+            }
+            self.dispatch_fee_conversion(&token, &amount, &treasury_token, &min_amount_out);
+        }
+    }
+
+    /// Callback of a fee conversion swap: every token received is booked
+    /// as protocol fees; a failed swap gives the fees back
+    #[promises_callback]
+    fn fee_conversion_callback(
+        &self,
+        token: TokenIdentifier,
+        amount: BigUint,
+        #[call_result] result: ManagedAsyncCallResult<MultiValueEncoded<EsdtTokenPayment>>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(_) => {
+                let treasury_token = self.treasury_token().get();
+                let mut output_amount = BigUint::zero();
+                for payment in self.call_value().all_esdt_transfers().iter() {
+                    if payment.token_nonce != 0 {
+                        continue;
+                    }
+                    if payment.token_identifier == treasury_token {
+                        output_amount += &payment.amount;
+                    }
+                    self.protocol_fees(&payment.token_identifier)
+                        .update(|accrued| *accrued += &payment.amount);
+                }
+                self.protocol_fees_converted_event(&token, &treasury_token, &amount, &output_amount);
+            }
+            ManagedAsyncCallResult::Err(err) => {
+                // The fees came back with the callback
+                self.protocol_fees(&token)
+                    .update(|accrued| *accrued += &amount);
+                self.protocol_fee_conversion_failed_event(&token, &amount, &err.err_msg);
+            }
+        }
+    }
+
+    // ========== HELPER FUNCTIONS ==========
+
+    /// Send the whole surplus balance of `token` to the treasury
+    /// Only what exceeds escrow and protocol pools at sweep time can move
+    fn sweep_surplus_to_treasury(&self, token: &TokenIdentifier) {
+        let amount = self.surplus_balance(token);
+        require!(amount > 0u64, ERROR_NO_SURPLUS);
+
+        let receiver = self.treasury_receiver();
+        self.send().direct_esdt(&receiver, token, 0, &amount);
+        self.surplus_swept_event(&receiver, token, &amount);
+    }
+
+    /// Swap `amount` of accrued fees in `token` for `treasury_token` on the
+    /// pair of the two, with the pair's swap call layout
+    fn dispatch_fee_conversion(
+        &self,
+        token: &TokenIdentifier,
+        amount: &BigUint,
+        treasury_token: &TokenIdentifier,
+        min_amount_out: &BigUint,
+    ) {
+        let pair_address = self.swap_pair(token, treasury_token);
+        let descriptor = self.swap_call_descriptor(&pair_address);
+
+        let mut swap_call = self
+            .tx()
+            .to(&pair_address)
+            .gas(FEE_CONVERSION_GAS)
+            .raw_call(descriptor.function);
+        for argument in descriptor.arguments.iter() {
+            swap_call = match *argument {
+                SwapArgument::TokenOut => swap_call.argument(treasury_token),
+                SwapArgument::MinAmountOut => swap_call.argument(min_amount_out),
+                SwapArgument::TokenIn => swap_call.argument(token),
+                SwapArgument::AmountIn => swap_call.argument(amount),
+            };
+        }
+        swap_call
+            .single_esdt(token, 0u64, amount)
+            .with_callback(self.callbacks().fee_conversion_callback(token, amount))
+            .with_extra_gas_for_callback(FEE_CONVERSION_CALLBACK_GAS)
+            .register_promise();
+    }
+
+    /// Take the protocol share out of a fill's output and accrue it, after
+    /// crediting the order's client with its share
+    /// Returns the fee amount
+    fn collect_protocol_fee(
+        &self,
+        order_id: u64,
+        token: &TokenIdentifier,
+        output_amount: &BigUint,
+    ) -> BigUint {
+        let fee = self.protocol_fee_amount(output_amount);
+        if fee > 0u64 {
+            let client_share = self.credit_client_fee_share(order_id, token, &fee);
+            self.accrue_protocol_fee(token, &(&fee - &client_share));
+            self.fee_collected_event(token, order_id, &fee);
+        }
+        fee
+    }
+
+    /// Book a protocol fee: the stakers' share goes to the staking reward
+    /// pool, the rest to the treasury
+    fn accrue_protocol_fee(&self, token: &TokenIdentifier, fee: &BigUint) {
+        let staker_share = self.distribute_staking_rewards(token, fee);
+        self.protocol_fees(token)
+            .update(|accrued| *accrued += fee - &staker_share);
+    }
+
+    /// Protocol share of a fill's output (rounded down)
+    fn protocol_fee_amount(&self, output_amount: &BigUint) -> BigUint {
+        output_amount * self.protocol_fee_bps().get() / MAX_BASIS_POINTS
+    }
+
+    /// Treasury address, falling back to the contract owner
+    fn treasury_receiver(&self) -> ManagedAddress {
+        if self.treasury_address().is_empty() {
+            self.blockchain().get_owner_address()
+        } else {
+            self.treasury_address().get()
+        }
+    }
+
+    // ========== STORAGE ==========
+
+    /// Token the protocol fees are converted into
+    #[view(getTreasuryToken)]
+    #[storage_mapper("treasuryToken")]
+    fn treasury_token(&self) -> SingleValueMapper<TokenIdentifier>;
+}
@@ -0,0 +1,140 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{BracketExits, OrderStatus, StellarNovaProxy};
+use stellarnova_sc::storage::StorageModule;
+
+const KEEPER: TestAddress = TestAddress::new("keeper");
+
+/// USER buys WEGLD at 30 USDC with 3,000,000 USDC (order 1), taking profit
+/// at 40 USDC (order 2) and stopping out below 25 USDC at 20 USDC or better
+/// (order 3); OWNER is the price oracle of WEGLD/USDC
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64);
+    world.account(KEEPER).nonce(1);
+    world.current_block().block_timestamp_seconds(1_000);
+
+    deploy_with_pair(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_price_oracle(OWNER)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .register_price_pair(WEGLD, USDC)
+        .run();
+
+    let exits = BracketExits {
+        take_profit_num: BigUint::from(40u64),
+        take_profit_denom: BigUint::from(1u64),
+        stop_trigger_num: BigUint::from(25u64),
+        stop_trigger_denom: BigUint::from(1u64),
+        stop_limit_num: BigUint::from(20u64),
+        stop_limit_denom: BigUint::from(1u64),
+        lifetime_seconds: 7_200,
+    };
+    let (entry, take_profit, stop_loss) = world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_bracket_order(WEGLD, 1u64, 30u64, 100u64, 36_000u64, exits)
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(3_000_000u64))
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple();
+    assert_eq!((entry, take_profit, stop_loss), (1, 2, 3));
+
+    world
+}
+
+fn post_wegld_price(world: &mut ScenarioWorld, price: u64) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .post_price(WEGLD, USDC, price, 1u64)
+        .run();
+}
+
+#[test]
+fn triggered_stop_loss_takes_over_the_take_profit_escrow() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+    assert_eq!(order(&mut world, 2).from_amount, 99_900u64);
+    assert!(order(&mut world, 3).status == OrderStatus::Dormant);
+
+    post_wegld_price(&mut world, 26);
+    world
+        .tx()
+        .from(KEEPER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .trigger_stop_loss(3u64)
+        .returns(ExpectError(4, "E524: Order price condition not met"))
+        .run();
+
+    post_wegld_price(&mut world, 24);
+    world
+        .tx()
+        .from(KEEPER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .trigger_stop_loss(3u64)
+        .run();
+
+    let take_profit = order(&mut world, 2);
+    assert!(take_profit.status == OrderStatus::Cancelled);
+    let stop_loss = order(&mut world, 3);
+    assert!(stop_loss.status == OrderStatus::Pending);
+    assert_eq!(stop_loss.from_amount, 99_900u64);
+    world.query().to(SC).whitebox(stellarnova_sc::contract_obj, |sc| {
+        assert_eq!(sc.escrow_balance(&WEGLD.to_token_identifier()).get(), 99_900u64);
+    });
+}
+
+#[test]
+fn cancelling_the_entry_cancels_both_exits() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(1u64)
+        .run();
+
+    assert!(order(&mut world, 2).status == OrderStatus::Cancelled);
+    assert!(order(&mut world, 3).status == OrderStatus::Cancelled);
+    world
+        .tx()
+        .from(KEEPER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .trigger_stop_loss(3u64)
+        .returns(ExpectError(4, "E416: Order is not a stop-loss awaiting its trigger"))
+        .run();
+}
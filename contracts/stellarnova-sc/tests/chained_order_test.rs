@@ -0,0 +1,88 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{OrderStatus, StellarNovaProxy};
+use stellarnova_sc::storage::StorageModule;
+
+const TAKE_PROFIT_LIFETIME: u64 = 7_200;
+
+/// USER buys WEGLD with 3,000,000 USDC (order 1) and chains a take-profit
+/// selling the WEGLD back at 40 USDC (order 2)
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64);
+    world.current_block().block_timestamp_seconds(1_000);
+
+    deploy_with_pair(&mut world);
+
+    create_order(&mut world, 3_000_000, 36_000);
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_chained_order(1u64, USDC, 40u64, 1u64, 100u64, TAKE_PROFIT_LIFETIME)
+        .run();
+
+    world
+}
+
+#[test]
+fn parent_output_funds_and_activates_the_chained_order() {
+    let mut world = setup();
+    let take_profit = order(&mut world, 2);
+    assert!(take_profit.status == OrderStatus::Dormant);
+    assert_eq!(take_profit.from_amount, 0u64);
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_chained_order(1u64, USDC, 50u64, 1u64, 100u64, TAKE_PROFIT_LIFETIME)
+        .returns(ExpectError(4, "E414: Order already has a chained order"))
+        .run();
+
+    world.current_block().block_timestamp_seconds(2_000);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+
+    // The 99,900 WEGLD output stays in the contract as the take-profit escrow
+    world.check_account(USER).esdt_balance(WEGLD, 0u64);
+    let take_profit = order(&mut world, 2);
+    assert!(take_profit.status == OrderStatus::Pending);
+    assert_eq!(take_profit.from_amount, 99_900u64);
+    assert_eq!(take_profit.expires_at, 2_000 + TAKE_PROFIT_LIFETIME);
+    world.query().to(SC).whitebox(stellarnova_sc::contract_obj, |sc| {
+        assert_eq!(sc.escrow_balance(&WEGLD.to_token_identifier()).get(), 99_900u64);
+    });
+}
+
+#[test]
+fn cancelling_the_parent_cancels_the_dormant_order() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(1u64)
+        .run();
+
+    world.check_account(USER).esdt_balance(USDC, 3_000_000u64);
+    assert!(order(&mut world, 2).status == OrderStatus::Cancelled);
+}
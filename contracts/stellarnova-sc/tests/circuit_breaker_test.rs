@@ -0,0 +1,208 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{OrderStatus, ReasonCode, StellarNovaProxy};
+
+const MAKER: TestAddress = TestAddress::new("maker");
+
+const WINDOW: u64 = 600;
+
+/// Orders 1-3 each sell 300,000 USDC for WEGLD on the mock pair (30 USDC per
+/// WEGLD); the breaker trips on a 10% move within `WINDOW`
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 900_000u64);
+    world.current_block().block_timestamp_seconds(1_000);
+
+    deploy_with_pair(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_circuit_breaker(1_000u64, WINDOW)
+        .run();
+
+    for _ in 0..3 {
+        create_order(&mut world, 300_000, 36_000);
+    }
+
+    world
+}
+
+fn is_tripped(world: &mut ScenarioWorld) -> bool {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .is_pair_tripped(PAIR)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn abnormal_move_halts_the_pair_until_cleared() {
+    let mut world = setup();
+
+    execute(&mut world, 1);
+    assert!(!is_tripped(&mut world));
+
+    // 30 -> 20 USDC per WEGLD is a 50% move of the WEGLD received per USDC
+    set_pair_price(&mut world, 20);
+    world.current_block().block_timestamp_seconds(1_000 + WINDOW);
+    execute(&mut world, 2);
+    assert!(is_tripped(&mut world));
+
+    // The tripping swap is settled normally
+    let order = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order(2u64)
+        .returns(ReturnsResult)
+        .run();
+    assert!(order.status == OrderStatus::Executed);
+
+    let reason = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .can_execute_order(3u64, 1u64, 30u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(reason, ReasonCode::PairTripped);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(3u64, 1u64, 30u64)
+        .returns(ExpectError(
+            4,
+            "E527: Executions on this pair are halted by the circuit breaker",
+        ))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .clear_circuit_breaker(PAIR)
+        .run();
+    assert!(!is_tripped(&mut world));
+
+    // The new price level is the reference now
+    execute(&mut world, 3);
+    assert!(!is_tripped(&mut world));
+    world.check_account(PAIR).esdt_balance(USDC, 900_000u64);
+}
+
+#[test]
+fn moves_outside_the_window_do_not_trip() {
+    let mut world = setup();
+
+    execute(&mut world, 1);
+    set_pair_price(&mut world, 20);
+    world.current_block().block_timestamp_seconds(1_000 + WINDOW + 1);
+    execute(&mut world, 2);
+    assert!(!is_tripped(&mut world));
+}
+
+#[test]
+fn clearing_requires_a_tripped_pair() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .clear_circuit_breaker(PAIR)
+        .returns(ExpectError(4, "E623: Circuit breaker is not tripped for this pair"))
+        .run();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_circuit_breaker(0u64, 0u64)
+        .returns(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+}
+
+#[test]
+fn tripped_pair_halts_matching() {
+    let mut world = setup();
+    world
+        .account(MAKER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 20_000u64);
+    // Order 4 sells 20,000 WEGLD for at least 29 USDC each, crossing order 3
+    world
+        .tx()
+        .from(MAKER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order(
+            USDC,
+            29u64,
+            1u64,
+            100u64,
+            36_000u64,
+            OptionalValue::<u64>::None,
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&WEGLD.to_token_identifier(), 0, &BigUint::from(20_000u64))
+        .run();
+
+    execute(&mut world, 1);
+    set_pair_price(&mut world, 20);
+    execute(&mut world, 2);
+    assert!(is_tripped(&mut world));
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .match_orders(3u64, 4u64)
+        .returns(ExpectError(
+            4,
+            "E527: Executions on this pair are halted by the circuit breaker",
+        ))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .clear_circuit_breaker(PAIR)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .match_orders(3u64, 4u64)
+        .run();
+
+    // At the midpoint of 30 and 29 USDC per WEGLD, 300,000 USDC buy
+    // 300,000 * 59 / 1,740 = 10,172 WEGLD
+    let buy = order(&mut world, 3);
+    let sell = order(&mut world, 4);
+    assert!(buy.status == OrderStatus::Executed);
+    assert!(sell.status == OrderStatus::Pending);
+    assert_eq!(sell.from_amount, BigUint::from(20_000u64 - 10_172));
+    world.check_account(PAIR).esdt_balance(USDC, 600_000u64);
+}
@@ -0,0 +1,246 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+
+const FRONTEND: TestAddress = TestAddress::new("frontend");
+
+/// 100 bps protocol fee, 20% of it shared with clients; client 1 pays out
+/// to FRONTEND
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 6_000_000u64);
+    world.account(FRONTEND).nonce(1);
+
+    deploy_with_pair(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_protocol_fee_bps(100u64)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_client_fee_share_bps(2_000u64)
+        .run();
+
+    let client_id = world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .register_client(FRONTEND)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(client_id, 1);
+
+    world
+}
+
+/// Sell 3,000,000 USDC for 100,000 WEGLD through `client_id`
+fn create_client_order(world: &mut ScenarioWorld, client_id: u64) -> u64 {
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_client_order(
+            client_id,
+            WEGLD,
+            1u64,
+            30u64,
+            100u64,
+            36_000u64,
+            OptionalValue::<u64>::None,
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(3_000_000u64))
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn client_fees(world: &mut ScenarioWorld) -> BigUint<StaticApi> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .client_fees(1u64, WEGLD)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn client_earns_share_of_protocol_fees() {
+    let mut world = setup();
+    let order_id = create_client_order(&mut world, 1);
+    assert_eq!(
+        world
+            .query()
+            .to(SC)
+            .typed(StellarNovaProxy)
+            .order_client(order_id)
+            .returns(ReturnsResult)
+            .run(),
+        1u64
+    );
+
+    // 1,000 WEGLD protocol fee: 200 for the client, 800 for the treasury
+    execute(&mut world, order_id);
+    assert_eq!(client_fees(&mut world), BigUint::from(200u64));
+    let protocol_fees = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .protocol_fees(WEGLD)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(protocol_fees, BigUint::from(800u64));
+    let volume = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .client_volume(1u64, USDC)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(volume, BigUint::from(3_000_000u64));
+
+    // Anyone can claim; the fees go to the payout address
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .claim_client_fees(1u64, WEGLD)
+        .run();
+    world.check_account(FRONTEND).esdt_balance(WEGLD, 200u64);
+    assert_eq!(client_fees(&mut world), BigUint::zero());
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .claim_client_fees(1u64, WEGLD)
+        .returns(ExpectError(4, "E807: No client fees to claim"))
+        .run();
+}
+
+#[test]
+fn orders_without_client_pay_full_fee_to_treasury() {
+    let mut world = setup();
+    create_order(&mut world, 3_000_000, 36_000);
+
+    execute(&mut world, 1);
+    assert_eq!(client_fees(&mut world), BigUint::zero());
+    let protocol_fees = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .protocol_fees(WEGLD)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(protocol_fees, BigUint::from(1_000u64));
+}
+
+#[test]
+fn only_active_clients_take_new_orders() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_client_order(
+            2u64,
+            WEGLD,
+            1u64,
+            30u64,
+            100u64,
+            36_000u64,
+            OptionalValue::<u64>::None,
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(3_000_000u64))
+        .returns(ExpectError(4, "E804: Client not found"))
+        .run();
+
+    let order_id = create_client_order(&mut world, 1);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_client_active(1u64, false)
+        .run();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_client_order(
+            1u64,
+            WEGLD,
+            1u64,
+            30u64,
+            100u64,
+            36_000u64,
+            OptionalValue::<u64>::None,
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(3_000_000u64))
+        .returns(ExpectError(4, "E806: Client is not active"))
+        .run();
+
+    // Orders attributed before deactivation still earn the client its share
+    execute(&mut world, order_id);
+    assert_eq!(client_fees(&mut world), BigUint::from(200u64));
+}
+
+#[test]
+fn client_admin_checks() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_client_payout_address(1u64, USER)
+        .returns(ExpectError(4, "E805: Not the client payout address or owner"))
+        .run();
+    world
+        .tx()
+        .from(FRONTEND)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_client_payout_address(1u64, USER)
+        .run();
+    let client = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_client(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(client.payout_address, USER.to_managed_address());
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_client_fee_share_bps(10_001u64)
+        .returns(ExpectError(4, "E628: Client fee share cannot exceed 10000 bps"))
+        .run();
+}
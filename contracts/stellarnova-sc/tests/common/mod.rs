@@ -0,0 +1,139 @@
+//! Accounts, tokens and deployments shared by the scenario tests
+//!
+//! Each test file only keeps the setup of its own scenario on top of these.
+
+#![allow(dead_code)]
+
+use multiversx_sc_scenario::imports::*;
+
+use stellarnova_sc::stellarnova_proxy::{LimitOrder, StellarNovaProxy};
+
+pub const OWNER: TestAddress = TestAddress::new("owner");
+pub const USER: TestAddress = TestAddress::new("user");
+pub const SC: TestSCAddress = TestSCAddress::new("stellarnova");
+pub const PAIR: TestSCAddress = TestSCAddress::new("mock-pair");
+pub const CODE_PATH: MxscPath = MxscPath::new("output/stellarnova-sc.mxsc.json");
+pub const PAIR_CODE_PATH: MxscPath = MxscPath::new("../mock-pair/output/mock-pair.mxsc.json");
+pub const LENDING_CODE_PATH: MxscPath =
+    MxscPath::new("../mock-lending/output/mock-lending.mxsc.json");
+
+pub const USDC: TestTokenIdentifier = TestTokenIdentifier::new("USDC-123456");
+pub const WEGLD: TestTokenIdentifier = TestTokenIdentifier::new("WEGLD-123456");
+
+/// WEGLD OWNER puts into the mock pair in `deploy_pair`
+pub const PAIR_RESERVE: u64 = 1_000_000;
+
+pub fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.set_current_dir_from_workspace("contracts/stellarnova-sc");
+    blockchain.register_contract(CODE_PATH, stellarnova_sc::ContractBuilder);
+    blockchain.register_contract(PAIR_CODE_PATH, mock_pair::ContractBuilder);
+    blockchain.register_contract(LENDING_CODE_PATH, mock_lending::ContractBuilder);
+    blockchain
+}
+
+/// StellarNova at SC, owned by OWNER: 5% max slippage, USDC and WEGLD
+/// whitelisted
+pub fn deploy(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER)
+        .typed(StellarNovaProxy)
+        .init(500u64, MultiValueVec::from(vec![USDC, WEGLD]))
+        .code(CODE_PATH)
+        .new_address(SC)
+        .run();
+}
+
+/// An empty mock WEGLD/USDC pair at `address`
+pub fn deploy_mock_pair(world: &mut ScenarioWorld, address: TestSCAddress) {
+    world
+        .tx()
+        .from(OWNER)
+        .raw_deploy()
+        .code(PAIR_CODE_PATH)
+        .argument(&WEGLD)
+        .argument(&USDC)
+        .new_address(address)
+        .run();
+}
+
+/// Mock WEGLD/USDC pair at PAIR holding PAIR_RESERVE WEGLD of OWNER and
+/// quoting 30 USDC per WEGLD
+pub fn deploy_pair(world: &mut ScenarioWorld) {
+    deploy_mock_pair(world, PAIR);
+    world
+        .tx()
+        .from(OWNER)
+        .to(PAIR)
+        .raw_call("addReserves")
+        .single_esdt(&WEGLD.to_token_identifier(), 0, &BigUint::from(PAIR_RESERVE))
+        .run();
+    set_pair_price(world, 30);
+}
+
+/// USDC per WEGLD on the mock pair
+pub fn set_pair_price(world: &mut ScenarioWorld, usdc_per_wegld: u64) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(PAIR)
+        .raw_call("setPrice")
+        .argument(&usdc_per_wegld)
+        .argument(&1u64)
+        .run();
+}
+
+/// `deploy_pair` and `deploy`, with WEGLD orders swapped on PAIR
+pub fn deploy_with_pair(world: &mut ScenarioWorld) {
+    deploy_pair(world);
+    deploy(world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_token_pair(WEGLD, PAIR)
+        .run();
+}
+
+/// USER sells `amount` USDC for at least 1 WEGLD per 30 USDC (1% slippage)
+pub fn create_order(world: &mut ScenarioWorld, amount: u64, expires_in_seconds: u64) {
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order(
+            WEGLD,
+            1u64,
+            30u64,
+            100u64,
+            expires_in_seconds,
+            OptionalValue::<u64>::None,
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(amount))
+        .run();
+}
+
+/// OWNER, the default executor, executes `order_id` at 30 USDC per WEGLD
+pub fn execute(world: &mut ScenarioWorld, order_id: u64) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(order_id, 1u64, 30u64)
+        .run();
+}
+
+pub fn order(world: &mut ScenarioWorld, order_id: u64) -> LimitOrder<StaticApi> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order(order_id)
+        .returns(ReturnsResult)
+        .run()
+}
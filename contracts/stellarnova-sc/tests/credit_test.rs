@@ -0,0 +1,201 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+use stellarnova_sc::storage::StorageModule;
+
+const MANAGER: TestAddress = TestAddress::new("manager");
+
+const CREDIT: u64 = 2_000_000;
+
+/// USER deposited `CREDIT` USDC and holds 1,000,000 more in the wallet
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world.account(MANAGER).nonce(1);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, CREDIT + 1_000_000);
+
+    deploy(&mut world);
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .deposit()
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(CREDIT))
+        .run();
+
+    world
+}
+
+fn user_credit(world: &mut ScenarioWorld) -> BigUint<StaticApi> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .user_credit(USER, USDC)
+        .returns(ReturnsResult)
+        .run()
+}
+
+/// The contract holds exactly `balance` USDC, all of it accounted for, of
+/// which `credit` is unused credit
+fn check_accounting(world: &mut ScenarioWorld, balance: u64, credit: u64) {
+    world.check_account(SC).esdt_balance(USDC, balance);
+    let total_credit = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .total_credit(USDC)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(total_credit, BigUint::from(credit));
+    world
+        .query()
+        .to(SC)
+        .whitebox(stellarnova_sc::contract_obj, |sc| {
+            let accounted = sc.accounted_balance(&USDC.to_token_identifier());
+            assert_eq!(accounted, BigUint::from(balance));
+        });
+}
+
+fn create_from_credit(world: &mut ScenarioWorld, amount: u64) -> u64 {
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order_from_credit(USDC, amount, WEGLD, 1u64, 30u64, 100u64, 3_600u64)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn deposit_and_withdraw_credit() {
+    let mut world = setup();
+    assert_eq!(user_credit(&mut world), BigUint::from(CREDIT));
+    check_accounting(&mut world, CREDIT, CREDIT);
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .withdraw_credit(USDC, 500_000u64)
+        .run();
+
+    assert_eq!(user_credit(&mut world), BigUint::from(CREDIT - 500_000));
+    world.check_account(USER).esdt_balance(USDC, 1_500_000u64);
+    check_accounting(&mut world, CREDIT - 500_000, CREDIT - 500_000);
+}
+
+#[test]
+fn withdrawal_is_limited_to_the_credit() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .withdraw_credit(USDC, CREDIT + 1)
+        .returns(ExpectError(4, "E310: Insufficient credit"))
+        .run();
+    world
+        .tx()
+        .from(MANAGER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .withdraw_credit(USDC, 1u64)
+        .returns(ExpectError(4, "E310: Insufficient credit"))
+        .run();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .withdraw_credit(USDC, 0u64)
+        .returns(ExpectError(4, "E307: Amount must be greater than zero"))
+        .run();
+
+    assert_eq!(user_credit(&mut world), BigUint::from(CREDIT));
+    check_accounting(&mut world, CREDIT, CREDIT);
+}
+
+#[test]
+fn order_from_credit_moves_credit_to_escrow() {
+    let mut world = setup();
+
+    let order_id = create_from_credit(&mut world, 1_500_000);
+    assert_eq!(order(&mut world, order_id).from_amount, BigUint::from(1_500_000u64));
+    assert_eq!(user_credit(&mut world), BigUint::from(500_000u64));
+    check_accounting(&mut world, CREDIT, 500_000);
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order_from_credit(USDC, 500_001u64, WEGLD, 1u64, 30u64, 100u64, 3_600u64)
+        .returns(ExpectError(4, "E310: Insufficient credit"))
+        .run();
+
+    // The refund goes to the wallet, not back to credit
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(order_id)
+        .run();
+    assert_eq!(user_credit(&mut world), BigUint::from(500_000u64));
+    world.check_account(USER).esdt_balance(USDC, 2_500_000u64);
+    check_accounting(&mut world, 500_000, 500_000);
+}
+
+#[test]
+fn manager_creates_orders_from_the_users_credit() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .approve_order_manager(MANAGER)
+        .run();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_manager_daily_cap(MANAGER, USDC, CREDIT)
+        .run();
+
+    let order_id = world
+        .tx()
+        .from(MANAGER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order_as_manager(USER, USDC, 1_000_000u64, WEGLD, 1u64, 30u64, 100u64, 3_600u64)
+        .returns(ReturnsResult)
+        .run();
+
+    assert_eq!(order(&mut world, order_id).user, USER.to_managed_address());
+    assert_eq!(user_credit(&mut world), BigUint::from(CREDIT - 1_000_000));
+    check_accounting(&mut world, CREDIT, CREDIT - 1_000_000);
+
+    // The manager's own credit is not used
+    world
+        .tx()
+        .from(MANAGER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order_from_credit(USDC, 1u64, WEGLD, 1u64, 30u64, 100u64, 3_600u64)
+        .returns(ExpectError(4, "E310: Insufficient credit"))
+        .run();
+}
@@ -0,0 +1,192 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+
+fn pow10(exp: u32) -> BigUint<StaticApi> {
+    BigUint::from(10u64).pow(exp)
+}
+
+/// Deploy with USDC (6 decimals) and WEGLD (18 decimals) registered
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+
+    deploy(&mut world);
+
+    for (token, decimals) in [(USDC, 6u8), (WEGLD, 18u8)] {
+        world
+            .tx()
+            .from(OWNER)
+            .to(SC)
+            .typed(StellarNovaProxy)
+            .set_token_decimals(token, decimals)
+            .run();
+    }
+
+    world
+}
+
+#[test]
+fn human_price_round_trip() {
+    let mut world = setup();
+
+    // 30 USDC per WEGLD
+    let human_price = BigUint::from(30u64) * pow10(18);
+    let (num, denom) = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .from_human_price(WEGLD, USDC, human_price.clone())
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple();
+
+    // One whole WEGLD (1e18 base units) buys 30 whole USDC (30e6 base units)
+    assert_eq!(pow10(18) * &num / &denom, BigUint::from(30_000_000u64));
+
+    let back = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .to_human_price(WEGLD, USDC, num, denom)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(back, human_price);
+
+    // A USDC -> WEGLD order at 1e12 / 30 WEGLD base units per USDC base
+    // unit pays 1/30 WEGLD per USDC
+    let human = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .to_human_price(USDC, WEGLD, pow10(12), 30u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(human, pow10(18) / 30u64);
+}
+
+#[test]
+fn decimals_registry_rejects_bad_input() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_token_decimals(USDC, 19u8)
+        .returns(ExpectError(4, "E315: Token decimals cannot exceed 18"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_token_decimals(TestTokenIdentifier::new("OTHER-123456"), 6u8)
+        .returns(ExpectError(4, "E303: Token not in whitelist"))
+        .run();
+
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .to_human_price(USDC, TestTokenIdentifier::new("OTHER-123456"), 1u64, 1u64)
+        .returns(ExpectError(4, "E314: Token decimals not set"))
+        .run();
+}
+
+#[test]
+fn scaled_price_order_uses_registry_decimals() {
+    let mut world = setup();
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 2_000_000u64);
+
+    // 1 raw WEGLD per 30 raw USDC
+    create_order(&mut world, 1_000_000, 3_600);
+
+    // 0.05 WEGLD per USDC
+    let order_id = world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order_scaled(
+            WEGLD,
+            BigUint::from(5u64) * pow10(16),
+            100u64,
+            3_600u64,
+            OptionalValue::<u64>::None,
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(1_000_000u64))
+        .returns(ReturnsResult)
+        .run();
+
+    let order = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order(order_id)
+        .returns(ReturnsResult)
+        .run();
+    // One whole USDC (1e6 base units) buys 0.05 WEGLD (5e16 base units)
+    assert_eq!(
+        BigUint::from(1_000_000u64) * &order.target_price_numerator / &order.target_price_denominator,
+        BigUint::from(5u64) * pow10(16)
+    );
+
+    let normalized = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .order_normalized_price(order_id)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(normalized, BigUint::from(5u64) * pow10(28));
+
+    // Lowest price first
+    let book: Vec<u64> = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order_book(USDC, WEGLD, 10usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|order| order.order_id)
+        .collect();
+    assert_eq!(book, vec![1, order_id]);
+}
+
+#[test]
+fn scaled_price_order_requires_decimals() {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 1_000_000u64);
+    deploy(&mut world);
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order_scaled(
+            WEGLD,
+            pow10(18),
+            100u64,
+            3_600u64,
+            OptionalValue::<u64>::None,
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(1_000_000u64))
+        .returns(ExpectError(4, "E314: Token decimals not set"))
+        .run();
+}
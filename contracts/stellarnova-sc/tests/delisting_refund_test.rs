@@ -0,0 +1,140 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{CancelReason, OrderStatus, StellarNovaProxy};
+
+const BLOCKED: TestAddress = TestAddress::new("blocked");
+const MEX: TestTokenIdentifier = TestTokenIdentifier::new("MEX-123456");
+
+const ORDER_AMOUNT: u64 = 1_000_000;
+
+/// USER's orders 1-3 sell USDC for WEGLD and order 4 for MEX; BLOCKED's
+/// order 5 sells USDC for WEGLD and BLOCKED is blacklisted
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 4 * ORDER_AMOUNT);
+    world
+        .account(BLOCKED)
+        .nonce(1)
+        .esdt_balance(USDC, ORDER_AMOUNT);
+
+    deploy(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .whitelist_token(MEX)
+        .run();
+
+    for _ in 0..3 {
+        create_order(&mut world, ORDER_AMOUNT, 3_600);
+    }
+    create_order_from(&mut world, USER, MEX);
+    create_order_from(&mut world, BLOCKED, WEGLD);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .add_to_blacklist(BLOCKED)
+        .run();
+
+    world
+}
+
+fn create_order_from(world: &mut ScenarioWorld, from: TestAddress, to_token: TestTokenIdentifier) {
+    world
+        .tx()
+        .from(from)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order(
+            to_token,
+            1u64,
+            30u64,
+            100u64,
+            3_600u64,
+            OptionalValue::<u64>::None,
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(ORDER_AMOUNT))
+        .run();
+}
+
+fn force_refund(world: &mut ScenarioWorld, max_count: u64) -> u64 {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .force_refund_orders_for_token(WEGLD, max_count)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn refund_needs_a_delisted_token() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .force_refund_orders_for_token(WEGLD, 10u64)
+        .returns(ExpectError(4, "E311: Token must be delisted first"))
+        .run();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .force_refund_orders_for_token(WEGLD, 10u64)
+        .returns(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+}
+
+#[test]
+fn delisted_token_orders_are_refunded_in_batches() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .remove_token(WEGLD)
+        .run();
+
+    // Cancelled orders leave the pending index, so each batch picks up
+    // where the last one stopped
+    assert_eq!(force_refund(&mut world, 2), 2);
+    world.check_account(USER).esdt_balance(USDC, 2 * ORDER_AMOUNT);
+    assert_eq!(force_refund(&mut world, 2), 1);
+    world.check_account(USER).esdt_balance(USDC, 3 * ORDER_AMOUNT);
+    assert_eq!(force_refund(&mut world, 2), 0);
+
+    for order_id in 1..=3u64 {
+        assert!(order(&mut world, order_id).status == OrderStatus::Cancelled);
+        let reason = world
+            .query()
+            .to(SC)
+            .typed(StellarNovaProxy)
+            .cancel_reason(order_id)
+            .returns(ReturnsResult)
+            .run();
+        assert!(reason == CancelReason::TokenDelisted);
+    }
+
+    // Orders on other tokens and of blacklisted owners are left alone
+    assert!(order(&mut world, 4).status == OrderStatus::Pending);
+    assert!(order(&mut world, 5).status == OrderStatus::Pending);
+    world.check_account(BLOCKED).esdt_balance(USDC, 0u64);
+    world.check_account(SC).esdt_balance(USDC, 2 * ORDER_AMOUNT);
+}
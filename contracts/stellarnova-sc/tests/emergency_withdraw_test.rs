@@ -0,0 +1,177 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+use stellarnova_sc::timelock::EMERGENCY_WITHDRAW_MIN_DELAY_SECONDS;
+
+const STRANGER: TestAddress = TestAddress::new("stranger");
+
+const ORDER_AMOUNT: u64 = 1_000_000;
+const SURPLUS: u64 = 500;
+const START: u64 = 1_000;
+
+/// USER escrows 1,000,000 USDC in order 1, then STRANGER sends 500 USDC
+/// straight to the contract
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, ORDER_AMOUNT);
+    world
+        .account(STRANGER)
+        .nonce(1)
+        .esdt_balance(USDC, SURPLUS);
+    world.current_block().block_timestamp_seconds(START);
+
+    world
+        .tx()
+        .from(OWNER)
+        .typed(StellarNovaProxy)
+        .init(500u64, MultiValueVec::from(vec![USDC, WEGLD]))
+        .code(CODE_PATH)
+        .code_metadata(CodeMetadata::PAYABLE)
+        .new_address(SC)
+        .run();
+
+    create_order(&mut world, ORDER_AMOUNT, 360_000);
+    world
+        .tx()
+        .from(STRANGER)
+        .to(SC)
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(SURPLUS))
+        .run();
+
+    world
+}
+
+fn queue_withdraw(world: &mut ScenarioWorld, amount: u64) -> u64 {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .emergency_withdraw(USDC, amount)
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn execute_action(world: &mut ScenarioWorld, action_id: u64) {
+    world
+        .tx()
+        .from(STRANGER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_queued_action(action_id)
+        .run();
+}
+
+#[test]
+fn withdrawal_waits_at_least_a_day() {
+    let mut world = setup();
+
+    // A shorter timelock delay does not shorten it
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_timelock_delay(3_600u64)
+        .run();
+    let action_id = queue_withdraw(&mut world, SURPLUS);
+
+    let queued = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_queued_action(action_id)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(queued.executable_at, START + EMERGENCY_WITHDRAW_MIN_DELAY_SECONDS);
+    world.check_account(OWNER).esdt_balance(USDC, 0u64);
+
+    world
+        .current_block()
+        .block_timestamp_seconds(START + EMERGENCY_WITHDRAW_MIN_DELAY_SECONDS - 1);
+    world
+        .tx()
+        .from(STRANGER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_queued_action(action_id)
+        .returns(ExpectError(4, "E611: Timelock delay has not elapsed"))
+        .run();
+
+    world
+        .current_block()
+        .block_timestamp_seconds(START + EMERGENCY_WITHDRAW_MIN_DELAY_SECONDS);
+    execute_action(&mut world, action_id);
+    world.check_account(OWNER).esdt_balance(USDC, SURPLUS);
+    world.check_account(SC).esdt_balance(USDC, ORDER_AMOUNT);
+}
+
+#[test]
+fn withdrawal_never_takes_escrow() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .emergency_withdraw(USDC, SURPLUS)
+        .returns(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .emergency_withdraw(USDC, 0u64)
+        .returns(ExpectError(4, "E307: Amount must be greater than zero"))
+        .run();
+
+    // The surplus is checked when the withdrawal is applied
+    let too_much = queue_withdraw(&mut world, SURPLUS + 1);
+    let surplus = queue_withdraw(&mut world, SURPLUS);
+    world
+        .current_block()
+        .block_timestamp_seconds(START + EMERGENCY_WITHDRAW_MIN_DELAY_SECONDS);
+    world
+        .tx()
+        .from(STRANGER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_queued_action(too_much)
+        .returns(ExpectError(4, "E612: Amount exceeds withdrawable surplus"))
+        .run();
+    execute_action(&mut world, surplus);
+
+    // Once the surplus is gone, not even one token of escrow can be taken
+    let escrow = queue_withdraw(&mut world, 1);
+    world
+        .current_block()
+        .block_timestamp_seconds(START + 2 * EMERGENCY_WITHDRAW_MIN_DELAY_SECONDS);
+    world
+        .tx()
+        .from(STRANGER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_queued_action(escrow)
+        .returns(ExpectError(4, "E612: Amount exceeds withdrawable surplus"))
+        .run();
+
+    world.check_account(OWNER).esdt_balance(USDC, SURPLUS);
+    world.check_account(SC).esdt_balance(USDC, ORDER_AMOUNT);
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(1u64)
+        .run();
+    world.check_account(USER).esdt_balance(USDC, ORDER_AMOUNT);
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+
+/// The mock pair sells WEGLD at 30 USDC; 10 bps execution fee (default)
+/// and 100 bps protocol fee
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world.current_block().block_epoch(10);
+
+    deploy_with_pair(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_protocol_fee_bps(100u64)
+        .run();
+
+    world
+}
+
+fn estimate(
+    world: &mut ScenarioWorld,
+    from_token: TestTokenIdentifier,
+    to_token: TestTokenIdentifier,
+    amount: u64,
+    slippage_bp: u64,
+) -> (BigUint<StaticApi>, BigUint<StaticApi>) {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .estimate_order_output(from_token, to_token, amount, slippage_bp)
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple()
+}
+
+#[test]
+fn estimate_applies_pair_quote_and_fees() {
+    let mut world = setup();
+
+    // 100,000 WEGLD quoted: 100 execution fee, 1,000 protocol fee
+    let (expected, min) = estimate(&mut world, USDC, WEGLD, 3_000_000, 100);
+    assert_eq!(expected, BigUint::from(98_900u64));
+    // 99,000 after 1% slippage: 99 execution fee, 990 protocol fee
+    assert_eq!(min, BigUint::from(97_911u64));
+
+    let (expected, min) = estimate(&mut world, WEGLD, USDC, 1_000, 0);
+    assert_eq!(expected, BigUint::from(29_670u64));
+    assert_eq!(min, expected);
+}
+
+#[test]
+fn estimate_rejects_invalid_parameters() {
+    let mut world = setup();
+
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .estimate_order_output(USDC, USDC, 1_000u64, 100u64)
+        .returns(ExpectError(4, "E306: Cannot swap token to itself"))
+        .run();
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .estimate_order_output(USDC, WEGLD, 1_000u64, 10_001u64)
+        .returns(ExpectError(4, "E403: Slippage cannot exceed 10000 bps"))
+        .run();
+}
+
+#[test]
+fn effective_price_deducts_current_fees() {
+    let mut world = setup();
+
+    let price = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_effective_price(1_000_000u64, 3u64, 100u64)
+        .returns(ReturnsResult)
+        .run();
+    // 30,000 out: 30 execution fee, 300 protocol fee, 29,670 to the owner
+    assert_eq!(price, BigUint::from(29_670_000_000_000_000u64));
+
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_effective_price(1_000_000u64, 3u64, 0u64)
+        .returns(ExpectError(4, "E402: Target price denominator must be positive"))
+        .run();
+}
@@ -0,0 +1,106 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{MinOutputBound, OrderStatus, ReasonCode, StellarNovaProxy};
+
+/// Pair price 30 USDC per WEGLD, default 10 bps execution fee
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64);
+
+    deploy_with_pair(&mut world);
+
+    world
+}
+
+/// Buy exactly 50,000 WEGLD for at most 3,000,000 USDC
+fn create_exact_output_order(world: &mut ScenarioWorld) -> u64 {
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_exact_output_order(WEGLD, 50_000u64, 36_000u64)
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(3_000_000u64))
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn exact_output_order_refunds_unused_input() {
+    let mut world = setup();
+    let order_id = create_exact_output_order(&mut world);
+
+    // 50,051 WEGLD covers the 10 bps fee: 1,501,530 USDC are swapped
+    let (min_amount_out, bound) = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_execution_min_output(order_id)
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple();
+    assert_eq!(min_amount_out, BigUint::from(50_051u64));
+    assert_eq!(bound, MinOutputBound::ExactOutput);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(order_id, 1u64, 60u64)
+        .run();
+
+    world.check_account(USER).esdt_balance(WEGLD, 50_001u64);
+    world.check_account(USER).esdt_balance(USDC, 1_498_470u64);
+    world.check_account(SC).esdt_balance(USDC, 0u64);
+    let order = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order(order_id)
+        .returns(ReturnsResult)
+        .run();
+    assert!(order.status == OrderStatus::Executed);
+    assert_eq!(order.from_amount, BigUint::from(1_501_530u64));
+}
+
+#[test]
+fn exact_output_order_only_executes_in_full_through_the_pool() {
+    let mut world = setup();
+    let order_id = create_exact_output_order(&mut world);
+
+    let reason = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .can_execute_order(order_id, 1u64, 60u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(reason, ReasonCode::Ok);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order_partial(order_id, 1_000_000u64, 1u64, 60u64)
+        .returns(ExpectError(4, "E529: Exact-output orders only execute in full"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .match_orders(order_id, order_id)
+        .returns(ExpectError(4, "E530: Exact-output orders only execute through the pool"))
+        .run();
+}
@@ -0,0 +1,170 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{ReasonCode, StellarNovaProxy};
+
+const KEEPER: TestAddress = TestAddress::new("keeper");
+
+const WINDOW: u64 = 60;
+
+/// USER has order 1 selling 3,000,000 USDC for WEGLD; orders of at least
+/// 1,000,000 USDC can be auctioned with a 60 second window
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64);
+    world.account(KEEPER).nonce(1);
+    world.current_block().block_timestamp_seconds(1_000);
+
+    deploy_with_pair(&mut world);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_execution_auction_window(WINDOW)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_execution_auction_min_amount(USDC, 1_000_000u64)
+        .run();
+
+    create_order(&mut world, 3_000_000, 36_000);
+
+    world
+}
+
+fn open_auction(world: &mut ScenarioWorld, from: TestAddress) {
+    world
+        .tx()
+        .from(from)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .open_execution_auction(1u64)
+        .run();
+}
+
+fn bid(world: &mut ScenarioWorld, fee_bps: u64) {
+    world
+        .tx()
+        .from(KEEPER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .bid_for_execution(1u64, fee_bps)
+        .run();
+}
+
+#[test]
+fn lowest_bidder_executes_at_its_fee() {
+    let mut world = setup();
+    open_auction(&mut world, OWNER);
+    bid(&mut world, 5);
+    world
+        .tx()
+        .from(KEEPER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .bid_for_execution(1u64, 5u64)
+        .returns(ExpectError(4, "E539: Bid must undercut the best bid"))
+        .run();
+
+    // Nobody executes while bids are open
+    let reason = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .can_execute_order(1u64, 1u64, 30u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(reason, ReasonCode::ExecutionAuctionOpen);
+
+    // Then only the winner does, at its 5 bps bid
+    world.current_block().block_timestamp_seconds(1_000 + WINDOW + 1);
+    world
+        .tx()
+        .from(KEEPER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .bid_for_execution(1u64, 4u64)
+        .returns(ExpectError(4, "E538: No execution auction open for bids on this order"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .returns(ExpectError(4, "E101: Only executor can execute orders"))
+        .run();
+    world
+        .tx()
+        .from(KEEPER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+
+    world.check_account(KEEPER).esdt_balance(WEGLD, 50u64);
+    world.check_account(USER).esdt_balance(WEGLD, 99_950u64);
+}
+
+#[test]
+fn unexercised_rights_fall_back_to_the_executor() {
+    let mut world = setup();
+    open_auction(&mut world, USER);
+    bid(&mut world, 5);
+
+    world.current_block().block_timestamp_seconds(1_000 + 2 * WINDOW + 1);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+    world.check_account(USER).esdt_balance(WEGLD, 99_900u64);
+}
+
+#[test]
+fn only_large_orders_are_auctioned() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(KEEPER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .open_execution_auction(1u64)
+        .returns(ExpectError(
+            4,
+            "E122: Only the executor or the order owner can auction an order",
+        ))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_execution_auction_min_amount(USDC, 5_000_000u64)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .open_execution_auction(1u64)
+        .returns(ExpectError(4, "E536: Order is not eligible for an execution auction"))
+        .run();
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{PauseLevel, StellarNovaProxy};
+
+const CREATED_AT: [u64; 3] = [1_000, 1_500, 2_000];
+
+/// USER creates orders 1-3 (1,000 USDC each) at `CREATED_AT`; only WEGLD
+/// has a pair
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64);
+    world.current_block().block_epoch(10);
+
+    deploy_with_pair(&mut world);
+
+    for created_at in CREATED_AT {
+        world.current_block().block_timestamp_seconds(created_at);
+        create_order(&mut world, 1_000, 36_000);
+    }
+
+    world
+}
+
+#[test]
+fn health_reports_orders_pairs_and_pause_level() {
+    let mut world = setup();
+    world.current_block().block_timestamp_seconds(5_000);
+
+    let health = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_executor_health()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(health.pause_level, PauseLevel::Active);
+    assert_eq!(health.pending_orders, 3);
+    assert_eq!(health.oldest_pending_age, 4_000);
+    assert_eq!(health.stuck_executions, 0);
+
+    let pairs: Vec<_> = health
+        .token_pairs
+        .iter()
+        .map(|status| (status.token.clone(), status.pair_configured, status.pair_tripped))
+        .collect();
+    assert_eq!(pairs.len(), 2);
+    assert!(pairs.contains(&(USDC.to_token_identifier(), false, false)));
+    assert!(pairs.contains(&(WEGLD.to_token_identifier(), true, false)));
+
+    // Executing the oldest order moves the age to the next one; a completed
+    // swap leaves nothing in execution
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_pause_level(PauseLevel::CreationPaused)
+        .run();
+
+    let health = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_executor_health()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(health.pause_level, PauseLevel::CreationPaused);
+    assert_eq!(health.pending_orders, 2);
+    assert_eq!(health.oldest_pending_age, 3_500);
+    assert_eq!(health.stuck_executions, 0);
+}
@@ -0,0 +1,133 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{ReasonCode, StellarNovaProxy};
+
+const LIMIT: u64 = 500_000;
+
+/// Orders 1-3 each sell 300,000 USDC for WEGLD on the mock pair; the owner
+/// (default executor) may execute `LIMIT` USDC per epoch
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 900_000u64);
+    world.current_block().block_epoch(10);
+
+    deploy_with_pair(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_executor_daily_limit(USDC, LIMIT)
+        .run();
+
+    for _ in 0..3 {
+        create_order(&mut world, 300_000, 36_000);
+    }
+
+    world
+}
+
+fn executor_volume(world: &mut ScenarioWorld) -> BigUint<StaticApi> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_executor_volume(OWNER, USDC)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn executor_volume_is_capped_per_epoch() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+    assert_eq!(executor_volume(&mut world), BigUint::from(300_000u64));
+
+    // A full fill of order 2 would exceed the limit, a 200,000 chunk fits
+    let reason = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .can_execute_order(2u64, 1u64, 30u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(reason, ReasonCode::ExecutorVolumeExceeded);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(2u64, 1u64, 30u64)
+        .returns(ExpectError(
+            4,
+            "E121: Executor daily volume limit reached for this token",
+        ))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order_partial(2u64, 200_000u64, 1u64, 30u64)
+        .run();
+    assert_eq!(executor_volume(&mut world), BigUint::from(LIMIT));
+
+    // The next epoch starts from zero
+    world.current_block().block_epoch(11);
+    assert_eq!(executor_volume(&mut world), BigUint::zero());
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(3u64, 1u64, 30u64)
+        .run();
+    assert_eq!(executor_volume(&mut world), BigUint::from(300_000u64));
+}
+
+#[test]
+fn failed_swaps_do_not_use_up_the_limit() {
+    let mut world = setup();
+
+    // Output below the order's minimum: the pair rejects the swap
+    set_pair_price(&mut world, 60);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+    assert_eq!(executor_volume(&mut world), BigUint::zero());
+}
+
+#[test]
+fn only_owner_sets_executor_limit() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_executor_daily_limit(USDC, 1u64)
+        .returns(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+}
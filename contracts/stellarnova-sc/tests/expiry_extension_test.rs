@@ -0,0 +1,151 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::limit_orders::MAX_ORDER_LIFETIME_SECONDS;
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+
+const OTHER: TestAddress = TestAddress::new("other");
+
+const CREATED_AT: u64 = 1_000;
+const EXPIRES_IN: u64 = 3_600;
+
+/// Deploy and create order 1, expiring an hour after `CREATED_AT`
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world.account(OTHER).nonce(1);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 1_000_000u64);
+    world.current_block().block_timestamp_seconds(CREATED_AT);
+
+    deploy(&mut world);
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order(
+            WEGLD,
+            1u64,
+            30u64,
+            100u64,
+            EXPIRES_IN,
+            OptionalValue::<u64>::None,
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(1_000_000u64))
+        .run();
+
+    world
+}
+
+fn expires_at(world: &mut ScenarioWorld) -> u64 {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order(1u64)
+        .returns(ReturnsResult)
+        .run()
+        .expires_at
+}
+
+#[test]
+fn extend_keeps_order_and_moves_expiry() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .extend_order_expiry(1u64, 7_200u64)
+        .run();
+    assert_eq!(expires_at(&mut world), CREATED_AT + EXPIRES_IN + 7_200);
+
+    // Still executable past the original expiry
+    world
+        .current_block()
+        .block_timestamp_seconds(CREATED_AT + EXPIRES_IN + 1);
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .extend_order_expiry(1u64, 60u64)
+        .run();
+    assert_eq!(expires_at(&mut world), CREATED_AT + EXPIRES_IN + 7_260);
+}
+
+#[test]
+fn extend_is_capped_at_max_lifetime() {
+    let mut world = setup();
+
+    let max_extra = MAX_ORDER_LIFETIME_SECONDS - EXPIRES_IN;
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .extend_order_expiry(1u64, max_extra + 1)
+        .returns(ExpectError(4, "E408: Expiry is too far in the future"))
+        .run();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .extend_order_expiry(1u64, u64::MAX)
+        .returns(ExpectError(4, "E408: Expiry is too far in the future"))
+        .run();
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .extend_order_expiry(1u64, max_extra)
+        .run();
+    assert_eq!(expires_at(&mut world), CREATED_AT + MAX_ORDER_LIFETIME_SECONDS);
+}
+
+#[test]
+fn extend_rejects_invalid_requests() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(OTHER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .extend_order_expiry(1u64, 60u64)
+        .returns(ExpectError(4, "E102: Not your order"))
+        .run();
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .extend_order_expiry(1u64, 0u64)
+        .returns(ExpectError(4, "E307: Amount must be greater than zero"))
+        .run();
+
+    // An expired order is refunded, not revived
+    world
+        .current_block()
+        .block_timestamp_seconds(CREATED_AT + EXPIRES_IN + 1);
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .extend_order_expiry(1u64, 60u64)
+        .returns(ExpectError(4, "E503: Order expired"))
+        .run();
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+
+/// A 1% protocol fee on selling 3,000,000 USDC for 100,000 WEGLD leaves
+/// 1,000 WEGLD of protocol fees; USDC is the treasury token
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64);
+
+    deploy_with_pair(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_protocol_fee_bps(100u64)
+        .run();
+
+    create_order(&mut world, 3_000_000, 36_000);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_treasury_token(USDC)
+        .run();
+
+    world
+}
+
+fn protocol_fees(world: &mut ScenarioWorld, token: TestTokenIdentifier) -> BigUint<StaticApi> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .protocol_fees(token)
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn convert(world: &mut ScenarioWorld, min_amount_out: u64) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .convert_protocol_fees(MultiValueVec::from(vec![MultiValue2::from((
+            WEGLD.to_token_identifier(),
+            BigUint::from(min_amount_out),
+        ))]))
+        .run();
+}
+
+#[test]
+fn fees_are_converted_into_the_treasury_token() {
+    let mut world = setup();
+    assert_eq!(protocol_fees(&mut world, WEGLD), BigUint::from(1_000u64));
+
+    convert(&mut world, 30_000);
+    assert_eq!(protocol_fees(&mut world, WEGLD), BigUint::zero());
+    assert_eq!(protocol_fees(&mut world, USDC), BigUint::from(30_000u64));
+
+    // Nothing left to convert
+    convert(&mut world, 30_000);
+    assert_eq!(protocol_fees(&mut world, USDC), BigUint::from(30_000u64));
+}
+
+#[test]
+fn fees_stay_accrued_below_the_minimum_output() {
+    let mut world = setup();
+    convert(&mut world, 30_001);
+    assert_eq!(protocol_fees(&mut world, WEGLD), BigUint::from(1_000u64));
+    assert_eq!(protocol_fees(&mut world, USDC), BigUint::zero());
+}
+
+#[test]
+fn treasury_token_is_not_converted() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .convert_protocol_fees(MultiValueVec::from(vec![MultiValue2::from((
+            USDC.to_token_identifier(),
+            BigUint::from(0u64),
+        ))]))
+        .returns(ExpectError(4, "E306: Cannot swap token to itself"))
+        .run();
+}
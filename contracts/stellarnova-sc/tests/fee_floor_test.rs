@@ -0,0 +1,99 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+
+/// Pair price 30 USDC per WEGLD, 10 bps execution fee (the owner executes)
+/// with a floor of 5 WEGLD
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64);
+
+    deploy_with_pair(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_execution_fee_floor(WEGLD, 5u64)
+        .run();
+
+    world
+}
+
+/// Sell `amount` USDC for WEGLD at the pair price and execute it
+fn create_and_execute(world: &mut ScenarioWorld, amount: u64) {
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order(
+            WEGLD,
+            1u64,
+            30u64,
+            100u64,
+            36_000u64,
+            OptionalValue::<u64>::None,
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(amount))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+}
+
+#[test]
+fn floor_applies_to_small_fills() {
+    let mut world = setup();
+    let floor = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execution_fee_floor(WEGLD)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(floor, BigUint::from(5u64));
+
+    // 100 WEGLD out: 10 bps would be 0
+    create_and_execute(&mut world, 3_000);
+    world.check_account(OWNER).esdt_balance(WEGLD, 5u64);
+    world.check_account(USER).esdt_balance(WEGLD, 95u64);
+}
+
+#[test]
+fn bps_fee_above_the_floor_is_kept() {
+    let mut world = setup();
+
+    // 100,000 WEGLD out: 10 bps is 100
+    create_and_execute(&mut world, 3_000_000);
+    world.check_account(OWNER).esdt_balance(WEGLD, 100u64);
+    world.check_account(USER).esdt_balance(WEGLD, 99_900u64);
+}
+
+#[test]
+fn floor_needs_a_whitelisted_token() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_execution_fee_floor(TestTokenIdentifier::new("OTHER-123456"), 5u64)
+        .returns(ExpectError(4, "E303: Token not in whitelist"))
+        .run();
+}
@@ -0,0 +1,105 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{OrderStatus, StellarNovaProxy};
+
+const BOT: TestAddress = TestAddress::new("bot");
+
+/// BOT is the executor; USER has order 1 selling 3,000 USDC for WEGLD at
+/// 30 USDC per WEGLD, the pair price
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000u64);
+    world.account(BOT).nonce(1);
+    world.current_block().block_timestamp_seconds(1_000);
+
+    deploy_with_pair(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_limit_order_executor(BOT)
+        .run();
+
+    create_order(&mut world, 3_000, 3_600);
+
+    world
+}
+
+#[test]
+fn owner_executes_in_place_of_the_executor() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .returns(ExpectError(4, "E101: Only executor can execute orders"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .force_execute(1u64, 1u64, 30u64)
+        .run();
+
+    let order = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(order.status, OrderStatus::Executed);
+    world.check_account(USER).esdt_balance(WEGLD, 100u64);
+}
+
+#[test]
+fn forced_execution_keeps_price_and_expiry_checks() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .force_execute(1u64, 1u64, 40u64)
+        .returns(ExpectError(4, "E504: Price condition not met"))
+        .run();
+
+    // Past expiry the order is refunded instead
+    world.current_block().block_timestamp_seconds(1_000 + 3_601);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .force_execute(1u64, 1u64, 30u64)
+        .run();
+    world.check_account(USER).esdt_balance(USDC, 3_000u64);
+}
+
+#[test]
+fn only_owner_forces_execution() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(BOT)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .force_execute(1u64, 1u64, 30u64)
+        .returns(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+}
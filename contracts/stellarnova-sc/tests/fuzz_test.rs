@@ -0,0 +1,402 @@
+mod common;
+
+use std::collections::BTreeMap;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use multiversx_sc_scenario::scenario_model::TxResponseStatus;
+use stellarnova_sc::limit_orders::{LimitOrdersModule, OrderStatus};
+use stellarnova_sc::storage::StorageModule;
+
+const USERS: [TestAddress; 3] = [
+    TestAddress::new("alice"),
+    TestAddress::new("bob"),
+    TestAddress::new("carol"),
+];
+
+/// Valid ESDT the users hold, never whitelisted
+const OTHER: TestTokenIdentifier = TestTokenIdentifier::new("OTHER-123456");
+const TOKENS: [TestTokenIdentifier; 3] = [USDC, WEGLD, OTHER];
+
+/// Random call sequences and steps per sequence
+const SEQUENCES: u64 = 20;
+const STEPS: usize = 150;
+
+const USER_BALANCE: u64 = 1_000_000_000;
+const POOL_RESERVE: u64 = 1_000_000_000_000;
+
+/// Largest small value the VM accepts when building a BigUint from a u64
+const MAX_SMALL: u64 = i64::MAX as u64;
+
+/// Failures a well-behaved contract may return: its own coded errors and the
+/// framework's argument and payment checks. Anything else is a finding.
+const EXPECTED_FRAMEWORK_ERRORS: &[&str] = &[
+    "argument decode error",
+    "wrong number of arguments",
+    "insufficient funds",
+    "function does not accept",
+    "incorrect number of",
+    "invalid token identifier",
+];
+
+/// Calls that must succeed at least once over all sequences
+const SUCCESS_REQUIRED: [&str; 5] = [
+    "createLimitOrder",
+    "cancelLimitOrder",
+    "modifyLimitOrder",
+    "replaceLimitOrder",
+    "executeLimitOrder",
+];
+
+/// Deterministic xorshift generator, so failures are reproducible
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.below(items.len() as u64) as usize]
+    }
+
+    /// One in four values is an edge case, the rest are plausible
+    fn edge(&mut self) -> bool {
+        self.below(4) == 0
+    }
+
+    /// Amount: zero, one wei or overflow-sized on the edges
+    fn amount(&mut self) -> BigUint<StaticApi> {
+        if !self.edge() {
+            return BigUint::from(1 + self.below(USER_BALANCE / 100));
+        }
+        match self.below(4) {
+            0 => BigUint::zero(),
+            1 => BigUint::from(1u64),
+            2 => BigUint::from(MAX_SMALL) * MAX_SMALL,
+            _ => BigUint::from(self.below(MAX_SMALL)),
+        }
+    }
+
+    /// u64 argument up to `typical`, or zero, just above it or `u64::MAX`
+    fn number(&mut self, typical: u64) -> u64 {
+        if !self.edge() {
+            return self.below(typical + 1);
+        }
+        match self.below(3) {
+            0 => 0,
+            1 => typical + 1,
+            _ => u64::MAX,
+        }
+    }
+
+    /// Token argument: a pair token, or a non-whitelisted or malformed one
+    fn token_arg(&mut self) -> ManagedBuffer<StaticApi> {
+        if !self.edge() {
+            return ManagedBuffer::from(self.pick(&[USDC, WEGLD]).as_str());
+        }
+        match self.below(3) {
+            0 => ManagedBuffer::from("NOT-A-TOKEN"),
+            1 => ManagedBuffer::new(),
+            _ => ManagedBuffer::from(OTHER.as_str()),
+        }
+    }
+
+    /// Existing order ids, with some unknown ones
+    fn order_id(&mut self, next_order_id: u64) -> u64 {
+        self.below(next_order_id + 2)
+    }
+}
+
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(USDC, POOL_RESERVE)
+        .esdt_balance(WEGLD, POOL_RESERVE);
+    for user in USERS {
+        world
+            .account(user)
+            .nonce(1)
+            .balance(USER_BALANCE)
+            .esdt_balance(USDC, USER_BALANCE)
+            .esdt_balance(WEGLD, USER_BALANCE)
+            .esdt_balance(OTHER, USER_BALANCE);
+    }
+
+    deploy_mock_pair(&mut world, PAIR);
+    world
+        .tx()
+        .from(OWNER)
+        .to(PAIR)
+        .raw_call("addReserves")
+        .esdt(TestEsdtTransfer(WEGLD, 0, POOL_RESERVE))
+        .esdt(TestEsdtTransfer(USDC, 0, POOL_RESERVE))
+        .run();
+
+    deploy(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .raw_call("setXExchangePair")
+        .argument(&PAIR)
+        .run();
+
+    world
+}
+
+fn next_order_id(world: &mut ScenarioWorld) -> u64 {
+    let mut next = 0;
+    world.query().to(SC).whitebox(stellarnova_sc::contract_obj, |sc| {
+        next = sc.next_order_id().get();
+    });
+    next
+}
+
+/// One random call; returns its name and outcome
+fn random_step(
+    world: &mut ScenarioWorld,
+    rng: &mut Rng,
+    now: &mut u64,
+) -> (&'static str, Result<(), TxResponseStatus>) {
+    let next_order_id = next_order_id(world);
+    let user = rng.pick(&USERS);
+
+    match rng.below(8) {
+        0 | 1 => {
+            let payment_token = if rng.edge() { OTHER } else { rng.pick(&[USDC, WEGLD]) };
+            let payment_amount = rng.amount().to_u64().unwrap_or(MAX_SMALL).min(MAX_SMALL);
+            let tx = world
+                .tx()
+                .from(user)
+                .to(SC)
+                .raw_call("createLimitOrder")
+                .argument(&rng.token_arg())
+                .argument(&rng.amount())
+                .argument(&rng.amount())
+                .argument(&rng.number(500))
+                .argument(&rng.number(3_600));
+            let result = if rng.below(10) > 0 {
+                tx.esdt(TestEsdtTransfer(payment_token, 0, payment_amount))
+                    .returns(ReturnsHandledOrError::new())
+                    .run()
+            } else {
+                tx.returns(ReturnsHandledOrError::new()).run()
+            };
+            ("createLimitOrder", result)
+        }
+        2 => {
+            let result = world
+                .tx()
+                .from(user)
+                .to(SC)
+                .raw_call("cancelLimitOrder")
+                .argument(&rng.order_id(next_order_id))
+                .returns(ReturnsHandledOrError::new())
+                .run();
+            ("cancelLimitOrder", result)
+        }
+        3 => {
+            let result = world
+                .tx()
+                .from(user)
+                .to(SC)
+                .raw_call("modifyLimitOrder")
+                .argument(&rng.order_id(next_order_id))
+                .argument(&rng.amount())
+                .argument(&rng.amount())
+                .argument(&rng.number(500))
+                .argument(&rng.number(3_600))
+                .returns(ReturnsHandledOrError::new())
+                .run();
+            ("modifyLimitOrder", result)
+        }
+        4 => {
+            let result = world
+                .tx()
+                .from(user)
+                .to(SC)
+                .raw_call("replaceLimitOrder")
+                .argument(&rng.order_id(next_order_id))
+                .argument(&rng.token_arg())
+                .argument(&rng.amount())
+                .argument(&rng.amount())
+                .argument(&rng.number(500))
+                .argument(&rng.number(3_600))
+                .returns(ReturnsHandledOrError::new())
+                .run();
+            ("replaceLimitOrder", result)
+        }
+        5 => {
+            // Mostly the executor, sometimes an unauthorized caller
+            let caller = if rng.below(4) > 0 { OWNER } else { user };
+            let order_id = rng.order_id(next_order_id);
+            let result = if rng.below(2) == 0 {
+                world
+                    .tx()
+                    .from(caller)
+                    .to(SC)
+                    .raw_call("executeLimitOrder")
+                    .argument(&order_id)
+                    .argument(&rng.amount())
+                    .argument(&rng.amount())
+                    .returns(ReturnsHandledOrError::new())
+                    .run()
+            } else {
+                world
+                    .tx()
+                    .from(caller)
+                    .to(SC)
+                    .raw_call("executeLimitOrderPartial")
+                    .argument(&order_id)
+                    .argument(&rng.amount())
+                    .argument(&rng.amount())
+                    .argument(&rng.amount())
+                    .returns(ReturnsHandledOrError::new())
+                    .run()
+            };
+            ("executeLimitOrder", result)
+        }
+        6 => {
+            // Move the pair price, or let it misbehave
+            let (name, result) = match rng.below(3) {
+                0 => (
+                    "setPrice",
+                    world
+                        .tx()
+                        .from(OWNER)
+                        .to(PAIR)
+                        .raw_call("setPrice")
+                        .argument(&rng.amount())
+                        .argument(&rng.amount())
+                        .returns(ReturnsHandledOrError::new())
+                        .run(),
+                ),
+                1 => (
+                    "setFailureMode",
+                    world
+                        .tx()
+                        .from(OWNER)
+                        .to(PAIR)
+                        .raw_call("setFailureMode")
+                        .argument(&(rng.below(3) as u8))
+                        .returns(ReturnsHandledOrError::new())
+                        .run(),
+                ),
+                _ => (
+                    "processExpiredOrders",
+                    world
+                        .tx()
+                        .from(user)
+                        .to(SC)
+                        .raw_call("processExpiredOrders")
+                        .argument(&rng.number(20))
+                        .returns(ReturnsHandledOrError::new())
+                        .run(),
+                ),
+            };
+            (name, result)
+        }
+        _ => {
+            // Orders live up to about an hour, so most steps stay inside it
+            *now += rng.below(900);
+            world.current_block().block_timestamp_seconds(*now);
+            ("advanceTime", Ok(()))
+        }
+    }
+}
+
+fn is_expected_error(message: &str) -> bool {
+    let coded = message.len() > 5
+        && message.starts_with('E')
+        && message[1..4].chars().all(|c| c.is_ascii_digit())
+        && &message[4..5] == ":";
+    coded
+        || EXPECTED_FRAMEWORK_ERRORS
+            .iter()
+            .any(|expected| message.contains(expected))
+}
+
+/// Escrow totals match the pending orders, the contract holds at least the
+/// escrow plus accrued fees, and the pending index matches order statuses
+fn check_invariants(world: &mut ScenarioWorld, context: &str) {
+    world.query().to(SC).whitebox(stellarnova_sc::contract_obj, |sc| {
+        let next = sc.next_order_id().get();
+        for token in TOKENS {
+            let token_id = token.to_token_identifier();
+            let mut pending_total = BigUint::<DebugApi>::zero();
+            for order_id in 1..next {
+                if sc.limit_orders(order_id).is_empty() {
+                    continue;
+                }
+                let order = sc.limit_orders(order_id).get();
+                let pending = matches!(order.status, OrderStatus::Pending);
+                assert_eq!(
+                    sc.pending_order_ids().contains(&order_id),
+                    pending,
+                    "{context}: pending index out of sync for order {order_id}"
+                );
+                if pending && order.from_token == token_id {
+                    pending_total += &order.from_amount;
+                }
+            }
+
+            let escrow = sc.escrow_balance(&token_id).get();
+            assert_eq!(
+                escrow, pending_total,
+                "{context}: escrow of {} does not match pending orders",
+                token.as_str()
+            );
+            let owed = escrow + sc.protocol_fees(&token_id).get();
+            let balance = sc
+                .blockchain()
+                .get_sc_balance(EgldOrEsdtTokenIdentifier::esdt(token_id.clone()), 0);
+            assert!(
+                balance >= owed,
+                "{context}: contract holds less {} than it owes",
+                token.as_str()
+            );
+        }
+    });
+}
+
+/// Random calls with edge-case tokens, amounts, prices, slippage and expiry
+/// never hit an unexpected failure and never break escrow accounting
+#[test]
+fn random_call_sequences() {
+    let mut succeeded = BTreeMap::new();
+    for sequence in 0..SEQUENCES {
+        let mut world = setup();
+        let mut rng = Rng(0x5EED_F022 + sequence);
+        let mut now = 0;
+
+        for step in 0..STEPS {
+            let (name, result) = random_step(&mut world, &mut rng, &mut now);
+            let context = format!("sequence {sequence} step {step} ({name})");
+            match result {
+                Ok(()) => *succeeded.entry(name).or_insert(0) += 1,
+                Err(err) => assert!(
+                    is_expected_error(&err.message),
+                    "{context}: unexpected failure: {}",
+                    err.message
+                ),
+            }
+            check_invariants(&mut world, &context);
+        }
+    }
+
+    // The generator must reach the success paths, not only the validation errors
+    for name in SUCCESS_REQUIRED {
+        assert!(succeeded.contains_key(name), "{name} never succeeded");
+    }
+}
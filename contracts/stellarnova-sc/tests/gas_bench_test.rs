@@ -0,0 +1,276 @@
+mod common;
+
+use std::collections::BTreeMap;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+
+const SEEDER: TestAddress = TestAddress::new("seeder");
+
+/// Order book sizes the operations are measured at
+const ORDER_COUNTS: [u64; 3] = [100, 1_000, 10_000];
+const SEED_AMOUNT: u64 = 1_000;
+const ORDER_AMOUNT: u64 = 3_000_000;
+
+const TX_GAS: u64 = 600_000_000;
+/// Views are measured as transactions; the limit only needs to fit full scans
+const VIEW_GAS: u64 = 100_000_000_000;
+
+/// Allowed gas increase over the recorded baseline
+const REGRESSION_TOLERANCE_PERCENT: u64 = 5;
+/// Allowed gas growth of constant-cost operations from the smallest to the
+/// largest order book; more means a storage scan crept in
+const FLAT_GROWTH_PERCENT: u64 = 10;
+
+/// Operations whose cost must not depend on the number of orders.
+/// `getPendingOrders` returns every pending order and the seeded orders stay
+/// pending, so it grows with the book by design; it reads the pending index
+/// only, never settled orders. `getOrderBook` reads the lowest price buckets,
+/// which the seeded orders share.
+const FLAT_OPERATIONS: [&str; 4] = ["createLimitOrder", "cancelLimitOrder", "executeLimitOrder", "getOrder"];
+
+const BASELINE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/gas_baseline.txt");
+const RECORD_ENV: &str = "GAS_BENCH_RECORD";
+
+/// Gas per (operation, order count)
+type Measurements = BTreeMap<(String, u64), u64>;
+
+/// `world` metering gas with the V8 schedule
+fn metered_world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new()
+        .executor_config(ExecutorConfig::compiled_tests_or(ExecutorConfig::Debugger))
+        .gas_schedule(GasScheduleVersion::V8);
+    blockchain.set_current_dir_from_workspace("contracts/stellarnova-sc");
+    blockchain.register_contract(CODE_PATH, stellarnova_sc::ContractBuilder);
+    blockchain.register_contract(PAIR_CODE_PATH, mock_pair::ContractBuilder);
+    blockchain
+}
+
+/// StellarNova wired to a mock pair quoting 30 USDC per WEGLD
+fn setup() -> ScenarioWorld {
+    let mut world = metered_world();
+    let seed_total = ORDER_COUNTS[ORDER_COUNTS.len() - 1] * SEED_AMOUNT;
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 100 * ORDER_AMOUNT);
+    world.account(SEEDER).nonce(1).esdt_balance(USDC, seed_total);
+
+    deploy_pair(&mut world);
+
+    deploy(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_xexchange_pair(PAIR)
+        .run();
+
+    world
+}
+
+/// Grow the book with resting orders that never reach their limit price
+fn seed(world: &mut ScenarioWorld, count: u64) {
+    for _ in 0..count {
+        world
+            .tx()
+            .from(SEEDER)
+            .to(SC)
+            .gas(TX_GAS)
+            .typed(StellarNovaProxy)
+            .create_limit_order(
+                WEGLD,
+                1u64,
+                1_000u64,
+                100u64,
+                86_400u64,
+                OptionalValue::<u64>::None,
+                OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+            )
+            .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(SEED_AMOUNT))
+            .run();
+    }
+}
+
+fn create_measured_order(world: &mut ScenarioWorld) -> (u64, u64) {
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .gas(TX_GAS)
+        .typed(StellarNovaProxy)
+        .create_limit_order(
+            WEGLD,
+            1u64,
+            30u64,
+            100u64,
+            3_600u64,
+            OptionalValue::<u64>::None,
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(ORDER_AMOUNT))
+        .returns(ReturnsResult)
+        .returns(ReturnsGasUsed)
+        .run()
+}
+
+/// Measure every operation against the current book
+fn measure(world: &mut ScenarioWorld, orders: u64, measurements: &mut Measurements) {
+    let mut record = |name: &str, gas: u64| {
+        measurements.insert((name.to_string(), orders), gas);
+    };
+
+    let (order_id, gas) = create_measured_order(world);
+    record("createLimitOrder", gas);
+
+    let gas = world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .gas(TX_GAS)
+        .typed(StellarNovaProxy)
+        .get_order(order_id)
+        .returns(ReturnsGasUsed)
+        .run();
+    record("getOrder", gas);
+
+    let gas = world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .gas(TX_GAS)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(order_id)
+        .returns(ReturnsGasUsed)
+        .run();
+    record("cancelLimitOrder", gas);
+
+    let (order_id, _) = create_measured_order(world);
+    let gas = world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .gas(TX_GAS)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(order_id, 1u64, 30u64)
+        .returns(ReturnsGasUsed)
+        .run();
+    record("executeLimitOrder", gas);
+
+    let gas = world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .gas(VIEW_GAS)
+        .typed(StellarNovaProxy)
+        .get_order_book(USDC, WEGLD, 10usize)
+        .returns(ReturnsGasUsed)
+        .run();
+    record("getOrderBook", gas);
+
+    let gas = world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .gas(VIEW_GAS)
+        .typed(StellarNovaProxy)
+        .get_pending_orders()
+        .returns(ReturnsGasUsed)
+        .run();
+    record("getPendingOrders", gas);
+}
+
+fn read_baseline() -> Measurements {
+    let Ok(content) = std::fs::read_to_string(BASELINE_PATH) else {
+        return Measurements::new();
+    };
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            assert_eq!(fields.len(), 3, "bad baseline line: {line}");
+            let orders = fields[1].parse().expect("bad order count");
+            let gas = fields[2].parse().expect("bad gas");
+            ((fields[0].to_string(), orders), gas)
+        })
+        .collect()
+}
+
+fn write_baseline(measurements: &Measurements) {
+    let mut content = String::from("# operation orders gas (GAS_BENCH_RECORD=1 to update)\n");
+    for ((name, orders), gas) in measurements {
+        content.push_str(&format!("{name} {orders} {gas}\n"));
+    }
+    std::fs::write(BASELINE_PATH, content).expect("cannot write gas baseline");
+}
+
+/// Gas of create/cancel/execute and the order views at 100, 1k and 10k orders
+///
+/// Gas is only metered when the compiled contract runs in the Wasmer executor:
+/// `sc-meta all build`, then
+/// `cargo test --test gas_bench_test --features multiversx-sc-scenario/compiled-sc-tests,multiversx-sc-scenario/wasmer-experimental -- --ignored --nocapture`.
+/// With `GAS_BENCH_RECORD=1` the run rewrites `tests/gas_baseline.txt` instead of checking it.
+#[test]
+#[ignore = "needs the compiled contract, see the doc comment"]
+fn gas_bench() {
+    let mut world = setup();
+    let mut measurements = Measurements::new();
+    let mut seeded = 0;
+    for orders in ORDER_COUNTS {
+        seed(&mut world, orders - seeded);
+        seeded = orders;
+        measure(&mut world, orders, &mut measurements);
+    }
+
+    for ((name, orders), gas) in &measurements {
+        println!("{name:<20} {orders:>6} orders {gas:>14} gas");
+    }
+    assert!(
+        measurements.values().any(|gas| *gas > 0),
+        "gas is not metered by the debugger executor, run the compiled contract"
+    );
+
+    let mut failures = Vec::new();
+    let smallest = ORDER_COUNTS[0];
+    let largest = ORDER_COUNTS[ORDER_COUNTS.len() - 1];
+    for name in FLAT_OPERATIONS {
+        let base = measurements[&(name.to_string(), smallest)];
+        let top = measurements[&(name.to_string(), largest)];
+        if top * 100 > base * (100 + FLAT_GROWTH_PERCENT) {
+            failures.push(format!(
+                "{name} grows with the book: {base} gas at {smallest} orders, {top} at {largest}"
+            ));
+        }
+    }
+
+    if std::env::var(RECORD_ENV).is_ok() {
+        write_baseline(&measurements);
+    } else {
+        let baseline = read_baseline();
+        for (key, gas) in &measurements {
+            match baseline.get(key) {
+                Some(expected) if gas * 100 > expected * (100 + REGRESSION_TOLERANCE_PERCENT) => {
+                    failures.push(format!(
+                        "{} at {} orders: {gas} gas, baseline {expected}",
+                        key.0, key.1
+                    ));
+                }
+                Some(_) => {}
+                None => failures.push(format!(
+                    "{} at {} orders has no baseline, record it with {RECORD_ENV}=1",
+                    key.0, key.1
+                )),
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "gas regressions:\n{}", failures.join("\n"));
+}
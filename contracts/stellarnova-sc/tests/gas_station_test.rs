@@ -0,0 +1,119 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+
+const TREASURY: TestAddress = TestAddress::new("treasury");
+
+/// Pair price 30 USDC per WEGLD, 10 bps execution fee (the owner executes)
+/// with a floor of 5 WEGLD; fills of up to 1,000 WEGLD are sponsored
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 6_000u64);
+    world
+        .account(TREASURY)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000u64);
+
+    deploy_with_pair(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_execution_fee_floor(WEGLD, 5u64)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_gas_station_max_output(WEGLD, 1_000u64)
+        .run();
+
+    world
+}
+
+fn top_up(world: &mut ScenarioWorld, amount: u64) {
+    world
+        .tx()
+        .from(TREASURY)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .top_up_gas_station()
+        .single_esdt(&WEGLD.to_token_identifier(), 0, &BigUint::from(amount))
+        .run();
+}
+
+/// Sell 3,000 USDC for 100 WEGLD (10 bps would be 0) and execute it
+fn create_and_execute(world: &mut ScenarioWorld, order_id: u64) {
+    create_order(world, 3_000, 36_000);
+    execute(world, order_id);
+}
+
+fn pool(world: &mut ScenarioWorld) -> BigUint<StaticApi> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .gas_station_pool(WEGLD)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn pool_pays_the_fee_floor_of_small_fills() {
+    let mut world = setup();
+    top_up(&mut world, 8);
+    assert_eq!(pool(&mut world), BigUint::from(8u64));
+
+    create_and_execute(&mut world, 1);
+    world.check_account(USER).esdt_balance(WEGLD, 100u64);
+    world.check_account(OWNER).esdt_balance(WEGLD, 5u64);
+    assert_eq!(pool(&mut world), BigUint::from(3u64));
+
+    // The pool covers 3 of the next floor, the user the other 2
+    create_and_execute(&mut world, 2);
+    world.check_account(USER).esdt_balance(WEGLD, 198u64);
+    world.check_account(OWNER).esdt_balance(WEGLD, 10u64);
+    assert_eq!(pool(&mut world), BigUint::zero());
+}
+
+#[test]
+fn larger_fills_are_not_sponsored() {
+    let mut world = setup();
+    top_up(&mut world, 8);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_gas_station_max_output(WEGLD, 99u64)
+        .run();
+
+    create_and_execute(&mut world, 1);
+    world.check_account(USER).esdt_balance(WEGLD, 95u64);
+    assert_eq!(pool(&mut world), BigUint::from(8u64));
+}
+
+#[test]
+fn pool_is_funded_from_protocol_fees() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .fund_gas_station_from_fees(WEGLD, 1u64)
+        .returns(ExpectError(4, "E613: No protocol fees to claim"))
+        .run();
+}
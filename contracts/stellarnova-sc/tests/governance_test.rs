@@ -0,0 +1,239 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{ProposalStatus, StellarNovaProxy, TimelockAction};
+
+const OTHER: TestAddress = TestAddress::new("other");
+
+const GOV: TestTokenIdentifier = TestTokenIdentifier::new("GOV-123456");
+
+const START: u64 = 1_000;
+const VOTING_PERIOD: u64 = 3_600;
+const TIMELOCK_DELAY: u64 = 600;
+
+/// USER holds 600 GOV and OTHER 400; proposals need 100 GOV and a quorum of
+/// 500 GOV, passed ones wait `TIMELOCK_DELAY`
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world.account(USER).nonce(1).esdt_balance(GOV, 600u64);
+    world.account(OTHER).nonce(1).esdt_balance(GOV, 400u64);
+    world.current_block().block_timestamp_seconds(START);
+
+    deploy(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_governance_config(GOV, 100u64, 500u64, VOTING_PERIOD)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_timelock_delay(TIMELOCK_DELAY)
+        .run();
+
+    world
+}
+
+fn propose(world: &mut ScenarioWorld, action: TimelockAction<StaticApi>, deposit: u64) -> u64 {
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .propose(action)
+        .single_esdt(&GOV.to_token_identifier(), 0, &BigUint::from(deposit))
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn vote(world: &mut ScenarioWorld, voter: TestAddress, support: bool, amount: u64) {
+    world
+        .tx()
+        .from(voter)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .vote(1u64, support)
+        .single_esdt(&GOV.to_token_identifier(), 0, &BigUint::from(amount))
+        .run();
+}
+
+fn finalize(world: &mut ScenarioWorld) -> ProposalStatus {
+    world
+        .tx()
+        .from(OTHER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .finalize_proposal(1u64)
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn withdraw(world: &mut ScenarioWorld, voter: TestAddress) {
+    world
+        .tx()
+        .from(voter)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .withdraw_vote_deposit(1u64)
+        .run();
+}
+
+fn max_slippage(world: &mut ScenarioWorld) -> u64 {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .max_slippage()
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn passed_proposal_executes_through_the_timelock() {
+    let mut world = setup();
+
+    assert_eq!(propose(&mut world, TimelockAction::SetMaxSlippage(800), 300), 1);
+    vote(&mut world, OTHER, false, 200);
+    vote(&mut world, USER, true, 100);
+
+    world
+        .tx()
+        .from(OTHER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .finalize_proposal(1u64)
+        .returns(ExpectError(4, "E908: Voting period has not ended"))
+        .run();
+
+    world
+        .current_block()
+        .block_timestamp_seconds(START + VOTING_PERIOD);
+    world
+        .tx()
+        .from(OTHER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .vote(1u64, false)
+        .single_esdt(&GOV.to_token_identifier(), 0, &BigUint::from(200u64))
+        .returns(ExpectError(4, "E907: Voting period has ended"))
+        .run();
+
+    // 400 yes against 200 no, 600 votes reach the quorum
+    assert!(finalize(&mut world) == ProposalStatus::Queued);
+    let proposal = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_proposal(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(proposal.yes_votes, BigUint::from(400u64));
+    assert_eq!(proposal.no_votes, BigUint::from(200u64));
+    assert_eq!(max_slippage(&mut world), 500);
+
+    world
+        .current_block()
+        .block_timestamp_seconds(START + VOTING_PERIOD + TIMELOCK_DELAY);
+    world
+        .tx()
+        .from(OTHER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_queued_action(proposal.action_id)
+        .run();
+    assert_eq!(max_slippage(&mut world), 800);
+
+    // Votes are unlocked after the voting period
+    withdraw(&mut world, USER);
+    withdraw(&mut world, OTHER);
+    world.check_account(USER).esdt_balance(GOV, 600u64);
+    world.check_account(OTHER).esdt_balance(GOV, 400u64);
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .withdraw_vote_deposit(1u64)
+        .returns(ExpectError(4, "E910: No vote deposit to withdraw"))
+        .run();
+}
+
+#[test]
+fn proposal_without_quorum_or_majority_is_defeated() {
+    let mut world = setup();
+
+    propose(&mut world, TimelockAction::SetExecutionFeeBps(50), 100);
+    vote(&mut world, OTHER, false, 400);
+    world
+        .current_block()
+        .block_timestamp_seconds(START + VOTING_PERIOD);
+
+    assert!(finalize(&mut world) == ProposalStatus::Defeated);
+    world
+        .tx()
+        .from(OTHER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .finalize_proposal(1u64)
+        .returns(ExpectError(4, "E909: Proposal already finalized"))
+        .run();
+
+    let queued = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_queued_actions()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(queued.len(), 0);
+}
+
+#[test]
+fn proposals_are_limited_to_governable_actions() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .propose(TimelockAction::SetLimitOrderExecutor(USER.to_managed_address()))
+        .single_esdt(&GOV.to_token_identifier(), 0, &BigUint::from(100u64))
+        .returns(ExpectError(4, "E905: Action cannot be proposed by governance"))
+        .run();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .propose(TimelockAction::SetMaxSlippage(800))
+        .single_esdt(&GOV.to_token_identifier(), 0, &BigUint::from(99u64))
+        .returns(ExpectError(4, "E904: Deposit below the proposal threshold"))
+        .run();
+    // Parameter bounds are checked when proposing
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .propose(TimelockAction::SetMaxSlippage(10_001))
+        .single_esdt(&GOV.to_token_identifier(), 0, &BigUint::from(100u64))
+        .returns(ExpectError(4, "E601: Max slippage cannot exceed 10000 bps"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_governance_config(USDC, 1u64, 1u64, 1u64)
+        .returns(ExpectError(4, "E902: Governance token cannot change"))
+        .run();
+}
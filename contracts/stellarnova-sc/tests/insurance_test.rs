@@ -0,0 +1,161 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+
+/// Half of the 10 bps execution fee goes to the insurance fund; USER has
+/// order 1 selling 3,000,000 USDC for 100,000 WEGLD on the mock pair
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64);
+    world.current_block().block_epoch(10);
+
+    deploy_with_pair(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_insurance_fee_bps(5_000u64)
+        .run();
+
+    create_order(&mut world, 3_000_000, 36_000);
+
+    world
+}
+
+fn insurance_fund(world: &mut ScenarioWorld) -> BigUint<StaticApi> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .insurance_fund(WEGLD)
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn execute_order(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+}
+
+#[test]
+fn execution_fee_slice_funds_insurance() {
+    let mut world = setup();
+    execute_order(&mut world);
+
+    // 100 WEGLD execution fee: 50 to the executor, 50 to the fund
+    assert_eq!(insurance_fund(&mut world), BigUint::from(50u64));
+    world.check_account(OWNER).esdt_balance(WEGLD, 50u64);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_insurance_fee_bps(5_001u64)
+        .returns(ExpectError(4, "E625: Insurance fee share cannot exceed 5000 bps"))
+        .run();
+}
+
+#[test]
+fn compensation_is_capped_by_cap_and_fund() {
+    let mut world = setup();
+    execute_order(&mut world);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .compensate(USER, WEGLD, 10u64)
+        .returns(ExpectError(4, "E626: Compensation exceeds the cap for this token"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_compensation_cap(WEGLD, 40u64)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .compensate(USER, WEGLD, 41u64)
+        .returns(ExpectError(4, "E626: Compensation exceeds the cap for this token"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .compensate(USER, WEGLD, 30u64)
+        .run();
+    assert_eq!(insurance_fund(&mut world), BigUint::from(20u64));
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .compensate(USER, WEGLD, 21u64)
+        .returns(ExpectError(4, "E627: Insurance fund is insufficient"))
+        .run();
+}
+
+#[test]
+fn queued_compensation_waits_for_the_timelock() {
+    let mut world = setup();
+    execute_order(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_compensation_cap(WEGLD, 50u64)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_timelock_delay(3_600u64)
+        .run();
+
+    let action_id = world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .compensate(USER, WEGLD, 50u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(insurance_fund(&mut world), BigUint::from(50u64));
+
+    world.current_block().block_timestamp_seconds(3_600);
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_queued_action(action_id)
+        .run();
+    assert_eq!(insurance_fund(&mut world), BigUint::zero());
+}
@@ -0,0 +1,250 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{OrderStatus, StellarNovaProxy};
+
+const LENDING: TestSCAddress = TestSCAddress::new("mock-lending");
+const HUSDC: TestTokenIdentifier = TestTokenIdentifier::new("HUSDC-123456");
+
+const ORDER_AMOUNT: u64 = 3_000_000;
+
+/// USER's order 1 sells 3,000,000 USDC for WEGLD on the mock pair; its
+/// escrow is supplied to a USDC money market at 1 USDC per share, which
+/// holds 1,000,000 USDC to pay interest from
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, PAIR_RESERVE)
+        .esdt_balance(USDC, 1_000_000u64)
+        .esdt_balance(HUSDC, ORDER_AMOUNT);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, ORDER_AMOUNT);
+
+    deploy_with_pair(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .raw_deploy()
+        .code(LENDING_CODE_PATH)
+        .argument(&USDC)
+        .argument(&HUSDC)
+        .new_address(LENDING)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(LENDING)
+        .raw_call("addReserves")
+        .esdt(TestEsdtTransfer(USDC, 0, 1_000_000u64))
+        .esdt(TestEsdtTransfer(HUSDC, 0, ORDER_AMOUNT))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_lending_market(USDC, LENDING, HUSDC)
+        .run();
+
+    create_order(&mut world, ORDER_AMOUNT, 3_600);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .supply_escrow(1u64)
+        .run();
+
+    world
+}
+
+/// Underlying paid per share from now on
+fn set_exchange_rate(world: &mut ScenarioWorld, rate_num: u64, rate_denom: u64) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(LENDING)
+        .raw_call("setExchangeRate")
+        .argument(&rate_num)
+        .argument(&rate_denom)
+        .run();
+}
+
+fn lent_escrow(world: &mut ScenarioWorld) -> BigUint<StaticApi> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .lent_escrow(USDC)
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn executor_volume(world: &mut ScenarioWorld) -> BigUint<StaticApi> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_executor_volume(OWNER, USDC)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn supplied_escrow_is_held_as_shares() {
+    let mut world = setup();
+
+    let lent = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .lent_escrows(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(lent.shares, BigUint::from(ORDER_AMOUNT));
+    assert_eq!(lent.principal, BigUint::from(ORDER_AMOUNT));
+    assert_eq!(lent_escrow(&mut world), BigUint::from(ORDER_AMOUNT));
+    world
+        .check_account(SC)
+        .esdt_balance(USDC, 0u64)
+        .esdt_balance(HUSDC, ORDER_AMOUNT);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .supply_escrow(1u64)
+        .returns(ExpectError(4, "E520: Order escrow is already lent"))
+        .run();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .supply_escrow(1u64)
+        .returns(ExpectError(4, "E101: Only executor can execute orders"))
+        .run();
+}
+
+#[test]
+fn execution_redeems_first_and_interest_goes_to_the_protocol() {
+    let mut world = setup();
+    set_exchange_rate(&mut world, 11, 10);
+
+    execute(&mut world, 1);
+
+    assert!(order(&mut world, 1).status == OrderStatus::Executed);
+    assert_eq!(lent_escrow(&mut world), BigUint::zero());
+    let protocol_fees = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .protocol_fees(USDC)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(protocol_fees, BigUint::from(ORDER_AMOUNT / 10));
+    world
+        .check_account(SC)
+        .esdt_balance(USDC, ORDER_AMOUNT / 10)
+        .esdt_balance(HUSDC, 0u64);
+    world.check_account(PAIR).esdt_balance(USDC, ORDER_AMOUNT);
+}
+
+#[test]
+fn interest_goes_to_the_order_when_configured() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_lending_interest_to_user(true)
+        .run();
+    set_exchange_rate(&mut world, 11, 10);
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(1u64)
+        .run();
+
+    assert!(order(&mut world, 1).status == OrderStatus::Cancelled);
+    world
+        .check_account(USER)
+        .esdt_balance(USDC, ORDER_AMOUNT + ORDER_AMOUNT / 10);
+    world.check_account(SC).esdt_balance(USDC, 0u64);
+    assert_eq!(lent_escrow(&mut world), BigUint::zero());
+}
+
+#[test]
+fn expired_lent_order_is_redeemed_and_refunded() {
+    let mut world = setup();
+    world.current_block().block_timestamp_seconds(3_601);
+
+    execute(&mut world, 1);
+
+    assert!(order(&mut world, 1).status == OrderStatus::Expired);
+    world.check_account(USER).esdt_balance(USDC, ORDER_AMOUNT);
+    world
+        .check_account(SC)
+        .esdt_balance(USDC, 0u64)
+        .esdt_balance(HUSDC, 0u64);
+}
+
+#[test]
+fn failed_redeem_gives_back_executor_volume() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(LENDING)
+        .raw_call("setFailRedeem")
+        .argument(&true)
+        .run();
+
+    execute(&mut world, 1);
+
+    // The shares came back: the escrow stays lent and the order pending
+    assert!(order(&mut world, 1).status == OrderStatus::Pending);
+    assert_eq!(lent_escrow(&mut world), BigUint::from(ORDER_AMOUNT));
+    world.check_account(SC).esdt_balance(HUSDC, ORDER_AMOUNT);
+    assert_eq!(executor_volume(&mut world), BigUint::zero());
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(LENDING)
+        .raw_call("setFailRedeem")
+        .argument(&false)
+        .run();
+    execute(&mut world, 1);
+    assert!(order(&mut world, 1).status == OrderStatus::Executed);
+    assert_eq!(executor_volume(&mut world), BigUint::from(ORDER_AMOUNT));
+}
+
+#[test]
+fn short_redeem_drops_the_execution() {
+    let mut world = setup();
+    set_exchange_rate(&mut world, 9, 10);
+
+    execute(&mut world, 1);
+
+    // The loss reduces the order, which no longer covers the chunk
+    let order = order(&mut world, 1);
+    assert!(order.status == OrderStatus::Pending);
+    assert_eq!(order.from_amount, BigUint::from(ORDER_AMOUNT - ORDER_AMOUNT / 10));
+    assert_eq!(lent_escrow(&mut world), BigUint::zero());
+    world
+        .check_account(SC)
+        .esdt_balance(USDC, ORDER_AMOUNT - ORDER_AMOUNT / 10);
+    assert_eq!(executor_volume(&mut world), BigUint::zero());
+}
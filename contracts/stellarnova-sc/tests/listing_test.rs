@@ -0,0 +1,199 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{OrderStatus, ReasonCode, StellarNovaProxy};
+
+const NEW: TestTokenIdentifier = TestTokenIdentifier::new("NEW-123456");
+
+/// NEW is whitelisted ahead of its listing and order 1 sells 3,000,000 USDC
+/// for at least 100,000 NEW; the NEW/USDC mock pair (30 USDC per NEW) is
+/// deployed but not configured yet
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(NEW, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64);
+
+    world
+        .tx()
+        .from(OWNER)
+        .raw_deploy()
+        .code(PAIR_CODE_PATH)
+        .argument(&NEW)
+        .argument(&USDC)
+        .new_address(PAIR)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(PAIR)
+        .raw_call("addReserves")
+        .single_esdt(&NEW.to_token_identifier(), 0, &BigUint::from(1_000_000u64))
+        .run();
+    set_pair_price(&mut world, 30);
+
+    deploy(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .whitelist_upcoming_token(NEW)
+        .run();
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order(
+            NEW,
+            1u64,
+            30u64,
+            100u64,
+            3_600u64,
+            OptionalValue::<u64>::None,
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(3_000_000u64))
+        .run();
+
+    world
+}
+
+fn set_token_pair(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_token_pair(NEW, PAIR)
+        .run();
+}
+
+fn is_dormant(world: &mut ScenarioWorld) -> bool {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .is_order_dormant(1u64)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn order_on_upcoming_token_is_dormant() {
+    let mut world = setup();
+
+    assert!(is_dormant(&mut world));
+    let reason = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .can_execute_order(1u64, 1u64, 30u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(reason, ReasonCode::OrderDormant);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .returns(ExpectError(4, "E526: Order is dormant until its token pair is set"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .activate_dormant_orders(NEW, 10u64)
+        .returns(ExpectError(4, "E313: Token has no pair yet"))
+        .run();
+
+    // Dormant orders can still be cancelled
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(1u64)
+        .run();
+    world.check_account(USER).esdt_balance(USDC, 3_000_000u64);
+}
+
+#[test]
+fn setting_the_pair_activates_and_routes_the_order() {
+    let mut world = setup();
+    set_token_pair(&mut world);
+
+    assert!(!is_dormant(&mut world));
+    let awaiting: Vec<_> = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .awaiting_pair_tokens()
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect();
+    assert!(awaiting.is_empty());
+    let dormant_count = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .dormant_orders(NEW)
+        .returns(ReturnsResult)
+        .run()
+        .len();
+    assert_eq!(dormant_count, 0);
+
+    let swap_pair = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .swap_pair(USDC, NEW)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(swap_pair, PAIR.to_managed_address());
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+
+    let order = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert!(order.status == OrderStatus::Executed);
+    world.check_account(PAIR).esdt_balance(USDC, 3_000_000u64);
+}
+
+#[test]
+fn token_pair_requires_whitelisted_token() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_token_pair(TestTokenIdentifier::new("OTHER-123456"), PAIR)
+        .returns(ExpectError(4, "E303: Token not in whitelist"))
+        .run();
+}
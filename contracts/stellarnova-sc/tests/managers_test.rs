@@ -0,0 +1,326 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use multiversx_sc_scenario::scenario_model::TxResponseStatus;
+use stellarnova_sc::stellarnova_proxy::{OrderStatus, StellarNovaProxy};
+
+const MANAGER: TestAddress = TestAddress::new("manager");
+
+const ORDER_AMOUNT: u64 = 1_000_000;
+const CREDIT: u64 = 2_000_000;
+
+/// USER's order 1 sells 1,000,000 USDC; USER also deposited `CREDIT` USDC
+/// and approved MANAGER
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world.account(MANAGER).nonce(1);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, ORDER_AMOUNT + CREDIT);
+
+    deploy(&mut world);
+    create_order(&mut world, ORDER_AMOUNT, 3_600);
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .deposit()
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(CREDIT))
+        .run();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .approve_order_manager(MANAGER)
+        .run();
+
+    world
+}
+
+fn is_manager(world: &mut ScenarioWorld) -> bool {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .is_order_manager(USER, MANAGER)
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn revoke(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .revoke_order_manager(MANAGER)
+        .run();
+}
+
+fn set_cap(world: &mut ScenarioWorld, cap: u64) {
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_manager_daily_cap(MANAGER, USDC, cap)
+        .run();
+}
+
+fn set_rate(world: &mut ScenarioWorld, max_orders_per_epoch: u64) {
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_manager_order_rate(MANAGER, max_orders_per_epoch)
+        .run();
+}
+
+/// MANAGER creates an order for USER from USER's credit
+fn create_as_manager(world: &mut ScenarioWorld, amount: u64) -> Result<u64, TxResponseStatus> {
+    world
+        .tx()
+        .from(MANAGER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order_as_manager(USER, USDC, amount, WEGLD, 1u64, 30u64, 100u64, 3_600u64)
+        .returns(ReturnsHandledOrError::new().returns(ReturnsResult))
+        .run()
+}
+
+#[test]
+fn approve_and_revoke_manager() {
+    let mut world = setup();
+    assert!(is_manager(&mut world));
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .approve_order_manager(MANAGER)
+        .returns(ExpectError(4, "E104: Manager already approved"))
+        .run();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .approve_order_manager(USER)
+        .returns(ExpectError(4, "E103: Cannot approve yourself as manager"))
+        .run();
+
+    revoke(&mut world);
+    assert!(!is_manager(&mut world));
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .revoke_order_manager(MANAGER)
+        .returns(ExpectError(4, "E105: Manager not approved"))
+        .run();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_manager_daily_cap(MANAGER, USDC, 1u64)
+        .returns(ExpectError(4, "E105: Manager not approved"))
+        .run();
+}
+
+#[test]
+fn manager_modifies_the_users_order() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(MANAGER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .modify_limit_order(1u64, 1u64, 25u64, 200u64, 7_200u64)
+        .run();
+
+    let modified = order(&mut world, 1);
+    assert_eq!(modified.user, USER.to_managed_address());
+    assert_eq!(modified.target_price_denominator, BigUint::from(25u64));
+    assert_eq!(modified.slippage_bp, 200);
+}
+
+#[test]
+fn manager_cancel_refunds_the_user() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(MANAGER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(1u64)
+        .run();
+
+    assert!(order(&mut world, 1).status == OrderStatus::Cancelled);
+    world.check_account(USER).esdt_balance(USDC, ORDER_AMOUNT);
+    world.check_account(MANAGER).esdt_balance(USDC, 0u64);
+}
+
+#[test]
+fn revoked_manager_loses_access() {
+    let mut world = setup();
+    revoke(&mut world);
+
+    world
+        .tx()
+        .from(MANAGER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(1u64)
+        .returns(ExpectError(4, "E102: Not your order"))
+        .run();
+    world
+        .tx()
+        .from(MANAGER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .modify_limit_order(1u64, 1u64, 25u64, 200u64, 7_200u64)
+        .returns(ExpectError(4, "E102: Not your order"))
+        .run();
+
+    assert!(order(&mut world, 1).status == OrderStatus::Pending);
+}
+
+#[test]
+fn reapproved_manager_starts_without_caps() {
+    let mut world = setup();
+    set_cap(&mut world, CREDIT);
+    set_rate(&mut world, 1);
+    create_as_manager(&mut world, ORDER_AMOUNT).unwrap();
+
+    revoke(&mut world);
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .approve_order_manager(MANAGER)
+        .run();
+
+    let cap = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .manager_daily_cap(USER, MANAGER, USDC)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(cap, BigUint::zero());
+    let rate = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .manager_order_rate(USER, MANAGER)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(rate, 0);
+    let result = create_as_manager(&mut world, 1);
+    assert_eq!(
+        result.unwrap_err().message,
+        "E115: Manager has no spend cap for this token"
+    );
+
+    // A new cap starts from a clean spend and order count
+    set_cap(&mut world, ORDER_AMOUNT);
+    set_rate(&mut world, 1);
+    create_as_manager(&mut world, ORDER_AMOUNT).unwrap();
+}
+
+#[test]
+fn manager_spend_is_limited_by_the_daily_cap() {
+    let mut world = setup();
+    set_cap(&mut world, 1_500_000);
+
+    create_as_manager(&mut world, ORDER_AMOUNT).unwrap();
+    let result = create_as_manager(&mut world, 500_001);
+    assert_eq!(result.unwrap_err().message, "E116: Manager daily spend cap exceeded");
+    create_as_manager(&mut world, 500_000).unwrap();
+
+    let spend = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .manager_spend(USER, MANAGER, USDC)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(spend.amount, BigUint::from(1_500_000u64));
+    let result = create_as_manager(&mut world, 1);
+    assert_eq!(result.unwrap_err().message, "E116: Manager daily spend cap exceeded");
+}
+
+#[test]
+fn daily_cap_resets_at_the_next_epoch() {
+    let mut world = setup();
+    set_cap(&mut world, ORDER_AMOUNT);
+    create_as_manager(&mut world, ORDER_AMOUNT).unwrap();
+    let result = create_as_manager(&mut world, 1);
+    assert_eq!(result.unwrap_err().message, "E116: Manager daily spend cap exceeded");
+
+    world.current_block().block_epoch(1);
+
+    create_as_manager(&mut world, ORDER_AMOUNT).unwrap();
+    let spend = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .manager_spend(USER, MANAGER, USDC)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(spend.epoch, 1);
+    assert_eq!(spend.amount, BigUint::from(ORDER_AMOUNT));
+}
+
+#[test]
+fn manager_order_rate_is_limited_per_epoch() {
+    let mut world = setup();
+    set_cap(&mut world, CREDIT);
+    set_rate(&mut world, 2);
+
+    create_as_manager(&mut world, 100_000).unwrap();
+    create_as_manager(&mut world, 100_000).unwrap();
+    let result = create_as_manager(&mut world, 100_000);
+    assert_eq!(result.unwrap_err().message, "E117: Manager order rate limit reached");
+
+    world.current_block().block_epoch(1);
+    create_as_manager(&mut world, 100_000).unwrap();
+
+    // Zero lifts the limit
+    set_rate(&mut world, 0);
+    create_as_manager(&mut world, 100_000).unwrap();
+    create_as_manager(&mut world, 100_000).unwrap();
+}
+
+#[test]
+fn manager_needs_a_cap_for_the_token() {
+    let mut world = setup();
+
+    let result = create_as_manager(&mut world, ORDER_AMOUNT);
+    assert_eq!(
+        result.unwrap_err().message,
+        "E115: Manager has no spend cap for this token"
+    );
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_manager_order_rate(OWNER, 1u64)
+        .returns(ExpectError(4, "E105: Manager not approved"))
+        .run();
+}
@@ -0,0 +1,291 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::limit_orders::{LimitOrder, LimitOrdersModule, OrderStatus, MAX_BASIS_POINTS};
+use stellarnova_sc::signals::{OraclePrice, PriceComparison, PriceCondition, SignalsModule};
+use stellarnova_sc::storage::StorageModule;
+use stellarnova_sc::timelock::{MAX_EXECUTION_FEE_BPS, MAX_PROTOCOL_FEE_BPS};
+use stellarnova_sc::treasury::TreasuryModule;
+
+/// Random cases per property
+const CASES: usize = 2_000;
+
+/// 1 unit of an 18 decimal token (WEGLD) and of a 6 decimal token (USDC)
+const ONE_18: u64 = 1_000_000_000_000_000_000;
+const ONE_6: u64 = 1_000_000;
+
+/// Largest small value the VM accepts when building a BigUint from a u64
+const MAX_SMALL: u64 = i64::MAX as u64;
+
+type Sc = stellarnova_sc::ContractObj<DebugApi>;
+
+/// StellarNova accepting any slippage, so every input reaches the math
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world
+        .tx()
+        .from(OWNER)
+        .raw_deploy()
+        .code(CODE_PATH)
+        .argument(&MAX_BASIS_POINTS)
+        .argument(&USDC)
+        .argument(&WEGLD)
+        .new_address(SC)
+        .run();
+    world
+}
+
+/// Deterministic xorshift generator, so failures are reproducible
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    /// Amount biased towards the edges: 1 wei, tiny values, whole units of
+    /// 6 or 18 decimal tokens, or up to ~10^38 wei
+    fn amount(&mut self) -> BigUint<DebugApi> {
+        match self.below(6) {
+            0 => BigUint::from(1u64),
+            1 => BigUint::from(1 + self.below(1_000)),
+            2 => BigUint::from(1 + self.below(1_000_000)) * ONE_6,
+            3 => BigUint::from(1 + self.below(1_000_000)) * ONE_18,
+            4 => BigUint::from(MAX_SMALL) * (1 + self.below(MAX_SMALL)),
+            _ => BigUint::from(1 + self.below(MAX_SMALL)),
+        }
+    }
+
+    /// Price as (num, denom), including 18/6 and 6/18 decimal conversions
+    fn price(&mut self) -> (BigUint<DebugApi>, BigUint<DebugApi>) {
+        let num = BigUint::from(1 + self.below(1_000_000));
+        let denom = BigUint::from(1 + self.below(1_000_000));
+        match self.below(3) {
+            0 => (num * ONE_18, denom * ONE_6),
+            1 => (num * ONE_6, denom * ONE_18),
+            _ => (num, denom),
+        }
+    }
+
+    fn bps(&mut self, max: u64) -> u64 {
+        match self.below(4) {
+            0 => 0,
+            1 => max,
+            _ => self.below(max + 1),
+        }
+    }
+}
+
+fn order(num: BigUint<DebugApi>, denom: BigUint<DebugApi>) -> LimitOrder<DebugApi> {
+    LimitOrder {
+        order_id: 1,
+        user: OWNER.to_managed_address(),
+        from_token: USDC.to_token_identifier(),
+        from_amount: BigUint::from(1u64),
+        to_token: WEGLD.to_token_identifier(),
+        target_price_numerator: num,
+        target_price_denominator: denom,
+        slippage_bp: 0,
+        expires_at: 0,
+        status: OrderStatus::Pending,
+        created_at: 0,
+    }
+}
+
+fn whitebox(world: &mut ScenarioWorld, f: impl FnOnce(Sc)) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .whitebox(stellarnova_sc::contract_obj, f);
+}
+
+#[test]
+fn min_output_edge_values() {
+    let mut world = setup();
+    whitebox(&mut world, |sc| {
+        let one = BigUint::from(1u64);
+
+        // 1 wei at par keeps 1 wei only without slippage
+        assert_eq!(sc.calculate_min_output(&one, &one, &one, 0), 1u64);
+        assert_eq!(sc.calculate_min_output(&one, &one, &one, 1), 0u64);
+
+        // 1 USDC (6 decimals) at 1/30 WEGLD (18 decimals) per USDC
+        let usdc = BigUint::from(ONE_6);
+        let num = BigUint::from(ONE_18);
+        let denom = BigUint::from(30 * ONE_6);
+        assert_eq!(
+            sc.calculate_min_output(&usdc, &num, &denom, 0),
+            33_333_333_333_333_333u64
+        );
+        assert_eq!(
+            sc.calculate_min_output(&usdc, &num, &denom, 100),
+            32_999_999_999_999_999u64
+        );
+
+        // Price below one base unit rounds to zero instead of panicking
+        assert_eq!(
+            sc.calculate_min_output(&one, &BigUint::from(ONE_6), &BigUint::from(ONE_18), 0),
+            0u64
+        );
+
+        // Max slippage accepts any output, even for huge amounts
+        let huge = BigUint::from(MAX_SMALL) * MAX_SMALL;
+        assert_eq!(
+            sc.calculate_min_output(&huge, &BigUint::from(ONE_18), &one, MAX_BASIS_POINTS),
+            0u64
+        );
+    });
+}
+
+#[test]
+fn min_output_is_never_above_the_exact_value() {
+    let mut world = setup();
+    whitebox(&mut world, |sc| {
+        let mut rng = Rng(0x5EED_0001);
+        for _ in 0..CASES {
+            let amount = rng.amount();
+            let (num, denom) = rng.price();
+            let slippage = rng.bps(MAX_BASIS_POINTS);
+
+            let min_output = sc.calculate_min_output(&amount, &num, &denom, slippage);
+
+            // Exact value with a single rounding; the two-step rounding may
+            // lose at most one unit and must never round up
+            let exact = &amount * &num * (MAX_BASIS_POINTS - slippage)
+                / (&denom * MAX_BASIS_POINTS);
+            assert!(min_output <= exact);
+            assert!(&exact - &min_output <= 1u64);
+
+            // More slippage never asks for more output
+            if slippage < MAX_BASIS_POINTS {
+                let looser = sc.calculate_min_output(&amount, &num, &denom, slippage + 1);
+                assert!(looser <= min_output);
+            }
+        }
+    });
+}
+
+#[test]
+fn limit_price_comparison() {
+    let mut world = setup();
+    whitebox(&mut world, |sc| {
+        // Limit 1/30 (18/6 decimals): equal price passes, one unit above fails
+        let order = order(BigUint::from(ONE_18), BigUint::from(30 * ONE_6));
+        let at = (BigUint::from(ONE_18), BigUint::from(30 * ONE_6));
+        assert!(sc.is_limit_price_met(&order, &at.0, &at.1));
+        assert!(sc.is_limit_price_met(&order, &(&at.0 * 2u64), &(&at.1 * 2u64)));
+        assert!(!sc.is_limit_price_met(&order, &(&at.0 + 1u64), &at.1));
+        assert!(sc.is_limit_price_met(&order, &(&at.0 - 1u64), &at.1));
+
+        let mut rng = Rng(0x5EED_0002);
+        for _ in 0..CASES {
+            let (num, denom) = rng.price();
+            let order = self::order(num.clone(), denom.clone());
+            let (current_num, current_denom) = rng.price();
+
+            // Cross multiplication must agree with a scaled comparison
+            let met = sc.is_limit_price_met(&order, &current_num, &current_denom);
+            let expected = &current_num * &denom <= &num * &current_denom;
+            assert_eq!(met, expected);
+
+            // Scaling both sides of a price never changes the outcome
+            let scale = 1 + rng.below(ONE_18);
+            assert_eq!(
+                sc.is_limit_price_met(&order, &(&current_num * scale), &(&current_denom * scale)),
+                met
+            );
+        }
+    });
+}
+
+#[test]
+fn cross_pair_condition_comparison() {
+    let mut world = setup();
+    whitebox(&mut world, |sc| {
+        let mut rng = Rng(0x5EED_0003);
+        for _ in 0..CASES {
+            let (threshold_num, threshold_denom) = rng.price();
+            let (price_num, price_denom) = rng.price();
+
+            let condition = |comparison| PriceCondition {
+                base_token: WEGLD.to_token_identifier(),
+                quote_token: USDC.to_token_identifier(),
+                comparison,
+                threshold_num: threshold_num.clone(),
+                threshold_denom: threshold_denom.clone(),
+            };
+            let price = OraclePrice {
+                price_num: price_num.clone(),
+                price_denom: price_denom.clone(),
+                updated_at: 0,
+            };
+            let above = sc.is_price_condition_met(&condition(PriceComparison::Above), &price);
+            let below = sc.is_price_condition_met(&condition(PriceComparison::Below), &price);
+
+            // Every price is on at least one side; both only at the threshold
+            assert!(above || below);
+            let at_threshold = &price_num * &threshold_denom == &threshold_num * &price_denom;
+            assert_eq!(above && below, at_threshold);
+
+            // The threshold itself satisfies both comparisons
+            let at = OraclePrice {
+                price_num: &threshold_num * 3u64,
+                price_denom: &threshold_denom * 3u64,
+                updated_at: 0,
+            };
+            assert!(sc.is_price_condition_met(&condition(PriceComparison::Above), &at));
+            assert!(sc.is_price_condition_met(&condition(PriceComparison::Below), &at));
+        }
+    });
+}
+
+#[test]
+fn fee_split_never_exceeds_the_output() {
+    let mut world = setup();
+    whitebox(&mut world, |sc| {
+        let mut rng = Rng(0x5EED_0004);
+        for _ in 0..CASES {
+            let execution_bps = rng.bps(MAX_EXECUTION_FEE_BPS);
+            let protocol_bps = rng.bps(MAX_PROTOCOL_FEE_BPS);
+            sc.execution_fee_bps().set(execution_bps);
+            sc.protocol_fee_bps().set(protocol_bps);
+
+            let output = rng.amount();
+            let execution_fee = sc.execution_fee_amount(&output);
+            let protocol_fee = sc.protocol_fee_amount(&output);
+
+            // Each fee rounds down, so the user keeps every rounding remainder
+            assert!(&execution_fee * MAX_BASIS_POINTS <= &output * execution_bps);
+            assert!(&protocol_fee * MAX_BASIS_POINTS <= &output * protocol_bps);
+            assert!(&output * execution_bps - &execution_fee * MAX_BASIS_POINTS < MAX_BASIS_POINTS);
+            assert!(&output * protocol_bps - &protocol_fee * MAX_BASIS_POINTS < MAX_BASIS_POINTS);
+
+            // The user share never underflows and never gets less than its exact share
+            let fees = &execution_fee + &protocol_fee;
+            assert!(fees <= output);
+            let user_amount = &output - &fees;
+            assert!(
+                &user_amount * MAX_BASIS_POINTS
+                    >= &output * (MAX_BASIS_POINTS - execution_bps - protocol_bps)
+            );
+        }
+
+        // 1 wei output pays no fee at the maximum rates
+        sc.execution_fee_bps().set(MAX_EXECUTION_FEE_BPS);
+        sc.protocol_fee_bps().set(MAX_PROTOCOL_FEE_BPS);
+        let one = BigUint::from(1u64);
+        assert_eq!(sc.execution_fee_amount(&one), 0u64);
+        assert_eq!(sc.protocol_fee_amount(&one), 0u64);
+    });
+}
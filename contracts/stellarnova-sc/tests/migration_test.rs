@@ -0,0 +1,175 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::limit_orders::{LimitOrdersModule, OrderStatus};
+use stellarnova_sc::migration::{LegacyLimitOrderV1, MigrationModule, STORAGE_VERSION};
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+use stellarnova_sc::storage::StorageModule;
+
+const CREATED_AT: u64 = 900;
+
+/// A version 1 deployment: orders 1 and 3 pending (1,000,000 USDC at 1/30
+/// and 2,000,000 USDC at 1/40), order 2 cancelled, in the v1 layout and
+/// in no index; then upgraded
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world.account(USER).nonce(1);
+    world.current_block().block_timestamp_seconds(1_000);
+
+    deploy(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .whitebox(stellarnova_sc::contract_obj, |sc| {
+            let orders = [
+                (1u64, 1_000_000u64, 30u64, OrderStatus::Pending),
+                (2, 500_000, 30, OrderStatus::Cancelled),
+                (3, 2_000_000, 40, OrderStatus::Pending),
+            ];
+            for (order_id, amount, price_denom, status) in orders {
+                sc.legacy_limit_orders(order_id).set(LegacyLimitOrderV1 {
+                    order_id,
+                    user: USER.to_managed_address(),
+                    from_token: USDC.to_token_identifier(),
+                    from_amount: BigUint::from(amount),
+                    to_token: WEGLD.to_token_identifier(),
+                    target_price_numerator: BigUint::from(1u64),
+                    target_price_denominator: BigUint::from(price_denom),
+                    slippage_bp: 100,
+                    expires_at: 10_000,
+                    status,
+                    created_at: CREATED_AT,
+                });
+            }
+            sc.next_order_id().set(4u64);
+            sc.storage_version().set(1u64);
+        });
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .upgrade()
+        .code(CODE_PATH)
+        .run();
+
+    world
+}
+
+fn migrate(world: &mut ScenarioWorld, max_count: u64) -> u64 {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .migrate_orders(max_count)
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn storage_version(world: &mut ScenarioWorld) -> u64 {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .storage_version()
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn is_migration_pending(world: &mut ScenarioWorld) -> bool {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .is_migration_pending()
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn upgrade_leaves_the_order_rewrite_to_migrate_orders() {
+    let mut world = setup();
+
+    assert!(is_migration_pending(&mut world));
+    assert_eq!(storage_version(&mut world), 1);
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .migrate_orders(10u64)
+        .returns(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+}
+
+#[test]
+fn orders_are_migrated_in_batches() {
+    let mut world = setup();
+
+    // v1 -> v2 in two batches: re-encoded, pending index and escrow totals
+    assert_eq!(migrate(&mut world, 2), 1);
+    assert_eq!(storage_version(&mut world), 1);
+    world.query().to(SC).whitebox(stellarnova_sc::contract_obj, |sc| {
+        assert_eq!(sc.order_migration_cursor().get(), 3);
+        assert!(sc.pending_order_ids().contains(&1));
+        assert!(!sc.pending_order_ids().contains(&2));
+        assert!(!sc.pending_order_ids().contains(&3));
+        assert_eq!(sc.escrow_balance(&USDC.to_token_identifier()).get(), 1_000_000u64);
+    });
+
+    // The last v1 batch starts the v2 -> v3 rewrite over all three orders
+    assert_eq!(migrate(&mut world, 2), 3);
+    assert_eq!(storage_version(&mut world), 2);
+    world.query().to(SC).whitebox(stellarnova_sc::contract_obj, |sc| {
+        assert_eq!(sc.pending_order_ids().len(), 2);
+        assert_eq!(sc.escrow_balance(&USDC.to_token_identifier()).get(), 3_000_000u64);
+        let order = sc.limit_orders(3).get();
+        assert!(matches!(order.status, OrderStatus::Pending));
+        assert_eq!(order.from_amount, 2_000_000u64);
+    });
+
+    assert_eq!(migrate(&mut world, 10), 3);
+    assert_eq!(storage_version(&mut world), 3);
+    assert_eq!(migrate(&mut world, 10), 0);
+    assert_eq!(storage_version(&mut world), STORAGE_VERSION);
+    assert!(!is_migration_pending(&mut world));
+
+    // Migrated orders show up in the price book and the creation-time index
+    let book: Vec<u64> = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order_book(USDC, WEGLD, 10usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|order| order.order_id)
+        .collect();
+    assert_eq!(book, vec![3, 1]);
+    let history: Vec<u64> = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_orders_created_between(0u64, 1_000u64, 0u64, 10u64)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|order| order.order_id)
+        .collect();
+    assert_eq!(history, vec![1, 2, 3]);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .migrate_orders(10u64)
+        .returns(ExpectError(4, "E704: No order migration pending"))
+        .run();
+}
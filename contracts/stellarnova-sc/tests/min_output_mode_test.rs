@@ -0,0 +1,151 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{
+    MinOutputBound, MinOutputMode, OrderStatus, StellarNovaProxy,
+};
+
+/// USER has order 1 selling 3,000,000 USDC at 60 USDC per WEGLD with 1%
+/// slippage, while the mock pair pays 1 WEGLD per 30 USDC
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 6_000_000u64);
+
+    deploy_with_pair(&mut world);
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order(
+            WEGLD,
+            1u64,
+            60u64,
+            100u64,
+            36_000u64,
+            OptionalValue::<u64>::None,
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(3_000_000u64))
+        .run();
+
+    world
+}
+
+fn min_output(world: &mut ScenarioWorld, order_id: u64) -> (BigUint<StaticApi>, MinOutputBound) {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_execution_min_output(order_id)
+        .returns(ReturnsResult)
+        .run()
+        .into_tuple()
+}
+
+fn set_live_quote_mode(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_min_output_mode(MinOutputMode::LiveQuote)
+        .run();
+}
+
+#[test]
+fn live_quote_raises_min_output_to_the_market() {
+    let mut world = setup();
+
+    // 50,000 WEGLD at the target price, minus 1%
+    assert_eq!(
+        min_output(&mut world, 1),
+        (BigUint::from(49_500u64), MinOutputBound::TargetPrice)
+    );
+
+    set_live_quote_mode(&mut world);
+    let mode = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .min_output_mode()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(mode, MinOutputMode::LiveQuote);
+
+    // 100,000 WEGLD quoted by the pair, minus 1%
+    assert_eq!(
+        min_output(&mut world, 1),
+        (BigUint::from(99_000u64), MinOutputBound::LiveQuote)
+    );
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 60u64)
+        .run();
+    let order = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert!(order.status == OrderStatus::Executed);
+    // 100,000 WEGLD minus the 10 bps execution fee
+    world.check_account(USER).esdt_balance(WEGLD, 99_900u64);
+}
+
+#[test]
+fn target_price_floor_applies_when_stricter_than_the_quote() {
+    let mut world = setup();
+    set_live_quote_mode(&mut world);
+
+    // 25 USDC per WEGLD asks for 120,000 WEGLD, above the 100,000 quote
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order(
+            WEGLD,
+            1u64,
+            25u64,
+            100u64,
+            36_000u64,
+            OptionalValue::<u64>::None,
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(3_000_000u64))
+        .run();
+
+    assert_eq!(
+        min_output(&mut world, 2),
+        (BigUint::from(118_800u64), MinOutputBound::TargetPrice)
+    );
+}
+
+#[test]
+fn only_owner_sets_min_output_mode() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_min_output_mode(MinOutputMode::LiveQuote)
+        .returns(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+}
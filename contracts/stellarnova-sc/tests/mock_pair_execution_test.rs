@@ -0,0 +1,132 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use mock_pair::FailureMode;
+use stellarnova_sc::limit_orders::{LimitOrdersModule, OrderStatus};
+use stellarnova_sc::storage::StorageModule;
+
+const STRAY: TestTokenIdentifier = TestTokenIdentifier::new("STRAY-123456");
+
+/// StellarNova wired to a mock WEGLD/USDC pair quoting 30 USDC per WEGLD,
+/// with a pending order selling 3,000,000 USDC for at least 100,000 WEGLD
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64)
+        .esdt_balance(STRAY, 1_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64);
+
+    deploy_pair(&mut world);
+
+    deploy(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .raw_call("setXExchangePair")
+        .argument(&PAIR)
+        .run();
+
+    create_order(&mut world, 3_000_000, 3_600);
+
+    world
+}
+
+#[test]
+fn execution_settles_through_the_pair() {
+    let mut world = setup();
+    execute(&mut world, 1);
+
+    // 100,000 WEGLD out, 0.1% execution fee to the executor (owner)
+    world.check_account(USER).esdt_balance(WEGLD, 99_900u64);
+    world.check_account(OWNER).esdt_balance(WEGLD, 100u64);
+    world.check_account(PAIR).esdt_balance(USDC, 3_000_000u64);
+
+    world.query().to(SC).whitebox(stellarnova_sc::contract_obj, |sc| {
+        let order = sc.limit_orders(1).get();
+        assert!(matches!(order.status, OrderStatus::Executed));
+        assert_eq!(sc.escrow_balance(&USDC.to_token_identifier()).get(), 0u64);
+        assert_eq!(sc.escrow_in_flight(&USDC.to_token_identifier()).get(), 0u64);
+        assert!(sc.pending_swap_executions(1).is_empty());
+    });
+}
+
+#[test]
+fn failed_swap_reaches_the_error_callback() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(PAIR)
+        .raw_call("setFailureMode")
+        .argument(&FailureMode::Revert)
+        .run();
+
+    execute(&mut world, 1);
+
+    // The swap is reverted, so the input never leaves the contract. The test
+    // VM does not hand the returned tokens to the error callback like the
+    // chain does, so only the callback bookkeeping is checked here.
+    world.check_account(SC).esdt_balance(USDC, 3_000_000u64);
+    world.check_account(USER).esdt_balance(WEGLD, 0u64);
+    world.query().to(SC).whitebox(stellarnova_sc::contract_obj, |sc| {
+        assert_eq!(sc.execution_attempts(1).get(), 1);
+        assert_eq!(sc.escrow_in_flight(&USDC.to_token_identifier()).get(), 0u64);
+        assert!(!sc.orders_in_execution().contains(&1));
+        assert!(sc.pending_swap_executions(1).is_empty());
+    });
+}
+
+#[test]
+fn unused_input_returned_by_the_pair_stays_in_the_order() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(PAIR)
+        .raw_call("setFailureMode")
+        .argument(&FailureMode::PartialInput)
+        .run();
+
+    execute(&mut world, 1);
+
+    // Half of the input swapped for 50,000 WEGLD, the other half came back
+    world.check_account(USER).esdt_balance(WEGLD, 49_950u64);
+    world.check_account(PAIR).esdt_balance(USDC, 1_500_000u64);
+    world.check_account(SC).esdt_balance(USDC, 1_500_000u64);
+    world.query().to(SC).whitebox(stellarnova_sc::contract_obj, |sc| {
+        let order = sc.limit_orders(1).get();
+        assert!(matches!(order.status, OrderStatus::Pending));
+        assert_eq!(order.from_amount, 1_500_000u64);
+        assert_eq!(sc.escrow_balance(&USDC.to_token_identifier()).get(), 1_500_000u64);
+    });
+}
+
+#[test]
+fn unexpected_tokens_from_the_pair_are_refunded() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(PAIR)
+        .raw_call("setBonusPayment")
+        .single_esdt(&STRAY.to_token_identifier(), 0, &BigUint::from(5u64))
+        .run();
+
+    execute(&mut world, 1);
+
+    world.check_account(USER).esdt_balance(WEGLD, 99_900u64);
+    world.check_account(USER).esdt_balance(STRAY, 5u64);
+    world.check_account(SC).esdt_balance(STRAY, 0u64);
+    world.query().to(SC).whitebox(stellarnova_sc::contract_obj, |sc| {
+        let order = sc.limit_orders(1).get();
+        assert!(matches!(order.status, OrderStatus::Executed));
+    });
+}
@@ -0,0 +1,751 @@
+mod common;
+
+use std::collections::BTreeMap;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use multiversx_sc_scenario::scenario_model::TxResponseStatus;
+
+use stellarnova_sc::errors::*;
+use stellarnova_sc::limit_orders::{LimitOrdersModule, OrderStatus, MAX_BASIS_POINTS};
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+use stellarnova_sc::storage::StorageModule;
+
+const USERS: [TestAddress; 3] = [
+    TestAddress::new("alice"),
+    TestAddress::new("bob"),
+    TestAddress::new("carol"),
+];
+
+/// Indexed by `Token`
+const TOKENS: [TestTokenIdentifier; 2] = [USDC, WEGLD];
+
+/// Random operation sequences and steps per sequence
+const SEQUENCES: u64 = 10;
+const STEPS: usize = 200;
+
+const USER_BALANCE: u64 = 1_000_000_000;
+const POOL_RESERVE: u64 = 1_000_000_000_000;
+
+const MAX_SLIPPAGE_BP: u64 = 500;
+const EXECUTION_FEE_BPS: u64 = 10;
+const PROTOCOL_FEE_BPS: u64 = 20;
+const CANCELLATION_WINDOW: u64 = 600;
+const CANCELLATION_FEE_BPS: u64 = 100;
+const MAX_EXECUTION_ATTEMPTS: u64 = 3;
+
+/// USDC per WEGLD the pair starts at
+const START_PRICE: u64 = 30;
+
+type Token = usize;
+const USDC_INDEX: Token = 0;
+const WEGLD_INDEX: Token = 1;
+
+/// Accounts the model tracks balances for: the users, then the owner (executor)
+const OWNER_INDEX: usize = USERS.len();
+
+fn account(index: usize) -> TestAddress<'static> {
+    if index == OWNER_INDEX {
+        OWNER
+    } else {
+        USERS[index]
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum FailureMode {
+    None,
+    Revert,
+    ShortOutput,
+}
+
+/// Expected state of one order
+#[derive(Clone, Debug)]
+struct ModelOrder {
+    user: usize,
+    from: Token,
+    to: Token,
+    /// Escrow left; the total filled input once executed
+    from_amount: u64,
+    filled: u64,
+    price: (u64, u64),
+    slippage_bp: u64,
+    expires_at: u64,
+    created_at: u64,
+    status: OrderStatus,
+    attempts: u64,
+}
+
+/// Mirror of the mock pair: a fixed USDC-per-WEGLD rate over finite reserves
+struct ModelPair {
+    price: (u64, u64),
+    failure_mode: FailureMode,
+    reserves: [u64; 2],
+}
+
+impl ModelPair {
+    fn quote(&self, token_in: Token, amount_in: u64) -> u64 {
+        let (num, denom) = self.price;
+        if token_in == WEGLD_INDEX {
+            amount_in * num / denom
+        } else {
+            amount_in * denom / num
+        }
+    }
+
+    /// Output of `swapTokensFixedInput`, or `None` when the swap reverts
+    fn swap(&mut self, token_in: Token, amount_in: u64, min_out: u64) -> Option<u64> {
+        let token_out = 1 - token_in;
+        let mut amount_out = self.quote(token_in, amount_in);
+        match self.failure_mode {
+            FailureMode::None if amount_out < min_out => return None,
+            FailureMode::None => {}
+            FailureMode::Revert => return None,
+            FailureMode::ShortOutput => amount_out /= 2,
+        }
+        if amount_out == 0 || self.reserves[token_out] <= amount_out {
+            return None;
+        }
+
+        self.reserves[token_in] += amount_in;
+        self.reserves[token_out] -= amount_out;
+        Some(amount_out)
+    }
+}
+
+/// Everything the contract should hold after each step, computed in plain Rust
+struct Model {
+    now: u64,
+    next_order_id: u64,
+    orders: BTreeMap<u64, ModelOrder>,
+    /// Same order as the contract's `UnorderedSetMapper` (swap-remove)
+    pending: Vec<u64>,
+    sweep_cursor: usize,
+    balances: [[u64; 2]; USERS.len() + 1],
+    escrow: [u64; 2],
+    protocol_fees: [u64; 2],
+    /// Bounced swap input the contract holds outside of any escrow, see `execute`
+    stranded: [u64; 2],
+    pair: ModelPair,
+}
+
+type Outcome = Result<(), &'static str>;
+
+impl Model {
+    fn new() -> Self {
+        let mut balances = [[USER_BALANCE; 2]; USERS.len() + 1];
+        balances[OWNER_INDEX] = [0; 2];
+        Model {
+            now: 0,
+            next_order_id: 1,
+            orders: BTreeMap::new(),
+            pending: Vec::new(),
+            sweep_cursor: 0,
+            balances,
+            escrow: [0; 2],
+            protocol_fees: [0; 2],
+            stranded: [0; 2],
+            pair: ModelPair {
+                price: (START_PRICE, 1),
+                failure_mode: FailureMode::None,
+                reserves: [POOL_RESERVE; 2],
+            },
+        }
+    }
+
+    fn unindex(&mut self, order_id: u64) {
+        let index = self.pending.iter().position(|id| *id == order_id).unwrap();
+        self.pending.swap_remove(index);
+    }
+
+    /// Refund the escrow left and give the order its final status
+    fn refund_and_close(&mut self, order_id: u64, status: OrderStatus) {
+        let order = self.orders.get_mut(&order_id).unwrap();
+        self.balances[order.user][order.from] += order.from_amount;
+        self.escrow[order.from] -= order.from_amount;
+        order.status = status;
+        self.unindex(order_id);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn create(
+        &mut self,
+        user: usize,
+        from: Token,
+        to: Token,
+        amount: u64,
+        price: (u64, u64),
+        slippage_bp: u64,
+        expires_in: u64,
+    ) -> Outcome {
+        if from == to {
+            return Err(ERROR_SAME_TOKEN);
+        }
+        if slippage_bp > MAX_SLIPPAGE_BP {
+            return Err(ERROR_SLIPPAGE_ABOVE_MAX);
+        }
+
+        let order_id = self.next_order_id;
+        self.next_order_id += 1;
+        self.balances[user][from] -= amount;
+        self.escrow[from] += amount;
+        self.orders.insert(
+            order_id,
+            ModelOrder {
+                user,
+                from,
+                to,
+                from_amount: amount,
+                filled: 0,
+                price,
+                slippage_bp,
+                expires_at: self.now + expires_in,
+                created_at: self.now,
+                status: OrderStatus::Pending,
+                attempts: 0,
+            },
+        );
+        self.pending.push(order_id);
+        Ok(())
+    }
+
+    fn cancel(&mut self, caller: usize, order_id: u64) -> Outcome {
+        let now = self.now;
+        let Some(order) = self.orders.get_mut(&order_id) else {
+            return Err(ERROR_ORDER_NOT_FOUND);
+        };
+        if order.user != caller {
+            return Err(ERROR_NOT_YOUR_ORDER);
+        }
+        if order.status != OrderStatus::Pending {
+            return Err(ERROR_ORDER_NOT_PENDING);
+        }
+
+        // Young orders pay the cancellation fee out of their escrow
+        if now < order.created_at + CANCELLATION_WINDOW {
+            let fee = order.from_amount * CANCELLATION_FEE_BPS / MAX_BASIS_POINTS;
+            order.from_amount -= fee;
+            self.escrow[order.from] -= fee;
+            self.protocol_fees[order.from] += fee;
+        }
+        self.refund_and_close(order_id, OrderStatus::Cancelled);
+        Ok(())
+    }
+
+    fn modify(
+        &mut self,
+        caller: usize,
+        order_id: u64,
+        price: (u64, u64),
+        slippage_bp: u64,
+        expires_in: u64,
+    ) -> Outcome {
+        let now = self.now;
+        let Some(order) = self.orders.get_mut(&order_id) else {
+            return Err(ERROR_ORDER_NOT_FOUND);
+        };
+        if order.status != OrderStatus::Pending {
+            return Err(ERROR_ORDER_NOT_PENDING);
+        }
+        if now > order.expires_at {
+            return Err(ERROR_ORDER_EXPIRED);
+        }
+        if order.user != caller {
+            return Err(ERROR_NOT_YOUR_ORDER);
+        }
+        if slippage_bp > MAX_SLIPPAGE_BP {
+            return Err(ERROR_SLIPPAGE_ABOVE_MAX);
+        }
+
+        order.price = price;
+        order.slippage_bp = slippage_bp;
+        order.expires_at = now + expires_in;
+        Ok(())
+    }
+
+    /// `executeLimitOrder(Partial)`: the swap and its callback complete
+    /// within the transaction
+    fn execute(
+        &mut self,
+        caller: usize,
+        order_id: u64,
+        fill_amount: Option<u64>,
+        current_price: (u64, u64),
+    ) -> Outcome {
+        if caller != OWNER_INDEX {
+            return Err(ERROR_ONLY_EXECUTOR);
+        }
+        let Some(order) = self.orders.get(&order_id).cloned() else {
+            return Err(ERROR_ORDER_NOT_FOUND);
+        };
+        if order.status != OrderStatus::Pending {
+            return Err(ERROR_ORDER_NOT_PENDING);
+        }
+        if self.now > order.expires_at {
+            self.refund_and_close(order_id, OrderStatus::Expired);
+            return Ok(());
+        }
+
+        let fill = fill_amount.unwrap_or(order.from_amount);
+        if fill == 0 {
+            return Err(ERROR_INVALID_AMOUNT);
+        }
+        if fill > order.from_amount {
+            return Err(ERROR_FILL_EXCEEDS_REMAINING);
+        }
+        if current_price.0 * order.price.1 > order.price.0 * current_price.1 {
+            return Err(ERROR_PRICE_NOT_MET);
+        }
+
+        let min_out = fill * order.price.0 / order.price.1
+            * (MAX_BASIS_POINTS - order.slippage_bp)
+            / MAX_BASIS_POINTS;
+        let Some(amount_out) = self.pair.swap(order.from, fill, min_out) else {
+            // The input bounces back to the contract, but the test VM does not
+            // hand it to the error callback like the chain does: the callback
+            // writes the chunk off as a shortfall
+            self.escrow[order.from] -= fill;
+            self.stranded[order.from] += fill;
+            let order = self.orders.get_mut(&order_id).unwrap();
+            order.from_amount -= fill;
+            order.attempts += 1;
+            if order.from_amount == 0 || order.attempts >= MAX_EXECUTION_ATTEMPTS {
+                self.refund_and_close(order_id, OrderStatus::Failed);
+            }
+            return Ok(());
+        };
+
+        // A short output (below `min_out`) is settled like any other
+        let execution_fee = amount_out * EXECUTION_FEE_BPS / MAX_BASIS_POINTS;
+        let protocol_fee = amount_out * PROTOCOL_FEE_BPS / MAX_BASIS_POINTS;
+        self.balances[OWNER_INDEX][order.to] += execution_fee;
+        self.balances[order.user][order.to] += amount_out - execution_fee - protocol_fee;
+        self.protocol_fees[order.to] += protocol_fee;
+        self.escrow[order.from] -= fill;
+
+        let order = self.orders.get_mut(&order_id).unwrap();
+        order.from_amount -= fill;
+        order.filled += fill;
+        if order.from_amount == 0 {
+            order.from_amount = order.filled;
+            order.status = OrderStatus::Executed;
+            self.unindex(order_id);
+        }
+        Ok(())
+    }
+
+    /// Returns the number of orders expired
+    fn process_expired(&mut self, max_count: u64) -> u64 {
+        let pending_len = self.pending.len();
+        if pending_len == 0 {
+            return 0;
+        }
+
+        let mut expired = Vec::new();
+        let mut index = self.sweep_cursor;
+        for _ in 0..(max_count as usize).min(pending_len) {
+            if index == 0 || index > pending_len {
+                index = 1;
+            }
+            let order_id = self.pending[index - 1];
+            if self.orders[&order_id].expires_at < self.now {
+                expired.push(order_id);
+            }
+            index += 1;
+        }
+        self.sweep_cursor = index;
+
+        for order_id in &expired {
+            self.refund_and_close(*order_id, OrderStatus::Expired);
+        }
+        expired.len() as u64
+    }
+}
+
+/// Deterministic xorshift generator, so failures are reproducible
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    fn chance(&mut self, one_in: u64) -> bool {
+        self.below(one_in) == 0
+    }
+
+    /// Mostly pending orders, otherwise any id including an unknown one
+    fn order_id(&mut self, model: &Model) -> u64 {
+        if !model.pending.is_empty() && !self.chance(4) {
+            return model.pending[self.below(model.pending.len() as u64) as usize];
+        }
+        self.below(model.next_order_id + 1)
+    }
+
+    /// Limit price within a few USDC per WEGLD of the pair's, in `to` per `from`
+    fn price(&mut self, model: &Model, from: Token) -> (u64, u64) {
+        let usdc_per_wegld = model.pair.price.0 + self.below(5) - 2;
+        if from == WEGLD_INDEX {
+            (usdc_per_wegld, 1)
+        } else {
+            (1, usdc_per_wegld)
+        }
+    }
+
+    /// Slippage up to the maximum, sometimes above it
+    fn slippage(&mut self) -> u64 {
+        if self.chance(10) {
+            MAX_SLIPPAGE_BP + 1 + self.below(100)
+        } else {
+            self.below(MAX_SLIPPAGE_BP + 1)
+        }
+    }
+}
+
+fn big(amount: u64) -> BigUint<StaticApi> {
+    BigUint::from(amount)
+}
+
+/// Contract with protocol and cancellation fees on, wired to a fixed-price pair
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(USDC, POOL_RESERVE)
+        .esdt_balance(WEGLD, POOL_RESERVE);
+    for user in USERS {
+        world
+            .account(user)
+            .nonce(1)
+            .esdt_balance(USDC, USER_BALANCE)
+            .esdt_balance(WEGLD, USER_BALANCE);
+    }
+
+    deploy_mock_pair(&mut world, PAIR);
+    world
+        .tx()
+        .from(OWNER)
+        .to(PAIR)
+        .raw_call("addReserves")
+        .esdt(TestEsdtTransfer(WEGLD, 0, POOL_RESERVE))
+        .esdt(TestEsdtTransfer(USDC, 0, POOL_RESERVE))
+        .run();
+    set_pair_quote(&mut world, (START_PRICE, 1));
+
+    world
+        .tx()
+        .from(OWNER)
+        .typed(StellarNovaProxy)
+        .init(MAX_SLIPPAGE_BP, MultiValueVec::from(vec![USDC, WEGLD]))
+        .code(CODE_PATH)
+        .new_address(SC)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_xexchange_pair(PAIR)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_protocol_fee_bps(PROTOCOL_FEE_BPS)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_cancellation_policy(CANCELLATION_WINDOW, CANCELLATION_FEE_BPS)
+        .run();
+
+    world
+}
+
+/// `price.0 / price.1` USDC per WEGLD on the mock pair
+fn set_pair_quote(world: &mut ScenarioWorld, price: (u64, u64)) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(PAIR)
+        .raw_call("setPrice")
+        .argument(&big(price.0))
+        .argument(&big(price.1))
+        .run();
+}
+
+fn outcome<T>(result: Result<T, TxResponseStatus>) -> Result<T, String> {
+    result.map_err(|err| err.message)
+}
+
+fn assert_outcome<T>(context: &str, actual: Result<T, String>, expected: Outcome) -> Option<T> {
+    match (actual, expected) {
+        (Ok(value), Ok(())) => Some(value),
+        (Err(message), Err(expected)) if message == expected => None,
+        (Ok(_), Err(expected)) => panic!("{context}: succeeded, the model expected {expected}"),
+        (Err(message), _) => panic!("{context}: failed with {message}, expected {expected:?}"),
+    }
+}
+
+/// Apply one random operation to both the contract and the model
+fn random_step(world: &mut ScenarioWorld, model: &mut Model, rng: &mut Rng, context: &str) {
+    let user = rng.below(USERS.len() as u64) as usize;
+
+    match rng.below(10) {
+        0..=2 => {
+            let from = rng.below(2) as usize;
+            let to = if rng.chance(10) { from } else { 1 - from };
+            let balance = model.balances[user][from];
+            if balance == 0 {
+                return;
+            }
+            let amount = 1 + rng.below(1_000_000).min(balance - 1);
+            let price = rng.price(model, from);
+            let slippage_bp = rng.slippage();
+            let expires_in = 60 + rng.below(3_600);
+
+            let result = world
+                .tx()
+                .from(USERS[user])
+                .to(SC)
+                .typed(StellarNovaProxy)
+                .create_limit_order(
+                    TOKENS[to],
+                    big(price.0),
+                    big(price.1),
+                    slippage_bp,
+                    expires_in,
+                    OptionalValue::<u64>::None,
+                    OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+                )
+                .esdt(TestEsdtTransfer(TOKENS[from], 0, amount))
+                .returns(ReturnsHandledOrError::new().returns(ReturnsResult))
+                .run();
+            let expected_id = model.next_order_id;
+            let expected = model.create(user, from, to, amount, price, slippage_bp, expires_in);
+            if let Some(order_id) = assert_outcome(context, outcome(result), expected) {
+                assert_eq!(order_id, expected_id, "{context}: order id");
+            }
+        }
+        3 => {
+            // Mostly the owner, sometimes someone else
+            let order_id = rng.order_id(model);
+            let caller = match model.orders.get(&order_id) {
+                Some(order) if !rng.chance(5) => order.user,
+                _ => user,
+            };
+            let result = world
+                .tx()
+                .from(USERS[caller])
+                .to(SC)
+                .typed(StellarNovaProxy)
+                .cancel_limit_order(order_id)
+                .returns(ReturnsHandledOrError::new())
+                .run();
+            let expected = model.cancel(caller, order_id);
+            assert_outcome(context, outcome(result), expected);
+        }
+        4 => {
+            let order_id = rng.order_id(model);
+            let (caller, from) = match model.orders.get(&order_id) {
+                Some(order) if !rng.chance(5) => (order.user, order.from),
+                Some(order) => (user, order.from),
+                None => (user, USDC_INDEX),
+            };
+            let price = rng.price(model, from);
+            let slippage_bp = rng.slippage();
+            let expires_in = 60 + rng.below(3_600);
+            let result = world
+                .tx()
+                .from(USERS[caller])
+                .to(SC)
+                .typed(StellarNovaProxy)
+                .modify_limit_order(order_id, big(price.0), big(price.1), slippage_bp, expires_in)
+                .returns(ReturnsHandledOrError::new())
+                .run();
+            let expected = model.modify(caller, order_id, price, slippage_bp, expires_in);
+            assert_outcome(context, outcome(result), expected);
+        }
+        5 | 6 => {
+            let order_id = rng.order_id(model);
+            let caller = if rng.chance(8) { user } else { OWNER_INDEX };
+            let remaining = model.orders.get(&order_id).map_or(1, |order| order.from_amount);
+            let from = model.orders.get(&order_id).map_or(USDC_INDEX, |order| order.from);
+            let fill_amount = if rng.chance(3) {
+                Some(rng.below(remaining + 2))
+            } else {
+                None
+            };
+
+            // The executor quotes the pair, sometimes with a stale price
+            let fill = fill_amount.unwrap_or(remaining).max(1);
+            let current_price = if rng.chance(6) {
+                rng.price(model, from)
+            } else {
+                (model.pair.quote(from, fill), fill)
+            };
+
+            let tx = world
+                .tx()
+                .from(account(caller))
+                .to(SC)
+                .gas(600_000_000u64)
+                .typed(StellarNovaProxy);
+            let result = match fill_amount {
+                None => tx
+                    .execute_limit_order(order_id, big(current_price.0), big(current_price.1))
+                    .returns(ReturnsHandledOrError::new())
+                    .run(),
+                Some(fill) => tx
+                    .execute_limit_order_partial(
+                        order_id,
+                        big(fill),
+                        big(current_price.0),
+                        big(current_price.1),
+                    )
+                    .returns(ReturnsHandledOrError::new())
+                    .run(),
+            };
+            let expected = model.execute(caller, order_id, fill_amount, current_price);
+            assert_outcome(context, outcome(result), expected);
+        }
+        7 => {
+            if rng.chance(2) {
+                let price = (26 + rng.below(9), 1);
+                set_pair_quote(world, price);
+                model.pair.price = price;
+            } else {
+                let mode = match rng.below(6) {
+                    0 => FailureMode::Revert,
+                    1 => FailureMode::ShortOutput,
+                    _ => FailureMode::None,
+                };
+                world
+                    .tx()
+                    .from(OWNER)
+                    .to(PAIR)
+                    .raw_call("setFailureMode")
+                    .argument(&(mode as u8))
+                    .run();
+                model.pair.failure_mode = mode;
+            }
+        }
+        8 => {
+            let max_count = 1 + rng.below(5);
+            let result = world
+                .tx()
+                .from(USERS[user])
+                .to(SC)
+                .typed(StellarNovaProxy)
+                .process_expired_orders(max_count)
+                .returns(ReturnsResult)
+                .run();
+            assert_eq!(result, model.process_expired(max_count), "{context}: expired count");
+        }
+        _ => {
+            model.now += rng.below(900);
+            world.current_block().block_timestamp_seconds(model.now);
+        }
+    }
+}
+
+/// Every order, the pending index, escrow, fees and all balances equal the model
+fn check_model(world: &mut ScenarioWorld, model: &Model, context: &str) {
+    world.query().to(SC).whitebox(stellarnova_sc::contract_obj, |sc| {
+        assert_eq!(sc.next_order_id().get(), model.next_order_id, "{context}: next order id");
+        assert!(
+            sc.orders_in_execution().is_empty(),
+            "{context}: an order is left in execution"
+        );
+
+        for (order_id, expected) in &model.orders {
+            let order = sc.limit_orders(*order_id).get();
+            let mismatch = |field: &str| format!("{context}: order {order_id} {field}");
+            assert_eq!(order.status, expected.status, "{}", mismatch("status"));
+            assert_eq!(order.user, account(expected.user).to_managed_address(), "{}", mismatch("user"));
+            assert_eq!(
+                order.from_token,
+                TOKENS[expected.from].to_token_identifier(),
+                "{}",
+                mismatch("from token")
+            );
+            assert_eq!(order.from_amount, expected.from_amount, "{}", mismatch("amount"));
+            assert_eq!(order.target_price_numerator, expected.price.0, "{}", mismatch("price"));
+            assert_eq!(order.target_price_denominator, expected.price.1, "{}", mismatch("price"));
+            assert_eq!(order.slippage_bp, expected.slippage_bp, "{}", mismatch("slippage"));
+            assert_eq!(order.expires_at, expected.expires_at, "{}", mismatch("expiry"));
+            assert_eq!(
+                sc.execution_attempts(*order_id).get(),
+                expected.attempts,
+                "{}",
+                mismatch("attempts")
+            );
+            assert!(
+                sc.pending_swap_executions(*order_id).is_empty(),
+                "{}",
+                mismatch("swap context left behind")
+            );
+        }
+
+        let pending: Vec<u64> = sc.pending_order_ids().iter().collect();
+        assert_eq!(pending, model.pending, "{context}: pending index");
+
+        for (token, token_id) in TOKENS.iter().enumerate() {
+            let token_id = token_id.to_token_identifier();
+            let name = token_id.to_string();
+            assert_eq!(
+                sc.escrow_balance(&token_id).get(),
+                model.escrow[token],
+                "{context}: {name} escrow"
+            );
+            assert_eq!(
+                sc.protocol_fees(&token_id).get(),
+                model.protocol_fees[token],
+                "{context}: {name} protocol fees"
+            );
+            assert_eq!(
+                sc.blockchain().get_sc_balance(EgldOrEsdtTokenIdentifier::esdt(token_id.clone()), 0),
+                model.escrow[token] + model.protocol_fees[token] + model.stranded[token],
+                "{context}: contract {name} balance"
+            );
+        }
+    });
+
+    // Whitebox code can only read the contract's own balances
+    for (index, balances) in model.balances.iter().enumerate() {
+        for (token, token_id) in TOKENS.iter().enumerate() {
+            world
+                .check_account(account(index))
+                .esdt_balance(*token_id, balances[token]);
+        }
+    }
+}
+
+/// Random operation sequences leave the contract in exactly the state a
+/// plain-Rust model of orders, escrow, fees and balances predicts
+#[test]
+fn contract_matches_model() {
+    for sequence in 0..SEQUENCES {
+        let mut world = setup();
+        let mut model = Model::new();
+        let mut rng = Rng(0x0DE1_5EED + sequence);
+
+        for step in 0..STEPS {
+            let context = format!("sequence {sequence} step {step}");
+            random_step(&mut world, &mut model, &mut rng, &context);
+            check_model(&mut world, &model, &context);
+        }
+    }
+}
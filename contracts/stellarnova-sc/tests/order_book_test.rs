@@ -0,0 +1,131 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+
+/// USER sells USDC for WEGLD at 1/30, 1/20, 1/40 and 1/31 (orders 1 to 4)
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 4_000_000u64);
+
+    deploy(&mut world);
+
+    for denominator in [30u64, 20, 40, 31] {
+        world
+            .tx()
+            .from(USER)
+            .to(SC)
+            .typed(StellarNovaProxy)
+            .create_limit_order(
+                WEGLD,
+                1u64,
+                denominator,
+                100u64,
+                3_600u64,
+                OptionalValue::<u64>::None,
+                OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+            )
+            .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(1_000_000u64))
+            .run();
+    }
+
+    world
+}
+
+fn order_book(world: &mut ScenarioWorld, depth: usize) -> Vec<u64> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order_book(USDC, WEGLD, depth)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|order| order.order_id)
+        .collect()
+}
+
+#[test]
+fn book_is_sorted_by_price_across_buckets() {
+    let mut world = setup();
+    assert_eq!(order_book(&mut world, 10), vec![3, 4, 1, 2]);
+    assert_eq!(order_book(&mut world, 2), vec![3, 4]);
+
+    // The other direction has no orders
+    let reverse = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order_book(WEGLD, USDC, 10usize)
+        .returns(ReturnsResult)
+        .run();
+    assert!(reverse.is_empty());
+}
+
+#[test]
+fn book_follows_cancellation_and_repricing() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(3u64)
+        .run();
+    assert_eq!(order_book(&mut world, 10), vec![4, 1, 2]);
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .modify_limit_order(2u64, 1u64, 50u64, 100u64, 3_600u64)
+        .run();
+    assert_eq!(order_book(&mut world, 10), vec![2, 4, 1]);
+
+    // Expired orders are skipped until they are closed
+    world.current_block().block_timestamp_seconds(3_601);
+    assert!(order_book(&mut world, 10).is_empty());
+}
+
+#[test]
+fn committed_order_is_listed_once_repriced() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(3u64)
+        .run();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_committed_order(
+            WEGLD,
+            ManagedByteArray::new_from_bytes(&[7u8; 32]),
+            100u64,
+            3_600u64,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(1_000_000u64))
+        .run();
+    assert_eq!(order_book(&mut world, 10), vec![4, 1, 2]);
+
+    // An explicit price makes it public
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .modify_limit_order(5u64, 1u64, 50u64, 100u64, 3_600u64)
+        .run();
+    assert_eq!(order_book(&mut world, 10), vec![5, 4, 1, 2]);
+}
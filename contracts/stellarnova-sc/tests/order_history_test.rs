@@ -0,0 +1,88 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+
+const DAY: u64 = 24 * 60 * 60;
+const CREATED_AT: [u64; 4] = [1_000, 50_000, DAY + 10, 3 * DAY];
+
+/// USER creates orders 1-4 (1,000 USDC each) at `CREATED_AT`: two on day 0,
+/// one on day 1 and one on day 3
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64);
+    world.current_block().block_epoch(10);
+
+    deploy_with_pair(&mut world);
+
+    for created_at in CREATED_AT {
+        world.current_block().block_timestamp_seconds(created_at);
+        create_order(&mut world, 1_000, 36_000);
+    }
+
+    world
+}
+
+fn orders_between(
+    world: &mut ScenarioWorld,
+    start_ts: u64,
+    end_ts: u64,
+    from_index: u64,
+    size: u64,
+) -> Vec<u64> {
+    let orders = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_orders_created_between(start_ts, end_ts, from_index, size)
+        .returns(ReturnsResult)
+        .run();
+    orders.into_iter().map(|order| order.order_id).collect()
+}
+
+#[test]
+fn orders_are_filtered_by_creation_time() {
+    let mut world = setup();
+
+    assert_eq!(orders_between(&mut world, 0, 4 * DAY, 0, 10), vec![1, 2, 3, 4]);
+    assert_eq!(orders_between(&mut world, 0, DAY, 0, 10), vec![1, 2]);
+    // Bounds are start inclusive, end exclusive, also inside a day
+    assert_eq!(orders_between(&mut world, 50_000, DAY + 10, 0, 10), vec![2]);
+    assert_eq!(orders_between(&mut world, DAY + 10, 10 * DAY, 0, 10), vec![3, 4]);
+    assert_eq!(orders_between(&mut world, 2 * DAY, 3 * DAY, 0, 10), Vec::<u64>::new());
+    assert_eq!(orders_between(&mut world, DAY, DAY, 0, 10), Vec::<u64>::new());
+}
+
+#[test]
+fn order_history_is_paginated() {
+    let mut world = setup();
+
+    assert_eq!(orders_between(&mut world, 0, 4 * DAY, 0, 3), vec![1, 2, 3]);
+    assert_eq!(orders_between(&mut world, 0, 4 * DAY, 3, 3), vec![4]);
+    assert_eq!(orders_between(&mut world, 0, 4 * DAY, 1, 2), vec![2, 3]);
+    assert_eq!(orders_between(&mut world, 0, 4 * DAY, 4, 3), Vec::<u64>::new());
+    assert_eq!(orders_between(&mut world, 0, u64::MAX, 0, 0), Vec::<u64>::new());
+}
+
+#[test]
+fn closed_orders_stay_in_the_history() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(2u64)
+        .run();
+
+    assert_eq!(orders_between(&mut world, 0, u64::MAX, 0, 10), vec![1, 2, 3, 4]);
+}
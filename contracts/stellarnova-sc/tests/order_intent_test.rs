@@ -0,0 +1,142 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{OrderIntent, StellarNovaProxy};
+
+const MODEL_VERSION: [u8; 32] = [9u8; 32];
+
+/// USER registered strategy 1
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 2_000_000u64);
+
+    deploy(&mut world);
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .register_strategy(ManagedBuffer::from("strategy"))
+        .run();
+
+    world
+}
+
+fn intent(confidence_bps: u64) -> OrderIntent<StaticApi> {
+    OrderIntent {
+        strategy_id: 1,
+        confidence_bps,
+        model_version: ManagedByteArray::new_from_bytes(&MODEL_VERSION),
+    }
+}
+
+fn intent_of(world: &mut ScenarioWorld, order_id: u64) -> Option<OrderIntent<StaticApi>> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order_intent(order_id)
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+}
+
+#[test]
+fn intent_is_stored_and_tags_the_strategy() {
+    let mut world = setup();
+
+    let order_id = world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order_with_intent(
+            WEGLD,
+            1u64,
+            30u64,
+            100u64,
+            3_600u64,
+            intent(7_500),
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(1_000_000u64))
+        .returns(ReturnsResult)
+        .run();
+
+    let stored = intent_of(&mut world, order_id).unwrap();
+    assert_eq!(stored.strategy_id, 1);
+    assert_eq!(stored.confidence_bps, 7_500);
+    assert_eq!(stored.model_version.to_byte_array(), MODEL_VERSION);
+
+    let strategy_id = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .order_strategy(order_id)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(strategy_id, 1u64);
+
+    // Replaced orders keep the intent they were placed with
+    let new_order_id = world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .replace_limit_order(order_id, WEGLD, 1u64, 25u64, 100u64, 3_600u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(intent_of(&mut world, new_order_id).unwrap().confidence_bps, 7_500);
+}
+
+#[test]
+fn orders_without_intent_have_none() {
+    let mut world = setup();
+
+    let order_id = world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order(
+            WEGLD,
+            1u64,
+            30u64,
+            100u64,
+            3_600u64,
+            OptionalValue::<u64>::None,
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(1_000_000u64))
+        .returns(ReturnsResult)
+        .run();
+    assert!(intent_of(&mut world, order_id).is_none());
+}
+
+#[test]
+fn confidence_is_capped_at_10000_bps() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order_with_intent(
+            WEGLD,
+            1u64,
+            30u64,
+            100u64,
+            3_600u64,
+            intent(10_001),
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(1_000_000u64))
+        .returns(ExpectError(4, "E412: Intent confidence cannot exceed 10000 bps"))
+        .run();
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{OrderStatus, StellarNovaProxy};
+
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64);
+
+    deploy(&mut world);
+
+    world
+}
+
+fn first_active_order_id(world: &mut ScenarioWorld) -> u64 {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_first_active_order_id()
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn order_ids_in_range(world: &mut ScenarioWorld, from_id: u64, to_id: u64) -> Vec<u64> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_orders_range(from_id, to_id)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|order| order.order_id)
+        .collect()
+}
+
+#[test]
+fn empty_contract_has_no_orders() {
+    let mut world = setup();
+    let count = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order_count()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(count, 0u64);
+    assert_eq!(first_active_order_id(&mut world), 0);
+    assert!(order_ids_in_range(&mut world, 0, 10).is_empty());
+}
+
+#[test]
+fn range_covers_settled_and_pending_orders() {
+    let mut world = setup();
+    for _ in 0..3 {
+        create_order(&mut world, 1_000_000, 3_600);
+    }
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(1u64)
+        .run();
+
+    let last_order_id = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_last_order_id()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(last_order_id, 3u64);
+    assert_eq!(first_active_order_id(&mut world), 2);
+
+    // Bounds past the last order are clamped
+    assert_eq!(order_ids_in_range(&mut world, 0, 100), vec![1, 2, 3]);
+    assert_eq!(order_ids_in_range(&mut world, 2, 2), vec![2]);
+    assert!(order_ids_in_range(&mut world, 3, 2).is_empty());
+
+    let cancelled = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_orders_range(1u64, 1u64)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .next()
+        .unwrap();
+    assert!(cancelled.status == OrderStatus::Cancelled);
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+
+const OTHER_USER: TestAddress = TestAddress::new("other-user");
+
+const REFERENCE: u64 = 42;
+
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    for user in [USER, OTHER_USER] {
+        world
+            .account(user)
+            .nonce(1)
+            .esdt_balance(USDC, 2_000_000u64);
+    }
+
+    deploy(&mut world);
+
+    world
+}
+
+fn create_with_reference(
+    world: &mut ScenarioWorld,
+    user: TestAddress,
+    reference: u64,
+    expected_error: Option<&str>,
+) -> u64 {
+    let tx = world
+        .tx()
+        .from(user)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order_with_reference(
+            reference,
+            WEGLD,
+            1u64,
+            30u64,
+            100u64,
+            3_600u64,
+            OptionalValue::<u64>::None,
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(1_000_000u64));
+    match expected_error {
+        Some(message) => {
+            tx.returns(ExpectError(4, message)).run();
+            0
+        }
+        None => tx.returns(ReturnsResult).run(),
+    }
+}
+
+fn order_by_reference(world: &mut ScenarioWorld, user: TestAddress, reference: u64) -> Option<u64> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order_by_reference(user, reference)
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+}
+
+#[test]
+fn retried_creation_is_rejected() {
+    let mut world = setup();
+    let order_id = create_with_reference(&mut world, USER, REFERENCE, None);
+    assert_eq!(order_by_reference(&mut world, USER, REFERENCE), Some(order_id));
+
+    create_with_reference(
+        &mut world,
+        USER,
+        REFERENCE,
+        Some("E419: Order reference already used by this user"),
+    );
+    // The retry kept its payment
+    world.check_account(USER).esdt_balance(USDC, 1_000_000u64);
+
+    // Still used once the order is gone
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(order_id)
+        .run();
+    create_with_reference(
+        &mut world,
+        USER,
+        REFERENCE,
+        Some("E419: Order reference already used by this user"),
+    );
+}
+
+#[test]
+fn references_are_scoped_per_user() {
+    let mut world = setup();
+    let first = create_with_reference(&mut world, USER, REFERENCE, None);
+    let second = create_with_reference(&mut world, OTHER_USER, REFERENCE, None);
+
+    assert_ne!(first, second);
+    assert_eq!(order_by_reference(&mut world, OTHER_USER, REFERENCE), Some(second));
+    assert_eq!(order_by_reference(&mut world, USER, REFERENCE + 1), None);
+}
@@ -0,0 +1,91 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+
+const CLIENT_TAG: [u8; 32] = [7u8; 32];
+
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 2_000_000u64);
+
+    deploy(&mut world);
+
+    world
+}
+
+fn create_tagged_order(
+    world: &mut ScenarioWorld,
+    client_tag: OptionalValue<ManagedByteArray<StaticApi, 32>>,
+) -> u64 {
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order(
+            WEGLD,
+            1u64,
+            30u64,
+            100u64,
+            3_600u64,
+            OptionalValue::Some(0u64),
+            client_tag,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(1_000_000u64))
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn client_tag_of(world: &mut ScenarioWorld, order_id: u64) -> Option<[u8; 32]> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order_client_tag(order_id)
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .map(|tag| tag.to_byte_array())
+}
+
+#[test]
+fn client_tag_is_stored_at_creation() {
+    let mut world = setup();
+
+    let tagged = create_tagged_order(
+        &mut world,
+        OptionalValue::Some(ManagedByteArray::new_from_bytes(&CLIENT_TAG)),
+    );
+    let untagged = create_tagged_order(&mut world, OptionalValue::None);
+
+    assert_eq!(client_tag_of(&mut world, tagged), Some(CLIENT_TAG));
+    assert_eq!(client_tag_of(&mut world, untagged), None);
+}
+
+#[test]
+fn client_tag_survives_replace() {
+    let mut world = setup();
+
+    let order_id = create_tagged_order(
+        &mut world,
+        OptionalValue::Some(ManagedByteArray::new_from_bytes(&CLIENT_TAG)),
+    );
+
+    let new_order_id = world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .replace_limit_order(order_id, WEGLD, 1u64, 25u64, 100u64, 3_600u64)
+        .returns(ReturnsResult)
+        .run();
+
+    assert_eq!(client_tag_of(&mut world, new_order_id), Some(CLIENT_TAG));
+}
@@ -0,0 +1,93 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+
+const DESTINATION: TestSCAddress = TestSCAddress::new("destination");
+
+/// USER buys WEGLD at 30 USDC with 3,000,000 USDC (order 1); a second
+/// mock pair stands in for the destination protocol, its `addReserves`
+/// for the deposit
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64);
+    world.current_block().block_timestamp_seconds(1_000);
+
+    deploy_with_pair(&mut world);
+    deploy_mock_pair(&mut world, DESTINATION);
+
+    create_order(&mut world, 3_000_000, 36_000);
+
+    world
+}
+
+fn allow_destination(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_forward_destination(DESTINATION, true)
+        .run();
+}
+
+fn set_output_forward(world: &mut ScenarioWorld, function: &str, expected_error: Option<&str>) {
+    let tx = world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_output_forward(
+            1u64,
+            DESTINATION,
+            ManagedBuffer::from(function),
+            MultiValueVec::<ManagedBuffer<StaticApi>>::new(),
+        );
+    match expected_error {
+        Some(message) => tx.returns(ExpectError(4, message)).run(),
+        None => tx.run(),
+    }
+}
+
+#[test]
+fn output_is_deposited_into_the_destination() {
+    let mut world = setup();
+    set_output_forward(
+        &mut world,
+        "addReserves",
+        Some("E417: Forward destination is not whitelisted"),
+    );
+
+    allow_destination(&mut world);
+    set_output_forward(
+        &mut world,
+        "",
+        Some("E418: Forward call needs a function and at most 8 arguments"),
+    );
+    set_output_forward(&mut world, "addReserves", None);
+    execute(&mut world, 1);
+
+    world.check_account(USER).esdt_balance(WEGLD, 0u64);
+    world.check_account(DESTINATION).esdt_balance(WEGLD, 99_900u64);
+}
+
+#[test]
+fn failed_forward_pays_the_owner() {
+    let mut world = setup();
+    allow_destination(&mut world);
+    set_output_forward(&mut world, "stake", None);
+    execute(&mut world, 1);
+
+    world.check_account(USER).esdt_balance(WEGLD, 99_900u64);
+    world.check_account(DESTINATION).esdt_balance(WEGLD, 0u64);
+    world.check_account(SC).esdt_balance(WEGLD, 0u64);
+}
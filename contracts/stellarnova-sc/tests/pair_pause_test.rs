@@ -0,0 +1,116 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{OrderStatus, ReasonCode, StellarNovaProxy};
+
+/// USER has orders 1 and 2, each selling 3,000,000 USDC for WEGLD
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 6_000_000u64);
+
+    deploy_with_pair(&mut world);
+
+    for _ in 0..2 {
+        create_order(&mut world, 3_000_000, 36_000);
+    }
+
+    world
+}
+
+fn set_pair_paused(world: &mut ScenarioWorld, paused: bool) {
+    // Given in the opposite direction of the orders: the pause covers both
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_pair_paused(WEGLD, USDC, paused)
+        .run();
+}
+
+#[test]
+fn paused_pair_blocks_execution_but_not_cancellation() {
+    let mut world = setup();
+    set_pair_paused(&mut world, true);
+
+    let paused = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .pair_paused(USDC, WEGLD)
+        .returns(ReturnsResult)
+        .run();
+    assert!(paused);
+    let reason = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .can_execute_order(1u64, 1u64, 30u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(reason, ReasonCode::PairPaused);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .returns(ExpectError(4, "E207: Executions on this pair are paused"))
+        .run();
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(2u64)
+        .run();
+    world.check_account(USER).esdt_balance(USDC, 3_000_000u64);
+
+    set_pair_paused(&mut world, false);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+    let order = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert!(order.status == OrderStatus::Executed);
+}
+
+#[test]
+fn only_owner_pauses_a_pair() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_pair_paused(WEGLD, USDC, true)
+        .returns(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_pair_paused(WEGLD, WEGLD, true)
+        .returns(ExpectError(4, "E306: Cannot swap token to itself"))
+        .run();
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{PauseLevel, StellarNovaProxy};
+
+/// USER has order 1 selling 3,000,000 USDC for WEGLD, expiring at 4,600
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64);
+    world.current_block().block_timestamp_seconds(1_000);
+
+    deploy_mock_pair(&mut world, PAIR);
+    deploy(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_token_pair(WEGLD, PAIR)
+        .run();
+
+    create_order(&mut world, 3_000_000, 3_600);
+
+    world
+}
+
+fn set_pause_level(world: &mut ScenarioWorld, level: PauseLevel) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_pause_level(level)
+        .run();
+}
+
+fn cancel(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(1u64)
+        .run();
+}
+
+#[test]
+fn every_pause_level_allows_cancellation() {
+    for level in [
+        PauseLevel::CreationPaused,
+        PauseLevel::ExecutionPaused,
+        PauseLevel::FullStop,
+    ] {
+        let mut world = setup();
+        set_pause_level(&mut world, level);
+        cancel(&mut world);
+        world.check_account(USER).esdt_balance(USDC, 3_000_000u64);
+    }
+}
+
+#[test]
+fn paused_pair_allows_cancellation() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_pair_paused(USDC, WEGLD, true)
+        .run();
+    cancel(&mut world);
+    world.check_account(USER).esdt_balance(USDC, 3_000_000u64);
+}
+
+#[test]
+fn expiry_refunds_run_during_a_full_stop() {
+    let mut world = setup();
+    set_pause_level(&mut world, PauseLevel::FullStop);
+
+    world.current_block().block_timestamp_seconds(4_601);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .process_expired_orders(10u64)
+        .run();
+    world.check_account(USER).esdt_balance(USDC, 3_000_000u64);
+}
+
+#[test]
+fn cancellation_window_is_waived_while_execution_is_halted() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_cancellation_policy(3_600u64, 0u64)
+        .run();
+
+    // Orders still executing keep the anti-spoofing window
+    set_pause_level(&mut world, PauseLevel::CreationPaused);
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(1u64)
+        .returns(ExpectError(
+            4,
+            "E512: Order cannot be cancelled this soon after creation",
+        ))
+        .run();
+
+    set_pause_level(&mut world, PauseLevel::ExecutionPaused);
+    cancel(&mut world);
+    world.check_account(USER).esdt_balance(USDC, 3_000_000u64);
+}
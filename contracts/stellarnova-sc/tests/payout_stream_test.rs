@@ -0,0 +1,176 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use mock_pair::FailureMode;
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+use stellarnova_sc::streams::MAX_PAYOUT_STREAM_SECONDS;
+
+const ORDER_AMOUNT: u64 = 3_000_000;
+const STREAM_SECONDS: u64 = 1_000;
+const START: u64 = 1_000;
+
+/// USER's order 1 sells 3,000,000 USDC for WEGLD on the mock pair, its
+/// output streamed over `STREAM_SECONDS`; the clock is at `START`
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, PAIR_RESERVE);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, ORDER_AMOUNT);
+    world.current_block().block_timestamp_seconds(START);
+
+    deploy_with_pair(&mut world);
+    create_order(&mut world, ORDER_AMOUNT, 36_000);
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_payout_stream(1u64, STREAM_SECONDS)
+        .run();
+
+    world
+}
+
+fn claimable(world: &mut ScenarioWorld) -> BigUint<StaticApi> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_claimable_stream(1u64)
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn claim(world: &mut ScenarioWorld) -> BigUint<StaticApi> {
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .claim_payout_stream(1u64)
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn streamed_balance(world: &mut ScenarioWorld) -> BigUint<StaticApi> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .streamed_balance(WEGLD)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn output_vests_linearly_and_is_claimed_in_parts() {
+    let mut world = setup();
+
+    // 100,000 WEGLD out, 0.1% execution fee to the executor, the rest locked
+    execute(&mut world, 1);
+    world.check_account(USER).esdt_balance(WEGLD, 0u64);
+    world.check_account(SC).esdt_balance(WEGLD, 99_900u64);
+    assert_eq!(claimable(&mut world), BigUint::zero());
+    assert_eq!(streamed_balance(&mut world), BigUint::from(99_900u64));
+
+    world.current_block().block_timestamp_seconds(START + 250);
+    assert_eq!(claim(&mut world), BigUint::from(24_975u64));
+    world.check_account(USER).esdt_balance(WEGLD, 24_975u64);
+    assert_eq!(streamed_balance(&mut world), BigUint::from(74_925u64));
+
+    // 250 of the remaining 750 seconds vest a third of the rest
+    world.current_block().block_timestamp_seconds(START + 500);
+    assert_eq!(claimable(&mut world), BigUint::from(24_975u64));
+    assert_eq!(claim(&mut world), BigUint::from(24_975u64));
+    assert_eq!(claim(&mut world), BigUint::zero());
+    assert_eq!(streamed_balance(&mut world), BigUint::from(49_950u64));
+
+    // Past the end everything is claimable and the stream is closed
+    world.current_block().block_timestamp_seconds(START + 2 * STREAM_SECONDS);
+    assert_eq!(claim(&mut world), BigUint::from(49_950u64));
+    world.check_account(USER).esdt_balance(WEGLD, 99_900u64);
+    world.check_account(SC).esdt_balance(WEGLD, 0u64);
+    assert_eq!(streamed_balance(&mut world), BigUint::zero());
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .claim_payout_stream(1u64)
+        .returns(ExpectError(4, "E523: No payout stream for this order"))
+        .run();
+}
+
+#[test]
+fn new_fill_restarts_the_schedule_for_the_locked_part() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(PAIR)
+        .raw_call("setFailureMode")
+        .argument(&FailureMode::PartialInput)
+        .run();
+    execute(&mut world, 1);
+    world
+        .tx()
+        .from(OWNER)
+        .to(PAIR)
+        .raw_call("setFailureMode")
+        .argument(&FailureMode::None)
+        .run();
+
+    // Half of the first 49,950 has vested when the second fill comes in
+    world.current_block().block_timestamp_seconds(START + 500);
+    execute(&mut world, 1);
+    assert_eq!(claimable(&mut world), BigUint::from(24_975u64));
+    assert_eq!(streamed_balance(&mut world), BigUint::from(99_900u64));
+
+    // The other 74,925 vest from the second fill on
+    world.current_block().block_timestamp_seconds(START + 1_000);
+    assert_eq!(claimable(&mut world), BigUint::from(24_975u64 + 37_462));
+    world.current_block().block_timestamp_seconds(START + 1_500);
+    assert_eq!(claim(&mut world), BigUint::from(99_900u64));
+    assert_eq!(streamed_balance(&mut world), BigUint::zero());
+}
+
+#[test]
+fn only_the_owner_sets_and_claims_the_stream() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_payout_stream(1u64, MAX_PAYOUT_STREAM_SECONDS + 1)
+        .returns(ExpectError(4, "E406: Payout stream duration too long"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_payout_stream(1u64, 0u64)
+        .returns(ExpectError(4, "E102: Not your order"))
+        .run();
+
+    execute(&mut world, 1);
+    world.current_block().block_timestamp_seconds(START + STREAM_SECONDS);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .claim_payout_stream(1u64)
+        .returns(ExpectError(4, "E102: Not your order"))
+        .run();
+    assert_eq!(claim(&mut world), BigUint::from(99_900u64));
+}
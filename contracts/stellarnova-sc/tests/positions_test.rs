@@ -0,0 +1,222 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use mock_pair::FailureMode;
+use stellarnova_sc::positions::PositionsModule;
+use stellarnova_sc::stellarnova_proxy::{OrderStatus, StellarNovaProxy};
+
+const HOLDER: TestAddress = TestAddress::new("holder");
+const POSITION: TestTokenIdentifier = TestTokenIdentifier::new("NOVAPOS-123456");
+
+const ORDER_AMOUNT: u64 = 3_000_000;
+
+/// USER's tokenized order 1 sells 3,000,000 USDC for WEGLD on the mock
+/// pair; its position NFT (nonce 1) was sent on to HOLDER
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, PAIR_RESERVE);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, ORDER_AMOUNT);
+    world.account(HOLDER).nonce(1);
+
+    deploy_with_pair(&mut world);
+    world.set_esdt_local_roles(
+        &SC.to_address(),
+        POSITION.as_bytes(),
+        &[EsdtLocalRole::NftCreate, EsdtLocalRole::NftBurn],
+    );
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_position_token(POSITION)
+        .run();
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order_with_position(
+            WEGLD,
+            1u64,
+            30u64,
+            100u64,
+            3_600u64,
+            OptionalValue::<u64>::None,
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(ORDER_AMOUNT))
+        .run();
+    world
+        .tx()
+        .from(USER)
+        .to(HOLDER)
+        .esdt(TestEsdtTransfer(POSITION, 1, 1u64))
+        .run();
+
+    world
+}
+
+fn position_balance(world: &mut ScenarioWorld, token: TestTokenIdentifier) -> BigUint<StaticApi> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .position_balance(1u64, token)
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn claim(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(HOLDER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .claim_position()
+        .esdt(TestEsdtTransfer(POSITION, 1, 1u64))
+        .run();
+}
+
+fn cancel(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(HOLDER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_with_position()
+        .esdt(TestEsdtTransfer(POSITION, 1, 1u64))
+        .run();
+}
+
+fn is_position_burned(world: &mut ScenarioWorld) -> bool {
+    let mut burned = false;
+    world
+        .query()
+        .to(SC)
+        .whitebox(stellarnova_sc::contract_obj, |sc| {
+            burned = sc.order_position(1).is_empty() && sc.position_order(1).is_empty();
+        });
+    burned
+}
+
+#[test]
+fn creator_no_longer_controls_a_transferred_order() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(1u64)
+        .returns(ExpectError(4, "E113: Order is controlled by its position token"))
+        .run();
+    world
+        .tx()
+        .from(HOLDER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(1u64)
+        .returns(ExpectError(4, "E113: Order is controlled by its position token"))
+        .run();
+
+    assert!(order(&mut world, 1).status == OrderStatus::Pending);
+    world.check_account(SC).esdt_balance(USDC, ORDER_AMOUNT);
+}
+
+#[test]
+fn holder_modifies_and_keeps_the_position() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(HOLDER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .modify_with_position(1u64, 25u64, 200u64, 7_200u64)
+        .esdt(TestEsdtTransfer(POSITION, 1, 1u64))
+        .run();
+
+    let modified = order(&mut world, 1);
+    assert_eq!(modified.user, USER.to_managed_address());
+    assert_eq!(modified.target_price_denominator, BigUint::from(25u64));
+    assert_eq!(modified.slippage_bp, 200);
+    assert!(!is_position_burned(&mut world));
+
+    // The NFT came back, so the holder can still cancel
+    cancel(&mut world);
+    assert!(order(&mut world, 1).status == OrderStatus::Cancelled);
+}
+
+#[test]
+fn holder_cancels_and_receives_the_refund() {
+    let mut world = setup();
+
+    cancel(&mut world);
+
+    assert!(order(&mut world, 1).status == OrderStatus::Cancelled);
+    world.check_account(HOLDER).esdt_balance(USDC, ORDER_AMOUNT);
+    world.check_account(USER).esdt_balance(USDC, 0u64);
+    world.check_account(SC).esdt_balance(USDC, 0u64);
+    assert!(is_position_burned(&mut world));
+}
+
+#[test]
+fn execution_output_is_held_for_the_holder() {
+    let mut world = setup();
+
+    execute(&mut world, 1);
+
+    // 100,000 WEGLD out, 0.1% execution fee to the executor (owner)
+    assert!(order(&mut world, 1).status == OrderStatus::Executed);
+    assert_eq!(position_balance(&mut world, WEGLD), BigUint::from(99_900u64));
+    world.check_account(USER).esdt_balance(WEGLD, 0u64);
+    world.check_account(HOLDER).esdt_balance(WEGLD, 0u64);
+    world.check_account(SC).esdt_balance(WEGLD, 99_900u64);
+
+    claim(&mut world);
+
+    world.check_account(HOLDER).esdt_balance(WEGLD, 99_900u64);
+    world.check_account(SC).esdt_balance(WEGLD, 0u64);
+    assert_eq!(position_balance(&mut world, WEGLD), BigUint::zero());
+    assert!(is_position_burned(&mut world));
+}
+
+#[test]
+fn partial_fill_is_claimed_while_the_order_stays_open() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(PAIR)
+        .raw_call("setFailureMode")
+        .argument(&FailureMode::PartialInput)
+        .run();
+
+    execute(&mut world, 1);
+    claim(&mut world);
+
+    // Half filled: the output is paid and the NFT handed back
+    assert!(order(&mut world, 1).status == OrderStatus::Pending);
+    world.check_account(HOLDER).esdt_balance(WEGLD, 49_950u64);
+    assert!(!is_position_burned(&mut world));
+
+    cancel(&mut world);
+
+    world
+        .check_account(HOLDER)
+        .esdt_balance(USDC, ORDER_AMOUNT / 2)
+        .esdt_balance(WEGLD, 49_950u64);
+    world.check_account(USER).esdt_balance(USDC, 0u64);
+    assert!(is_position_burned(&mut world));
+}
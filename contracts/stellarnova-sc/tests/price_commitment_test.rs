@@ -0,0 +1,254 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use mock_pair::FailureMode;
+use stellarnova_sc::stellarnova_proxy::{OrderStatus, ReasonCode, StellarNovaProxy};
+
+const SALT: [u8; 32] = [9u8; 32];
+
+/// Pair price 30 USDC per WEGLD
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64);
+
+    deploy_with_pair(&mut world);
+
+    world
+}
+
+fn commitment(
+    world: &mut ScenarioWorld,
+    price_num: u64,
+    price_denom: u64,
+    salt: [u8; 32],
+) -> ManagedByteArray<StaticApi, 32> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .compute_price_commitment(price_num, price_denom, ManagedByteArray::new_from_bytes(&salt))
+        .returns(ReturnsResult)
+        .run()
+}
+
+/// Sell 3,000,000 USDC for WEGLD at a hidden 1 WEGLD per 30 USDC
+fn create_committed_order(world: &mut ScenarioWorld) -> u64 {
+    let price_commitment = commitment(world, 1, 30, SALT);
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_committed_order(WEGLD, price_commitment, 100u64, 36_000u64)
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(3_000_000u64))
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn execute_committed(world: &mut ScenarioWorld, order_id: u64, price_denom: u64, salt: [u8; 32]) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_committed_order(
+            order_id,
+            1u64,
+            price_denom,
+            ManagedByteArray::new_from_bytes(&salt),
+            1u64,
+            30u64,
+        )
+        .run();
+}
+
+fn order_book(world: &mut ScenarioWorld) -> Vec<u64> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order_book(USDC, WEGLD, 10usize)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|order| order.order_id)
+        .collect()
+}
+
+#[test]
+fn committed_price_is_hidden_until_revealed_at_execution() {
+    let mut world = setup();
+    let order_id = create_committed_order(&mut world);
+
+    let order = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order(order_id)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(order.target_price_numerator, BigUint::from(1u64));
+    assert_eq!(order.target_price_denominator, BigUint::from(1u64));
+
+    let reason = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .can_execute_order(order_id, 1u64, 30u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(reason, ReasonCode::PriceNotRevealed);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(order_id, 1u64, 30u64)
+        .returns(ExpectError(
+            4,
+            "E532: Order price is committed, reveal it with executeCommittedOrder",
+        ))
+        .run();
+
+    execute_committed(&mut world, order_id, 30, SALT);
+
+    let order = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order(order_id)
+        .returns(ReturnsResult)
+        .run();
+    assert!(order.status == OrderStatus::Executed);
+    assert_eq!(order.target_price_denominator, BigUint::from(30u64));
+    world.check_account(USER).esdt_balance(WEGLD, 99_900u64);
+}
+
+#[test]
+fn wrong_preimage_is_rejected() {
+    let mut world = setup();
+    let order_id = create_committed_order(&mut world);
+
+    for (price_denom, salt) in [(30u64, [8u8; 32]), (31u64, SALT)] {
+        world
+            .tx()
+            .from(OWNER)
+            .to(SC)
+            .typed(StellarNovaProxy)
+            .execute_committed_order(
+                order_id,
+                1u64,
+                price_denom,
+                ManagedByteArray::new_from_bytes(&salt),
+                1u64,
+                30u64,
+            )
+            .returns(ExpectError(4, "E534: Revealed price does not match the commitment"))
+            .run();
+    }
+}
+
+#[test]
+fn expired_order_is_refunded_without_revealing() {
+    let mut world = setup();
+    let order_id = create_committed_order(&mut world);
+    world.current_block().block_timestamp_seconds(36_001);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_committed_order(
+            order_id,
+            1u64,
+            30u64,
+            ManagedByteArray::new_from_bytes(&SALT),
+            1u64,
+            30u64,
+        )
+        .returns(ExpectError(4, "E503: Order expired"))
+        .run();
+
+    // Expiry goes through executeLimitOrder and leaves the commitment closed
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(order_id, 1u64, 30u64)
+        .run();
+    let order = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order(order_id)
+        .returns(ReturnsResult)
+        .run();
+    assert!(order.status == OrderStatus::Expired);
+    assert_eq!(order.target_price_denominator, BigUint::from(1u64));
+    world.check_account(USER).esdt_balance(USDC, 3_000_000u64);
+}
+
+#[test]
+fn only_the_executor_can_reveal() {
+    let mut world = setup();
+    let order_id = create_committed_order(&mut world);
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_committed_order(
+            order_id,
+            1u64,
+            30u64,
+            ManagedByteArray::new_from_bytes(&SALT),
+            1u64,
+            30u64,
+        )
+        .returns(ExpectError(4, "E101: Only executor can execute orders"))
+        .run();
+
+    let reason = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .can_execute_order(order_id, 1u64, 30u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(reason, ReasonCode::PriceNotRevealed);
+}
+
+#[test]
+fn committed_order_is_booked_once_revealed() {
+    let mut world = setup();
+    let order_id = create_committed_order(&mut world);
+
+    // The 1/1 placeholder is no price level
+    assert!(order_book(&mut world).is_empty());
+
+    // A partial fill reveals the price and leaves the rest pending
+    world
+        .tx()
+        .from(OWNER)
+        .to(PAIR)
+        .raw_call("setFailureMode")
+        .argument(&FailureMode::PartialInput)
+        .run();
+    execute_committed(&mut world, order_id, 30, SALT);
+    let revealed = order(&mut world, order_id);
+    assert!(revealed.status == OrderStatus::Pending);
+    assert_eq!(revealed.target_price_denominator, BigUint::from(30u64));
+    assert_eq!(order_book(&mut world), vec![order_id]);
+}
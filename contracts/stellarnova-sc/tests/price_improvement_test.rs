@@ -0,0 +1,106 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+
+/// Pair price 30 USDC per WEGLD, 10 bps execution fee (the owner executes);
+/// the executor gets 20% and the treasury 10% of price improvements
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64);
+
+    deploy_with_pair(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_price_improvement_sharing(2_000u64, 1_000u64)
+        .run();
+
+    world
+}
+
+/// Sell 3,000,000 USDC for WEGLD with a target of 1 WEGLD per
+/// `target_denom` USDC and execute it at the pair price
+fn create_and_execute(world: &mut ScenarioWorld, target_denom: u64) {
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order(
+            WEGLD,
+            1u64,
+            target_denom,
+            100u64,
+            36_000u64,
+            OptionalValue::<u64>::None,
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(3_000_000u64))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+}
+
+fn protocol_fees(world: &mut ScenarioWorld) -> BigUint<StaticApi> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .protocol_fees(WEGLD)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn improvement_is_shared() {
+    let mut world = setup();
+
+    // Target promises 75,000 WEGLD, the swap gives 100,000: 25,000 surplus
+    create_and_execute(&mut world, 40);
+    world.check_account(OWNER).esdt_balance(WEGLD, 5_100u64);
+    world.check_account(USER).esdt_balance(WEGLD, 92_400u64);
+    assert_eq!(protocol_fees(&mut world), BigUint::from(2_500u64));
+}
+
+#[test]
+fn fills_at_target_are_not_shared() {
+    let mut world = setup();
+
+    create_and_execute(&mut world, 30);
+    world.check_account(OWNER).esdt_balance(WEGLD, 100u64);
+    world.check_account(USER).esdt_balance(WEGLD, 99_900u64);
+    assert_eq!(protocol_fees(&mut world), BigUint::zero());
+}
+
+#[test]
+fn shares_cannot_exceed_the_improvement() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_price_improvement_sharing(6_000u64, 5_000u64)
+        .returns(ExpectError(
+            4,
+            "E630: Price improvement shares cannot exceed 10000 bps",
+        ))
+        .run();
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{OrderStatus, StellarNovaProxy};
+
+#[test]
+fn typed_proxy_round_trip() {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64);
+
+    deploy(&mut world);
+
+    let order_id = world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order(
+            WEGLD,
+            1u64,
+            30u64,
+            100u64,
+            3_600u64,
+            OptionalValue::<u64>::None,
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(3_000_000u64))
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(order_id, 1);
+
+    let pending: Vec<_> = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_pending_orders()
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect();
+    assert_eq!(pending.len(), 1);
+
+    let order = &pending[0];
+    assert_eq!(order.order_id, order_id);
+    assert_eq!(order.user, USER.to_managed_address());
+    assert_eq!(order.from_amount, 3_000_000u64);
+    assert_eq!(order.target_price_denominator, 30u64);
+    assert!(order.status == OrderStatus::Pending);
+}
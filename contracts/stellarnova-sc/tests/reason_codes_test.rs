@@ -0,0 +1,215 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{OrderStatus, PauseLevel, ReasonCode, StellarNovaProxy};
+
+const ORDER_AMOUNT: u64 = 3_000_000;
+
+/// Deploy with a 5% max slippage and create one order selling USDC for
+/// WEGLD at 1/30, expiring in an hour
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, ORDER_AMOUNT);
+    world.current_block().block_timestamp_seconds(1_000);
+
+    deploy(&mut world);
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order(
+            WEGLD,
+            1u64,
+            30u64,
+            100u64,
+            3_600u64,
+            OptionalValue::<u64>::None,
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(ORDER_AMOUNT))
+        .run();
+
+    world
+}
+
+fn can_execute(world: &mut ScenarioWorld, order_id: u64, price_num: u64, price_denom: u64) -> ReasonCode {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .can_execute_order(order_id, price_num, price_denom)
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn validate(
+    world: &mut ScenarioWorld,
+    from_token: TestTokenIdentifier,
+    to_token: TestTokenIdentifier,
+    price_num: u64,
+    slippage_bp: u64,
+) -> ReasonCode {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .validate_limit_order(
+            USER,
+            from_token,
+            1_000u64,
+            to_token,
+            price_num,
+            30u64,
+            slippage_bp,
+            3_600u64,
+        )
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn can_execute_reports_first_blocking_reason() {
+    let mut world = setup();
+
+    assert_eq!(can_execute(&mut world, 1, 1, 30), ReasonCode::Ok);
+    assert_eq!(can_execute(&mut world, 1, 1, 20), ReasonCode::PriceNotMet);
+    assert_eq!(can_execute(&mut world, 2, 1, 30), ReasonCode::OrderNotFound);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_pause_level(PauseLevel::ExecutionPaused)
+        .run();
+    assert_eq!(can_execute(&mut world, 1, 1, 30), ReasonCode::ExecutionPaused);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_pause_level(PauseLevel::Active)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .add_to_blacklist(USER)
+        .run();
+    assert_eq!(can_execute(&mut world, 1, 1, 30), ReasonCode::AddressBlacklisted);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .remove_from_blacklist(USER)
+        .run();
+    world.current_block().block_timestamp_seconds(1_000 + 3_601);
+    assert_eq!(can_execute(&mut world, 1, 1, 30), ReasonCode::OrderExpired);
+}
+
+#[test]
+fn reason_matches_endpoint_error() {
+    let mut world = setup();
+
+    let reason = can_execute(&mut world, 1, 1, 20);
+    assert_eq!(reason, ReasonCode::PriceNotMet);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 20u64)
+        .returns(ExpectError(4, "E504: Price condition not met"))
+        .run();
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(1u64)
+        .run();
+    assert_eq!(can_execute(&mut world, 1, 1, 30), ReasonCode::OrderNotPending);
+}
+
+#[test]
+fn try_execute_returns_reason_without_reverting() {
+    let mut world = setup();
+
+    let reason = world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .try_execute_limit_order(1u64, 1u64, 20u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(reason, ReasonCode::PriceNotMet);
+
+    let reason = world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .try_execute_limit_order(1u64, 1u64, 30u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(reason, ReasonCode::NotExecutor);
+
+    // Expired orders are still refunded, as by executeLimitOrder
+    world.current_block().block_timestamp_seconds(1_000 + 3_601);
+    let reason = world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .try_execute_limit_order(1u64, 1u64, 30u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(reason, ReasonCode::OrderExpired);
+
+    let order = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert!(order.status == OrderStatus::Expired);
+    world.check_account(USER).esdt_balance(USDC, ORDER_AMOUNT);
+}
+
+#[test]
+fn validate_limit_order_reports_creation_reason() {
+    let mut world = setup();
+
+    assert_eq!(validate(&mut world, USDC, WEGLD, 1, 100), ReasonCode::Ok);
+    assert_eq!(validate(&mut world, USDC, USDC, 1, 100), ReasonCode::SameToken);
+    assert_eq!(validate(&mut world, USDC, WEGLD, 0, 100), ReasonCode::PriceNumZero);
+    assert_eq!(validate(&mut world, USDC, WEGLD, 1, 501), ReasonCode::SlippageAboveMax);
+    assert_eq!(
+        validate(&mut world, USDC, WEGLD, 1, 10_001),
+        ReasonCode::SlippageAbove100Percent
+    );
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_pause_level(PauseLevel::CreationPaused)
+        .run();
+    assert_eq!(validate(&mut world, USDC, WEGLD, 1, 100), ReasonCode::CreationPaused);
+}
@@ -0,0 +1,192 @@
+mod common;
+
+use ed25519_dalek::{Signer, SigningKey};
+use multiversx_sc::codec::top_encode_to_vec_u8_or_panic;
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use multiversx_sc_scenario::scenario_model::TxResponseStatus;
+use stellarnova_sc::stellarnova_proxy::{RelayedOrder, StellarNovaProxy};
+
+const RELAYER: TestAddress = TestAddress::new("relayer");
+const OTHER_SC: TestSCAddress = TestSCAddress::new("other-stellarnova");
+
+const SIGNER_SEED: [u8; 32] = [1u8; 32];
+const OTHER_SEED: [u8; 32] = [2u8; 32];
+
+const CREDIT: u64 = 2_000_000;
+const ORDER_AMOUNT: u64 = 1_000_000;
+
+/// Wallet whose address is the public key of `seed`
+fn signer(seed: [u8; 32]) -> (SigningKey, Address) {
+    let key = SigningKey::from_bytes(&seed);
+    let address = Address::from(key.verifying_key().to_bytes());
+    (key, address)
+}
+
+/// The signer deposited `CREDIT` USDC; the relayer holds no tokens
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world.account(RELAYER).nonce(1);
+    let (_, address) = signer(SIGNER_SEED);
+    world
+        .account(&address)
+        .nonce(1)
+        .esdt_balance(USDC, CREDIT);
+
+    deploy(&mut world);
+    world
+        .tx()
+        .from(&address)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .deposit()
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(CREDIT))
+        .run();
+
+    world
+}
+
+/// `amount` USDC for at least 1 WEGLD per 30 USDC (1% slippage)
+fn relayed_order(amount: u64) -> RelayedOrder<StaticApi> {
+    RelayedOrder {
+        from_token: USDC.to_token_identifier(),
+        from_amount: BigUint::from(amount),
+        to_token: WEGLD.to_token_identifier(),
+        target_price_numerator: BigUint::from(1u64),
+        target_price_denominator: BigUint::from(30u64),
+        slippage_bp: 100,
+        expires_in_seconds: 3_600,
+    }
+}
+
+/// Signature of `key` over the top-encoded `RelayedOrderMessage`
+fn sign(
+    key: &SigningKey,
+    contract: TestSCAddress,
+    user: &Address,
+    nonce: u64,
+    order: &RelayedOrder<StaticApi>,
+) -> ManagedBuffer<StaticApi> {
+    let mut message = Vec::new();
+    message.extend_from_slice(contract.to_address().as_bytes());
+    message.extend_from_slice(user.as_bytes());
+    message.extend_from_slice(&nonce.to_be_bytes());
+    message.extend_from_slice(&top_encode_to_vec_u8_or_panic(order));
+    ManagedBuffer::new_from_bytes(&key.sign(&message).to_bytes())
+}
+
+fn relay(
+    world: &mut ScenarioWorld,
+    user: &Address,
+    order: RelayedOrder<StaticApi>,
+    nonce: u64,
+    signature: ManagedBuffer<StaticApi>,
+) -> Result<u64, TxResponseStatus> {
+    world
+        .tx()
+        .from(RELAYER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order_for(ManagedAddress::from(user), order, nonce, signature)
+        .returns(ReturnsHandledOrError::new().returns(ReturnsResult))
+        .run()
+}
+
+fn relay_nonce(world: &mut ScenarioWorld, user: &Address) -> u64 {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .relay_nonce(ManagedAddress::from(user))
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn user_credit(world: &mut ScenarioWorld, user: &Address) -> BigUint<StaticApi> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .user_credit(ManagedAddress::from(user), USDC)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn signed_order_is_created_from_credit() {
+    let mut world = setup();
+    let (key, user) = signer(SIGNER_SEED);
+
+    let order_to_sign = relayed_order(ORDER_AMOUNT);
+    let signature = sign(&key, SC, &user, 0, &order_to_sign);
+    let order_id = relay(&mut world, &user, order_to_sign, 0, signature).unwrap();
+
+    let created = order(&mut world, order_id);
+    assert_eq!(created.user, ManagedAddress::from(&user));
+    assert_eq!(created.from_amount, BigUint::from(ORDER_AMOUNT));
+    assert_eq!(relay_nonce(&mut world, &user), 1);
+    assert_eq!(user_credit(&mut world, &user), BigUint::from(CREDIT - ORDER_AMOUNT));
+    world.check_account(SC).esdt_balance(USDC, CREDIT);
+    world.check_account(RELAYER).esdt_balance(USDC, 0u64);
+}
+
+#[test]
+fn replayed_nonce_is_rejected() {
+    let mut world = setup();
+    let (key, user) = signer(SIGNER_SEED);
+
+    let signature = sign(&key, SC, &user, 0, &relayed_order(ORDER_AMOUNT));
+    relay(&mut world, &user, relayed_order(ORDER_AMOUNT), 0, signature.clone()).unwrap();
+
+    let replay = relay(&mut world, &user, relayed_order(ORDER_AMOUNT), 0, signature);
+    assert_eq!(replay.unwrap_err().message, "E112: Invalid relay nonce");
+    assert_eq!(relay_nonce(&mut world, &user), 1);
+    assert_eq!(user_credit(&mut world, &user), BigUint::from(CREDIT - ORDER_AMOUNT));
+}
+
+#[test]
+fn signature_of_another_user_is_rejected() {
+    let mut world = setup();
+    let (_, user) = signer(SIGNER_SEED);
+    let (other_key, _) = signer(OTHER_SEED);
+
+    let signature = sign(&other_key, SC, &user, 0, &relayed_order(ORDER_AMOUNT));
+    assert!(relay(&mut world, &user, relayed_order(ORDER_AMOUNT), 0, signature).is_err());
+    assert_eq!(relay_nonce(&mut world, &user), 0);
+    assert_eq!(user_credit(&mut world, &user), BigUint::from(CREDIT));
+}
+
+#[test]
+fn signature_for_another_contract_is_rejected() {
+    let mut world = setup();
+    let (key, user) = signer(SIGNER_SEED);
+
+    let signature = sign(&key, OTHER_SC, &user, 0, &relayed_order(ORDER_AMOUNT));
+    assert!(relay(&mut world, &user, relayed_order(ORDER_AMOUNT), 0, signature).is_err());
+    assert_eq!(relay_nonce(&mut world, &user), 0);
+    assert_eq!(user_credit(&mut world, &user), BigUint::from(CREDIT));
+}
+
+#[test]
+fn signed_terms_cannot_be_changed() {
+    let mut world = setup();
+    let (key, user) = signer(SIGNER_SEED);
+
+    let signature = sign(&key, SC, &user, 0, &relayed_order(ORDER_AMOUNT));
+    assert!(relay(&mut world, &user, relayed_order(CREDIT), 0, signature).is_err());
+    assert_eq!(user_credit(&mut world, &user), BigUint::from(CREDIT));
+}
+
+#[test]
+fn order_above_credit_is_rejected() {
+    let mut world = setup();
+    let (key, user) = signer(SIGNER_SEED);
+
+    let order_to_sign = relayed_order(CREDIT + 1);
+    let signature = sign(&key, SC, &user, 0, &order_to_sign);
+    let result = relay(&mut world, &user, order_to_sign, 0, signature);
+    assert_eq!(result.unwrap_err().message, "E310: Insufficient credit");
+    assert_eq!(relay_nonce(&mut world, &user), 0);
+}
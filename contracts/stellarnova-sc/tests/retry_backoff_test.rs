@@ -0,0 +1,113 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::limit_orders::LimitOrdersModule;
+use stellarnova_sc::stellarnova_proxy::{ReasonCode, StellarNovaProxy};
+
+const BACKOFF: u64 = 60;
+
+/// USER has order 1 selling 3,000,000 USDC for WEGLD at the pair price
+/// (30 USDC per WEGLD), expiring at 11,000; failed orders back off 60 seconds
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64);
+    world.current_block().block_timestamp_seconds(1_000);
+
+    deploy_with_pair(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_retry_backoff(BACKOFF)
+        .run();
+
+    create_order(&mut world, 3_000_000, 10_000);
+
+    world
+}
+
+/// Record the `attempts`-th failure of order 1. The test VM does not return
+/// the input of a reverted swap to the callback, so the failure itself is
+/// not driven through the pair.
+fn fail(world: &mut ScenarioWorld, attempts: u64) -> u64 {
+    let mut retry_after = 0;
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .whitebox(stellarnova_sc::contract_obj, |sc| {
+            sc.execution_attempts(1).set(attempts);
+            sc.schedule_retry(1, 11_000, attempts);
+            retry_after = sc.retry_after(1).get();
+        });
+    retry_after
+}
+
+fn reason(world: &mut ScenarioWorld) -> ReasonCode {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .can_execute_order(1u64, 1u64, 30u64)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn retries_wait_for_the_backoff() {
+    let mut world = setup();
+    assert_eq!(fail(&mut world, 1), 1_000 + BACKOFF);
+    assert_eq!(reason(&mut world), ReasonCode::RetryBackoff);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .returns(ExpectError(4, "E540: Order is backing off after a failed execution"))
+        .run();
+
+    world.current_block().block_timestamp_seconds(1_000 + BACKOFF);
+    assert_eq!(reason(&mut world), ReasonCode::Ok);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+    world.check_account(USER).esdt_balance(WEGLD, 99_900u64);
+}
+
+#[test]
+fn backoff_doubles_until_halfway_to_the_deadline() {
+    let mut world = setup();
+    assert_eq!(fail(&mut world, 3), 1_000 + 4 * BACKOFF);
+
+    // 60 * 2^9 would end past the expiry: capped halfway to it
+    assert_eq!(fail(&mut world, 10), 6_000);
+}
+
+#[test]
+fn forced_execution_ignores_the_backoff() {
+    let mut world = setup();
+    fail(&mut world, 1);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .force_execute(1u64, 1u64, 30u64)
+        .run();
+    world.check_account(USER).esdt_balance(WEGLD, 99_900u64);
+}
@@ -0,0 +1,214 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::breaker::CircuitBreakerModule;
+use stellarnova_sc::stellarnova_proxy::{OrderStatus, StellarNovaProxy};
+
+const MAKER: TestAddress = TestAddress::new("maker");
+
+const ORDER_AMOUNT: u64 = 3_000_000;
+const DEPOSIT: u64 = 150_000;
+
+/// USER's order 1 sells 3,000,000 USDC for at least 1 WEGLD per 30 USDC;
+/// MAKER's quote 1 gives up to 150,000 WEGLD at 1 WEGLD per 29 USDC
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, PAIR_RESERVE);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, ORDER_AMOUNT);
+    world
+        .account(MAKER)
+        .nonce(1)
+        .esdt_balance(WEGLD, DEPOSIT);
+
+    deploy_with_pair(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .add_market_maker(MAKER)
+        .run();
+
+    create_order(&mut world, ORDER_AMOUNT, 36_000);
+    world
+        .tx()
+        .from(MAKER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .post_quote(USDC, 1u64, 29u64, 36_000u64)
+        .single_esdt(&WEGLD.to_token_identifier(), 0, &BigUint::from(DEPOSIT))
+        .run();
+
+    world
+}
+
+fn fill(world: &mut ScenarioWorld, fill_amount: u64) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .fill_order_with_quote(1u64, 1u64, fill_amount)
+        .run();
+}
+
+fn quote_deposits(world: &mut ScenarioWorld) -> BigUint<StaticApi> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .quote_deposits(WEGLD)
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn cancel_quote(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(MAKER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_quote(1u64)
+        .run();
+}
+
+#[test]
+fn fill_pays_the_user_and_the_maker() {
+    let mut world = setup();
+
+    fill(&mut world, 2_900_000);
+
+    // 2,900,000 / 29 = 100,000 WEGLD, 0.1% execution fee to the executor
+    world.check_account(USER).esdt_balance(WEGLD, 99_900u64);
+    world.check_account(OWNER).esdt_balance(WEGLD, 100u64);
+    world.check_account(MAKER).esdt_balance(USDC, 2_900_000u64);
+    world
+        .check_account(SC)
+        .esdt_balance(WEGLD, DEPOSIT - 100_000)
+        .esdt_balance(USDC, ORDER_AMOUNT - 2_900_000);
+
+    let quote = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_quote(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(quote.give_amount, BigUint::from(DEPOSIT - 100_000));
+    assert_eq!(quote_deposits(&mut world), BigUint::from(DEPOSIT - 100_000));
+    let partly_filled = order(&mut world, 1);
+    assert!(partly_filled.status == OrderStatus::Pending);
+    assert_eq!(partly_filled.from_amount, BigUint::from(ORDER_AMOUNT - 2_900_000));
+
+    // 100,000 / 29 = 3,448 WEGLD complete the order
+    fill(&mut world, ORDER_AMOUNT - 2_900_000);
+    assert!(order(&mut world, 1).status == OrderStatus::Executed);
+    assert_eq!(quote_deposits(&mut world), BigUint::from(DEPOSIT - 103_448));
+}
+
+#[test]
+fn cancel_quote_refunds_the_deposit() {
+    let mut world = setup();
+    fill(&mut world, 290_000);
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_quote(1u64)
+        .returns(ExpectError(4, "E118: Caller is not an approved market maker"))
+        .run();
+    cancel_quote(&mut world);
+
+    world
+        .check_account(MAKER)
+        .esdt_balance(WEGLD, DEPOSIT - 10_000)
+        .esdt_balance(USDC, 290_000u64);
+    world.check_account(SC).esdt_balance(WEGLD, 0u64);
+    assert_eq!(quote_deposits(&mut world), BigUint::zero());
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .fill_order_with_quote(1u64, 1u64, 290_000u64)
+        .returns(ExpectError(4, "E515: Quote not found"))
+        .run();
+}
+
+#[test]
+fn removed_maker_cannot_be_filled_but_can_cancel() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .remove_market_maker(MAKER)
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .fill_order_with_quote(1u64, 1u64, 290_000u64)
+        .returns(ExpectError(4, "E118: Caller is not an approved market maker"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .remove_market_maker(MAKER)
+        .returns(ExpectError(4, "E118: Caller is not an approved market maker"))
+        .run();
+
+    cancel_quote(&mut world);
+    world.check_account(MAKER).esdt_balance(WEGLD, DEPOSIT);
+    assert!(order(&mut world, 1).status == OrderStatus::Pending);
+}
+
+#[test]
+fn tripped_pair_halts_quote_fills() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .whitebox(stellarnova_sc::contract_obj, |sc| {
+            sc.tripped_pairs().insert(PAIR.to_managed_address());
+        });
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .fill_order_with_quote(1u64, 1u64, 290_000u64)
+        .returns(ExpectError(
+            4,
+            "E527: Executions on this pair are halted by the circuit breaker",
+        ))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .clear_circuit_breaker(PAIR)
+        .run();
+    fill(&mut world, 290_000);
+    world.check_account(MAKER).esdt_balance(USDC, 290_000u64);
+}
@@ -0,0 +1,149 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{ReasonCode, StellarNovaProxy};
+
+/// USDC orders are capped at 1,000,000 each and 1,500,000 in total
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 5_000_000u64);
+
+    deploy(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_risk_caps(USDC, 1_000_000u64, 1_500_000u64)
+        .run();
+
+    world
+}
+
+fn create_order_expect_err(world: &mut ScenarioWorld, amount: u64, message: &str) {
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order(
+            WEGLD,
+            1u64,
+            30u64,
+            100u64,
+            3_600u64,
+            OptionalValue::<u64>::None,
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(amount))
+        .returns(ExpectError(4, message))
+        .run();
+}
+
+fn validate(world: &mut ScenarioWorld, amount: u64) -> ReasonCode {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .validate_limit_order(USER, USDC, amount, WEGLD, 1u64, 30u64, 100u64, 3_600u64)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn order_above_max_size_is_rejected() {
+    let mut world = setup();
+
+    assert_eq!(validate(&mut world, 1_000_001), ReasonCode::OrderTooLarge);
+    create_order_expect_err(
+        &mut world,
+        1_000_001,
+        "E409: Order amount exceeds the token's max order size",
+    );
+
+    assert_eq!(validate(&mut world, 1_000_000), ReasonCode::Ok);
+    create_order(&mut world, 1_000_000, 3_600);
+
+    // Uncapped tokens are not affected
+    let max_order_amount = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .max_order_amount(WEGLD)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(max_order_amount, BigUint::zero());
+}
+
+#[test]
+fn open_interest_cap_frees_up_on_cancel() {
+    let mut world = setup();
+
+    create_order(&mut world, 1_000_000, 3_600);
+    assert_eq!(validate(&mut world, 600_000), ReasonCode::OpenInterestCapReached);
+    create_order_expect_err(&mut world, 600_000, "E410: Token open interest cap reached");
+    create_order(&mut world, 500_000, 3_600);
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(1u64)
+        .run();
+    create_order(&mut world, 1_000_000, 3_600);
+}
+
+#[test]
+fn lowering_caps_keeps_open_orders() {
+    let mut world = setup();
+    create_order(&mut world, 1_000_000, 3_600);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_risk_caps(USDC, 100_000u64, 500_000u64)
+        .run();
+    create_order_expect_err(&mut world, 100_000, "E410: Token open interest cap reached");
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(1u64)
+        .run();
+    world.check_account(USER).esdt_balance(USDC, 5_000_000u64);
+
+    // 0 lifts the caps
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_risk_caps(USDC, 0u64, 0u64)
+        .run();
+    create_order(&mut world, 5_000_000, 3_600);
+}
+
+#[test]
+fn only_owner_sets_caps() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_risk_caps(USDC, 1u64, 1u64)
+        .returns(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+}
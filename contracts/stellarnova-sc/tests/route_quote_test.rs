@@ -0,0 +1,114 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+
+const USDC_PAIR: TestSCAddress = TestSCAddress::new("usdc-pair");
+const MEX_PAIR: TestSCAddress = TestSCAddress::new("mex-pair");
+
+const MEX: TestTokenIdentifier = TestTokenIdentifier::new("MEX-123456");
+
+fn deploy_wegld_pair(
+    world: &mut ScenarioWorld,
+    address: TestSCAddress,
+    second_token: TestTokenIdentifier,
+    price: u64,
+) {
+    world
+        .tx()
+        .from(OWNER)
+        .raw_deploy()
+        .code(PAIR_CODE_PATH)
+        .argument(&WEGLD)
+        .argument(&second_token)
+        .new_address(address)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(address)
+        .raw_call("setPrice")
+        .argument(&price)
+        .argument(&1u64)
+        .run();
+}
+
+/// 30 USDC and 1,000 MEX per WEGLD, each token routed to its WEGLD pair
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+
+    deploy_wegld_pair(&mut world, USDC_PAIR, USDC, 30);
+    deploy_wegld_pair(&mut world, MEX_PAIR, MEX, 1_000);
+
+    world
+        .tx()
+        .from(OWNER)
+        .typed(StellarNovaProxy)
+        .init(500u64, MultiValueVec::from(vec![USDC, WEGLD, MEX]))
+        .code(CODE_PATH)
+        .new_address(SC)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_token_pair(USDC, USDC_PAIR)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_token_pair(MEX, MEX_PAIR)
+        .run();
+
+    world
+}
+
+fn route(tokens: &[TestTokenIdentifier]) -> ManagedVec<StaticApi, TokenIdentifier<StaticApi>> {
+    let mut route = ManagedVec::new();
+    for token in tokens {
+        route.push(token.to_token_identifier());
+    }
+    route
+}
+
+#[test]
+fn quote_route_chains_hops() {
+    let mut world = setup();
+
+    let amounts: Vec<BigUint<StaticApi>> = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .quote_route(route(&[USDC, WEGLD, MEX]), 3_000u64)
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .collect();
+    assert_eq!(amounts, vec![BigUint::from(100u64), BigUint::from(100_000u64)]);
+}
+
+#[test]
+fn quote_route_rejects_bad_routes() {
+    let mut world = setup();
+
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .quote_route(route(&[USDC]), 3_000u64)
+        .returns(ExpectError(4, "E411: Route needs at least two tokens"))
+        .run();
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .quote_route(route(&[USDC, WEGLD, WEGLD]), 3_000u64)
+        .returns(ExpectError(4, "E306: Cannot swap token to itself"))
+        .run();
+}
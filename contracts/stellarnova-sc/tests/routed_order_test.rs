@@ -0,0 +1,113 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{ReasonCode, StellarNovaProxy};
+
+const ROUTER: TestSCAddress = TestSCAddress::new("mock-pair");
+
+const MEX: TestTokenIdentifier = TestTokenIdentifier::new("MEX-123456");
+
+/// USDC, WEGLD and MEX are whitelisted; no router is set yet
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64);
+    world.current_block().block_timestamp_seconds(1_000);
+
+    // Stands in for the router: only its address is used here
+    deploy_mock_pair(&mut world, ROUTER);
+
+    deploy(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .whitelist_token(MEX)
+        .run();
+
+    world
+}
+
+fn set_router(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_xexchange_router(ROUTER)
+        .run();
+}
+
+fn create_routed_order(
+    world: &mut ScenarioWorld,
+    via_token: TestTokenIdentifier,
+    expected_error: Option<&str>,
+) {
+    let tx = world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_routed_order(via_token, MEX, 1u64, 1u64, 100u64, 36_000u64)
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(1_000_000u64));
+    match expected_error {
+        Some(message) => tx.returns(ExpectError(4, message)).run(),
+        None => tx.run(),
+    }
+}
+
+#[test]
+fn routed_orders_need_a_router_and_a_distinct_whitelisted_via_token() {
+    let mut world = setup();
+    create_routed_order(&mut world, WEGLD, Some("E413: No swap router is set"));
+
+    set_router(&mut world);
+    create_routed_order(&mut world, USDC, Some("E306: Cannot swap token to itself"));
+    create_routed_order(&mut world, MEX, Some("E306: Cannot swap token to itself"));
+    create_routed_order(
+        &mut world,
+        TestTokenIdentifier::new("UTK-123456"),
+        Some("E303: Token not in whitelist"),
+    );
+
+    create_routed_order(&mut world, WEGLD, None);
+    let via = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order_route_via(1u64)
+        .returns(ReturnsResult)
+        .run()
+        .into_option()
+        .expect("route recorded");
+    assert_eq!(via, WEGLD.to_token_identifier());
+}
+
+#[test]
+fn a_paused_second_leg_blocks_execution() {
+    let mut world = setup();
+    set_router(&mut world);
+    create_routed_order(&mut world, WEGLD, None);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_pair_paused(WEGLD, MEX, true)
+        .run();
+    let reason = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .can_execute_order(1u64, 1u64, 1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(reason, ReasonCode::PairPaused);
+}
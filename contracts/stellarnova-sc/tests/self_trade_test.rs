@@ -0,0 +1,128 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{OrderStatus, StellarNovaProxy};
+
+/// USER sells 3,000,000 USDC for WEGLD (order 1) and 100,000 WEGLD for USDC
+/// (order 2), both at 30 USDC per WEGLD, and is a market maker
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64)
+        .esdt_balance(WEGLD, 200_000u64);
+
+    deploy(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .add_market_maker(USER)
+        .run();
+
+    create_order(&mut world, 3_000_000, 36_000);
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order(
+            USDC,
+            30u64,
+            1u64,
+            100u64,
+            36_000u64,
+            OptionalValue::<u64>::None,
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&WEGLD.to_token_identifier(), 0, &BigUint::from(100_000u64))
+        .run();
+
+    world
+}
+
+fn set_self_trade_prevention(world: &mut ScenarioWorld, enabled: bool) {
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_self_trade_prevention(enabled)
+        .run();
+}
+
+fn order_status(world: &mut ScenarioWorld, order_id: u64) -> OrderStatus {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order(order_id)
+        .returns(ReturnsResult)
+        .run()
+        .status
+}
+
+#[test]
+fn self_trade_prevention_blocks_matching_own_orders() {
+    let mut world = setup();
+    set_self_trade_prevention(&mut world, true);
+    let prevented = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .self_trade_prevention(USER)
+        .returns(ReturnsResult)
+        .run();
+    assert!(prevented);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .match_orders(1u64, 2u64)
+        .returns(ExpectError(4, "E531: Both sides of this trade belong to the same address"))
+        .run();
+
+    // Off again (the default), the orders match
+    set_self_trade_prevention(&mut world, false);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .match_orders(1u64, 2u64)
+        .run();
+    assert!(order_status(&mut world, 1) == OrderStatus::Executed);
+    assert!(order_status(&mut world, 2) == OrderStatus::Executed);
+}
+
+#[test]
+fn self_trade_prevention_blocks_filling_from_own_quote() {
+    let mut world = setup();
+    let quote_id = world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .post_quote(USDC, 1u64, 30u64, 36_000u64)
+        .single_esdt(&WEGLD.to_token_identifier(), 0, &BigUint::from(100_000u64))
+        .returns(ReturnsResult)
+        .run();
+    set_self_trade_prevention(&mut world, true);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .fill_order_with_quote(1u64, quote_id, 3_000_000u64)
+        .returns(ExpectError(4, "E531: Both sides of this trade belong to the same address"))
+        .run();
+    assert!(order_status(&mut world, 1) == OrderStatus::Pending);
+}
@@ -0,0 +1,208 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use multiversx_sc_scenario::scenario_model::TxResponseStatus;
+use stellarnova_sc::managers::{
+    ManagersModule, SESSION_PERMISSION_CANCEL, SESSION_PERMISSION_CREATE,
+    SESSION_PERMISSION_MODIFY,
+};
+use stellarnova_sc::stellarnova_proxy::{OrderStatus, StellarNovaProxy};
+
+const KEY: TestAddress = TestAddress::new("session-key");
+
+const SPEND_CAP: u64 = 2_000_000;
+const KEY_EXPIRES_AT: u64 = 1_000;
+
+/// KEY holds 3,000,000 USDC and some WEGLD and may create and cancel
+/// USER's orders, spending up to `SPEND_CAP` USDC until `KEY_EXPIRES_AT`
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world.account(USER).nonce(1);
+    world
+        .account(KEY)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64)
+        .esdt_balance(WEGLD, 1_000u64);
+
+    deploy(&mut world);
+    grant(
+        &mut world,
+        SESSION_PERMISSION_CREATE | SESSION_PERMISSION_CANCEL,
+        KEY_EXPIRES_AT,
+    );
+
+    world
+}
+
+fn grant(world: &mut ScenarioWorld, permissions: u32, expires_at: u64) {
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .grant_session_key(KEY, permissions, USDC, SPEND_CAP, expires_at)
+        .run();
+}
+
+/// KEY creates an order for USER paid with `amount` of `token`
+fn create_with_key(
+    world: &mut ScenarioWorld,
+    token: TestTokenIdentifier,
+    amount: u64,
+) -> Result<u64, TxResponseStatus> {
+    world
+        .tx()
+        .from(KEY)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order_with_session_key(USER, WEGLD, 1u64, 30u64, 100u64, 3_600u64)
+        .single_esdt(&token.to_token_identifier(), 0, &BigUint::from(amount))
+        .returns(ReturnsHandledOrError::new().returns(ReturnsResult))
+        .run()
+}
+
+fn cancel_with_key(world: &mut ScenarioWorld, order_id: u64) -> Result<(), TxResponseStatus> {
+    world
+        .tx()
+        .from(KEY)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(order_id)
+        .returns(ReturnsHandledOrError::new())
+        .run()
+}
+
+fn spent(world: &mut ScenarioWorld) -> u64 {
+    let mut spent = 0u64;
+    world
+        .query()
+        .to(SC)
+        .whitebox(stellarnova_sc::contract_obj, |sc| {
+            let session = sc.session_key(&USER.to_managed_address(), &KEY.to_managed_address());
+            spent = session.get().spent.to_u64().unwrap();
+        });
+    spent
+}
+
+#[test]
+fn key_creates_and_cancels_orders_for_the_user() {
+    let mut world = setup();
+
+    let order_id = create_with_key(&mut world, USDC, 1_000_000).unwrap();
+    assert_eq!(order(&mut world, order_id).user, USER.to_managed_address());
+    assert_eq!(spent(&mut world), 1_000_000);
+
+    // The refund goes to the user, not to the key that paid
+    cancel_with_key(&mut world, order_id).unwrap();
+    assert!(order(&mut world, order_id).status == OrderStatus::Cancelled);
+    world.check_account(USER).esdt_balance(USDC, 1_000_000u64);
+    world.check_account(KEY).esdt_balance(USDC, 2_000_000u64);
+}
+
+#[test]
+fn spend_cap_is_enforced() {
+    let mut world = setup();
+
+    create_with_key(&mut world, USDC, 1_500_000).unwrap();
+    let result = create_with_key(&mut world, USDC, SPEND_CAP - 1_500_000 + 1);
+    assert_eq!(result.unwrap_err().message, "E111: Session key spend cap exceeded");
+    let result = create_with_key(&mut world, WEGLD, 1_000);
+    assert_eq!(result.unwrap_err().message, "E110: Session key cannot spend this token");
+
+    // Cancelling does not give the allowance back
+    cancel_with_key(&mut world, 1).unwrap();
+    assert_eq!(spent(&mut world), 1_500_000);
+    create_with_key(&mut world, USDC, SPEND_CAP - 1_500_000).unwrap();
+    assert_eq!(spent(&mut world), SPEND_CAP);
+}
+
+#[test]
+fn expired_key_is_rejected() {
+    let mut world = setup();
+    let order_id = create_with_key(&mut world, USDC, 1_000_000).unwrap();
+
+    world.current_block().block_timestamp_seconds(KEY_EXPIRES_AT + 1);
+
+    let result = create_with_key(&mut world, USDC, 1_000_000);
+    assert_eq!(result.unwrap_err().message, "E108: Session key expired");
+    let result = cancel_with_key(&mut world, order_id);
+    assert_eq!(result.unwrap_err().message, "E108: Session key expired");
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .grant_session_key(KEY, SESSION_PERMISSION_CREATE, USDC, SPEND_CAP, KEY_EXPIRES_AT)
+        .returns(ExpectError(4, "E108: Session key expired"))
+        .run();
+}
+
+#[test]
+fn revoked_key_is_rejected() {
+    let mut world = setup();
+    let order_id = create_with_key(&mut world, USDC, 1_000_000).unwrap();
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .revoke_session_key(KEY)
+        .run();
+
+    let result = create_with_key(&mut world, USDC, 1_000_000);
+    assert_eq!(result.unwrap_err().message, "E102: Not your order");
+    let result = cancel_with_key(&mut world, order_id);
+    assert_eq!(result.unwrap_err().message, "E102: Not your order");
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .revoke_session_key(KEY)
+        .returns(ExpectError(4, "E107: Session key not found"))
+        .run();
+}
+
+#[test]
+fn key_is_limited_to_its_permissions() {
+    let mut world = setup();
+    let order_id = create_with_key(&mut world, USDC, 1_000_000).unwrap();
+
+    world
+        .tx()
+        .from(KEY)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .modify_limit_order(order_id, 1u64, 25u64, 100u64, 3_600u64)
+        .returns(ExpectError(4, "E109: Session key lacks permission"))
+        .run();
+
+    // Granting again replaces the key and its spend
+    grant(&mut world, SESSION_PERMISSION_MODIFY, KEY_EXPIRES_AT);
+    assert_eq!(spent(&mut world), 0);
+    let result = cancel_with_key(&mut world, order_id);
+    assert_eq!(result.unwrap_err().message, "E109: Session key lacks permission");
+    let result = create_with_key(&mut world, USDC, 1_000_000);
+    assert_eq!(result.unwrap_err().message, "E109: Session key lacks permission");
+    world
+        .tx()
+        .from(KEY)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .modify_limit_order(order_id, 1u64, 25u64, 100u64, 3_600u64)
+        .run();
+    assert_eq!(order(&mut world, order_id).target_price_denominator, BigUint::from(25u64));
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .grant_session_key(KEY, 8u32, USDC, SPEND_CAP, KEY_EXPIRES_AT)
+        .returns(ExpectError(4, "E106: Invalid session key permissions"))
+        .run();
+}
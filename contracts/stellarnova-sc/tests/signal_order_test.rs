@@ -0,0 +1,150 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{OrderStatus, ReasonCode, StellarNovaProxy};
+
+const ORACLE: TestAddress = TestAddress::new("oracle");
+
+const SIGNAL: u64 = 7;
+const ORDER_AMOUNT: u64 = 3_000_000;
+
+/// ORACLE fires signals; USER holds `2 * ORDER_AMOUNT` USDC to sell for
+/// WEGLD on the mock pair
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, PAIR_RESERVE);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 2 * ORDER_AMOUNT);
+    world.account(ORACLE).nonce(1);
+
+    deploy_with_pair(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_signal_oracle(ORACLE)
+        .run();
+
+    world
+}
+
+/// USER sells `ORDER_AMOUNT` USDC once `SIGNAL` fired
+fn create_signal_order(world: &mut ScenarioWorld) -> u64 {
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_signal_order(SIGNAL, WEGLD, 1u64, 30u64, 100u64, 3_600u64)
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(ORDER_AMOUNT))
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn fire(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(ORACLE)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .fire_signal(SIGNAL)
+        .run();
+}
+
+fn reason(world: &mut ScenarioWorld, order_id: u64) -> ReasonCode {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .can_execute_order(order_id, 1u64, 30u64)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn order_waits_for_its_signal() {
+    let mut world = setup();
+    let order_id = create_signal_order(&mut world);
+
+    assert_eq!(reason(&mut world, order_id), ReasonCode::SignalNotFired);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(order_id, 1u64, 30u64)
+        .returns(ExpectError(4, "E509: Order signal has not fired yet"))
+        .run();
+
+    fire(&mut world);
+
+    assert_eq!(reason(&mut world, order_id), ReasonCode::Ok);
+    execute(&mut world, order_id);
+    assert!(order(&mut world, order_id).status == OrderStatus::Executed);
+    world.check_account(USER).esdt_balance(WEGLD, 99_900u64);
+}
+
+#[test]
+fn only_the_oracle_fires_signals() {
+    let mut world = setup();
+    let order_id = create_signal_order(&mut world);
+
+    for caller in [USER, OWNER] {
+        world
+            .tx()
+            .from(caller)
+            .to(SC)
+            .typed(StellarNovaProxy)
+            .fire_signal(SIGNAL)
+            .returns(ExpectError(4, "E114: Only the signal oracle can fire signals"))
+            .run();
+    }
+    assert_eq!(reason(&mut world, order_id), ReasonCode::SignalNotFired);
+
+    fire(&mut world);
+    world
+        .tx()
+        .from(ORACLE)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .fire_signal(SIGNAL)
+        .returns(ExpectError(4, "E510: Signal already fired"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_signal_oracle(USER)
+        .run();
+    world
+        .tx()
+        .from(ORACLE)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .fire_signal(SIGNAL + 1)
+        .returns(ExpectError(4, "E114: Only the signal oracle can fire signals"))
+        .run();
+}
+
+#[test]
+fn order_on_a_fired_signal_is_executable_right_away() {
+    let mut world = setup();
+    let first = create_signal_order(&mut world);
+    fire(&mut world);
+
+    // Signals fire once, so a later order is not held back by it
+    let second = create_signal_order(&mut world);
+    assert_eq!(reason(&mut world, second), ReasonCode::Ok);
+    execute(&mut world, second);
+    assert!(order(&mut world, second).status == OrderStatus::Executed);
+    assert!(order(&mut world, first).status == OrderStatus::Pending);
+}
@@ -0,0 +1,129 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::limit_orders::LimitOrdersModule;
+use stellarnova_sc::storage::StorageModule;
+
+/// StellarNova with a `max_slippage_bp` cap; USER holds 1,000,000 USDC
+fn deploy_with_max_slippage(world: &mut ScenarioWorld, max_slippage_bp: u64) {
+    world.account(OWNER).nonce(1);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 1_000_000u64);
+
+    world
+        .tx()
+        .from(OWNER)
+        .raw_deploy()
+        .code(CODE_PATH)
+        .argument(&max_slippage_bp)
+        .argument(&USDC)
+        .argument(&WEGLD)
+        .new_address(SC)
+        .run();
+}
+
+#[test]
+fn init_rejects_max_slippage_above_100_percent() {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+
+    world
+        .tx()
+        .from(OWNER)
+        .raw_deploy()
+        .code(CODE_PATH)
+        .argument(&10_001u64)
+        .new_address(SC)
+        .returns(ExpectError(4, "E601: Max slippage cannot exceed 10000 bps"))
+        .run();
+}
+
+#[test]
+fn set_max_slippage_is_capped() {
+    let mut world = world();
+    deploy_with_max_slippage(&mut world, 500);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .raw_call("setMaxSlippage")
+        .argument(&10_001u64)
+        .returns(ExpectError(4, "E601: Max slippage cannot exceed 10000 bps"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .raw_call("setMaxSlippage")
+        .argument(&10_000u64)
+        .run();
+
+    world.query().to(SC).whitebox(stellarnova_sc::contract_obj, |sc| {
+        assert_eq!(sc.max_slippage().get(), 10_000u64);
+    });
+}
+
+#[test]
+fn create_order_rejects_slippage_above_max() {
+    let mut world = world();
+    deploy_with_max_slippage(&mut world, 500);
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .raw_call("createLimitOrder")
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(1_000u64))
+        .argument(&WEGLD)
+        .argument(&1u64)
+        .argument(&1u64)
+        .argument(&501u64)
+        .argument(&3_600u64)
+        .returns(ExpectError(4, "E404: Slippage exceeds maximum allowed"))
+        .run();
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .raw_call("createLimitOrder")
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(1_000u64))
+        .argument(&WEGLD)
+        .argument(&1u64)
+        .argument(&1u64)
+        .argument(&10_001u64)
+        .argument(&3_600u64)
+        .returns(ExpectError(4, "E403: Slippage cannot exceed 10000 bps"))
+        .run();
+}
+
+#[test]
+fn min_output_never_underflows() {
+    let mut world = world();
+    deploy_with_max_slippage(&mut world, 10_000);
+
+    world.query().to(SC).whitebox(stellarnova_sc::contract_obj, |sc| {
+        let amount = BigUint::from(1_000_000u64);
+        let num = BigUint::from(2u64);
+        let denom = BigUint::from(1u64);
+
+        assert_eq!(
+            sc.calculate_min_output(&amount, &num, &denom, 0),
+            BigUint::from(2_000_000u64)
+        );
+        assert_eq!(
+            sc.calculate_min_output(&amount, &num, &denom, 500),
+            BigUint::from(1_900_000u64)
+        );
+        assert_eq!(
+            sc.calculate_min_output(&amount, &num, &denom, 10_000),
+            BigUint::zero()
+        );
+    });
+}
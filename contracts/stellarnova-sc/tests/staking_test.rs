@@ -0,0 +1,231 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+
+const ALICE: TestAddress = TestAddress::new("alice");
+const BOB: TestAddress = TestAddress::new("bob");
+
+const NOVA: TestTokenIdentifier = TestTokenIdentifier::new("NOVA-123456");
+
+const UNBOND_EPOCHS: u64 = 7;
+
+/// 100 bps protocol fee, half of it shared with NOVA stakers; USER has
+/// order 1 selling 3,000,000 USDC for 100,000 WEGLD on the mock pair
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_010u64);
+    world.account(ALICE).nonce(1).esdt_balance(NOVA, 1_000u64);
+    world.account(BOB).nonce(1).esdt_balance(NOVA, 1_000u64);
+    world.current_block().block_epoch(10);
+
+    deploy_with_pair(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_protocol_fee_bps(100u64)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_staking_config(NOVA, 5_000u64, UNBOND_EPOCHS)
+        .run();
+
+    create_order(&mut world, 3_000_000, 36_000);
+
+    world
+}
+
+fn stake(world: &mut ScenarioWorld, staker: TestAddress, amount: u64) {
+    world
+        .tx()
+        .from(staker)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .stake()
+        .single_esdt(&NOVA.to_token_identifier(), 0, &BigUint::from(amount))
+        .run();
+}
+
+fn claimable(world: &mut ScenarioWorld, staker: TestAddress) -> BigUint<StaticApi> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_claimable_staking_rewards(staker, WEGLD)
+        .returns(ReturnsResult)
+        .run()
+}
+
+fn protocol_fees(world: &mut ScenarioWorld) -> BigUint<StaticApi> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .protocol_fees(WEGLD)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn fees_are_shared_pro_rata_with_stakers() {
+    let mut world = setup();
+    stake(&mut world, ALICE, 100);
+    stake(&mut world, BOB, 300);
+
+    // 1,000 WEGLD protocol fee: 500 for stakers, 500 for the treasury
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+    assert_eq!(protocol_fees(&mut world), BigUint::from(500u64));
+    assert_eq!(claimable(&mut world, ALICE), BigUint::from(125u64));
+    assert_eq!(claimable(&mut world, BOB), BigUint::from(375u64));
+
+    world
+        .tx()
+        .from(ALICE)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .claim_staking_rewards(WEGLD)
+        .run();
+    world
+        .check_account(ALICE)
+        .esdt_balance(WEGLD, 125u64)
+        .esdt_balance(NOVA, 900u64);
+    assert_eq!(claimable(&mut world, ALICE), BigUint::zero());
+    world
+        .tx()
+        .from(ALICE)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .claim_staking_rewards(WEGLD)
+        .returns(ExpectError(4, "E918: No staking rewards to claim"))
+        .run();
+}
+
+#[test]
+fn fees_go_to_treasury_without_stakers() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+    assert_eq!(protocol_fees(&mut world), BigUint::from(1_000u64));
+}
+
+#[test]
+fn unstaked_tokens_stop_earning_and_unbond() {
+    let mut world = setup();
+    stake(&mut world, ALICE, 100);
+    stake(&mut world, BOB, 100);
+
+    world
+        .tx()
+        .from(ALICE)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .unstake(100u64)
+        .run();
+
+    // Bob is the only staker left and earns the whole staker share
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+    assert_eq!(claimable(&mut world, ALICE), BigUint::zero());
+    assert_eq!(claimable(&mut world, BOB), BigUint::from(500u64));
+
+    world
+        .tx()
+        .from(ALICE)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .withdraw_unstaked()
+        .returns(ExpectError(4, "E917: Unstaked tokens are still unbonding"))
+        .run();
+
+    world.current_block().block_epoch(10 + UNBOND_EPOCHS);
+    world
+        .tx()
+        .from(ALICE)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .withdraw_unstaked()
+        .run();
+    world.check_account(ALICE).esdt_balance(NOVA, 1_000u64);
+
+    world
+        .tx()
+        .from(ALICE)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .withdraw_unstaked()
+        .returns(ExpectError(4, "E916: No unstaked tokens to withdraw"))
+        .run();
+}
+
+#[test]
+fn staking_rejects_wrong_token_and_excess_unstake() {
+    let mut world = setup();
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .stake()
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(10u64))
+        .returns(ExpectError(4, "E914: Payment must be the staking token"))
+        .run();
+
+    stake(&mut world, ALICE, 100);
+    world
+        .tx()
+        .from(ALICE)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .unstake(101u64)
+        .returns(ExpectError(4, "E915: Insufficient stake"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_staking_config(USDC, 5_000u64, UNBOND_EPOCHS)
+        .returns(ExpectError(4, "E913: Staking token cannot change"))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_staking_config(NOVA, 10_001u64, UNBOND_EPOCHS)
+        .returns(ExpectError(4, "E624: Staking fee share cannot exceed 10000 bps"))
+        .run();
+}
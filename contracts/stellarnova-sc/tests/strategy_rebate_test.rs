@@ -0,0 +1,146 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+
+const STRATEGIST: TestAddress = TestAddress::new("strategist");
+
+/// Pair price 30 USDC per WEGLD, 10 bps execution fee (the owner executes);
+/// STRATEGIST owns strategy 1, rebating half of the execution fee
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64);
+    world
+        .account(STRATEGIST)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000u64);
+
+    deploy_with_pair(&mut world);
+
+    world
+        .tx()
+        .from(STRATEGIST)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .register_strategy(ManagedBuffer::from("strategy"))
+        .run();
+    world
+        .tx()
+        .from(STRATEGIST)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_strategy_rebate_bps(1u64, 5_000u64)
+        .run();
+
+    world
+}
+
+fn fund(world: &mut ScenarioWorld, amount: u64) {
+    world
+        .tx()
+        .from(STRATEGIST)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .fund_strategy_rebates(1u64)
+        .single_esdt(&WEGLD.to_token_identifier(), 0, &BigUint::from(amount))
+        .run();
+}
+
+/// Sell 3,000,000 USDC for WEGLD under strategy 1 and execute it
+fn create_and_execute(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_limit_order(
+            WEGLD,
+            1u64,
+            30u64,
+            100u64,
+            36_000u64,
+            OptionalValue::Some(1u64),
+            OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+        )
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(3_000_000u64))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+}
+
+fn pool(world: &mut ScenarioWorld) -> BigUint<StaticApi> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .strategy_rebate_pool(1u64, WEGLD)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn fills_get_part_of_the_fee_back() {
+    let mut world = setup();
+    fund(&mut world, 1_000);
+
+    // 100,000 WEGLD out: the executor keeps its 100, the user gets 50 back
+    create_and_execute(&mut world);
+    world.check_account(OWNER).esdt_balance(WEGLD, 100u64);
+    world.check_account(USER).esdt_balance(WEGLD, 99_950u64);
+    assert_eq!(pool(&mut world), BigUint::from(950u64));
+}
+
+#[test]
+fn rebate_is_limited_by_the_pool() {
+    let mut world = setup();
+    fund(&mut world, 30);
+
+    create_and_execute(&mut world);
+    world.check_account(USER).esdt_balance(WEGLD, 99_930u64);
+    assert_eq!(pool(&mut world), BigUint::zero());
+}
+
+#[test]
+fn only_the_strategy_owner_manages_the_pool() {
+    let mut world = setup();
+    fund(&mut world, 1_000);
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .withdraw_strategy_rebates(1u64, WEGLD, 1_000u64)
+        .returns(ExpectError(4, "E802: Not the strategy owner"))
+        .run();
+    world
+        .tx()
+        .from(STRATEGIST)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .withdraw_strategy_rebates(1u64, WEGLD, 1_001u64)
+        .returns(ExpectError(4, "E809: Strategy rebate pool is too small"))
+        .run();
+    world
+        .tx()
+        .from(STRATEGIST)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .withdraw_strategy_rebates(1u64, WEGLD, 1_000u64)
+        .run();
+    world.check_account(STRATEGIST).esdt_balance(WEGLD, 1_000u64);
+}
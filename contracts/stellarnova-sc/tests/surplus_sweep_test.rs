@@ -0,0 +1,146 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+use stellarnova_sc::storage::StorageModule;
+
+const STRANGER: TestAddress = TestAddress::new("stranger");
+const TREASURY: TestAddress = TestAddress::new("treasury");
+const DEX: TestAddress = TestAddress::new("dex");
+
+const SWEEP_MIN_DELAY: u64 = 24 * 60 * 60;
+
+/// USER escrows 1,000,000 USDC in order 1, then STRANGER sends 500 USDC
+/// straight to the contract
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world.account(TREASURY).nonce(1);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 1_000_000u64);
+    world
+        .account(STRANGER)
+        .nonce(1)
+        .esdt_balance(USDC, 500u64);
+    world.current_block().block_timestamp_seconds(1_000);
+
+    world
+        .tx()
+        .from(OWNER)
+        .typed(StellarNovaProxy)
+        .init(500u64, MultiValueVec::from(vec![USDC, WEGLD]))
+        .code(CODE_PATH)
+        .code_metadata(CodeMetadata::PAYABLE)
+        .new_address(SC)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_treasury_address(TREASURY)
+        .run();
+
+    create_order(&mut world, 1_000_000, 36_000);
+    assert_eq!(reconcile(&mut world), BigInt::from(0i64));
+
+    world
+        .tx()
+        .from(STRANGER)
+        .to(SC)
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(500u64))
+        .run();
+
+    world
+}
+
+fn reconcile(world: &mut ScenarioWorld) -> BigInt<StaticApi> {
+    world
+        .tx()
+        .from(STRANGER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .reconcile(USDC)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn reconcile_reports_tokens_sent_by_mistake() {
+    let mut world = setup();
+    assert_eq!(reconcile(&mut world), BigInt::from(500i64));
+}
+
+/// The test VM runs a swap and its callback in one transaction, so the
+/// state in between is set up directly: order 1's escrow is at the DEX
+#[test]
+fn reconcile_ignores_escrow_in_flight() {
+    let mut world = setup();
+    world.account(DEX).nonce(1);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .whitebox(stellarnova_sc::contract_obj, |sc| {
+            let usdc = USDC.to_token_identifier();
+            let fill_amount = BigUint::from(1_000_000u64);
+            sc.escrow_in_flight(&usdc).set(&fill_amount);
+            sc.send()
+                .direct_esdt(&DEX.to_managed_address(), &usdc, 0, &fill_amount);
+        });
+
+    world.check_account(SC).esdt_balance(USDC, 500u64);
+    assert_eq!(reconcile(&mut world), BigInt::from(500i64));
+}
+
+#[test]
+fn sweep_moves_only_the_surplus_to_the_treasury() {
+    let mut world = setup();
+    let action_id = world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .sweep_surplus(USDC)
+        .returns(ReturnsResult)
+        .run();
+
+    // Waits the emergency withdrawal delay even with no timelock delay set
+    world.check_account(TREASURY).esdt_balance(USDC, 0u64);
+    world
+        .tx()
+        .from(STRANGER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_queued_action(action_id)
+        .returns(ExpectError(4, "E611: Timelock delay has not elapsed"))
+        .run();
+
+    world
+        .current_block()
+        .block_timestamp_seconds(1_000 + SWEEP_MIN_DELAY);
+    world
+        .tx()
+        .from(STRANGER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_queued_action(action_id)
+        .run();
+
+    world.check_account(TREASURY).esdt_balance(USDC, 500u64);
+    world.check_account(SC).esdt_balance(USDC, 1_000_000u64);
+    assert_eq!(reconcile(&mut world), BigInt::from(0i64));
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .sweep_surplus(USDC)
+        .returns(ExpectError(4, "E632: No surplus balance to sweep"))
+        .run();
+}
@@ -0,0 +1,107 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{OrderStatus, StellarNovaProxy, SwapArgument};
+
+/// USER has order 1 selling 3,000,000 USDC for 100,000 WEGLD on the mock pair
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64);
+
+    deploy_with_pair(&mut world);
+
+    create_order(&mut world, 3_000_000, 36_000);
+
+    world
+}
+
+fn set_swap_descriptor(world: &mut ScenarioWorld, function: &str, arguments: Vec<SwapArgument>) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_swap_descriptor(PAIR, ManagedBuffer::from(function), MultiValueVec::from(arguments))
+        .run();
+}
+
+fn swap_function(world: &mut ScenarioWorld) -> ManagedBuffer<StaticApi> {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .swap_call_descriptor(PAIR)
+        .returns(ReturnsResult)
+        .run()
+        .function
+}
+
+#[test]
+fn swap_uses_the_pair_descriptor() {
+    let mut world = setup();
+    assert_eq!(swap_function(&mut world), ManagedBuffer::from("swapTokensFixedInput"));
+
+    set_swap_descriptor(
+        &mut world,
+        "swapExactIn",
+        vec![SwapArgument::MinAmountOut, SwapArgument::TokenOut],
+    );
+    let descriptor = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .swap_call_descriptor(PAIR)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(descriptor.function, ManagedBuffer::from("swapExactIn"));
+    let arguments: Vec<_> = descriptor.arguments.iter().map(|argument| *argument).collect();
+    assert_eq!(arguments, vec![SwapArgument::MinAmountOut, SwapArgument::TokenOut]);
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+    let order = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order(1u64)
+        .returns(ReturnsResult)
+        .run();
+    assert!(order.status == OrderStatus::Executed);
+    // 100,000 WEGLD minus the 10 bps execution fee
+    world.check_account(USER).esdt_balance(WEGLD, 99_900u64);
+}
+
+#[test]
+fn empty_function_restores_the_default() {
+    let mut world = setup();
+    set_swap_descriptor(
+        &mut world,
+        "swapExactIn",
+        vec![SwapArgument::MinAmountOut, SwapArgument::TokenOut],
+    );
+    set_swap_descriptor(&mut world, "", Vec::new());
+    assert_eq!(swap_function(&mut world), ManagedBuffer::from("swapTokensFixedInput"));
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+    world.check_account(USER).esdt_balance(WEGLD, 99_900u64);
+}
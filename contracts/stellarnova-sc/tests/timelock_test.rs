@@ -0,0 +1,139 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{StellarNovaProxy, TimelockAction};
+
+const DELAY: u64 = 3_600;
+const QUEUED_AT: u64 = 1_000;
+
+/// Deploy with a `DELAY` timelock and queue `setMaxSlippage(800)` at `QUEUED_AT`
+fn setup() -> (ScenarioWorld, u64) {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world.account(USER).nonce(1);
+    world.current_block().block_timestamp_seconds(QUEUED_AT);
+
+    deploy(&mut world);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_timelock_delay(DELAY)
+        .run();
+
+    let next_id = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_next_action_id()
+        .returns(ReturnsResult)
+        .run();
+    let action_id = world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_max_slippage(800u64)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(action_id, next_id);
+
+    (world, action_id)
+}
+
+fn executable_count(world: &mut ScenarioWorld) -> usize {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_executable_actions()
+        .returns(ReturnsResult)
+        .run()
+        .len()
+}
+
+#[test]
+fn anyone_applies_an_elapsed_action() {
+    let (mut world, action_id) = setup();
+
+    let queued = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_queued_action(action_id)
+        .returns(ReturnsResult)
+        .run();
+    assert!(matches!(queued.action, TimelockAction::SetMaxSlippage(800)));
+    assert_eq!(queued.executable_at, QUEUED_AT + DELAY);
+    assert_eq!(executable_count(&mut world), 0);
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_queued_action(action_id)
+        .returns(ExpectError(4, "E611: Timelock delay has not elapsed"))
+        .run();
+
+    world.current_block().block_timestamp_seconds(QUEUED_AT + DELAY);
+    assert_eq!(executable_count(&mut world), 1);
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_queued_action(action_id)
+        .run();
+
+    let max_slippage = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .max_slippage()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(max_slippage, 800u64);
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_queued_action(action_id)
+        .returns(ExpectError(4, "E610: Queued action not found"))
+        .run();
+}
+
+#[test]
+fn proposing_and_cancelling_stay_owner_only() {
+    let (mut world, action_id) = setup();
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_max_slippage(900u64)
+        .returns(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_queued_action(action_id)
+        .returns(ExpectError(4, "Endpoint can only be called by owner"))
+        .run();
+
+    // Fee claims pay the treasury whoever calls
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .claim_protocol_fees(USDC)
+        .returns(ExpectError(4, "E613: No protocol fees to claim"))
+        .run();
+}
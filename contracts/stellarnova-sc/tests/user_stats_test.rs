@@ -0,0 +1,235 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::stellarnova_proxy::{StellarNovaProxy, UserStats};
+
+/// USER has orders 1-3 each selling 1,000,000 USDC for WEGLD on the mock
+/// pair; order 3 expires after 100 seconds
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 1_000_000u64);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 3_000_000u64);
+    world.current_block().block_epoch(10);
+
+    deploy_with_pair(&mut world);
+
+    for expires_in in [36_000u64, 36_000u64, 100u64] {
+        world
+            .tx()
+            .from(USER)
+            .to(SC)
+            .typed(StellarNovaProxy)
+            .create_limit_order(
+                WEGLD,
+                1u64,
+                30u64,
+                100u64,
+                expires_in,
+                OptionalValue::<u64>::None,
+                OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+            )
+            .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(1_000_000u64))
+            .run();
+    }
+
+    world
+}
+
+fn user_stats(world: &mut ScenarioWorld) -> UserStats {
+    world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_user_stats(USER)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn user_stats_follow_the_order_lifecycle() {
+    let mut world = setup();
+    assert_eq!(
+        user_stats(&mut world),
+        UserStats {
+            orders_created: 3,
+            orders_executed: 0,
+            orders_cancelled: 0,
+            orders_expired: 0,
+        }
+    );
+
+    // 33,333 WEGLD out, 33 WEGLD execution fee
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(2u64)
+        .run();
+    world.current_block().block_timestamp_seconds(200);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .process_expired_orders(10u64)
+        .run();
+
+    assert_eq!(
+        user_stats(&mut world),
+        UserStats {
+            orders_created: 3,
+            orders_executed: 1,
+            orders_cancelled: 1,
+            orders_expired: 1,
+        }
+    );
+    let volume = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .user_volume(USER, USDC)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(volume, BigUint::from(1_000_000u64));
+    let fees = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .user_fees_paid(USER, WEGLD)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(fees, BigUint::from(33u64));
+}
+
+#[test]
+fn partial_fills_count_volume_but_not_executions() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order_partial(1u64, 400_000u64, 1u64, 30u64)
+        .run();
+
+    assert_eq!(user_stats(&mut world).orders_executed, 0);
+    let volume = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .user_volume(USER, USDC)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(volume, BigUint::from(400_000u64));
+}
+
+#[test]
+fn pair_analytics_aggregate_all_orders_of_the_pair() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order_partial(1u64, 400_000u64, 1u64, 30u64)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+    world.current_block().block_timestamp_seconds(200);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .process_expired_orders(10u64)
+        .run();
+
+    let stats = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_pair_stats(USDC, WEGLD)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(stats.orders_created, 3);
+    assert_eq!(stats.orders_executed, 1);
+    assert_eq!(stats.orders_expired, 1);
+    assert_eq!(stats.fill_count, 2);
+    assert_eq!(stats.volume, BigUint::from(1_000_000u64));
+
+    // Order 2 is still open: one executed and one expired of two closed
+    let analytics = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_pair_analytics(USDC, WEGLD)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(analytics.volume, BigUint::from(1_000_000u64));
+    assert_eq!(analytics.average_fill_size, BigUint::from(500_000u64));
+    assert_eq!(analytics.fill_rate_bps, 5_000);
+    assert_eq!(analytics.expiry_rate_bps, 5_000);
+
+    let other_side = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_pair_analytics(WEGLD, USDC)
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(other_side.fill_rate_bps, 0);
+    assert_eq!(other_side.average_fill_size, BigUint::zero());
+}
+
+#[test]
+fn protocol_stats_sum_up_all_accounts() {
+    let mut world = setup();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .execute_limit_order(1u64, 1u64, 30u64)
+        .run();
+
+    let stats = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_protocol_stats()
+        .returns(ReturnsResult)
+        .run();
+    assert_eq!(stats.orders_created, 3);
+    assert_eq!(stats.orders_executed, 1);
+    assert_eq!(stats.open_orders, 2);
+
+    assert_eq!(stats.volume.len(), 1);
+    let volume = stats.volume.get(0);
+    assert_eq!(volume.token_identifier, USDC.to_token_identifier());
+    assert_eq!(volume.amount, BigUint::from(1_000_000u64));
+    assert_eq!(stats.fees.len(), 1);
+    let fees = stats.fees.get(0);
+    assert_eq!(fees.token_identifier, WEGLD.to_token_identifier());
+    assert_eq!(fees.amount, BigUint::from(33u64));
+}
@@ -0,0 +1,162 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::pause::WIND_DOWN_GRACE_SECONDS;
+use stellarnova_sc::stellarnova_proxy::{CancelReason, OrderStatus, StellarNovaProxy};
+
+const OTHER: TestAddress = TestAddress::new("other");
+
+const PAUSED_AT: u64 = 1_000;
+
+/// USER owns orders 1 and 2, OTHER owns order 3; the contract is fully
+/// stopped at `PAUSED_AT`
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, 2_000_000u64);
+    world
+        .account(OTHER)
+        .nonce(1)
+        .esdt_balance(USDC, 1_000_000u64);
+    world.current_block().block_timestamp_seconds(PAUSED_AT);
+
+    deploy(&mut world);
+
+    for user in [USER, USER, OTHER] {
+        world
+            .tx()
+            .from(user)
+            .to(SC)
+            .typed(StellarNovaProxy)
+            .create_limit_order(
+                WEGLD,
+                1u64,
+                30u64,
+                100u64,
+                2 * WIND_DOWN_GRACE_SECONDS,
+                OptionalValue::<u64>::None,
+                OptionalValue::<ManagedByteArray<StaticApi, 32>>::None,
+            )
+            .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(1_000_000u64))
+            .run();
+    }
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_paused(true)
+        .run();
+
+    world
+}
+
+fn wind_down_refund(world: &mut ScenarioWorld, max_count: u64) -> u64 {
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .wind_down_refund(max_count)
+        .returns(ReturnsResult)
+        .run()
+}
+
+#[test]
+fn wind_down_opens_after_grace_period() {
+    let mut world = setup();
+
+    world
+        .current_block()
+        .block_timestamp_seconds(PAUSED_AT + WIND_DOWN_GRACE_SECONDS - 1);
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .wind_down_refund(10u64)
+        .returns(ExpectError(4, "E206: Wind-down is not active"))
+        .run();
+
+    world
+        .current_block()
+        .block_timestamp_seconds(PAUSED_AT + WIND_DOWN_GRACE_SECONDS);
+    assert_eq!(wind_down_refund(&mut world, 1), 1);
+    assert_eq!(wind_down_refund(&mut world, 10), 1);
+    assert_eq!(wind_down_refund(&mut world, 10), 0);
+    world.check_account(USER).esdt_balance(USDC, 2_000_000u64);
+
+    for order_id in [1u64, 2] {
+        let order = world
+            .query()
+            .to(SC)
+            .typed(StellarNovaProxy)
+            .get_order(order_id)
+            .returns(ReturnsResult)
+            .run();
+        assert!(order.status == OrderStatus::Cancelled);
+        let reason = world
+            .query()
+            .to(SC)
+            .typed(StellarNovaProxy)
+            .cancel_reason(order_id)
+            .returns(ReturnsResult)
+            .run();
+        assert!(reason == CancelReason::WindDown);
+    }
+
+    // Only the caller's own orders are refunded
+    let order = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .get_order(3u64)
+        .returns(ReturnsResult)
+        .run();
+    assert!(order.status == OrderStatus::Pending);
+}
+
+#[test]
+fn unpausing_resets_the_grace_period() {
+    let mut world = setup();
+
+    world.current_block().block_timestamp_seconds(PAUSED_AT + 100);
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_paused(false)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_paused(true)
+        .run();
+
+    // Measured from the latest FullStop
+    world
+        .current_block()
+        .block_timestamp_seconds(PAUSED_AT + WIND_DOWN_GRACE_SECONDS);
+    let active = world
+        .query()
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .is_wind_down_active()
+        .returns(ReturnsResult)
+        .run();
+    assert!(!active);
+
+    world
+        .current_block()
+        .block_timestamp_seconds(PAUSED_AT + 100 + WIND_DOWN_GRACE_SECONDS);
+    assert_eq!(wind_down_refund(&mut world, 10), 2);
+}
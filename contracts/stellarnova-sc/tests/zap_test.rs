@@ -0,0 +1,220 @@
+mod common;
+
+use multiversx_sc_scenario::imports::*;
+
+use common::*;
+use stellarnova_sc::limit_orders::{LimitOrdersModule, OrderStatus};
+use stellarnova_sc::storage::{StorageModule, ZapExecution};
+use stellarnova_sc::stellarnova_proxy::StellarNovaProxy;
+
+const LP: TestTokenIdentifier = TestTokenIdentifier::new("WEGLDUSDC-123456");
+
+const ORDER_AMOUNT: u64 = 3_000_000;
+const HALF: u64 = ORDER_AMOUNT / 2;
+/// 1,500,000 USDC at 30 per WEGLD, less the 0.1% execution fee
+const SWAP_OUTPUT: u64 = 49_950;
+
+/// USER's zap order 1 sells 3,000,000 USDC on the mock WEGLD/USDC pair,
+/// which mints LP; OWNER keeps a second `PAIR_RESERVE` of WEGLD
+fn setup() -> ScenarioWorld {
+    let mut world = world();
+    world
+        .account(OWNER)
+        .nonce(1)
+        .esdt_balance(WEGLD, 2 * PAIR_RESERVE);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, ORDER_AMOUNT);
+
+    deploy_with_pair(&mut world);
+    world.set_esdt_local_roles(&PAIR.to_address(), LP.as_bytes(), &[EsdtLocalRole::Mint]);
+    world
+        .tx()
+        .from(OWNER)
+        .to(PAIR)
+        .raw_call("setLpToken")
+        .argument(&LP)
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_zap_config(LP, WEGLD)
+        .run();
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_zap_order(WEGLD, 1u64, 30u64, 100u64, 3_600u64)
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(ORDER_AMOUNT))
+        .run();
+
+    world
+}
+
+#[test]
+fn zap_order_needs_the_configured_pair() {
+    let mut world = world();
+    world.account(OWNER).nonce(1);
+    world
+        .account(USER)
+        .nonce(1)
+        .esdt_balance(USDC, ORDER_AMOUNT);
+    deploy(&mut world);
+
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_zap_order(WEGLD, 1u64, 30u64, 100u64, 3_600u64)
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(ORDER_AMOUNT))
+        .returns(ExpectError(4, "E619: Zap orders are not configured"))
+        .run();
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_zap_config(LP, USDC)
+        .run();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_zap_order(WEGLD, 1u64, 30u64, 100u64, 3_600u64)
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(ORDER_AMOUNT))
+        .run();
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .set_zap_config(LP, TestTokenIdentifier::new("OTHER-123456"))
+        .run();
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .create_zap_order(WEGLD, 1u64, 30u64, 100u64, 3_600u64)
+        .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(ORDER_AMOUNT))
+        .returns(ExpectError(
+            4,
+            "E312: Zap orders must trade a token of the configured pair",
+        ))
+        .run();
+}
+
+#[test]
+fn execution_swaps_half_and_adds_liquidity() {
+    let mut world = setup();
+
+    execute(&mut world, 1);
+
+    // Half swapped for 50,000 WEGLD, 50 of them the execution fee. The
+    // other half matches 1,498,500 USDC of liquidity, the rest comes back
+    world.check_account(OWNER).esdt_balance(WEGLD, PAIR_RESERVE + 50);
+    world
+        .check_account(USER)
+        .esdt_balance(LP, SWAP_OUTPUT)
+        .esdt_balance(USDC, 1_500u64)
+        .esdt_balance(WEGLD, 0u64);
+    world
+        .check_account(SC)
+        .esdt_balance(USDC, 0u64)
+        .esdt_balance(WEGLD, 0u64);
+    world.check_account(PAIR).esdt_balance(USDC, HALF + 1_498_500);
+
+    world.query().to(SC).whitebox(stellarnova_sc::contract_obj, |sc| {
+        let order = sc.limit_orders(1).get();
+        assert!(matches!(order.status, OrderStatus::Executed));
+        assert_eq!(order.from_amount, ORDER_AMOUNT);
+        assert_eq!(sc.escrow_balance(&USDC.to_token_identifier()).get(), 0u64);
+        assert_eq!(sc.escrow_in_flight(&USDC.to_token_identifier()).get(), 0u64);
+        assert!(sc.zap_executions(1).is_empty());
+        assert!(!sc.orders_in_execution().contains(&1));
+    });
+}
+
+/// The test VM does not hand the inputs of a reverted addLiquidity back to
+/// the error callback, so the callback is called with them directly, OWNER
+/// standing in for the pair. Before it, order 1 is where the swap callback
+/// leaves it: half swapped, the other half sent to the pair with the output
+fn fail_add_liquidity(world: &mut ScenarioWorld) {
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .whitebox(stellarnova_sc::contract_obj, |sc| {
+            sc.tx()
+                .to(&OWNER.to_managed_address())
+                .single_esdt(&USDC.to_token_identifier(), 0, &BigUint::from(ORDER_AMOUNT))
+                .transfer();
+            sc.zap_executions(1).set(ZapExecution {
+                executor: OWNER.to_managed_address(),
+                fill_amount: BigUint::from(ORDER_AMOUNT),
+            });
+            sc.escrow_in_flight(&USDC.to_token_identifier())
+                .set(BigUint::from(HALF));
+            sc.orders_in_execution().insert(1);
+        });
+
+    world
+        .tx()
+        .from(OWNER)
+        .to(SC)
+        .esdt(TestEsdtTransfer(WEGLD, 0, SWAP_OUTPUT))
+        .esdt(TestEsdtTransfer(USDC, 0, HALF))
+        .whitebox(stellarnova_sc::contract_obj, |sc| {
+            sc.add_liquidity_callback(
+                1,
+                ManagedAsyncCallResult::Err(ManagedAsyncCallError {
+                    err_code: 4,
+                    err_msg: ManagedBuffer::from(mock_pair::ERROR_ADD_LIQUIDITY_FAILED),
+                }),
+            );
+        });
+}
+
+#[test]
+fn failed_add_liquidity_keeps_the_unswapped_half() {
+    let mut world = setup();
+
+    fail_add_liquidity(&mut world);
+
+    // The swap output is paid out and the unswapped half stays escrowed
+    world.check_account(USER).esdt_balance(WEGLD, SWAP_OUTPUT);
+    world.check_account(SC).esdt_balance(USDC, HALF);
+    world.query().to(SC).whitebox(stellarnova_sc::contract_obj, |sc| {
+        let order = sc.limit_orders(1).get();
+        assert!(matches!(order.status, OrderStatus::Pending));
+        assert_eq!(order.from_amount, HALF);
+        assert_eq!(sc.filled_input(1).get(), HALF);
+        assert_eq!(sc.escrow_balance(&USDC.to_token_identifier()).get(), HALF);
+        assert_eq!(sc.escrow_in_flight(&USDC.to_token_identifier()).get(), 0u64);
+        assert!(sc.zap_executions(1).is_empty());
+        assert!(!sc.orders_in_execution().contains(&1));
+    });
+
+    // The leftover is refunded on cancel
+    world
+        .tx()
+        .from(USER)
+        .to(SC)
+        .typed(StellarNovaProxy)
+        .cancel_limit_order(1u64)
+        .run();
+    world
+        .check_account(USER)
+        .esdt_balance(USDC, HALF)
+        .esdt_balance(WEGLD, SWAP_OUTPUT)
+        .esdt_balance(LP, 0u64);
+    world.check_account(SC).esdt_balance(USDC, 0u64);
+}
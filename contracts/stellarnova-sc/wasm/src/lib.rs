@@ -6,10 +6,10 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           20
+// Endpoints:                          325
 // Async Callback (empty):               1
-// Promise callbacks:                    1
-// Total number of exported functions:  24
+// Promise callbacks:                    7
+// Total number of exported functions: 335
 
 #![no_std]
 
@@ -22,26 +22,337 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         whitelistToken => whitelist_token
+        setZapConfig => set_zap_config
         removeToken => remove_token
-        setPaused => set_paused
         setMaxSlippage => set_max_slippage
         setXExchangePair => set_xexchange_pair
+        setTokenPair => set_token_pair
+        setSwapDescriptor => set_swap_descriptor
+        listToken => list_token
+        setXExchangeRouter => set_xexchange_router
+        setForwardDestination => set_forward_destination
+        setLimitOrderExecutor => set_limit_order_executor
+        setExecutionFeeBps => set_execution_fee_bps
+        setExecutionFeeFloor => set_execution_fee_floor
+        setPriceImprovementSharing => set_price_improvement_sharing
+        setProtocolFeeBps => set_protocol_fee_bps
+        setTreasuryAddress => set_treasury_address
+        setCancellationPolicy => set_cancellation_policy
+        setLendingMarket => set_lending_market
+        setLendingInterestToUser => set_lending_interest_to_user
+        compensate => compensate
+        sweepSurplus => sweep_surplus
+        emergencyWithdraw => emergency_withdraw
         isTokenWhitelisted => is_token_whitelisted
         getWhitelistedTokens => whitelisted_tokens
         getXExchangePair => xexchange_pair
+        getTokenPair => token_pair
+        getXExchangeRouter => xexchange_router
+        getContractVersion => contract_version
         getOwner => owner
-        isPaused => paused
         getMaxSlippage => max_slippage
+        getEscrowBalance => escrow_balance
+        getExpiryBountyPool => expiry_bounty_pool
+        getProtocolFees => protocol_fees
+        getTotalCredit => total_credit
+        getPositionHeldBalance => position_held_balance
+        getQuoteDeposits => quote_deposits
+        getStreamedBalance => streamed_balance
+        getGovernanceDeposits => governance_deposits
+        getStakingDeposits => staking_deposits
+        getStakingRewardPool => staking_reward_pool
+        getClientFeeBalance => client_fee_balance
+        getInsuranceFund => insurance_fund
+        getStrategyRebateDeposits => strategy_rebate_deposits
+        getGasStationPool => gas_station_pool
+        getLentEscrow => lent_escrow
+        getEscrowInFlight => escrow_in_flight
+        getLendingShares => lending_shares
         getExecutionFeeBps => execution_fee_bps
+        getExecutionFeeFloor => execution_fee_floor
+        getImprovementExecutorBps => improvement_executor_bps
+        getImprovementTreasuryBps => improvement_treasury_bps
+        getProtocolFeeBps => protocol_fee_bps
+        getLendingMarket => lending_market
+        getLendingInterestToUser => lending_interest_to_user
+        getZapLpToken => zap_lp_token
+        getZapFirstToken => zap_first_token
+        getCancellationWindow => cancellation_window
+        getCancellationFeeBps => cancellation_fee_bps
+        getTreasuryAddress => treasury_address
+        setPairHealthCheck => set_pair_health_check
+        setMinOutputMode => set_min_output_mode
+        getSwapPair => swap_pair
+        getSwapDescriptor => swap_call_descriptor
+        quoteRoute => quote_route
+        getMinReserveMultiple => min_reserve_multiple
+        getMinOutputMode => min_output_mode
         createLimitOrder => create_limit_order
+        createLimitOrderScaled => create_limit_order_scaled
+        createLimitOrderWithPosition => create_limit_order_with_position
+        createSignalOrder => create_signal_order
+        createClientOrder => create_client_order
+        createLimitOrderWithIntent => create_limit_order_with_intent
+        createLimitOrderWithReference => create_limit_order_with_reference
+        createRoutedOrder => create_routed_order
+        createChainedOrder => create_chained_order
+        createBracketOrder => create_bracket_order
+        triggerStopLoss => trigger_stop_loss
+        createZapOrder => create_zap_order
+        createExactOutputOrder => create_exact_output_order
+        createCommittedOrder => create_committed_order
+        createLimitOrderWithSessionKey => create_limit_order_with_session_key
+        modifyLimitOrder => modify_limit_order
+        extendOrderExpiry => extend_order_expiry
+        modifyWithPosition => modify_with_position
+        replaceLimitOrder => replace_limit_order
         executeLimitOrder => execute_limit_order
+        forceExecute => force_execute
+        executeCommittedOrder => execute_committed_order
+        executeLimitOrderPartial => execute_limit_order_partial
+        tryExecuteLimitOrder => try_execute_limit_order
+        openExecutionAuction => open_execution_auction
+        matchOrders => match_orders
+        setSelfTradePrevention => set_self_trade_prevention
+        supplyEscrow => supply_escrow
+        redeemEscrow => redeem_escrow_endpoint
+        fetchTokenDecimals => fetch_token_decimals
+        setOrderCondition => set_order_condition
+        clearOrderCondition => clear_order_condition
+        setPayoutStream => set_payout_stream
+        setOutputForward => set_output_forward
+        clearOutputForward => clear_output_forward
         cancelLimitOrder => cancel_limit_order
+        cancelWithPosition => cancel_with_position
+        claimPosition => claim_position
+        cancelStrategyOrders => cancel_strategy_orders
+        forceRefundOrdersForToken => force_refund_orders_for_token
+        windDownRefund => wind_down_refund
+        activateDormantOrders => activate_dormant_orders
+        processExpiredOrders => process_expired_orders
+        setMaxExecutionAttempts => set_max_execution_attempts
+        setRetryBackoff => set_retry_backoff
+        fundExpiryBountyPool => fund_expiry_bounty_pool
+        setExpiryBountyBps => set_expiry_bounty_bps
         getPendingOrders => get_pending_orders
         getUserOrders => get_user_orders
+        getStrategyOpenOrders => get_strategy_open_orders
+        getOrderBook => get_order_book
+        getOrdersCreatedBetween => get_orders_created_between
+        getOrderCount => get_order_count
+        getLastOrderId => get_last_order_id
+        getFirstActiveOrderId => get_first_active_order_id
+        getOrdersRange => get_orders_range
+        getExecutorHealth => get_executor_health
+        getProtocolStats => get_protocol_stats
         getOrder => get_order
-        setLimitOrderExecutor => set_limit_order_executor
-        setExecutionFeeBps => set_execution_fee_bps
+        getOrderClientTag => get_order_client_tag
+        getOrderIntent => get_order_intent
+        getOrderByReference => get_order_by_reference
+        getOrderRouteVia => get_order_route_via
+        getChainedOrder => get_chained_order
+        getChainParent => get_chain_parent
+        getOrderStopLoss => get_order_stop_loss
+        isOrderDormant => is_order_dormant
+        canExecuteOrder => can_execute_order
+        validateLimitOrder => validate_limit_order
+        estimateOrderOutput => estimate_order_output
+        getEffectivePrice => get_effective_price
+        getExecutionMinOutput => get_execution_min_output
+        getOrderLentEscrow => lent_escrows
+        isZapOrder => zap_order
+        isSelfTradePrevented => self_trade_prevention
+        getExactOutputAmount => exact_output_amount
+        getStopLossTarget => stop_loss_target
+        getFilledAmount => filled_input
+        getFilledOutput => filled_output
+        getCancelReason => cancel_reason
+        getExecutionAttempts => execution_attempts
+        getOrderNormalizedPrice => order_normalized_price
+        getMaxExecutionAttempts => max_execution_attempts
+        getRetryBackoffBase => retry_backoff_base
+        getRetryAfter => retry_after
+        getExpiryBountyBps => expiry_bounty_bps
+        setPaused => set_paused
+        setPauseLevel => set_pause_level
+        setPairPaused => set_pair_paused
+        isPaused => is_paused
+        isWindDownActive => is_wind_down_active
+        getPauseLevel => pause_level
+        getFullStopSince => full_stop_since
+        isPairPaused => pair_paused
+        setTimelockDelay => set_timelock_delay
+        executeQueuedAction => execute_queued_action
+        cancelQueuedAction => cancel_queued_action
+        getQueuedActions => get_queued_actions
+        getQueuedAction => get_queued_action
+        getExecutableActions => get_executable_actions
+        getNextActionId => get_next_action_id
+        getTimelockDelay => timelock_delay
+        addToBlacklist => add_to_blacklist
+        removeFromBlacklist => remove_from_blacklist
+        isBlacklisted => is_blacklisted
+        getBlacklistedAddresses => blacklisted_addresses
+        claimProtocolFees => claim_protocol_fees
+        reconcile => reconcile
+        setTreasuryToken => set_treasury_token
+        convertProtocolFees => convert_protocol_fees
+        getTreasuryToken => treasury_token
+        migrateOrders => migrate_orders
+        isMigrationPending => is_migration_pending
+        getStorageVersion => storage_version
+        approveOrderManager => approve_order_manager
+        revokeOrderManager => revoke_order_manager
+        setManagerDailyCap => set_manager_daily_cap
+        setManagerOrderRate => set_manager_order_rate
+        grantSessionKey => grant_session_key
+        revokeSessionKey => revoke_session_key
+        isOrderManager => is_order_manager
+        getSessionKeys => get_session_keys
+        getOrderManagers => order_managers
+        getManagerDailyCap => manager_daily_cap
+        getManagerSpend => manager_spend
+        getManagerOrderRate => manager_order_rate
+        deposit => deposit
+        withdrawCredit => withdraw_credit
+        createLimitOrderFromCredit => create_limit_order_from_credit
+        createLimitOrderAsManager => create_limit_order_as_manager
+        getUserCredit => user_credit
+        createLimitOrderFor => create_limit_order_for
+        getRelayNonce => relay_nonce
+        setPositionToken => set_position_token
+        getPositionToken => position_token
+        getOrderPosition => order_position
+        getPositionOrder => position_order
+        getPositionBalance => position_balance
+        registerStrategy => register_strategy
+        updateStrategyMetadata => update_strategy_metadata
+        deactivateStrategy => deactivate_strategy
+        setStrategyPaused => set_strategy_paused
+        setStrategyRebateBps => set_strategy_rebate_bps
+        fundStrategyRebates => fund_strategy_rebates
+        withdrawStrategyRebates => withdraw_strategy_rebates
+        getStrategy => get_strategy
+        getOrderStrategy => order_strategy
+        isStrategyPaused => strategy_paused
+        getStrategyStats => strategy_stats
+        getStrategyVolumeIn => strategy_volume_in
+        getStrategyVolumeOut => strategy_volume_out
+        getStrategyPriceImprovement => strategy_price_improvement
+        getStrategyRebateBps => strategy_rebate_bps
+        getStrategyRebatePool => strategy_rebate_pool
+        setSignalOracle => set_signal_oracle
+        fireSignal => fire_signal
+        setPriceOracle => set_price_oracle
+        registerPricePair => register_price_pair
+        setPriceMaxAge => set_price_max_age
+        postPrice => post_price
+        isSignalFired => is_signal_fired
+        getSignalOracle => signal_oracle
+        getSignalFiredAt => signal_fired_at
+        getPriceOracle => price_oracle
+        getPricePairs => price_pairs
+        getPriceMaxAge => price_max_age
+        getOraclePrice => oracle_price
+        getOrderCondition => order_condition
+        getOrderSignal => order_signal
+        addMarketMaker => add_market_maker
+        removeMarketMaker => remove_market_maker
+        postQuote => post_quote
+        cancelQuote => cancel_quote
+        fillOrderWithQuote => fill_order_with_quote
+        isMarketMaker => is_market_maker
+        getQuote => get_quote
+        getMarketMakers => market_makers
+        claimPayoutStream => claim_payout_stream
+        getClaimableStream => get_claimable_stream
+        getPayoutStreamDuration => payout_stream_duration
+        getPayoutStream => payout_streams
+        whitelistUpcomingToken => whitelist_upcoming_token
+        getAwaitingPairTokens => awaiting_pair_tokens
+        getDormantOrders => dormant_orders
+        setTokenDecimals => set_token_decimals
+        toHumanPrice => to_human_price
+        fromHumanPrice => from_human_price
+        getTokenDecimals => token_decimals
+        setRiskCaps => set_risk_caps
+        setExecutorDailyLimit => set_executor_daily_limit
+        getExecutorVolume => get_executor_volume
+        getMaxOrderAmount => max_order_amount
+        getMaxOpenInterest => max_open_interest
+        getExecutorDailyLimit => executor_daily_limit
+        setCircuitBreaker => set_circuit_breaker
+        clearCircuitBreaker => clear_circuit_breaker
+        isPairTripped => is_pair_tripped
+        getBreakerMaxDeviationBp => breaker_max_deviation_bp
+        getBreakerWindowSeconds => breaker_window_seconds
+        getLastSwapPrice => last_swap_price
+        getTrippedPairs => tripped_pairs
+        setGovernanceConfig => set_governance_config
+        propose => propose
+        vote => vote
+        finalizeProposal => finalize_proposal
+        withdrawVoteDeposit => withdraw_vote_deposit
+        getProposal => get_proposal
+        getGovernanceToken => governance_token
+        getProposalThreshold => proposal_threshold
+        getGovernanceQuorum => governance_quorum
+        getVotingPeriod => voting_period
+        getVoteDeposit => vote_deposit
+        setStakingConfig => set_staking_config
+        stake => stake
+        unstake => unstake
+        withdrawUnstaked => withdraw_unstaked
+        claimStakingRewards => claim_staking_rewards
+        getClaimableStakingRewards => get_claimable_staking_rewards
+        getStakingToken => staking_token
+        getStakingFeeShareBps => staking_fee_share_bps
+        getUnbondEpochs => unbond_epochs
+        getStakedAmount => staked_amount
+        getTotalStaked => total_staked
+        getUnbonding => unbonding
+        getStakingRewardTokens => staking_reward_tokens
+        getRewardPerShare => reward_per_share
+        setInsuranceFeeBps => set_insurance_fee_bps
+        setCompensationCap => set_compensation_cap
+        getInsuranceFeeBps => insurance_fee_bps
+        getCompensationCap => compensation_cap
+        getUserStats => get_user_stats
+        getPairStats => get_pair_stats
+        getPairAnalytics => get_pair_analytics
+        getUserVolume => user_volume
+        getUserFeesPaid => user_fees_paid
+        registerClient => register_client
+        setClientPayoutAddress => set_client_payout_address
+        setClientActive => set_client_active
+        setClientFeeShareBps => set_client_fee_share_bps
+        claimClientFees => claim_client_fees
+        getClient => get_client
+        getOrderClient => order_client
+        getClientFeeShareBps => client_fee_share_bps
+        getClientVolume => client_volume
+        getClientFees => client_fees
+        computePriceCommitment => compute_price_commitment
+        getOrderPriceCommitment => order_price_commitment
+        setExecutionAuctionWindow => set_execution_auction_window
+        setExecutionAuctionMinAmount => set_execution_auction_min_amount
+        bidForExecution => bid_for_execution
+        getExecutionAuction => get_execution_auction
+        getExecutionAuctionWindow => execution_auction_window
+        getExecutionAuctionMinAmount => execution_auction_min_amount
+        setGasStationMaxOutput => set_gas_station_max_output
+        topUpGasStation => top_up_gas_station
+        fundGasStationFromFees => fund_gas_station_from_fees
+        getGasStationMaxOutput => gas_station_max_output
+        getForwardDestinations => forward_destinations
+        getOrderForward => order_forward
         swap_callback => swap_callback
+        add_liquidity_callback => add_liquidity_callback
+        supply_callback => supply_callback
+        redeem_callback => redeem_callback
+        token_properties_callback => token_properties_callback
+        fee_conversion_callback => fee_conversion_callback
+        forward_callback => forward_callback
     )
 }
 
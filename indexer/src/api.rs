@@ -0,0 +1,141 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::Deserialize;
+
+use crate::config::IndexerConfig;
+
+/// Elasticsearch keeps `from + size` under this window
+const MAX_RESULT_WINDOW: u64 = 10_000;
+
+/// Contract event as served by the MultiversX API `/events` endpoint
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiEvent {
+    pub tx_hash: String,
+    pub identifier: String,
+    #[serde(default)]
+    pub topics: Vec<String>,
+    #[serde(default)]
+    pub data: Option<String>,
+    pub timestamp: u64,
+    #[serde(default)]
+    pub tx_order: u64,
+    #[serde(default)]
+    pub event_order: u64,
+}
+
+/// Event with base64 topics and data decoded to raw bytes
+/// `topics[0]` is the event name, the indexed arguments follow
+#[derive(Debug, Clone)]
+pub struct RawEvent {
+    pub tx_hash: String,
+    pub event_index: u64,
+    pub name: String,
+    pub topics: Vec<Vec<u8>>,
+    pub data: Vec<u8>,
+    pub timestamp: u64,
+}
+
+impl ApiEvent {
+    pub fn into_raw(self) -> Option<RawEvent> {
+        let topics = self
+            .topics
+            .iter()
+            .map(|topic| STANDARD.decode(topic).ok())
+            .collect::<Option<Vec<_>>>()?;
+        let name = String::from_utf8(topics.first()?.clone()).ok()?;
+        let data = match &self.data {
+            Some(data) => STANDARD.decode(data).ok()?,
+            None => Vec::new(),
+        };
+
+        Some(RawEvent {
+            tx_hash: self.tx_hash,
+            // Unique within the transaction, across its sub-calls
+            event_index: self.tx_order * 1_000 + self.event_order,
+            name,
+            topics,
+            data,
+            timestamp: self.timestamp,
+        })
+    }
+}
+
+pub struct EventsApi {
+    client: reqwest::Client,
+    api_url: String,
+    contract_address: String,
+    page_size: u64,
+}
+
+impl EventsApi {
+    pub fn new(config: &IndexerConfig) -> Self {
+        EventsApi {
+            client: reqwest::Client::new(),
+            api_url: config.api_url.trim_end_matches('/').to_string(),
+            contract_address: config.contract_address.clone(),
+            page_size: config.page_size,
+        }
+    }
+
+    /// Every contract event at or after `after_timestamp`, oldest first
+    ///
+    /// The API lists newest first and caps `from + size`, so a backlog larger
+    /// than one window is walked backwards with `before`. Events on window
+    /// and cursor boundaries come back twice; storage skips them.
+    pub async fn events_since(&self, after_timestamp: u64) -> Result<Vec<ApiEvent>, reqwest::Error> {
+        let mut events = Vec::new();
+        let mut before = None;
+        loop {
+            let (window, full) = self.window(after_timestamp, before).await?;
+            let oldest = window.iter().map(|event| event.timestamp).min();
+            events.extend(window);
+
+            match oldest {
+                Some(oldest) if full && before != Some(oldest) => before = Some(oldest),
+                _ => break,
+            }
+        }
+
+        events.sort_by_key(|event| (event.timestamp, event.tx_order, event.event_order));
+        Ok(events)
+    }
+
+    /// Pages of one result window; `true` when the window was exhausted
+    async fn window(
+        &self,
+        after_timestamp: u64,
+        before: Option<u64>,
+    ) -> Result<(Vec<ApiEvent>, bool), reqwest::Error> {
+        let mut events = Vec::new();
+        let mut from = 0;
+        while from + self.page_size <= MAX_RESULT_WINDOW {
+            let mut query = vec![
+                ("address", self.contract_address.clone()),
+                ("after", after_timestamp.to_string()),
+                ("from", from.to_string()),
+                ("size", self.page_size.to_string()),
+            ];
+            if let Some(before) = before {
+                query.push(("before", before.to_string()));
+            }
+
+            let page: Vec<ApiEvent> = self
+                .client
+                .get(format!("{}/events", self.api_url))
+                .query(&query)
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await?;
+
+            let last_page = (page.len() as u64) < self.page_size;
+            events.extend(page);
+            if last_page {
+                return Ok((events, false));
+            }
+            from += self.page_size;
+        }
+        Ok((events, true))
+    }
+}
@@ -0,0 +1,22 @@
+use serde::Deserialize;
+
+pub const CONFIG_FILE: &str = "config.toml";
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct IndexerConfig {
+    pub api_url: String,
+    pub contract_address: String,
+    pub database_url: String,
+    pub page_size: u64,
+    pub poll_interval_seconds: u64,
+}
+
+impl IndexerConfig {
+    /// Load the config file given as first argument, `config.toml` otherwise
+    pub fn load() -> Self {
+        let path = std::env::args().nth(1).unwrap_or_else(|| CONFIG_FILE.to_string());
+        let content =
+            std::fs::read_to_string(&path).unwrap_or_else(|_| panic!("cannot open {path}"));
+        toml::from_str(&content).unwrap_or_else(|err| panic!("invalid {path}: {err}"))
+    }
+}
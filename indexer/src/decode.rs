@@ -0,0 +1,262 @@
+use multiversx_sc_snippets::imports::*;
+use stellarnova_sc::limit_orders::{CancelReason, OrderTerms};
+
+use crate::api::RawEvent;
+
+/// Order terms with amounts as decimal strings
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Terms {
+    pub to_token: String,
+    pub target_price_num: String,
+    pub target_price_denom: String,
+    pub slippage_bp: u64,
+    pub expires_at: u64,
+}
+
+/// Final status of an order, as stored in `orders.status`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClosedStatus {
+    Cancelled,
+    Expired,
+    Failed,
+}
+
+impl ClosedStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ClosedStatus::Cancelled => "cancelled",
+            ClosedStatus::Expired => "expired",
+            ClosedStatus::Failed => "failed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeeKind {
+    /// Executor share of the final fill
+    Execution,
+    /// Treasury share of a fill, or an early cancellation fee
+    Protocol,
+}
+
+impl FeeKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FeeKind::Execution => "execution",
+            FeeKind::Protocol => "protocol",
+        }
+    }
+}
+
+/// Normalized record materialized from one contract event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Record {
+    OrderCreated {
+        order_id: u64,
+        user: String,
+        from_token: String,
+        from_amount: String,
+        to_token: String,
+        target_price_num: String,
+        target_price_denom: String,
+        expires_at: u64,
+    },
+    OrderModified {
+        order_id: u64,
+        terms: Terms,
+    },
+    OrderReplaced {
+        old_order_id: u64,
+        new_order_id: u64,
+        terms: Terms,
+    },
+    /// `amount_out` is what the owner received, after fees; only the final
+    /// fill carries the executor and the execution price
+    Fill {
+        order_id: u64,
+        executor: Option<String>,
+        amount_in: String,
+        amount_out: String,
+        execution_price: Option<String>,
+        remaining: String,
+    },
+    OrderClosed {
+        order_id: u64,
+        status: ClosedStatus,
+        refunded: String,
+        reason: Option<String>,
+    },
+    Fee {
+        order_id: u64,
+        kind: FeeKind,
+        token: String,
+        amount: String,
+    },
+}
+
+#[derive(Debug)]
+pub struct DecodeFailure {
+    pub name: String,
+    pub message: String,
+}
+
+/// Records of one event; events the indexer does not track give none
+pub fn decode_event(event: &RawEvent) -> Result<Vec<Record>, DecodeFailure> {
+    let topics = Topics {
+        event,
+        topics: &event.topics,
+    };
+
+    let record = match event.name.as_str() {
+        "limit_order_created" => Record::OrderCreated {
+            order_id: topics.u64(2)?,
+            user: topics.address(3)?,
+            from_token: topics.token(4)?,
+            from_amount: topics.big_uint(5)?,
+            to_token: topics.token(6)?,
+            target_price_num: topics.big_uint(7)?,
+            target_price_denom: topics.big_uint(8)?,
+            expires_at: topics.data::<u64>()?,
+        },
+        "limit_order_modified" => Record::OrderModified {
+            order_id: topics.u64(2)?,
+            terms: topics.terms()?,
+        },
+        "limit_order_replaced" => Record::OrderReplaced {
+            old_order_id: topics.u64(2)?,
+            new_order_id: topics.u64(3)?,
+            terms: topics.terms()?,
+        },
+        "limit_order_partially_filled" => Record::Fill {
+            order_id: topics.u64(2)?,
+            executor: None,
+            amount_in: topics.big_uint(4)?,
+            amount_out: topics.big_uint(5)?,
+            execution_price: None,
+            remaining: display(&topics.data::<BigUint<StaticApi>>()?),
+        },
+        "limit_order_executed" => {
+            let order_id = topics.u64(2)?;
+            let fill = Record::Fill {
+                order_id,
+                executor: Some(topics.address(4)?),
+                amount_in: topics.big_uint(6)?,
+                amount_out: topics.big_uint(8)?,
+                execution_price: Some(topics.big_uint(9)?),
+                remaining: "0".to_string(),
+            };
+            let fee = Record::Fee {
+                order_id,
+                kind: FeeKind::Execution,
+                token: topics.token(7)?,
+                amount: display(&topics.data::<BigUint<StaticApi>>()?),
+            };
+            return Ok(vec![fill, fee]);
+        }
+        "limit_order_cancelled" => Record::OrderClosed {
+            order_id: topics.u64(2)?,
+            status: ClosedStatus::Cancelled,
+            refunded: display(&topics.data::<BigUint<StaticApi>>()?),
+            reason: None,
+        },
+        "order_force_cancelled" => Record::OrderClosed {
+            order_id: topics.u64(2)?,
+            status: ClosedStatus::Cancelled,
+            refunded: display(&topics.data::<BigUint<StaticApi>>()?),
+            reason: Some(cancel_reason(topics.decode::<CancelReason>(5)?).to_string()),
+        },
+        "limit_order_expired" => Record::OrderClosed {
+            order_id: topics.u64(1)?,
+            status: ClosedStatus::Expired,
+            refunded: display(&topics.data::<BigUint<StaticApi>>()?),
+            reason: None,
+        },
+        "limit_order_failed" => Record::OrderClosed {
+            order_id: topics.u64(2)?,
+            status: ClosedStatus::Failed,
+            refunded: topics.big_uint(5)?,
+            reason: None,
+        },
+        "fee_collected" => Record::Fee {
+            order_id: topics.u64(2)?,
+            kind: FeeKind::Protocol,
+            token: topics.token(1)?,
+            amount: display(&topics.data::<BigUint<StaticApi>>()?),
+        },
+        _ => return Ok(Vec::new()),
+    };
+    Ok(vec![record])
+}
+
+fn cancel_reason(reason: CancelReason) -> &'static str {
+    match reason {
+        CancelReason::User => "user",
+        CancelReason::StrategyKill => "strategy_kill",
+        CancelReason::TokenDelisted => "token_delisted",
+        CancelReason::WindDown => "wind_down",
+    }
+}
+
+fn display(value: &BigUint<StaticApi>) -> String {
+    value.to_display().to_string()
+}
+
+/// Top-decodes indexed arguments and data with the contract ABI types
+struct Topics<'a> {
+    event: &'a RawEvent,
+    topics: &'a [Vec<u8>],
+}
+
+impl Topics<'_> {
+    fn failure(&self, message: String) -> DecodeFailure {
+        DecodeFailure {
+            name: self.event.name.clone(),
+            message,
+        }
+    }
+
+    fn decode<T: TopDecode>(&self, index: usize) -> Result<T, DecodeFailure> {
+        let topic = self
+            .topics
+            .get(index)
+            .ok_or_else(|| self.failure(format!("missing topic {index}")))?;
+        T::top_decode(topic.as_slice())
+            .map_err(|err| self.failure(format!("topic {index}: {}", err.message_str())))
+    }
+
+    fn data<T: TopDecode>(&self) -> Result<T, DecodeFailure> {
+        T::top_decode(self.event.data.as_slice())
+            .map_err(|err| self.failure(format!("data: {}", err.message_str())))
+    }
+
+    fn u64(&self, index: usize) -> Result<u64, DecodeFailure> {
+        self.decode(index)
+    }
+
+    fn big_uint(&self, index: usize) -> Result<String, DecodeFailure> {
+        Ok(display(&self.decode::<BigUint<StaticApi>>(index)?))
+    }
+
+    fn token(&self, index: usize) -> Result<String, DecodeFailure> {
+        Ok(self
+            .decode::<TokenIdentifier<StaticApi>>(index)?
+            .to_string())
+    }
+
+    fn address(&self, index: usize) -> Result<String, DecodeFailure> {
+        let address = self.decode::<ManagedAddress<StaticApi>>(index)?;
+        Ok(Bech32Address::from(address.to_address()).to_bech32_string())
+    }
+
+    /// New terms of a modify/replace event
+    fn terms(&self) -> Result<Terms, DecodeFailure> {
+        let terms = self.data::<OrderTerms<StaticApi>>()?;
+        Ok(Terms {
+            to_token: terms.to_token.to_string(),
+            target_price_num: display(&terms.target_price_numerator),
+            target_price_denom: display(&terms.target_price_denominator),
+            slippage_bp: terms.slippage_bp,
+            expires_at: terms.expires_at,
+        })
+    }
+}
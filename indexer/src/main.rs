@@ -0,0 +1,74 @@
+//! StellarNova event indexer
+//!
+//! Reads the contract's events from the MultiversX API, decodes them with the
+//! contract's ABI types and materializes order, fill and fee records in
+//! SQLite or Postgres for the analytics dashboard and the AI pipeline.
+//! Every event is applied at most once, so the indexer can be restarted or
+//! pointed at an overlapping range at any time.
+
+mod api;
+mod config;
+mod decode;
+mod store;
+
+use std::time::Duration;
+
+use log::{error, info, warn};
+
+use api::EventsApi;
+use config::IndexerConfig;
+use decode::decode_event;
+use store::Store;
+
+#[tokio::main]
+async fn main() {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+    let config = IndexerConfig::load();
+    let api = EventsApi::new(&config);
+    let store = Store::connect(&config.database_url)
+        .await
+        .unwrap_or_else(|err| panic!("cannot open {}: {err}", config.database_url));
+
+    loop {
+        if let Err(err) = index_once(&api, &store).await {
+            error!("indexing round failed: {err}");
+        }
+        tokio::time::sleep(Duration::from_secs(config.poll_interval_seconds)).await;
+    }
+}
+
+/// Index every event since the stored cursor
+async fn index_once(api: &EventsApi, store: &Store) -> Result<(), Box<dyn std::error::Error>> {
+    let cursor = store.cursor().await?;
+    let events = api.events_since(cursor).await?;
+
+    let mut indexed = 0;
+    let mut newest = cursor;
+    for event in events {
+        let Some(event) = event.into_raw() else {
+            continue;
+        };
+        newest = newest.max(event.timestamp);
+
+        let records = match decode_event(&event) {
+            Ok(records) => records,
+            Err(failure) => {
+                // Keep going: one undecodable event must not stall the index
+                warn!(
+                    "skipping {} in {}: {}",
+                    failure.name, event.tx_hash, failure.message
+                );
+                Vec::new()
+            }
+        };
+        if store.apply(&event, &records).await? {
+            indexed += 1;
+        }
+    }
+
+    store.set_cursor(newest).await?;
+    if indexed > 0 {
+        info!("indexed {indexed} events up to {newest}");
+    }
+    Ok(())
+}
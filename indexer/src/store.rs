@@ -0,0 +1,301 @@
+use sqlx::{any::AnyPoolOptions, AnyConnection, AnyPool};
+
+use crate::api::RawEvent;
+use crate::decode::Record;
+
+/// Portable between SQLite and Postgres: amounts are decimal TEXT (they
+/// overflow 64 bit integers), timestamps and ids BIGINT
+const SCHEMA: &[&str] = &[
+    "CREATE TABLE IF NOT EXISTS indexer_state (
+        key TEXT PRIMARY KEY,
+        value BIGINT NOT NULL
+    )",
+    "CREATE TABLE IF NOT EXISTS processed_events (
+        tx_hash TEXT NOT NULL,
+        event_index BIGINT NOT NULL,
+        name TEXT NOT NULL,
+        timestamp BIGINT NOT NULL,
+        PRIMARY KEY (tx_hash, event_index)
+    )",
+    "CREATE TABLE IF NOT EXISTS orders (
+        order_id BIGINT PRIMARY KEY,
+        user_address TEXT NOT NULL,
+        from_token TEXT NOT NULL,
+        from_amount TEXT NOT NULL,
+        remaining TEXT NOT NULL,
+        to_token TEXT NOT NULL,
+        target_price_num TEXT NOT NULL,
+        target_price_denom TEXT NOT NULL,
+        slippage_bp BIGINT,
+        expires_at BIGINT NOT NULL,
+        status TEXT NOT NULL,
+        close_reason TEXT,
+        refunded TEXT,
+        replaced_by BIGINT,
+        created_at BIGINT NOT NULL,
+        updated_at BIGINT NOT NULL
+    )",
+    "CREATE TABLE IF NOT EXISTS fills (
+        tx_hash TEXT NOT NULL,
+        event_index BIGINT NOT NULL,
+        order_id BIGINT NOT NULL,
+        executor TEXT,
+        amount_in TEXT NOT NULL,
+        amount_out TEXT NOT NULL,
+        execution_price TEXT,
+        remaining TEXT NOT NULL,
+        timestamp BIGINT NOT NULL,
+        PRIMARY KEY (tx_hash, event_index)
+    )",
+    "CREATE TABLE IF NOT EXISTS fees (
+        tx_hash TEXT NOT NULL,
+        event_index BIGINT NOT NULL,
+        kind TEXT NOT NULL,
+        order_id BIGINT NOT NULL,
+        token TEXT NOT NULL,
+        amount TEXT NOT NULL,
+        timestamp BIGINT NOT NULL,
+        PRIMARY KEY (tx_hash, event_index, kind)
+    )",
+    "CREATE INDEX IF NOT EXISTS orders_user ON orders (user_address)",
+    "CREATE INDEX IF NOT EXISTS fills_order ON fills (order_id)",
+    "CREATE INDEX IF NOT EXISTS fees_token ON fees (token)",
+];
+
+const CURSOR_KEY: &str = "last_timestamp";
+
+pub struct Store {
+    pool: AnyPool,
+}
+
+impl Store {
+    pub async fn connect(database_url: &str) -> Result<Self, sqlx::Error> {
+        sqlx::any::install_default_drivers();
+        // One writer keeps event application in order
+        let pool = AnyPoolOptions::new()
+            .max_connections(1)
+            .connect(database_url)
+            .await?;
+        for statement in SCHEMA {
+            sqlx::query(statement).execute(&pool).await?;
+        }
+        Ok(Store { pool })
+    }
+
+    /// Timestamp of the newest indexed event (0 before the first run)
+    pub async fn cursor(&self) -> Result<u64, sqlx::Error> {
+        let value: Option<i64> = sqlx::query_scalar("SELECT value FROM indexer_state WHERE key = $1")
+            .bind(CURSOR_KEY)
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(value.unwrap_or(0) as u64)
+    }
+
+    pub async fn set_cursor(&self, timestamp: u64) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "INSERT INTO indexer_state (key, value) VALUES ($1, $2)
+             ON CONFLICT (key) DO UPDATE SET value = excluded.value",
+        )
+        .bind(CURSOR_KEY)
+        .bind(timestamp as i64)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Apply the records of one event atomically, at most once per event
+    /// Returns false when the event was already indexed
+    pub async fn apply(&self, event: &RawEvent, records: &[Record]) -> Result<bool, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let inserted = sqlx::query(
+            "INSERT INTO processed_events (tx_hash, event_index, name, timestamp)
+             VALUES ($1, $2, $3, $4)
+             ON CONFLICT (tx_hash, event_index) DO NOTHING",
+        )
+        .bind(&event.tx_hash)
+        .bind(event.event_index as i64)
+        .bind(&event.name)
+        .bind(event.timestamp as i64)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        if inserted == 0 {
+            return Ok(false);
+        }
+
+        for record in records {
+            apply_record(&mut tx, event, record).await?;
+        }
+        tx.commit().await?;
+        Ok(true)
+    }
+}
+
+async fn apply_record(
+    conn: &mut AnyConnection,
+    event: &RawEvent,
+    record: &Record,
+) -> Result<(), sqlx::Error> {
+    let timestamp = event.timestamp as i64;
+    match record {
+        Record::OrderCreated {
+            order_id,
+            user,
+            from_token,
+            from_amount,
+            to_token,
+            target_price_num,
+            target_price_denom,
+            expires_at,
+        } => {
+            sqlx::query(
+                "INSERT INTO orders (order_id, user_address, from_token, from_amount, remaining,
+                    to_token, target_price_num, target_price_denom, expires_at, status,
+                    created_at, updated_at)
+                 VALUES ($1, $2, $3, $4, $4, $5, $6, $7, $8, 'pending', $9, $9)
+                 ON CONFLICT (order_id) DO NOTHING",
+            )
+            .bind(*order_id as i64)
+            .bind(user)
+            .bind(from_token)
+            .bind(from_amount)
+            .bind(to_token)
+            .bind(target_price_num)
+            .bind(target_price_denom)
+            .bind(*expires_at as i64)
+            .bind(timestamp)
+            .execute(&mut *conn)
+            .await?;
+        }
+        Record::OrderModified { order_id, terms } => {
+            sqlx::query(
+                "UPDATE orders SET to_token = $1, target_price_num = $2, target_price_denom = $3,
+                    slippage_bp = $4, expires_at = $5, updated_at = $6
+                 WHERE order_id = $7",
+            )
+            .bind(&terms.to_token)
+            .bind(&terms.target_price_num)
+            .bind(&terms.target_price_denom)
+            .bind(terms.slippage_bp as i64)
+            .bind(terms.expires_at as i64)
+            .bind(timestamp)
+            .bind(*order_id as i64)
+            .execute(&mut *conn)
+            .await?;
+        }
+        Record::OrderReplaced {
+            old_order_id,
+            new_order_id,
+            terms,
+        } => {
+            // The new order takes over the escrow of the old one
+            sqlx::query(
+                "INSERT INTO orders (order_id, user_address, from_token, from_amount, remaining,
+                    to_token, target_price_num, target_price_denom, slippage_bp, expires_at,
+                    status, created_at, updated_at)
+                 SELECT $1, user_address, from_token, remaining, remaining, $2, $3, $4, $5, $6,
+                    'pending', $7, $7
+                 FROM orders WHERE order_id = $8
+                 ON CONFLICT (order_id) DO NOTHING",
+            )
+            .bind(*new_order_id as i64)
+            .bind(&terms.to_token)
+            .bind(&terms.target_price_num)
+            .bind(&terms.target_price_denom)
+            .bind(terms.slippage_bp as i64)
+            .bind(terms.expires_at as i64)
+            .bind(timestamp)
+            .bind(*old_order_id as i64)
+            .execute(&mut *conn)
+            .await?;
+            sqlx::query(
+                "UPDATE orders SET status = 'replaced', replaced_by = $1, remaining = '0',
+                    updated_at = $2
+                 WHERE order_id = $3",
+            )
+            .bind(*new_order_id as i64)
+            .bind(timestamp)
+            .bind(*old_order_id as i64)
+            .execute(&mut *conn)
+            .await?;
+        }
+        Record::Fill {
+            order_id,
+            executor,
+            amount_in,
+            amount_out,
+            execution_price,
+            remaining,
+        } => {
+            sqlx::query(
+                "INSERT INTO fills (tx_hash, event_index, order_id, executor, amount_in,
+                    amount_out, execution_price, remaining, timestamp)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+            )
+            .bind(&event.tx_hash)
+            .bind(event.event_index as i64)
+            .bind(*order_id as i64)
+            .bind(executor.clone())
+            .bind(amount_in)
+            .bind(amount_out)
+            .bind(execution_price.clone())
+            .bind(remaining)
+            .bind(timestamp)
+            .execute(&mut *conn)
+            .await?;
+
+            let status = if executor.is_some() { "executed" } else { "pending" };
+            sqlx::query(
+                "UPDATE orders SET remaining = $1, status = $2, updated_at = $3
+                 WHERE order_id = $4",
+            )
+            .bind(remaining)
+            .bind(status)
+            .bind(timestamp)
+            .bind(*order_id as i64)
+            .execute(&mut *conn)
+            .await?;
+        }
+        Record::OrderClosed {
+            order_id,
+            status,
+            refunded,
+            reason,
+        } => {
+            sqlx::query(
+                "UPDATE orders SET status = $1, refunded = $2, close_reason = $3, remaining = '0',
+                    updated_at = $4
+                 WHERE order_id = $5",
+            )
+            .bind(status.as_str())
+            .bind(refunded)
+            .bind(reason.clone())
+            .bind(timestamp)
+            .bind(*order_id as i64)
+            .execute(&mut *conn)
+            .await?;
+        }
+        Record::Fee {
+            order_id,
+            kind,
+            token,
+            amount,
+        } => {
+            sqlx::query(
+                "INSERT INTO fees (tx_hash, event_index, kind, order_id, token, amount, timestamp)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7)",
+            )
+            .bind(&event.tx_hash)
+            .bind(event.event_index as i64)
+            .bind(kind.as_str())
+            .bind(*order_id as i64)
+            .bind(token)
+            .bind(amount)
+            .bind(timestamp)
+            .execute(&mut *conn)
+            .await?;
+        }
+    }
+    Ok(())
+}